@@ -0,0 +1,36 @@
+//! Example using Anti-Captcha provider.
+//!
+//! Run with: `cargo run --example anticaptcha_provider`
+//!
+//! Required environment variable:
+//! - `ANTICAPTCHA_API_KEY` - Your Anti-Captcha API key
+
+use captcha_solvers::anticaptcha::AnticaptchaProvider;
+use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ImageToText};
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = env::var("ANTICAPTCHA_API_KEY").expect("ANTICAPTCHA_API_KEY must be set");
+
+    // Anti-Captcha provider - same interface as the other providers
+    let provider = AnticaptchaProvider::new(api_key)?;
+    let service = CaptchaSolverService::new(provider);
+
+    // 1x1 pixel PNG - for demonstration only, use a real captcha image
+    let base64_image = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+
+    // Image captchas are solved by human workers, so constraints help accuracy
+    let task = ImageToText::from_base64(base64_image)
+        .case_sensitive()
+        .with_min_length(4);
+
+    println!("Solving image captcha with Anti-Captcha...");
+
+    let solution = service.solve_captcha(task).await?;
+
+    let text = solution.into_image_to_text();
+    println!("Solved! Recognized text: {}", text.text());
+
+    Ok(())
+}
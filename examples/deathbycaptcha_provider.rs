@@ -0,0 +1,38 @@
+//! Example using the DeathByCaptcha provider.
+//!
+//! Run with: `cargo run --example deathbycaptcha_provider`
+//!
+//! Required environment variables:
+//! - `DEATHBYCAPTCHA_USERNAME` - Your DeathByCaptcha username
+//! - `DEATHBYCAPTCHA_PASSWORD` - Your DeathByCaptcha password
+
+use captcha_solvers::deathbycaptcha::DeathbycaptchaProvider;
+use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ReCaptchaV2};
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let username =
+        env::var("DEATHBYCAPTCHA_USERNAME").expect("DEATHBYCAPTCHA_USERNAME must be set");
+    let password =
+        env::var("DEATHBYCAPTCHA_PASSWORD").expect("DEATHBYCAPTCHA_PASSWORD must be set");
+
+    let provider = DeathbycaptchaProvider::new(username, password)?;
+    let service = CaptchaSolverService::new(provider);
+
+    // Google's reCAPTCHA demo page
+    let task = ReCaptchaV2::new(
+        "https://www.google.com/recaptcha/api2/demo",
+        "6Le-wvkSAAAAAPBMRTvw0Q4Muexq9bi0DJwx_mJ-",
+    );
+
+    println!("Solving ReCaptcha V2...");
+
+    let solution = service.solve_captcha(task).await?;
+
+    // DeathByCaptcha returns a bare text answer; convert it to the expected type
+    let recaptcha = solution.into_recaptcha();
+    println!("Solved! Token: {}...", &recaptcha.token()[..50]);
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! Example using the generic createTask-compatible provider.
+//!
+//! Run with: `cargo run --example generic_provider`
+//!
+//! Required environment variables:
+//! - `GENERIC_API_KEY` - API key for the target service
+//! - `GENERIC_API_URL` - Base URL of the service's createTask API
+
+use captcha_solvers::generic::{ErrorClass, GenericProfile, GenericProvider, GenericTaskKind};
+use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, Turnstile};
+use std::env;
+use url::Url;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = env::var("GENERIC_API_KEY").expect("GENERIC_API_KEY must be set");
+    let api_url = env::var("GENERIC_API_URL").expect("GENERIC_API_URL must be set");
+
+    // Describe how the service differs from the standard createTask API
+    let profile = GenericProfile::new("ExampleCaptcha")
+        .with_standard_task_types()
+        .task_type_with_proxy(
+            GenericTaskKind::Turnstile,
+            "AntiTurnstileTaskProxyLess",
+            "AntiTurnstileTask",
+        )
+        .error_class("ERROR_RATE_LIMIT", ErrorClass::Transient);
+
+    let provider = GenericProvider::new(Url::parse(&api_url)?, profile, api_key)?;
+    let service = CaptchaSolverService::new(provider);
+
+    // Cloudflare's always-passing Turnstile test sitekey
+    let task = Turnstile::new("https://example.com", "1x00000000000000000000AA");
+
+    println!("Solving Turnstile...");
+
+    let solution = service.solve_captcha(task).await?;
+    let token = solution.into_token();
+    println!("Solved! Token: {}", token.token());
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! Example using the legacy in.php/res.php provider.
+//!
+//! Run with: `cargo run --example legacy_provider`
+//!
+//! Required environment variable:
+//! - `LEGACY_API_KEY` - API key for the target service
+//!
+//! Optional environment variable:
+//! - `LEGACY_API_URL` - Base URL of a 2Captcha-compatible service (default: `https://2captcha.com`)
+
+use captcha_solvers::legacy::LegacyProvider;
+use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ReCaptchaV2};
+use std::env;
+use url::Url;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = env::var("LEGACY_API_KEY").expect("LEGACY_API_KEY must be set");
+
+    // Any service speaking the classic in.php/res.php protocol can be targeted
+    let provider = match env::var("LEGACY_API_URL") {
+        Ok(url) => LegacyProvider::with_url(Url::parse(&url)?, api_key)?,
+        Err(_) => LegacyProvider::new(api_key)?,
+    };
+    println!("Using in.php/res.php service at {}", provider.url());
+
+    let service = CaptchaSolverService::new(provider);
+
+    // Google's reCAPTCHA demo page
+    let task = ReCaptchaV2::new(
+        "https://www.google.com/recaptcha/api2/demo",
+        "6Le-wvkSAAAAAPBMRTvw0Q4Muexq9bi0DJwx_mJ-",
+    );
+
+    println!("Solving ReCaptcha V2...");
+
+    let solution = service.solve_captcha(task).await?;
+
+    // The legacy API returns a bare answer; convert it to the expected type
+    let recaptcha = solution.into_recaptcha();
+    println!("Solved! Token: {}...", &recaptcha.token()[..50]);
+
+    Ok(())
+}
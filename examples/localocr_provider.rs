@@ -0,0 +1,30 @@
+//! Example using the local OCR provider.
+//!
+//! Run with: `cargo run --example localocr_provider --features localocr -- captcha.png`
+//!
+//! The image should be a simple numeric captcha with separated digits.
+
+use captcha_solvers::localocr::LocalOcrProvider;
+use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ImageToText};
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = env::args()
+        .nth(1)
+        .expect("usage: localocr_provider <image>");
+    let image = std::fs::read(path)?;
+
+    // Built-in digit templates; add samples with `TemplateSet::with_sample`
+    let provider = LocalOcrProvider::new();
+    let service = CaptchaSolverService::new(provider);
+
+    let task = ImageToText::from_bytes(image).numbers_only();
+
+    println!("Recognizing image locally...");
+
+    let solution = service.solve_captcha(task).await?;
+    println!("Solved! Text: {}", solution.text());
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+//! Example using 2Captcha provider.
+//!
+//! Run with: `cargo run --example twocaptcha_provider`
+//!
+//! Required environment variable:
+//! - `TWOCAPTCHA_API_KEY` - Your 2Captcha API key
+
+use captcha_solvers::twocaptcha::TwocaptchaProvider;
+use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ReCaptchaV2};
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = env::var("TWOCAPTCHA_API_KEY").expect("TWOCAPTCHA_API_KEY must be set");
+
+    // 2Captcha provider - same task types as RuCaptcha
+    let provider = TwocaptchaProvider::new(api_key)?;
+    let service = CaptchaSolverService::new(provider);
+
+    let task = ReCaptchaV2::new(
+        "https://2captcha.com/demo/recaptcha-v2",
+        "6LfD3PIbAAAAAJs_eEHvoOl75_83eXSqpPSRFJ_u",
+    );
+
+    println!("Solving ReCaptcha V2 with 2Captcha...");
+
+    let solution = service.solve_captcha(task).await?;
+
+    let recaptcha = solution.into_recaptcha();
+    println!("Solved! Token length: {}", recaptcha.token().len());
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! Standalone provider API emulator.
+//!
+//! Run with: `cargo run --features emulator --bin captcha-emulator -- --help`
+
+use captcha_solvers::emulator::{Emulator, EmulatorConfig, Flavor};
+use std::net::SocketAddr;
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: captcha-emulator [OPTIONS]
+
+Options:
+  --flavor <NAME>            capsolver, capmonster or rucaptcha [default: capsolver]
+  --listen <ADDR>            Address to listen on [default: 127.0.0.1:8080]
+  --api-key <KEY>            Only accept this client key [default: any]
+  --ready-after-polls <N>    \"processing\" answers before a task is ready [default: 1]
+  --ready-after-ms <MS>      Minimum task age before it is ready [default: 0]
+  --text-answer <TEXT>       Answer for image-to-text tasks [default: emulated]
+  -h, --help                 Print this help";
+
+fn parse_args() -> Result<(EmulatorConfig, SocketAddr), String> {
+    let mut flavor = Flavor::Capsolver;
+    let mut listen = SocketAddr::from(([127, 0, 0, 1], 8080));
+    let mut api_key = None;
+    let mut ready_after_polls = 1;
+    let mut ready_after = Duration::ZERO;
+    let mut text_answer = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            return Err(String::new());
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {arg}"))?;
+        let invalid = |e: &dyn std::fmt::Display| format!("invalid value for {arg}: {e}");
+        match arg.as_str() {
+            "--flavor" => flavor = value.parse()?,
+            "--listen" => listen = value.parse().map_err(|e| invalid(&e))?,
+            "--api-key" => api_key = Some(value),
+            "--ready-after-polls" => ready_after_polls = value.parse().map_err(|e| invalid(&e))?,
+            "--ready-after-ms" => {
+                ready_after = Duration::from_millis(value.parse().map_err(|e| invalid(&e))?)
+            }
+            "--text-answer" => text_answer = Some(value),
+            _ => return Err(format!("unknown option {arg}")),
+        }
+    }
+
+    let mut config = EmulatorConfig::new(flavor)
+        .with_ready_after_polls(ready_after_polls)
+        .with_ready_after(ready_after);
+    if let Some(key) = api_key {
+        config = config.with_api_key(key);
+    }
+    if let Some(text) = text_answer {
+        config = config.with_text_answer(text);
+    }
+    Ok((config, listen))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let (config, listen) = match parse_args() {
+        Ok(parsed) => parsed,
+        Err(message) if message.is_empty() => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let flavor = config.flavor();
+    match Emulator::bind(config, listen).await {
+        Ok(emulator) => {
+            println!("{flavor} emulator listening on {}", emulator.url());
+            emulator.wait().await;
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: failed to listen on {listen}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Error types for cassette recording and replay.
+
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CassetteError {
+    #[error("Failed to read or write cassette {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid cassette {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Failed to build HTTP client: {0}")]
+    BuildHttpClient(#[source] reqwest::Error),
+
+    #[error("Failed to read response body while recording: {0}")]
+    ReadBody(#[source] reqwest::Error),
+
+    #[error("Streamed request body cannot be recorded or matched: {method} {target}")]
+    StreamedBody { method: String, target: String },
+
+    #[error("Recorded response cannot be replayed: {0}")]
+    InvalidResponse(#[source] http::Error),
+
+    #[error("No recorded interaction left for {method} {target}")]
+    NoMatch { method: String, target: String },
+}
+
+pub type Result<T> = std::result::Result<T, CassetteError>;
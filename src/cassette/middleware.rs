@@ -0,0 +1,295 @@
+//! Recording and replaying `reqwest_middleware` middleware.
+
+use super::errors::{CassetteError, Result};
+use super::model::{Cassette, Interaction, RecordedRequest, RecordedResponse, Scrubber};
+use http::Extensions;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Request, Response};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware, Next};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Whether the middleware talks to the network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Forward requests and append each exchange to the cassette file
+    Record,
+    /// Answer requests from the cassette without any network access
+    Replay,
+}
+
+#[derive(Debug)]
+struct State {
+    path: PathBuf,
+    cassette: Cassette,
+    /// Replay: which interactions were already served
+    used: Vec<bool>,
+    /// Record: interactions not written to `path` yet
+    dirty: bool,
+}
+
+impl State {
+    fn save(&mut self) -> Result<()> {
+        if self.dirty {
+            self.cassette.save(&self.path)?;
+            self.dirty = false;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        // Best effort; call `save()` to see the error
+        let _ = self.save();
+    }
+}
+
+/// Middleware that records provider exchanges to a JSON cassette or replays them
+///
+/// Secrets (`clientKey`, `key`, `password`, ... see
+/// [`DEFAULT_SCRUBBED_FIELDS`](super::DEFAULT_SCRUBBED_FIELDS)) are replaced
+/// with `[REDACTED]` in JSON bodies, form bodies and query strings, both when
+/// recording and when matching, so a cassette recorded with a real key replays
+/// with any key.
+///
+/// During replay each request is answered by the first unused interaction
+/// with the same method, path and query, and body. Repeated polls therefore
+/// replay in recording order.
+///
+/// Recorded interactions are kept in memory and written to the cassette file
+/// by [`save`](Self::save), or when the last clone (including the ones held
+/// by [`client`](Self::client)s) is dropped.
+///
+/// Requests with streamed bodies, such as `multipart` uploads, can neither be
+/// recorded nor matched and fail with [`CassetteError::StreamedBody`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::capsolver::CapsolverProvider;
+/// use captcha_solvers::cassette::CassetteMiddleware;
+///
+/// // Records on the first run, replays afterwards
+/// let cassette = CassetteMiddleware::auto("tests/cassettes/capsolver_turnstile.json")?;
+/// let provider = CapsolverProvider::builder(api_key)
+///     .http_client(cassette.client()?)
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct CassetteMiddleware {
+    path: PathBuf,
+    mode: CassetteMode,
+    scrubber: Scrubber,
+    state: Arc<Mutex<State>>,
+}
+
+impl CassetteMiddleware {
+    /// Record into `path`, replacing any existing cassette once saved
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self::with_cassette(path.into(), CassetteMode::Record, Cassette::default())
+    }
+
+    /// Replay the cassette at `path`
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+        Ok(Self::with_cassette(path, CassetteMode::Replay, cassette))
+    }
+
+    /// Replay `path` if it exists, otherwise record it
+    pub fn auto(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        if path.exists() {
+            Self::replay(path)
+        } else {
+            Ok(Self::record(path))
+        }
+    }
+
+    fn with_cassette(path: PathBuf, mode: CassetteMode, cassette: Cassette) -> Self {
+        let used = vec![false; cassette.interactions.len()];
+        let state = State {
+            path: path.clone(),
+            cassette,
+            used,
+            dirty: false,
+        };
+        Self {
+            path,
+            mode,
+            scrubber: Scrubber::new(),
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Also scrub this JSON field, form field or query parameter
+    pub fn scrub_field(mut self, name: impl Into<String>) -> Self {
+        self.scrubber.add(name.into());
+        self
+    }
+
+    /// HTTP client using this middleware, for a provider builder's `http_client`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn client(&self) -> Result<ClientWithMiddleware> {
+        let client = reqwest::Client::builder()
+            .build()
+            .map_err(CassetteError::BuildHttpClient)?;
+        Ok(ClientBuilder::new(client).with(self.clone()).build())
+    }
+
+    /// Write recorded interactions to the cassette file
+    ///
+    /// Does nothing if nothing was recorded since the last save.
+    pub fn save(&self) -> Result<()> {
+        self.state().save()
+    }
+
+    /// Whether requests go to the network
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Cassette file path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Interactions recorded or loaded so far
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state().cassette.interactions.clone()
+    }
+
+    /// Number of loaded interactions not replayed yet
+    pub fn remaining(&self) -> usize {
+        self.state().used.iter().filter(|used| !**used).count()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Scrubbed form of `req`, as written to and matched against the cassette
+    pub(super) fn recorded_request(&self, req: &Request) -> Result<RecordedRequest> {
+        let method = req.method().to_string();
+        let target = self.scrubber.target(req.url());
+        let body = match req.body() {
+            None => None,
+            Some(body) => match body.as_bytes() {
+                Some(bytes) => Some(bytes),
+                None => return Err(CassetteError::StreamedBody { method, target }),
+            },
+        };
+        Ok(RecordedRequest {
+            method,
+            target,
+            body: self.scrubber.body(body),
+        })
+    }
+
+    async fn record_exchange(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let request = self
+            .recorded_request(&req)
+            .map_err(reqwest_middleware::Error::middleware)?;
+        let response = next.run(req, extensions).await?;
+
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| reqwest_middleware::Error::middleware(CassetteError::ReadBody(e)))?;
+
+        let recorded = RecordedResponse {
+            status: status.as_u16(),
+            content_type,
+            body: self.scrubber.body(Some(&bytes)),
+        };
+
+        {
+            let mut state = self.state();
+            state.cassette.interactions.push(Interaction {
+                request,
+                response: recorded.clone(),
+            });
+            state.dirty = true;
+        }
+
+        // Hand back the real body, not the scrubbed copy
+        build_response(status.as_u16(), &recorded.content_type, bytes.to_vec())
+            .map_err(reqwest_middleware::Error::middleware)
+    }
+
+    fn replay_exchange(&self, req: &Request) -> Result<Response> {
+        let request = self.recorded_request(req)?;
+        let mut state = self.state();
+        let State { cassette, used, .. } = &mut *state;
+
+        let index = cassette
+            .interactions
+            .iter()
+            .zip(used.iter())
+            .position(|(interaction, used)| !used && matches(&interaction.request, &request))
+            .ok_or_else(|| CassetteError::NoMatch {
+                method: request.method.clone(),
+                target: request.target.clone(),
+            })?;
+        used[index] = true;
+
+        let response = &cassette.interactions[index].response;
+        let body = match &response.body {
+            Value::Null => Vec::new(),
+            Value::String(text) => text.clone().into_bytes(),
+            json => json.to_string().into_bytes(),
+        };
+        build_response(response.status, &response.content_type, body)
+    }
+}
+
+fn matches(recorded: &RecordedRequest, request: &RecordedRequest) -> bool {
+    recorded.method == request.method
+        && recorded.target == request.target
+        && recorded.body == request.body
+}
+
+/// Response with a recorded status, which may be edited into an invalid one
+fn build_response(status: u16, content_type: &Option<String>, body: Vec<u8>) -> Result<Response> {
+    let mut builder = http::Response::builder().status(status);
+    if let Some(content_type) = content_type {
+        builder = builder.header(CONTENT_TYPE, content_type);
+    }
+    builder
+        .body(body)
+        .map(Response::from)
+        .map_err(CassetteError::InvalidResponse)
+}
+
+#[async_trait::async_trait]
+impl Middleware for CassetteMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        match self.mode {
+            CassetteMode::Record => self.record_exchange(req, extensions, next).await,
+            CassetteMode::Replay => self
+                .replay_exchange(&req)
+                .map_err(reqwest_middleware::Error::middleware),
+        }
+    }
+}
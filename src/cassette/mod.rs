@@ -0,0 +1,57 @@
+//! # Record/Replay HTTP Fixtures
+//!
+//! [`CassetteMiddleware`] is a `reqwest_middleware` middleware that records
+//! provider exchanges to a JSON cassette once, then replays them in tests
+//! without network access. Plug it into any provider through the builder's
+//! `http_client` hook:
+//!
+//! ```rust,ignore
+//! use captcha_solvers::capmonster::CapmonsterProvider;
+//! use captcha_solvers::cassette::CassetteMiddleware;
+//!
+//! // First run with a real key records; later runs replay with any key
+//! let cassette = CassetteMiddleware::auto("tests/cassettes/capmonster_recaptcha.json")?;
+//! let api_key = std::env::var("CAPMONSTER_API_KEY").unwrap_or_else(|_| "replay".into());
+//! let provider = CapmonsterProvider::builder(api_key)
+//!     .http_client(cassette.client()?)
+//!     .build()?;
+//! // ... solve captchas ...
+//! cassette.save()?;
+//! ```
+//!
+//! API keys and credentials are scrubbed before anything is written; add
+//! provider-specific names with [`CassetteMiddleware::scrub_field`].
+//!
+//! Cassettes are plain JSON:
+//!
+//! ```json
+//! {
+//!   "interactions": [
+//!     {
+//!       "request": {
+//!         "method": "POST",
+//!         "target": "/createTask",
+//!         "body": { "clientKey": "[REDACTED]", "task": { "type": "TurnstileTask" } }
+//!       },
+//!       "response": {
+//!         "status": 200,
+//!         "content_type": "application/json",
+//!         "body": { "errorId": 0, "taskId": 123 }
+//!       }
+//!     }
+//!   ]
+//! }
+//! ```
+
+mod errors;
+mod middleware;
+mod model;
+
+#[cfg(test)]
+mod tests;
+
+pub use errors::CassetteError;
+pub use middleware::{CassetteMiddleware, CassetteMode};
+pub use model::{
+    Cassette, DEFAULT_SCRUBBED_FIELDS, Interaction, RecordedRequest, RecordedResponse,
+};
@@ -0,0 +1,162 @@
+//! On-disk cassette format and secret scrubbing.
+
+use super::errors::{CassetteError, Result};
+use crate::utils::REDACTED;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+use url::Url;
+
+/// Field and query parameter names scrubbed by default
+pub const DEFAULT_SCRUBBED_FIELDS: &[&str] = &[
+    "clientKey",
+    "key",
+    "apiKey",
+    "api_key",
+    "authtoken",
+    "username",
+    "password",
+];
+
+/// Recorded request and response pairs, in recording order
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+/// One recorded exchange
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// Request as matched during replay
+///
+/// `target` is the path and query of the URL, so a cassette replays against
+/// any base URL. The body is JSON when it parses as JSON, a string when it is
+/// other text, and `null` when there is no body or it is binary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub target: String,
+    #[serde(default)]
+    pub body: Value,
+}
+
+/// Response returned during replay
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// JSON bodies are stored as JSON, anything else as a string
+    pub body: Value,
+}
+
+impl Cassette {
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        let data = std::fs::read(path).map_err(|source| CassetteError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        serde_json::from_slice(&data).map_err(|source| CassetteError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        let io_error = |source| CassetteError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let data = serde_json::to_vec_pretty(self).expect("cassette serializes to JSON");
+        std::fs::write(path, data).map_err(io_error)
+    }
+}
+
+/// Replaces secrets in recorded URLs and bodies
+#[derive(Debug, Clone)]
+pub(crate) struct Scrubber {
+    fields: Vec<String>,
+}
+
+impl Scrubber {
+    pub(crate) fn new() -> Self {
+        Self {
+            fields: DEFAULT_SCRUBBED_FIELDS
+                .iter()
+                .map(|f| f.to_string())
+                .collect(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, field: String) {
+        if !self.fields.contains(&field) {
+            self.fields.push(field);
+        }
+    }
+
+    fn is_secret(&self, name: &str) -> bool {
+        self.fields.iter().any(|f| f == name)
+    }
+
+    /// Path and query of `url`, with secret query parameters redacted
+    pub(crate) fn target(&self, url: &Url) -> String {
+        let mut target = url.path().to_string();
+        if url.query().is_some() {
+            target.push('?');
+            target.push_str(&self.form(url.query().unwrap_or_default()));
+        }
+        target
+    }
+
+    /// Body as JSON, form text or `null`, with secrets redacted
+    pub(crate) fn body(&self, bytes: Option<&[u8]>) -> Value {
+        let Some(bytes) = bytes.filter(|b| !b.is_empty()) else {
+            return Value::Null;
+        };
+        if let Ok(mut json) = serde_json::from_slice::<Value>(bytes) {
+            self.json(&mut json);
+            return json;
+        }
+        match std::str::from_utf8(bytes) {
+            Ok(text) if text.contains('=') && !text.contains(char::is_whitespace) => {
+                Value::String(self.form(text))
+            }
+            Ok(text) => Value::String(text.to_string()),
+            Err(_) => Value::Null,
+        }
+    }
+
+    fn json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (name, value) in map.iter_mut() {
+                    if self.is_secret(name) && !value.is_null() {
+                        *value = Value::String(REDACTED.to_string());
+                    } else {
+                        self.json(value);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.json(item)),
+            _ => {}
+        }
+    }
+
+    /// Redact `name=value` pairs of an urlencoded string, keeping the order
+    fn form(&self, text: &str) -> String {
+        text.split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((name, _)) if self.is_secret(name) => format!("{name}={REDACTED}"),
+                _ => pair.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
@@ -0,0 +1,274 @@
+//! Tests for cassette recording and replay.
+
+use super::errors::CassetteError;
+use super::middleware::{CassetteMiddleware, CassetteMode};
+use super::model::Cassette;
+use serde_json::json;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn cassette_path(name: &str) -> PathBuf {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir()
+        .join(format!("captcha-cassettes-{}", std::process::id()))
+        .join(format!("{name}-{n}.json"))
+}
+
+#[cfg(feature = "capsolver")]
+#[tokio::test]
+async fn test_record_then_replay_capsolver() {
+    use crate::providers::capsolver::CapsolverProvider;
+    use crate::service::{
+        CaptchaSolverService, CaptchaSolverServiceConfig, CaptchaSolverServiceTrait,
+    };
+    use crate::tasks::Turnstile;
+    use std::time::Duration;
+    use url::Url;
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "taskId": "task-1",
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "status": "processing",
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "status": "ready",
+            "solution": { "token": "recorded-token" },
+        })))
+        .mount(&server)
+        .await;
+
+    let config = CaptchaSolverServiceConfig::builder()
+        .poll_interval(Duration::from_millis(10))
+        .build();
+    let solve = |cassette: &CassetteMiddleware, key: &str, url: &str| {
+        let provider = CapsolverProvider::builder(key)
+            .url(Url::parse(url).unwrap())
+            .http_client(cassette.client().unwrap())
+            .build()
+            .unwrap();
+        let service = CaptchaSolverService::with_config(provider, config.clone());
+        async move {
+            service
+                .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+                .await
+        }
+    };
+
+    let path = cassette_path("capsolver");
+    let recorder = CassetteMiddleware::auto(&path).unwrap();
+    assert_eq!(recorder.mode(), CassetteMode::Record);
+    let solution = solve(&recorder, "live-secret-key", &server.uri())
+        .await
+        .unwrap();
+    assert_eq!(solution.into_turnstile().token(), Some("recorded-token"));
+
+    // Nothing touches the disk until saved
+    assert!(!path.exists());
+    recorder.save().unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert!(!written.contains("live-secret-key"));
+    assert!(written.contains("[REDACTED]"));
+    assert_eq!(recorder.interactions().len(), 3);
+
+    // Replays with another key, against a base URL nothing listens on
+    drop(server);
+    let player = CassetteMiddleware::auto(&path).unwrap();
+    assert_eq!(player.mode(), CassetteMode::Replay);
+    let solution = solve(&player, "other-key", "http://127.0.0.1:9")
+        .await
+        .unwrap();
+    assert_eq!(solution.into_turnstile().token(), Some("recorded-token"));
+    assert_eq!(player.remaining(), 0);
+}
+
+#[cfg(feature = "legacy")]
+#[tokio::test]
+async fn test_form_and_query_secrets_are_scrubbed() {
+    use crate::providers::legacy::LegacyProvider;
+    use crate::providers::traits::Provider;
+    use crate::tasks::Turnstile;
+    use crate::utils::types::TaskId;
+    use url::Url;
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/in.php"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK|42"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/res.php"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK|legacy-token"))
+        .mount(&server)
+        .await;
+
+    let path = cassette_path("legacy");
+    let recorder = CassetteMiddleware::record(&path);
+    let provider = LegacyProvider::builder("form-secret")
+        .url(Url::parse(&server.uri()).unwrap())
+        .http_client(recorder.client().unwrap())
+        .build()
+        .unwrap();
+
+    let task_id = provider
+        .create_task(Turnstile::new("https://example.com", "site-key").into())
+        .await
+        .unwrap()
+        .task_id()
+        .clone();
+    assert_eq!(task_id, TaskId::from("42"));
+    provider.get_task_result(&task_id).await.unwrap().unwrap();
+
+    // The last clones live in the provider's client
+    drop(recorder);
+    drop(provider);
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert!(!written.contains("form-secret"));
+    let cassette: Cassette = serde_json::from_str(&written).unwrap();
+    let res = &cassette.interactions[1];
+    assert!(res.request.target.starts_with("/res.php?"));
+    assert!(res.request.target.contains("key=[REDACTED]"));
+    assert_eq!(res.response.body, "OK|legacy-token");
+}
+
+#[tokio::test]
+async fn test_replay_without_match_fails() {
+    let path = cassette_path("empty");
+    let mut cassette = Cassette::default();
+    cassette.interactions.push(super::model::Interaction {
+        request: super::model::RecordedRequest {
+            method: "POST".to_string(),
+            target: "/createTask".to_string(),
+            body: json!({ "clientKey": "[REDACTED]", "task": { "type": "A" } }),
+        },
+        response: super::model::RecordedResponse {
+            status: 200,
+            content_type: Some("application/json".to_string()),
+            body: json!({ "errorId": 0, "taskId": 1 }),
+        },
+    });
+    cassette.save(&path).unwrap();
+
+    let player = CassetteMiddleware::replay(&path).unwrap();
+    let client = player.client().unwrap();
+    let url = "http://127.0.0.1:9/createTask";
+
+    let response = client
+        .post(url)
+        .json(&json!({ "clientKey": "anything", "task": { "type": "A" } }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["taskId"], 1);
+
+    // The only matching interaction is used up
+    let err = client
+        .post(url)
+        .json(&json!({ "clientKey": "anything", "task": { "type": "A" } }))
+        .send()
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("No recorded interaction left for POST /createTask")
+    );
+}
+
+#[test]
+fn test_missing_cassette() {
+    let err = CassetteMiddleware::replay(cassette_path("missing")).unwrap_err();
+    assert!(matches!(err, CassetteError::Io { .. }));
+}
+
+#[test]
+fn test_custom_scrub_field() {
+    let path = cassette_path("custom");
+    let middleware = CassetteMiddleware::record(&path).scrub_field("softId");
+    let request = reqwest::Client::new()
+        .post("http://example.com/createTask?softId=7&lang=en")
+        .json(&json!({ "softId": 7, "nested": [{ "password": "p" }] }))
+        .build()
+        .unwrap();
+
+    let recorded = middleware.recorded_request(&request).unwrap();
+    assert_eq!(recorded.target, "/createTask?softId=[REDACTED]&lang=en");
+    assert_eq!(
+        recorded.body,
+        json!({ "softId": "[REDACTED]", "nested": [{ "password": "[REDACTED]" }] })
+    );
+}
+
+// Multipart bodies are streamed; the feature enables reqwest's `multipart`
+#[cfg(feature = "deathbycaptcha")]
+#[tokio::test]
+async fn test_streamed_body_fails() {
+    let recorder = CassetteMiddleware::record(cassette_path("streamed"));
+    let form = reqwest::multipart::Form::new().text("username", "user");
+
+    let err = recorder
+        .client()
+        .unwrap()
+        .post("http://127.0.0.1:9/api/captcha")
+        .multipart(form)
+        .send()
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Streamed request body cannot be recorded or matched: POST /api/captcha")
+    );
+    assert!(recorder.interactions().is_empty());
+}
+
+#[tokio::test]
+async fn test_replay_invalid_status_fails() {
+    let path = cassette_path("invalid-status");
+    let mut cassette = Cassette::default();
+    cassette.interactions.push(super::model::Interaction {
+        request: super::model::RecordedRequest {
+            method: "GET".to_string(),
+            target: "/res.php".to_string(),
+            body: serde_json::Value::Null,
+        },
+        response: super::model::RecordedResponse {
+            status: 1000,
+            content_type: None,
+            body: json!("OK|token"),
+        },
+    });
+    cassette.save(&path).unwrap();
+
+    let player = CassetteMiddleware::replay(&path).unwrap();
+    let err = player
+        .client()
+        .unwrap()
+        .get("http://127.0.0.1:9/res.php")
+        .send()
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Recorded response cannot be replayed")
+    );
+}
@@ -0,0 +1,108 @@
+//! `provider://KEY@host?option=value` connection strings.
+
+use super::errors::SolverConfigError;
+use super::provider::ProviderKind;
+use super::settings::SolverConfig;
+use crate::utils::serde_helpers::parse_duration;
+use percent_encoding::percent_decode_str;
+use std::borrow::Cow;
+use std::time::Duration;
+use url::Url;
+
+/// Parse a DSN into a [`SolverConfig`]
+///
+/// ```text
+/// provider[+http|+https]://API_KEY[@host[:port][/path]][?option=value&...]
+/// ```
+///
+/// The key, host and query names and values are percent-decoded after
+/// splitting, so `%40`, `%26`, `%3D` and `%3F` stand for `@`, `&`, `=` and `?`.
+///
+/// See [`SolverConfig::from_dsn`] for the options.
+pub(super) fn parse(dsn: &str) -> Result<SolverConfig, SolverConfigError> {
+    let (scheme, rest) = dsn
+        .trim()
+        .split_once("://")
+        .ok_or_else(|| invalid("expected 'provider://API_KEY[@host]'"))?;
+
+    let (provider, url_scheme) = match scheme.split_once('+') {
+        Some((provider, "http")) => (provider, "http"),
+        Some((provider, "https")) => (provider, "https"),
+        Some((_, other)) => return Err(invalid(format!("unknown transport '+{other}'"))),
+        None => (scheme, "https"),
+    };
+    let provider: ProviderKind = provider.parse().map_err(invalid)?;
+
+    let (location, query) = match rest.split_once('?') {
+        Some((location, query)) => (location, Some(query)),
+        None => (rest, None),
+    };
+    // The key may itself contain '@', the host never does
+    let (api_key, host) = match location.rsplit_once('@') {
+        Some((api_key, host)) => (api_key, Some(host)),
+        None => (location.trim_end_matches('/'), None),
+    };
+    if api_key.is_empty() {
+        return Err(invalid("missing API key"));
+    }
+
+    let mut config = SolverConfig::new(provider, decode(api_key, "API key")?);
+    if let Some(host) = host.filter(|host| !host.is_empty()) {
+        let host = decode(host, "host")?;
+        let url = Url::parse(&format!("{url_scheme}://{host}"))
+            .map_err(|e| invalid(format!("invalid host: {e}")))?;
+        config.url = Some(url);
+    }
+
+    for pair in query
+        .into_iter()
+        .flat_map(|q| q.split('&'))
+        .filter(|p| !p.is_empty())
+    {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let key = decode(key, "option name")?;
+        let value = decode(value, "option value")?;
+        let value = value.as_ref();
+        let option = |message: String| invalid(format!("option '{key}': {message}"));
+        match key.as_ref() {
+            "timeout" => config.service.timeout = duration(value).map_err(option)?,
+            "poll_interval" => config.service.poll_interval = duration(value).map_err(option)?,
+            "min_delay" => config.retry.min_delay = duration(value).map_err(option)?,
+            "max_delay" => config.retry.max_delay = duration(value).map_err(option)?,
+            "max_retries" => {
+                config.retry.max_retries = value
+                    .parse()
+                    .map_err(|_| option("expected a whole number".to_string()))?;
+            }
+            "factor" => {
+                config.retry.factor = value
+                    .parse()
+                    .map_err(|_| option("expected a number".to_string()))?;
+            }
+            "proxy" => config.proxy = Some(value.parse().map_err(option)?),
+            _ => return Err(invalid(format!("unknown option '{key}'"))),
+        }
+    }
+
+    Ok(config)
+}
+
+/// Human-readable duration, or whole seconds without a unit
+fn duration(value: &str) -> Result<Duration, String> {
+    match value.parse::<u64>() {
+        Ok(secs) => Ok(Duration::from_secs(secs)),
+        Err(_) => parse_duration(value),
+    }
+}
+
+/// Percent-decode one DSN component; `what` names it in errors instead of its value
+fn decode<'a>(part: &'a str, what: &str) -> Result<Cow<'a, str>, SolverConfigError> {
+    percent_decode_str(part)
+        .decode_utf8()
+        .map_err(|_| invalid(format!("{what} is not valid UTF-8 once percent-decoded")))
+}
+
+/// The DSN itself is never echoed back, as it carries the API key
+fn invalid(message: impl Into<String>) -> SolverConfigError {
+    SolverConfigError::InvalidDsn(message.into())
+}
@@ -0,0 +1,41 @@
+//! Configuration loading errors.
+
+use super::provider::ConfiguredError;
+use crate::service::ConfigError;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Error loading a [`SolverConfig`](super::SolverConfig) or building its service
+#[derive(Debug, Error)]
+pub enum SolverConfigError {
+    #[error("Failed to read config file {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Unsupported config file {} (expected a .toml or .json extension)", path.display())]
+    UnsupportedFormat { path: PathBuf },
+
+    #[error("Invalid TOML config: {0}")]
+    Toml(#[source] toml::de::Error),
+
+    #[error("Invalid JSON config: {0}")]
+    Json(#[source] serde_json::Error),
+
+    #[error("Invalid provider DSN: {0}")]
+    InvalidDsn(String),
+
+    #[error("Missing environment variable {0}")]
+    MissingEnv(String),
+
+    #[error("Invalid environment variable {name}: {message}")]
+    InvalidEnv { name: String, message: String },
+
+    #[error("Invalid service config: {0}")]
+    Service(#[from] ConfigError),
+
+    #[error("Failed to build provider: {0}")]
+    Provider(#[source] ConfiguredError),
+}
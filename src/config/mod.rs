@@ -0,0 +1,39 @@
+//! # Solver Configuration
+//!
+//! Loads the provider choice, API key, service settings and retry policy
+//! from environment variables or a TOML/JSON file, and builds a ready
+//! service from them:
+//!
+//! ```rust,ignore
+//! use captcha_solvers::config::SolverConfig;
+//!
+//! // CAPTCHA_PROVIDER=capsolver, CAPTCHA_TIMEOUT=3m, CAPSOLVER_API_KEY=...
+//! let service = SolverConfig::from_env("CAPTCHA")?.build_service()?;
+//! ```
+//!
+//! The built service wraps a [`ConfiguredProvider`] (one variant per enabled
+//! createTask provider: Capsolver, CapMonster, RuCaptcha, Anti-Captcha,
+//! 2Captcha) in a [`CaptchaRetryableProvider`](crate::CaptchaRetryableProvider).
+//! Providers that need more than an API key (legacy, generic,
+//! DeathByCaptcha, local OCR) are built in code. The module is only compiled
+//! when at least one createTask provider feature is enabled.
+//!
+//! [`CaptchaSolverServiceConfig`](crate::CaptchaSolverServiceConfig),
+//! [`RetryConfig`](crate::RetryConfig) and [`ProxyConfig`](crate::ProxyConfig)
+//! implement `Deserialize` on their own and can be embedded in an
+//! application's own configuration instead.
+
+mod dsn;
+mod errors;
+mod provider;
+mod settings;
+
+#[cfg(test)]
+mod tests;
+
+pub use errors::SolverConfigError;
+pub use provider::{
+    ConfiguredError, ConfiguredProvider, ConfiguredProviderBuilder, ConfiguredSolution,
+    ProviderKind,
+};
+pub use settings::{ConfiguredService, SolverConfig};
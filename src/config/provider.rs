@@ -0,0 +1,380 @@
+//! Provider selected at runtime from configuration.
+
+use crate::errors::{KeyFault, RetryableError};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::solutions::{
+    ImageToTextSolution, ProviderSolution, ReCaptchaSolution, TurnstileSolution,
+};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use reqwest_middleware::ClientWithMiddleware;
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+use url::Url;
+
+/// Expands the provider list into [`ProviderKind`], [`ConfiguredProvider`],
+/// [`ConfiguredSolution`] and [`ConfiguredError`], one variant per provider
+/// whose feature is enabled.
+macro_rules! configured_providers {
+    ($(
+        $(#[$doc:meta])*
+        $variant:ident {
+            feature: $feature:literal,
+            name: $name:literal,
+            env: $env:literal,
+            provider: $provider:path,
+            solution: $solution:path,
+            error: $error:path $(,)?
+        }
+    ),+ $(,)?) => {
+        /// Provider selected by [`SolverConfig`](super::SolverConfig)
+        ///
+        /// Deserializes from the lower-case name (`"capsolver"`, `"capmonster"`,
+        /// ...). Only providers whose feature is enabled are available.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        pub enum ProviderKind {
+            $(
+                $(#[$doc])*
+                #[cfg(feature = $feature)]
+                $variant,
+            )+
+        }
+
+        impl ProviderKind {
+            /// All providers compiled into this build
+            pub const ALL: &'static [ProviderKind] = &[
+                $(
+                    #[cfg(feature = $feature)]
+                    ProviderKind::$variant,
+                )+
+            ];
+
+            /// Configuration name of the provider
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ProviderKind::$variant => $name,
+                    )+
+                }
+            }
+
+            /// Conventional environment variable holding the provider's API key
+            ///
+            /// Used by [`SolverConfig::from_env`](super::SolverConfig::from_env)
+            /// when the prefixed `API_KEY` variable is not set.
+            pub fn api_key_var(&self) -> &'static str {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ProviderKind::$variant => $env,
+                    )+
+                }
+            }
+
+        }
+
+        impl ConfiguredProviderBuilder {
+            /// Build the selected provider through its own builder
+            pub fn build(self) -> Result<ConfiguredProvider, ConfiguredError> {
+                match self.kind {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ProviderKind::$variant => {
+                            type Target = $provider;
+                            let mut builder = Target::builder(self.api_key.expose_secret());
+                            if let Some(url) = self.url {
+                                builder = builder.url(url);
+                            }
+                            if let Some(client) = self.http_client {
+                                builder = builder.http_client(client);
+                            }
+                            let provider = builder.build().map_err(ConfiguredError::$variant)?;
+                            Ok(ConfiguredProvider::$variant(provider))
+                        }
+                    )+
+                }
+            }
+        }
+
+        impl FromStr for ProviderKind {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                ProviderKind::ALL
+                    .iter()
+                    .copied()
+                    .find(|kind| kind.as_str().eq_ignore_ascii_case(s.trim()))
+                    .ok_or_else(|| {
+                        let names: Vec<_> = ProviderKind::ALL.iter().map(|k| k.as_str()).collect();
+                        format!("unknown provider '{s}' (expected one of: {})", names.join(", "))
+                    })
+            }
+        }
+
+        /// Provider built from a [`SolverConfig`](super::SolverConfig)
+        ///
+        /// Dispatches to the selected provider, so one service type covers
+        /// every provider choice.
+        #[derive(Debug, Clone)]
+        pub enum ConfiguredProvider {
+            $(
+                $(#[$doc])*
+                #[cfg(feature = $feature)]
+                $variant($provider),
+            )+
+        }
+
+        impl ConfiguredProvider {
+            /// Create a builder for the given provider
+            pub fn builder(
+                kind: ProviderKind,
+                api_key: impl Into<String>,
+            ) -> ConfiguredProviderBuilder {
+                ConfiguredProviderBuilder {
+                    kind,
+                    api_key: SecretString::from(api_key.into()),
+                    url: None,
+                    http_client: None,
+                }
+            }
+
+            /// Which provider this is
+            pub fn kind(&self) -> ProviderKind {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredProvider::$variant(_) => ProviderKind::$variant,
+                    )+
+                }
+            }
+        }
+
+        impl Provider for ConfiguredProvider {
+            type Solution = ConfiguredSolution;
+            type Error = ConfiguredError;
+
+            async fn create_task(
+                &self,
+                task: CaptchaTask,
+            ) -> Result<TaskCreationOutcome<Self::Solution>, Self::Error> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredProvider::$variant(ref provider) => {
+                            let outcome = provider
+                                .create_task(task)
+                                .await
+                                .map_err(ConfiguredError::$variant)?;
+                            Ok(match outcome {
+                                TaskCreationOutcome::Pending(task_id) => {
+                                    TaskCreationOutcome::Pending(task_id)
+                                }
+                                TaskCreationOutcome::Ready { task_id, solution } => {
+                                    TaskCreationOutcome::Ready {
+                                        task_id,
+                                        solution: ConfiguredSolution::$variant(solution),
+                                    }
+                                }
+                            })
+                        }
+                    )+
+                }
+            }
+
+            async fn get_task_result(
+                &self,
+                task_id: &TaskId,
+            ) -> Result<Option<Self::Solution>, Self::Error> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredProvider::$variant(ref provider) => provider
+                            .get_task_result(task_id)
+                            .await
+                            .map(|solution| solution.map(ConfiguredSolution::$variant))
+                            .map_err(ConfiguredError::$variant),
+                    )+
+                }
+            }
+        }
+
+        /// Solution returned by a [`ConfiguredProvider`]
+        ///
+        /// Wraps the selected provider's solution type. The accessors cover
+        /// the solution kinds every configurable provider returns; match on
+        /// the variant for provider-specific solutions.
+        #[derive(Debug, Clone)]
+        pub enum ConfiguredSolution {
+            $(
+                $(#[$doc])*
+                #[cfg(feature = $feature)]
+                $variant($solution),
+            )+
+        }
+
+        impl ProviderSolution for ConfiguredSolution {}
+
+        impl ConfiguredSolution {
+            /// Try to extract ReCaptcha solution (returns reference)
+            pub fn as_recaptcha(&self) -> Option<&ReCaptchaSolution> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredSolution::$variant(ref solution) => solution.as_recaptcha(),
+                    )+
+                }
+            }
+
+            /// Try to extract Turnstile solution (returns reference)
+            pub fn as_turnstile(&self) -> Option<&TurnstileSolution> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredSolution::$variant(ref solution) => solution.as_turnstile(),
+                    )+
+                }
+            }
+
+            /// Try to extract ImageToText solution (returns reference)
+            pub fn as_image_to_text(&self) -> Option<&ImageToTextSolution> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredSolution::$variant(ref solution) => solution.as_image_to_text(),
+                    )+
+                }
+            }
+        }
+
+        /// Error returned by a [`ConfiguredProvider`]
+        ///
+        /// Wraps the selected provider's error and keeps its retryability.
+        #[derive(Debug, Error)]
+        pub enum ConfiguredError {
+            $(
+                $(#[$doc])*
+                #[cfg(feature = $feature)]
+                #[error(transparent)]
+                $variant($error),
+            )+
+        }
+
+        impl RetryableError for ConfiguredError {
+            fn is_retryable(&self) -> bool {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredError::$variant(ref error) => error.is_retryable(),
+                    )+
+                }
+            }
+
+            fn should_retry_operation(&self) -> bool {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredError::$variant(ref error) => error.should_retry_operation(),
+                    )+
+                }
+            }
+
+            fn key_fault(&self) -> Option<KeyFault> {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        ConfiguredError::$variant(ref error) => error.key_fault(),
+                    )+
+                }
+            }
+        }
+    };
+}
+
+configured_providers! {
+    /// Capsolver
+    Capsolver {
+        feature: "capsolver",
+        name: "capsolver",
+        env: "CAPSOLVER_API_KEY",
+        provider: crate::providers::capsolver::CapsolverProvider,
+        solution: crate::providers::capsolver::CapsolverSolution,
+        error: crate::providers::capsolver::CapsolverError,
+    },
+    /// CapMonster Cloud
+    Capmonster {
+        feature: "capmonster",
+        name: "capmonster",
+        env: "CAPMONSTER_API_KEY",
+        provider: crate::providers::capmonster::CapmonsterProvider,
+        solution: crate::providers::capmonster::CapmonsterSolution,
+        error: crate::providers::capmonster::CapmonsterError,
+    },
+    /// RuCaptcha
+    Rucaptcha {
+        feature: "rucaptcha",
+        name: "rucaptcha",
+        env: "RUCAPTCHA_API_KEY",
+        provider: crate::providers::rucaptcha::RucaptchaProvider,
+        solution: crate::providers::rucaptcha::RucaptchaSolution,
+        error: crate::providers::rucaptcha::RucaptchaError,
+    },
+    /// Anti-Captcha
+    Anticaptcha {
+        feature: "anticaptcha",
+        name: "anticaptcha",
+        env: "ANTICAPTCHA_API_KEY",
+        provider: crate::providers::anticaptcha::AnticaptchaProvider,
+        solution: crate::providers::anticaptcha::AnticaptchaSolution,
+        error: crate::providers::anticaptcha::AnticaptchaError,
+    },
+    /// 2Captcha
+    Twocaptcha {
+        feature: "twocaptcha",
+        name: "twocaptcha",
+        env: "TWOCAPTCHA_API_KEY",
+        provider: crate::providers::twocaptcha::TwocaptchaProvider,
+        solution: crate::providers::twocaptcha::TwocaptchaSolution,
+        error: crate::providers::twocaptcha::TwocaptchaError,
+    },
+}
+
+/// Builder for [`ConfiguredProvider`]
+///
+/// Forwards the overrides to the selected provider's own builder.
+#[derive(Debug)]
+pub struct ConfiguredProviderBuilder {
+    kind: ProviderKind,
+    api_key: SecretString,
+    url: Option<Url>,
+    http_client: Option<ClientWithMiddleware>,
+}
+
+impl ConfiguredProviderBuilder {
+    /// Which provider will be built
+    pub fn kind(&self) -> ProviderKind {
+        self.kind
+    }
+
+    /// Override the provider's default API URL
+    pub fn url(mut self, url: Url) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Use a custom HTTP client (e.g. with extra middleware)
+    pub fn http_client(mut self, client: ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+}
+
+impl fmt::Display for ProviderKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,343 @@
+//! Top-level solver configuration and its loaders.
+
+use super::errors::SolverConfigError;
+use super::provider::{ConfiguredProvider, ConfiguredProviderBuilder, ProviderKind};
+use crate::providers::CaptchaRetryableProvider;
+use crate::service::{CaptchaSolverService, CaptchaSolverServiceConfig};
+use crate::utils::proxy::ProxyConfig;
+use crate::utils::retry::RetryConfig;
+use crate::utils::serde_helpers::parse_duration;
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use url::Url;
+
+/// Service built by [`SolverConfig::build_service`]
+pub type ConfiguredService = CaptchaSolverService<CaptchaRetryableProvider<ConfiguredProvider>>;
+
+/// Provider choice, credentials, service settings and retry policy in one place
+///
+/// # File format
+///
+/// ```toml
+/// provider = "capsolver"
+/// api_key = "CAP-..."
+/// # url = "http://127.0.0.1:8080"   # optional API URL override
+/// # proxy = "socks5:10.0.0.1:1080:user:pass"
+///
+/// [service]
+/// timeout = "3m"
+/// poll_interval = "5s"
+///
+/// [retry]
+/// max_retries = 5
+/// min_delay = "500ms"
+/// max_delay = "30s"
+/// factor = 2.0
+/// ```
+///
+/// The same keys work in JSON. Durations are human-readable (`"1m 30s"`,
+/// `"500ms"`) or whole seconds; missing sections keep their defaults.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::CaptchaSolverServiceTrait;
+/// use captcha_solvers::ReCaptchaV2;
+/// use captcha_solvers::config::SolverConfig;
+///
+/// let config = SolverConfig::from_path("captcha.toml")?;
+/// // or: SolverConfig::from_env("CAPTCHA")?
+/// let service = config.build_service()?;
+///
+/// let solution = service.solve_captcha(task).await?;
+///
+/// // The configured proxy goes on tasks that accept one
+/// let mut task = ReCaptchaV2::new("https://example.com", "site-key");
+/// if let Some(proxy) = config.proxy() {
+///     task = task.with_proxy(proxy.clone());
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SolverConfig {
+    /// Which provider to build
+    pub provider: ProviderKind,
+    /// API key for the provider
+    pub api_key: SecretString,
+    /// API URL override (e.g. a local emulator); the provider default otherwise
+    #[serde(default)]
+    pub url: Option<Url>,
+    /// Timeout and polling settings
+    #[serde(default)]
+    pub service: CaptchaSolverServiceConfig,
+    /// Retry policy for the provider wrapper (`max_retries = 0` disables retries)
+    #[serde(default)]
+    pub retry: RetryConfig,
+    /// Default proxy for tasks that accept one
+    ///
+    /// The service does not apply it: proxies are set per task, so pass
+    /// [`proxy`](Self::proxy) to the task builders.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl FromStr for SolverConfig {
+    type Err = SolverConfigError;
+
+    /// Same as [`SolverConfig::from_dsn`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_dsn(s)
+    }
+}
+
+impl SolverConfig {
+    /// Create a configuration with default service and retry settings
+    pub fn new(provider: ProviderKind, api_key: impl Into<String>) -> Self {
+        Self {
+            provider,
+            api_key: SecretString::from(api_key.into()),
+            url: None,
+            service: CaptchaSolverServiceConfig::default(),
+            retry: RetryConfig::default(),
+            proxy: None,
+        }
+    }
+
+    /// Parse a TOML document
+    pub fn from_toml_str(input: &str) -> Result<Self, SolverConfigError> {
+        toml::from_str(input).map_err(SolverConfigError::Toml)
+    }
+
+    /// Parse a JSON document
+    pub fn from_json_str(input: &str) -> Result<Self, SolverConfigError> {
+        serde_json::from_str(input).map_err(SolverConfigError::Json)
+    }
+
+    /// Load a `.toml` or `.json` file, chosen by extension
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, SolverConfigError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        let parse = match extension.as_deref() {
+            Some("toml") => Self::from_toml_str,
+            Some("json") => Self::from_json_str,
+            _ => {
+                return Err(SolverConfigError::UnsupportedFormat {
+                    path: path.to_path_buf(),
+                });
+            }
+        };
+
+        let input = std::fs::read_to_string(path).map_err(|source| SolverConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        parse(&input)
+    }
+
+    /// Parse a `provider://API_KEY[@host]?option=value` connection string
+    ///
+    /// ```rust
+    /// use captcha_solvers::config::{ProviderKind, SolverConfig};
+    /// use std::time::Duration;
+    ///
+    /// let config = SolverConfig::from_dsn("capsolver://KEY@api.capsolver.com?timeout=120").unwrap();
+    /// assert_eq!(config.provider, ProviderKind::Capsolver);
+    /// assert_eq!(config.service.timeout, Duration::from_secs(120));
+    ///
+    /// // Default API URL; `+http` for plain-HTTP hosts such as a local emulator
+    /// let config = SolverConfig::from_dsn("rucaptcha://KEY").unwrap();
+    /// let config = SolverConfig::from_dsn("capmonster+http://KEY@127.0.0.1:8080").unwrap();
+    /// ```
+    ///
+    /// Query options: `timeout`, `poll_interval`, `max_retries`, `min_delay`,
+    /// `max_delay`, `factor` and `proxy`. Durations take a unit (`"90s"`,
+    /// `"2m"`) or are whole seconds.
+    ///
+    /// The key, host and option values are percent-decoded, so a key or proxy
+    /// password containing `@`, `&`, `=` or `?` is written as `%40`, `%26`,
+    /// `%3D` or `%3F`.
+    pub fn from_dsn(dsn: &str) -> Result<Self, SolverConfigError> {
+        super::dsn::parse(dsn)
+    }
+
+    /// Load from environment variables named `{prefix}_{KEY}`
+    ///
+    /// `{prefix}_DSN` (see [`from_dsn`](Self::from_dsn)) selects provider and
+    /// key in one variable; the other variables override its values.
+    ///
+    /// | Variable | Value |
+    /// |----------|-------|
+    /// | `{prefix}_DSN` | Connection string (replaces `PROVIDER`, `API_KEY` and `URL`) |
+    /// | `{prefix}_PROVIDER` | Provider name (required without a DSN) |
+    /// | `{prefix}_API_KEY` | API key; falls back to the provider's own variable, e.g. `CAPSOLVER_API_KEY` |
+    /// | `{prefix}_URL` | API URL override |
+    /// | `{prefix}_TIMEOUT`, `{prefix}_POLL_INTERVAL` | Durations (`"2m"`, `"3s"`) |
+    /// | `{prefix}_RETRY_MAX_RETRIES`, `{prefix}_RETRY_FACTOR` | Numbers |
+    /// | `{prefix}_RETRY_MIN_DELAY`, `{prefix}_RETRY_MAX_DELAY` | Durations |
+    /// | `{prefix}_PROXY` | `type:address:port[:login:password]` |
+    pub fn from_env(prefix: &str) -> Result<Self, SolverConfigError> {
+        Self::from_vars(prefix, std::env::vars())
+    }
+
+    /// Load from `(name, value)` pairs using the [`from_env`](Self::from_env) names
+    pub fn from_vars<I, K, V>(prefix: &str, vars: I) -> Result<Self, SolverConfigError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let vars: HashMap<String, String> = vars
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        let env = EnvVars {
+            prefix,
+            vars: &vars,
+        };
+
+        let mut config = match env.get("DSN") {
+            Some(dsn) => Self::from_dsn(dsn)?,
+            None => {
+                let provider_var = env.name("PROVIDER");
+                let provider: ProviderKind = env
+                    .parse("PROVIDER", |s| s.parse())?
+                    .ok_or(SolverConfigError::MissingEnv(provider_var))?;
+
+                let api_key = env
+                    .get("API_KEY")
+                    .or_else(|| vars.get(provider.api_key_var()).map(String::as_str))
+                    .ok_or_else(|| SolverConfigError::MissingEnv(env.name("API_KEY")))?;
+
+                let mut config = Self::new(provider, api_key);
+                config.url = env.parse("URL", |s| Url::parse(s).map_err(|e| e.to_string()))?;
+                config
+            }
+        };
+        if let Some(proxy) = env.parse("PROXY", ProxyConfig::from_str)? {
+            config.proxy = Some(proxy);
+        }
+
+        if let Some(timeout) = env.duration("TIMEOUT")? {
+            config.service.timeout = timeout;
+        }
+        if let Some(interval) = env.duration("POLL_INTERVAL")? {
+            config.service.poll_interval = interval;
+        }
+        if let Some(delay) = env.duration("RETRY_MIN_DELAY")? {
+            config.retry.min_delay = delay;
+        }
+        if let Some(delay) = env.duration("RETRY_MAX_DELAY")? {
+            config.retry.max_delay = delay;
+        }
+        if let Some(factor) = env.parse("RETRY_FACTOR", |s| {
+            s.parse().map_err(|_| "expected a number")
+        })? {
+            config.retry.factor = factor;
+        }
+        if let Some(retries) = env.parse("RETRY_MAX_RETRIES", |s| {
+            s.parse().map_err(|_| "expected a whole number")
+        })? {
+            config.retry.max_retries = retries;
+        }
+
+        Ok(config)
+    }
+
+    /// Provider builder with this configuration's API key and URL
+    ///
+    /// Use it to override the URL or HTTP client before building.
+    pub fn provider_builder(&self) -> ConfiguredProviderBuilder {
+        let builder = ConfiguredProvider::builder(self.provider, self.api_key.expose_secret());
+        match &self.url {
+            Some(url) => builder.url(url.clone()),
+            None => builder,
+        }
+    }
+
+    /// Proxy for the task builders' `with_proxy`, if one is configured
+    ///
+    /// Tasks that require a proxy (`Kasada`, `Imperva`, `CloudflareChallenge`)
+    /// take it in their constructor instead.
+    pub fn proxy(&self) -> Option<&ProxyConfig> {
+        self.proxy.as_ref()
+    }
+
+    /// Build the configured provider without retry wrapper or service
+    pub fn build_provider(&self) -> Result<ConfiguredProvider, SolverConfigError> {
+        self.provider_builder()
+            .build()
+            .map_err(SolverConfigError::Provider)
+    }
+
+    /// Validate the settings and build the provider, retry wrapper and service
+    pub fn build_service(&self) -> Result<ConfiguredService, SolverConfigError> {
+        self.service_with(self.build_provider()?)
+    }
+
+    /// Validate the settings and wrap an already built provider in the retry
+    /// wrapper and service
+    ///
+    /// Use with [`provider_builder`](Self::provider_builder) to override the
+    /// HTTP client.
+    pub fn service_with(
+        &self,
+        provider: ConfiguredProvider,
+    ) -> Result<ConfiguredService, SolverConfigError> {
+        self.service.validate()?;
+        let provider = CaptchaRetryableProvider::with_config(provider, self.retry.clone());
+        Ok(CaptchaSolverService::with_config(
+            provider,
+            self.service.clone(),
+        ))
+    }
+}
+
+/// Prefixed view over a set of environment variables
+struct EnvVars<'a> {
+    prefix: &'a str,
+    vars: &'a HashMap<String, String>,
+}
+
+impl EnvVars<'_> {
+    fn name(&self, key: &str) -> String {
+        if self.prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}_{key}", self.prefix)
+        }
+    }
+
+    /// Value of `{prefix}_{key}`, with blank values treated as unset
+    fn get(&self, key: &str) -> Option<&str> {
+        self.vars
+            .get(&self.name(key))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn parse<T, E: ToString>(
+        &self,
+        key: &str,
+        parse: impl FnOnce(&str) -> Result<T, E>,
+    ) -> Result<Option<T>, SolverConfigError> {
+        self.get(key)
+            .map(|value| {
+                parse(value).map_err(|e| SolverConfigError::InvalidEnv {
+                    name: self.name(key),
+                    message: e.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    fn duration(&self, key: &str) -> Result<Option<Duration>, SolverConfigError> {
+        self.parse(key, parse_duration)
+    }
+}
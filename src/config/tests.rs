@@ -0,0 +1,388 @@
+//! Tests for loading solver configuration.
+
+use super::{ConfiguredProvider, ProviderKind, SolverConfig, SolverConfigError};
+use crate::service::ConfigError;
+use std::time::Duration;
+
+#[cfg(feature = "capsolver")]
+#[test]
+fn test_from_toml() {
+    let config = SolverConfig::from_toml_str(
+        r#"
+        provider = "capsolver"
+        api_key = "CAP-123"
+        url = "http://127.0.0.1:8080"
+        proxy = "socks5:10.0.0.1:1080:user:pass"
+
+        [service]
+        timeout = "3m"
+        poll_interval = "1500ms"
+
+        [retry]
+        max_retries = 5
+        min_delay = "250ms"
+        factor = 1.5
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.provider, ProviderKind::Capsolver);
+    assert_eq!(config.url.as_ref().unwrap().port(), Some(8080));
+    assert_eq!(config.service.timeout, Duration::from_secs(180));
+    assert_eq!(config.service.poll_interval, Duration::from_millis(1500));
+    assert_eq!(config.retry.max_retries, 5);
+    assert_eq!(config.retry.min_delay, Duration::from_millis(250));
+    assert_eq!(config.retry.max_delay, Duration::from_secs(30));
+    assert_eq!(config.retry.factor, 1.5);
+    assert_eq!(config.proxy().unwrap().login.as_deref(), Some("user"));
+    assert!(!format!("{config:?}").contains("CAP-123"));
+}
+
+#[cfg(feature = "capmonster")]
+#[test]
+fn test_from_json_defaults() {
+    let config =
+        SolverConfig::from_json_str(r#"{"provider": "capmonster", "api_key": "key"}"#).unwrap();
+
+    assert_eq!(config.provider, ProviderKind::Capmonster);
+    assert!(config.url.is_none());
+    assert!(config.proxy().is_none());
+    assert_eq!(config.service.timeout, Duration::from_secs(120));
+    assert_eq!(config.retry.max_retries, 3);
+}
+
+#[test]
+fn test_invalid_documents() {
+    let err = SolverConfig::from_json_str(r#"{"provider": "nope", "api_key": "key"}"#).unwrap_err();
+    assert!(matches!(err, SolverConfigError::Json(_)));
+
+    let err =
+        SolverConfig::from_toml_str("provider = \"capsolver\"\napi_key = \"k\"\ntimeout = \"1m\"")
+            .unwrap_err();
+    assert!(err.to_string().contains("timeout"), "{err}");
+
+    let err =
+        SolverConfig::from_toml_str("api_key = \"k\"\n[service]\ntimeout = \"soon\"").unwrap_err();
+    assert!(matches!(err, SolverConfigError::Toml(_)));
+}
+
+#[cfg(feature = "rucaptcha")]
+#[test]
+fn test_from_path() {
+    let dir = std::env::temp_dir().join(format!("captcha-config-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("solver.toml");
+    std::fs::write(&path, "provider = \"rucaptcha\"\napi_key = \"key\"\n").unwrap();
+    assert_eq!(
+        SolverConfig::from_path(&path).unwrap().provider,
+        ProviderKind::Rucaptcha
+    );
+
+    let path = dir.join("solver.json");
+    std::fs::write(&path, r#"{"provider": "rucaptcha", "api_key": "key"}"#).unwrap();
+    assert!(SolverConfig::from_path(&path).is_ok());
+
+    let err = SolverConfig::from_path(dir.join("solver.yaml")).unwrap_err();
+    assert!(matches!(err, SolverConfigError::UnsupportedFormat { .. }));
+
+    let err = SolverConfig::from_path(dir.join("missing.toml")).unwrap_err();
+    assert!(matches!(err, SolverConfigError::Io { .. }));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "capsolver")]
+#[test]
+fn test_from_vars() {
+    let config = SolverConfig::from_vars(
+        "CAPTCHA",
+        [
+            ("CAPTCHA_PROVIDER", "Capsolver"),
+            ("CAPSOLVER_API_KEY", "fallback-key"),
+            ("CAPTCHA_TIMEOUT", "2m 30s"),
+            ("CAPTCHA_POLL_INTERVAL", "4s"),
+            ("CAPTCHA_RETRY_MAX_RETRIES", "0"),
+            ("CAPTCHA_RETRY_MAX_DELAY", "1m"),
+            ("CAPTCHA_PROXY", "http:10.0.0.1:3128"),
+            ("CAPTCHA_URL", " "),
+            ("UNRELATED", "value"),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(config.provider, ProviderKind::Capsolver);
+    assert_eq!(config.service.timeout, Duration::from_secs(150));
+    assert_eq!(config.service.poll_interval, Duration::from_secs(4));
+    assert_eq!(config.retry.max_retries, 0);
+    assert_eq!(config.retry.max_delay, Duration::from_secs(60));
+    assert_eq!(config.proxy.unwrap().port, 3128);
+    assert!(config.url.is_none());
+
+    use secrecy::ExposeSecret;
+    assert_eq!(config.api_key.expose_secret(), "fallback-key");
+
+    let config = SolverConfig::from_vars(
+        "CAPTCHA",
+        [
+            ("CAPTCHA_PROVIDER", "capsolver"),
+            ("CAPTCHA_API_KEY", "prefixed-key"),
+            ("CAPSOLVER_API_KEY", "fallback-key"),
+        ],
+    )
+    .unwrap();
+    assert_eq!(config.api_key.expose_secret(), "prefixed-key");
+}
+
+#[cfg(feature = "capsolver")]
+#[test]
+fn test_from_vars_errors() {
+    let err = SolverConfig::from_vars("APP", [("APP_API_KEY", "key")]).unwrap_err();
+    assert!(matches!(err, SolverConfigError::MissingEnv(ref name) if name == "APP_PROVIDER"));
+
+    let err = SolverConfig::from_vars("APP", [("APP_PROVIDER", "capsolver")]).unwrap_err();
+    assert!(matches!(err, SolverConfigError::MissingEnv(ref name) if name == "APP_API_KEY"));
+
+    let err = SolverConfig::from_vars("APP", [("APP_PROVIDER", "deathbycaptcha")]).unwrap_err();
+    assert!(err.to_string().contains("APP_PROVIDER"), "{err}");
+    assert!(err.to_string().contains("capsolver"), "{err}");
+
+    let err = SolverConfig::from_vars(
+        "APP",
+        [
+            ("APP_PROVIDER", "capsolver"),
+            ("APP_API_KEY", "key"),
+            ("APP_RETRY_FACTOR", "fast"),
+        ],
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        SolverConfigError::InvalidEnv { ref name, .. } if name == "APP_RETRY_FACTOR"
+    ));
+
+    let err = SolverConfig::from_vars(
+        "APP",
+        [
+            ("APP_PROVIDER", "capsolver"),
+            ("APP_API_KEY", "key"),
+            ("APP_TIMEOUT", "30"),
+        ],
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("missing unit"), "{err}");
+}
+
+#[cfg(feature = "capsolver")]
+#[test]
+fn test_build_service_validates_settings() {
+    let mut config = SolverConfig::new(ProviderKind::Capsolver, "key");
+    config.service.poll_interval = config.service.timeout;
+
+    let err = config.build_service().unwrap_err();
+    assert!(matches!(
+        err,
+        SolverConfigError::Service(ConfigError::PollIntervalExceedsTimeout { .. })
+    ));
+}
+
+#[cfg(feature = "capsolver")]
+#[tokio::test(start_paused = true)]
+async fn test_build_service_solves_with_retries() {
+    use crate::service::CaptchaSolverServiceTrait;
+    use crate::tasks::Turnstile;
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({"clientKey": "file-key"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 1,
+            "errorCode": "ERROR_SERVICE_UNAVALIABLE",
+            "errorDescription": "busy"
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({"errorId": 0, "taskId": "77"})),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "status": "ready",
+            "solution": {"token": "configured-token"}
+        })))
+        .mount(&server)
+        .await;
+
+    let config = SolverConfig::from_json_str(&format!(
+        r#"{{"provider": "capsolver", "api_key": "file-key", "url": "{}",
+            "service": {{"timeout": "1m", "poll_interval": "1s"}},
+            "retry": {{"min_delay": "10ms", "max_delay": "10ms"}}}}"#,
+        server.uri()
+    ))
+    .unwrap();
+    let service = config.build_service().unwrap();
+    assert_eq!(service.provider().inner().kind(), ProviderKind::Capsolver);
+
+    let solution = service
+        .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+        .await
+        .unwrap();
+    assert_eq!(
+        solution.as_turnstile().and_then(|s| s.token.as_deref()),
+        Some("configured-token")
+    );
+
+    let creates = server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|r| r.url.path() == "/createTask")
+        .count();
+    assert_eq!(creates, 2);
+}
+
+#[cfg(all(feature = "capsolver", feature = "rucaptcha"))]
+#[test]
+fn test_from_dsn() {
+    use secrecy::ExposeSecret;
+
+    let config: SolverConfig =
+        "capsolver://CAP-KEY@api.capsolver.com?timeout=120&poll_interval=2s&max_retries=1"
+            .parse()
+            .unwrap();
+    assert_eq!(config.provider, ProviderKind::Capsolver);
+    assert_eq!(config.api_key.expose_secret(), "CAP-KEY");
+    assert_eq!(config.url.unwrap().as_str(), "https://api.capsolver.com/");
+    assert_eq!(config.service.timeout, Duration::from_secs(120));
+    assert_eq!(config.service.poll_interval, Duration::from_secs(2));
+    assert_eq!(config.retry.max_retries, 1);
+
+    let config = SolverConfig::from_dsn("rucaptcha://KEY").unwrap();
+    assert_eq!(config.provider, ProviderKind::Rucaptcha);
+    assert_eq!(config.api_key.expose_secret(), "KEY");
+    assert!(config.url.is_none());
+
+    let config =
+        SolverConfig::from_dsn("capsolver+http://a@b@127.0.0.1:8080/v1?proxy=http:10.0.0.1:3128")
+            .unwrap();
+    assert_eq!(config.api_key.expose_secret(), "a@b");
+    assert_eq!(config.url.unwrap().as_str(), "http://127.0.0.1:8080/v1");
+    assert_eq!(config.proxy.unwrap().port, 3128);
+}
+
+#[cfg(feature = "capsolver")]
+#[test]
+fn test_from_dsn_percent_decoding() {
+    use secrecy::ExposeSecret;
+
+    let config = SolverConfig::from_dsn("capsolver://a%2Fb%40c%3Fd@api%2Ecapsolver.com").unwrap();
+    assert_eq!(config.api_key.expose_secret(), "a/b@c?d");
+    assert_eq!(config.url.unwrap().as_str(), "https://api.capsolver.com/");
+
+    let config = SolverConfig::from_dsn(
+        "capsolver://KEY?proxy=http:10.0.0.1:3128:us%3Der:p%26ss%3Dw%3Frd&timeout=%39%30",
+    )
+    .unwrap();
+    let proxy = config.proxy.unwrap();
+    assert_eq!(proxy.login.as_deref(), Some("us=er"));
+    assert_eq!(proxy.password.as_deref(), Some("p&ss=w?rd"));
+    assert_eq!(config.service.timeout, Duration::from_secs(90));
+}
+
+#[cfg(feature = "capsolver")]
+#[test]
+fn test_from_dsn_errors() {
+    let cases = [
+        ("capsolver:SECRET", "provider://"),
+        ("nope://SECRET", "unknown provider"),
+        ("capsolver+ftp://SECRET", "+ftp"),
+        ("capsolver://@api.capsolver.com", "missing API key"),
+        ("capsolver://SECRET@bad host", "invalid host"),
+        ("capsolver://SECRET?timeout=soon", "option 'timeout'"),
+        ("capsolver://SECRET?max_retries=-1", "option 'max_retries'"),
+        ("capsolver://SECRET?region=eu", "unknown option 'region'"),
+        ("capsolver://SECRET%FF", "API key is not valid UTF-8"),
+        (
+            "capsolver://SECRET?proxy=SECRET%FF",
+            "option value is not valid UTF-8",
+        ),
+    ];
+    for (dsn, expected) in cases {
+        let err = SolverConfig::from_dsn(dsn).unwrap_err();
+        let message = err.to_string();
+        assert!(matches!(err, SolverConfigError::InvalidDsn(_)), "{dsn}");
+        assert!(message.contains(expected), "{dsn}: {message}");
+        assert!(!message.contains("SECRET"), "{dsn}: {message}");
+    }
+}
+
+#[cfg(feature = "capmonster")]
+#[test]
+fn test_from_vars_dsn() {
+    use secrecy::ExposeSecret;
+
+    let config = SolverConfig::from_vars(
+        "CAPTCHA",
+        [
+            ("CAPTCHA_DSN", "capmonster://dsn-key?timeout=90"),
+            ("CAPTCHA_PROVIDER", "ignored"),
+            ("CAPTCHA_POLL_INTERVAL", "7s"),
+        ],
+    )
+    .unwrap();
+    assert_eq!(config.provider, ProviderKind::Capmonster);
+    assert_eq!(config.api_key.expose_secret(), "dsn-key");
+    assert_eq!(config.service.timeout, Duration::from_secs(90));
+    assert_eq!(config.service.poll_interval, Duration::from_secs(7));
+}
+
+#[cfg(feature = "capsolver")]
+#[tokio::test]
+async fn test_provider_builder_overrides() {
+    use crate::providers::traits::Provider;
+    use crate::tasks::Turnstile;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({"errorId": 0, "taskId": "5"})),
+        )
+        .mount(&server)
+        .await;
+
+    // The DSN points elsewhere; the builder override wins
+    let config = SolverConfig::from_dsn("capsolver+http://key@127.0.0.1:1").unwrap();
+    let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+    let provider = config
+        .provider_builder()
+        .url(server.uri().parse().unwrap())
+        .http_client(client)
+        .build()
+        .unwrap();
+    assert!(matches!(provider, ConfiguredProvider::Capsolver(_)));
+
+    let outcome = provider
+        .create_task(Turnstile::new("https://example.com", "site-key").into())
+        .await
+        .unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "5");
+
+    let service = config.service_with(provider).unwrap();
+    assert_eq!(service.config().timeout, Duration::from_secs(120));
+}
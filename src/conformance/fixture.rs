@@ -0,0 +1,227 @@
+//! The trait a provider implements to run the conformance suite.
+
+#![allow(async_fn_in_trait)]
+
+use crate::providers::traits::Provider;
+use crate::tasks::{
+    AudioToText, CaptchaTask, Capy, GridClassification, HCaptcha, ImageToText, Kasada, Lemin,
+    MtCaptcha, ReCaptchaV2, Rotate, Tencent, TextQuestion, Turnstile,
+};
+use crate::utils::proxy::ProxyConfig;
+use url::Url;
+use wiremock::MockServer;
+
+/// 1x1 transparent PNG, base64 encoded
+const PIXEL_PNG: &str =
+    "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+
+/// ID3 tag header standing in for an MP3 file, base64 encoded
+const MP3_HEADER: &str = "SUQzBAA=";
+
+/// Solution shapes the suite checks parsing for
+///
+/// Each kind names the value the fixture puts on the wire and reads back in
+/// [`ConformanceFixture::solution_value`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SolutionKind {
+    /// `ReCaptchaV2` task answered with a reCAPTCHA token
+    ReCaptcha,
+    /// `Turnstile` task answered with a Turnstile token
+    Turnstile,
+    /// `ImageToText` task answered with recognized text
+    ImageToText,
+    /// `HCaptcha` task answered with an hCaptcha token
+    HCaptcha,
+    /// `MtCaptcha` task answered with a bare token
+    Token,
+    /// `Kasada` task answered with cookies; the value is the `conformance` cookie
+    AntiBot,
+    /// `GridClassification` task; the value is comma-separated zero-based tiles
+    Grid,
+    /// `Rotate` task; the value is the angle in degrees
+    Rotate,
+    /// `Tencent` task; the value is the ticket
+    Tencent,
+    /// `Lemin` task; the value is the answer
+    Lemin,
+    /// `Capy` task; the value is the answer
+    Capy,
+    /// `AudioToText` task answered with recognized speech
+    AudioToText,
+    /// `TextQuestion` task answered with text
+    TextQuestion,
+}
+
+impl SolutionKind {
+    /// All solution kinds
+    pub const ALL: [SolutionKind; 13] = [
+        SolutionKind::ReCaptcha,
+        SolutionKind::Turnstile,
+        SolutionKind::ImageToText,
+        SolutionKind::HCaptcha,
+        SolutionKind::Token,
+        SolutionKind::AntiBot,
+        SolutionKind::Grid,
+        SolutionKind::Rotate,
+        SolutionKind::Tencent,
+        SolutionKind::Lemin,
+        SolutionKind::Capy,
+        SolutionKind::AudioToText,
+        SolutionKind::TextQuestion,
+    ];
+
+    /// Task the suite submits for this kind unless the fixture overrides it
+    pub fn default_task(&self) -> CaptchaTask {
+        match self {
+            SolutionKind::ReCaptcha => {
+                ReCaptchaV2::new("https://example.com", "conformance-site-key").into()
+            }
+            SolutionKind::Turnstile => {
+                Turnstile::new("https://example.com", "conformance-site-key").into()
+            }
+            SolutionKind::ImageToText => ImageToText::from_base64(PIXEL_PNG).into(),
+            SolutionKind::HCaptcha => {
+                HCaptcha::new("https://example.com", "conformance-site-key").into()
+            }
+            SolutionKind::Token => {
+                MtCaptcha::new("https://example.com", "conformance-site-key").into()
+            }
+            SolutionKind::AntiBot => {
+                Kasada::new("https://example.com", ProxyConfig::http("127.0.0.1", 3128)).into()
+            }
+            SolutionKind::Grid => GridClassification::from_grid_base64(PIXEL_PNG, 3, 3)
+                .expect("3x3 is a valid grid")
+                .into(),
+            SolutionKind::Rotate => Rotate::from_base64(PIXEL_PNG).into(),
+            SolutionKind::Tencent => Tencent::new("https://example.com", "conformance-app").into(),
+            SolutionKind::Lemin => Lemin::new(
+                "https://example.com",
+                "conformance-captcha",
+                "conformance-div",
+            )
+            .into(),
+            SolutionKind::Capy => Capy::new("https://example.com", "conformance-site-key").into(),
+            SolutionKind::AudioToText => AudioToText::from_base64(MP3_HEADER, "en").into(),
+            SolutionKind::TextQuestion => TextQuestion::new("2 + 2?").into(),
+        }
+    }
+}
+
+/// API failures whose [`RetryableError`](crate::RetryableError) answers the suite checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorScenario {
+    /// Rate limit, no free workers: retry the same request
+    Transient,
+    /// Wrong API key: never retry
+    InvalidKey,
+    /// Account out of funds: never retry
+    ZeroBalance,
+    /// Task could not be solved: retry with a fresh task
+    Unsolvable,
+}
+
+impl ErrorScenario {
+    /// All scenarios
+    pub const ALL: [ErrorScenario; 4] = [
+        ErrorScenario::Transient,
+        ErrorScenario::InvalidKey,
+        ErrorScenario::ZeroBalance,
+        ErrorScenario::Unsolvable,
+    ];
+
+    /// Expected `(is_retryable, should_retry_operation)`
+    pub fn expected(&self) -> (bool, bool) {
+        match self {
+            ErrorScenario::Transient => (true, true),
+            ErrorScenario::InvalidKey | ErrorScenario::ZeroBalance => (false, false),
+            ErrorScenario::Unsolvable => (false, true),
+        }
+    }
+
+    /// Whether the error is returned by `getTaskResult` rather than `createTask`
+    pub fn on_result(&self) -> bool {
+        matches!(self, ErrorScenario::Unsolvable)
+    }
+}
+
+/// Describes a provider's wire protocol to the conformance suite
+///
+/// The suite starts a fresh [`MockServer`] for every check, asks the fixture
+/// to mount the responses the check needs, then drives the provider built by
+/// [`provider`](Self::provider) against it.
+///
+/// Mount methods return `false` when the provider has no equivalent (e.g. no
+/// zero-balance error code); the check is then reported as skipped.
+pub trait ConformanceFixture {
+    /// The provider under test
+    type Provider: Provider;
+
+    /// Name used in the report
+    fn name(&self) -> &str;
+
+    /// Build the provider with `url` as its API base URL
+    fn provider(&self, url: &Url) -> Self::Provider;
+
+    /// Mount a `createTask` response that issues `task_id` for polling
+    async fn mount_created(&self, server: &MockServer, task_id: &str);
+
+    /// Mount a `getTaskResult` response saying `task_id` is still processing
+    async fn mount_processing(&self, server: &MockServer, task_id: &str);
+
+    /// Mount a `getTaskResult` response with a `kind` solution carrying `value`
+    ///
+    /// `value` is the token for token kinds, the text for recognition tasks;
+    /// see [`SolutionKind`] for the others.
+    async fn mount_solution(
+        &self,
+        server: &MockServer,
+        task_id: &str,
+        kind: SolutionKind,
+        value: &str,
+    ) -> bool;
+
+    /// Mount a `createTask` response that returns a `kind` solution immediately
+    ///
+    /// Only for providers that answer some tasks synchronously; the default
+    /// skips the check.
+    async fn mount_ready(
+        &self,
+        server: &MockServer,
+        task_id: &str,
+        kind: SolutionKind,
+        value: &str,
+    ) -> bool {
+        let _ = (server, task_id, kind, value);
+        false
+    }
+
+    /// Mount the API error for `scenario`
+    ///
+    /// On `createTask`, or on `getTaskResult` for `task_id` when
+    /// [`ErrorScenario::on_result`] is `true`.
+    async fn mount_error(
+        &self,
+        server: &MockServer,
+        task_id: &str,
+        scenario: ErrorScenario,
+    ) -> bool;
+
+    /// Extract the `kind` value from a solution, `None` if it is another kind
+    ///
+    /// Format it as [`SolutionKind`] describes, e.g. `"0,3"` for grid tiles.
+    fn solution_value(
+        &self,
+        kind: SolutionKind,
+        solution: <Self::Provider as Provider>::Solution,
+    ) -> Option<String>;
+
+    /// Task submitted for `kind`
+    fn task(&self, kind: SolutionKind) -> CaptchaTask {
+        kind.default_task()
+    }
+
+    /// Tasks the provider must reject with an `UnsupportedTaskError`
+    fn unsupported_tasks(&self) -> Vec<CaptchaTask> {
+        Vec::new()
+    }
+}
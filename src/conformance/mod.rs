@@ -0,0 +1,54 @@
+//! # Provider Conformance Suite
+//!
+//! Checks that a [`Provider`](crate::Provider) implementation honours the
+//! contracts the service relies on, against a `wiremock` [`MockServer`]:
+//!
+//! | Check | Contract |
+//! |-------|----------|
+//! | `create_task_pending` | A created task is `TaskCreationOutcome::Pending` with the issued ID |
+//! | `get_task_result_processing` | A task still processing polls as `Ok(None)` |
+//! | `solution_*` | Each solution kind the provider returns parses and exposes its value |
+//! | `create_task_ready_*` | Synchronous answers are `Ready` with solution and ID (optional) |
+//! | `error_*` | API errors have the expected `is_retryable` / `should_retry_operation` |
+//! | `error_transport` | Network failures are retryable |
+//! | `unsupported_task_*` | Unsupported tasks fail with `UnsupportedTaskError`, send nothing, and are not retried |
+//!
+//! Implement [`ConformanceFixture`] to describe the provider's wire format,
+//! then call [`assert_conformance`] from a test:
+//!
+//! ```rust,ignore
+//! use captcha_solvers::conformance::{
+//!     ConformanceFixture, ErrorScenario, SolutionKind, assert_conformance,
+//! };
+//!
+//! struct MyFixture;
+//!
+//! impl ConformanceFixture for MyFixture {
+//!     type Provider = MyProvider;
+//!     // mount_created, mount_processing, mount_solution, mount_error, ...
+//! }
+//!
+//! #[tokio::test]
+//! async fn my_provider_conforms() {
+//!     assert_conformance(&MyFixture).await;
+//! }
+//! ```
+//!
+//! The in-tree Capsolver, CapMonster and RuCaptcha providers run the same
+//! suite in this module's tests.
+//!
+//! [`MockServer`]: wiremock::MockServer
+
+mod fixture;
+mod report;
+mod suite;
+
+#[cfg(test)]
+mod tests;
+
+pub use fixture::{ConformanceFixture, ErrorScenario, SolutionKind};
+pub use report::{CheckOutcome, CheckResult, ConformanceReport};
+pub use suite::{TASK_ID, assert_conformance, run_conformance};
+
+/// Re-exported so fixtures need no direct `wiremock` dependency
+pub use wiremock;
@@ -0,0 +1,75 @@
+//! Conformance results.
+
+use std::fmt;
+
+/// Result of one check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckOutcome {
+    Passed,
+    Failed(String),
+    Skipped(String),
+}
+
+/// One named check and its result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    pub name: String,
+    pub outcome: CheckOutcome,
+}
+
+/// All check results for one fixture
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConformanceReport {
+    pub provider: String,
+    pub checks: Vec<CheckResult>,
+}
+
+impl ConformanceReport {
+    pub(crate) fn new(provider: impl Into<String>) -> Self {
+        Self {
+            provider: provider.into(),
+            checks: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, name: impl Into<String>, outcome: CheckOutcome) {
+        self.checks.push(CheckResult {
+            name: name.into(),
+            outcome,
+        });
+    }
+
+    /// Checks that failed
+    pub fn failures(&self) -> impl Iterator<Item = &CheckResult> {
+        self.checks
+            .iter()
+            .filter(|c| matches!(c.outcome, CheckOutcome::Failed(_)))
+    }
+
+    /// Returns `true` if no check failed
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Outcome of the check called `name`
+    pub fn outcome(&self, name: &str) -> Option<&CheckOutcome> {
+        self.checks
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| &c.outcome)
+    }
+}
+
+impl fmt::Display for ConformanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Conformance report for {}:", self.provider)?;
+        for check in &self.checks {
+            match &check.outcome {
+                CheckOutcome::Passed => writeln!(f, "  ok      {}", check.name)?,
+                CheckOutcome::Failed(reason) => writeln!(f, "  FAILED  {}: {reason}", check.name)?,
+                CheckOutcome::Skipped(reason) => writeln!(f, "  skipped {}: {reason}", check.name)?,
+            }
+        }
+        Ok(())
+    }
+}
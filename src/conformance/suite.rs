@@ -0,0 +1,307 @@
+//! The conformance checks.
+
+use super::fixture::{ConformanceFixture, ErrorScenario, SolutionKind};
+use super::report::{CheckOutcome, ConformanceReport};
+use crate::errors::{RetryableError, UnsupportedTaskError};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use std::error::Error as StdError;
+use url::Url;
+use wiremock::MockServer;
+
+/// Task ID the fixtures are asked to issue
+///
+/// Numeric, so it fits providers with numeric task IDs as well.
+pub const TASK_ID: &str = "73915";
+
+/// Address nothing listens on, for the transport failure check
+const UNREACHABLE_URL: &str = "http://127.0.0.1:1";
+
+type Check = Result<CheckOutcome, String>;
+
+fn fail<T>(message: impl Into<String>) -> Result<T, String> {
+    Err(message.into())
+}
+
+/// Run every check against `fixture` and collect the results
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[tokio::test]
+/// async fn my_provider_conforms() {
+///     let report = captcha_solvers::conformance::run_conformance(&MyFixture).await;
+///     assert!(report.is_success(), "{report}");
+/// }
+/// ```
+pub async fn run_conformance<F: ConformanceFixture>(fixture: &F) -> ConformanceReport {
+    let mut report = ConformanceReport::new(fixture.name());
+
+    report.push("create_task_pending", finish(check_pending(fixture).await));
+    report.push(
+        "get_task_result_processing",
+        finish(check_processing(fixture).await),
+    );
+
+    for kind in SolutionKind::ALL {
+        report.push(
+            format!("solution_{}", kind_name(kind)),
+            finish(check_solution(fixture, kind).await),
+        );
+        report.push(
+            format!("create_task_ready_{}", kind_name(kind)),
+            finish(check_ready(fixture, kind).await),
+        );
+    }
+
+    for scenario in ErrorScenario::ALL {
+        report.push(
+            format!("error_{}", scenario_name(scenario)),
+            finish(check_error(fixture, scenario).await),
+        );
+    }
+    report.push("error_transport", finish(check_transport(fixture).await));
+
+    let unsupported = fixture.unsupported_tasks();
+    if unsupported.is_empty() {
+        report.push(
+            "unsupported_tasks",
+            CheckOutcome::Skipped("fixture lists no unsupported tasks".to_string()),
+        );
+    }
+    for (i, task) in unsupported.into_iter().enumerate() {
+        report.push(
+            format!("unsupported_task_{i}_{task}"),
+            finish(check_unsupported(fixture, task).await),
+        );
+    }
+
+    report
+}
+
+/// Run every check and panic with the report if any failed
+pub async fn assert_conformance<F: ConformanceFixture>(fixture: &F) -> ConformanceReport {
+    let report = run_conformance(fixture).await;
+    assert!(report.is_success(), "{report}");
+    report
+}
+
+fn finish(check: Check) -> CheckOutcome {
+    check.unwrap_or_else(CheckOutcome::Failed)
+}
+
+fn skipped(reason: &str) -> Check {
+    Ok(CheckOutcome::Skipped(reason.to_string()))
+}
+
+async fn start<F: ConformanceFixture>(fixture: &F) -> (MockServer, F::Provider) {
+    let server = MockServer::start().await;
+    let url = Url::parse(&server.uri()).expect("mock server URI is a valid URL");
+    let provider = fixture.provider(&url);
+    (server, provider)
+}
+
+/// `createTask` issuing an ID must be `Pending` with that ID
+async fn check_pending<F: ConformanceFixture>(fixture: &F) -> Check {
+    let (server, provider) = start(fixture).await;
+    fixture.mount_created(&server, TASK_ID).await;
+
+    let outcome = provider
+        .create_task(fixture.task(SolutionKind::ReCaptcha))
+        .await
+        .map_err(|e| format!("create_task failed: {e}"))?;
+    match outcome {
+        TaskCreationOutcome::Pending(id) if id.as_ref() == TASK_ID => Ok(CheckOutcome::Passed),
+        TaskCreationOutcome::Pending(id) => fail(format!("expected task ID {TASK_ID}, got {id}")),
+        TaskCreationOutcome::Ready { .. } => fail("expected Pending, got Ready"),
+    }
+}
+
+/// A task still processing must poll as `Ok(None)`
+async fn check_processing<F: ConformanceFixture>(fixture: &F) -> Check {
+    let (server, provider) = start(fixture).await;
+    fixture.mount_processing(&server, TASK_ID).await;
+
+    match provider.get_task_result(&TASK_ID.into()).await {
+        Ok(None) => Ok(CheckOutcome::Passed),
+        Ok(Some(_)) => fail("expected Ok(None), got a solution"),
+        Err(e) => fail(format!("expected Ok(None), got error: {e}")),
+    }
+}
+
+/// A ready task must parse into a solution of the right kind
+async fn check_solution<F: ConformanceFixture>(fixture: &F, kind: SolutionKind) -> Check {
+    let (server, provider) = start(fixture).await;
+    let value = sample_value(kind, "conformance");
+    if !fixture.mount_solution(&server, TASK_ID, kind, &value).await {
+        return skipped("kind not supported by the provider");
+    }
+
+    let solution = match provider.get_task_result(&TASK_ID.into()).await {
+        Ok(Some(solution)) => solution,
+        Ok(None) => return fail("expected a solution, got Ok(None)"),
+        Err(e) => return fail(format!("expected a solution, got error: {e}")),
+    };
+    expect_value(fixture, kind, solution, &value)
+}
+
+/// A synchronous answer must be `Ready` with the solution and task ID
+async fn check_ready<F: ConformanceFixture>(fixture: &F, kind: SolutionKind) -> Check {
+    let (server, provider) = start(fixture).await;
+    let value = sample_value(kind, "conformance-ready");
+    if !fixture.mount_ready(&server, TASK_ID, kind, &value).await {
+        return skipped("provider does not answer this kind synchronously");
+    }
+
+    let outcome = provider
+        .create_task(fixture.task(kind))
+        .await
+        .map_err(|e| format!("create_task failed: {e}"))?;
+    match outcome {
+        TaskCreationOutcome::Ready { task_id, solution } => {
+            if task_id.as_ref() != TASK_ID {
+                return fail(format!("expected task ID {TASK_ID}, got {task_id}"));
+            }
+            expect_value(fixture, kind, solution, &value)
+        }
+        TaskCreationOutcome::Pending(_) => fail("expected Ready, got Pending"),
+    }
+}
+
+fn expect_value<F: ConformanceFixture>(
+    fixture: &F,
+    kind: SolutionKind,
+    solution: <F::Provider as Provider>::Solution,
+    expected: &str,
+) -> Check {
+    match fixture.solution_value(kind, solution) {
+        Some(value) if value == expected => Ok(CheckOutcome::Passed),
+        Some(value) => fail(format!("expected {expected:?}, parsed {value:?}")),
+        None => fail(format!("solution did not parse as {}", kind_name(kind))),
+    }
+}
+
+/// API errors must carry the expected retry classification
+async fn check_error<F: ConformanceFixture>(fixture: &F, scenario: ErrorScenario) -> Check {
+    let (server, provider) = start(fixture).await;
+    if !fixture.mount_error(&server, TASK_ID, scenario).await {
+        return skipped("provider has no equivalent error");
+    }
+
+    let err = if scenario.on_result() {
+        match provider.get_task_result(&TASK_ID.into()).await {
+            Err(e) => e,
+            Ok(_) => return fail("get_task_result succeeded, expected an error"),
+        }
+    } else {
+        match provider
+            .create_task(fixture.task(SolutionKind::ReCaptcha))
+            .await
+        {
+            Err(e) => e,
+            Ok(_) => return fail("create_task succeeded, expected an error"),
+        }
+    };
+    expect_classification(&err, scenario.expected())
+}
+
+/// Network failures must be retryable
+async fn check_transport<F: ConformanceFixture>(fixture: &F) -> Check {
+    let url = Url::parse(UNREACHABLE_URL).expect("valid URL");
+    let provider = fixture.provider(&url);
+    match provider
+        .create_task(fixture.task(SolutionKind::ReCaptcha))
+        .await
+    {
+        Err(e) => expect_classification(&e, (true, true)),
+        Ok(_) => fail(format!("create_task against {UNREACHABLE_URL} succeeded")),
+    }
+}
+
+fn expect_classification<E: RetryableError + std::fmt::Display>(
+    err: &E,
+    (retryable, retry_operation): (bool, bool),
+) -> Check {
+    let actual = (err.is_retryable(), err.should_retry_operation());
+    if actual == (retryable, retry_operation) {
+        Ok(CheckOutcome::Passed)
+    } else {
+        fail(format!(
+            "\"{err}\": expected is_retryable={retryable}, should_retry_operation={retry_operation}, \
+             got {}, {}",
+            actual.0, actual.1
+        ))
+    }
+}
+
+/// Unsupported tasks must fail with `UnsupportedTaskError` before any request
+async fn check_unsupported<F: ConformanceFixture>(
+    fixture: &F,
+    task: crate::tasks::CaptchaTask,
+) -> Check {
+    let (server, provider) = start(fixture).await;
+    let err = match provider.create_task(task).await {
+        Err(e) => e,
+        Ok(_) => return fail("create_task succeeded, expected UnsupportedTaskError"),
+    };
+
+    if find_unsupported(&err).is_none() {
+        return fail(format!("\"{err}\" does not wrap an UnsupportedTaskError"));
+    }
+    if err.is_retryable() || err.should_retry_operation() {
+        return fail("unsupported task errors must not be retried");
+    }
+    let requests = server.received_requests().await.unwrap_or_default();
+    if !requests.is_empty() {
+        return fail(format!("{} request(s) reached the server", requests.len()));
+    }
+    Ok(CheckOutcome::Passed)
+}
+
+fn find_unsupported<'a>(err: &'a (dyn StdError + 'static)) -> Option<&'a UnsupportedTaskError> {
+    let mut current = Some(err);
+    while let Some(err) = current {
+        if let Some(unsupported) = err.downcast_ref::<UnsupportedTaskError>() {
+            return Some(unsupported);
+        }
+        current = err.source();
+    }
+    None
+}
+
+/// Value the fixture is asked to serve for `kind`
+///
+/// Numeric kinds get fixed values; `prefix` keeps the others distinct per check.
+fn sample_value(kind: SolutionKind, prefix: &str) -> String {
+    match kind {
+        SolutionKind::Grid => "0,3".to_string(),
+        SolutionKind::Rotate => "90".to_string(),
+        _ => format!("{prefix}-{}", kind_name(kind)),
+    }
+}
+
+fn kind_name(kind: SolutionKind) -> &'static str {
+    match kind {
+        SolutionKind::ReCaptcha => "recaptcha",
+        SolutionKind::Turnstile => "turnstile",
+        SolutionKind::ImageToText => "image_to_text",
+        SolutionKind::HCaptcha => "hcaptcha",
+        SolutionKind::Token => "token",
+        SolutionKind::AntiBot => "anti_bot",
+        SolutionKind::Grid => "grid",
+        SolutionKind::Rotate => "rotate",
+        SolutionKind::Tencent => "tencent",
+        SolutionKind::Lemin => "lemin",
+        SolutionKind::Capy => "capy",
+        SolutionKind::AudioToText => "audio_to_text",
+        SolutionKind::TextQuestion => "text_question",
+    }
+}
+
+fn scenario_name(scenario: ErrorScenario) -> &'static str {
+    match scenario {
+        ErrorScenario::Transient => "transient",
+        ErrorScenario::InvalidKey => "invalid_key",
+        ErrorScenario::ZeroBalance => "zero_balance",
+        ErrorScenario::Unsolvable => "unsolvable",
+    }
+}
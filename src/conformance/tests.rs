@@ -0,0 +1,489 @@
+//! The conformance suite run against the in-tree createTask providers.
+
+use super::fixture::{ConformanceFixture, ErrorScenario, SolutionKind};
+use super::report::CheckOutcome;
+use super::suite::assert_conformance;
+use crate::tasks::{CaptchaTask, HCaptcha, TextQuestion, TurnstileChallenge};
+use serde_json::{Value, json};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount(server: &MockServer, endpoint: &str, body: Value) {
+    Mock::given(method("POST"))
+        .and(path(endpoint))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+/// Solution shapes every provider shares; grid and rotate answers differ
+fn solution_json(kind: SolutionKind, value: &str) -> Option<Value> {
+    let solution = match kind {
+        SolutionKind::ReCaptcha | SolutionKind::HCaptcha => {
+            json!({ "gRecaptchaResponse": value })
+        }
+        SolutionKind::Turnstile | SolutionKind::Token => json!({ "token": value }),
+        SolutionKind::ImageToText | SolutionKind::AudioToText | SolutionKind::TextQuestion => {
+            json!({ "text": value })
+        }
+        SolutionKind::AntiBot => json!({ "cookies": { "conformance": value } }),
+        SolutionKind::Tencent => json!({ "ticket": value, "randstr": "@abc", "appid": "app" }),
+        SolutionKind::Lemin => json!({ "answer": value, "challenge_id": "challenge" }),
+        SolutionKind::Capy => {
+            json!({ "captchakey": "captcha", "challengekey": "challenge", "answer": value })
+        }
+        SolutionKind::Grid | SolutionKind::Rotate => return None,
+    };
+    Some(solution)
+}
+
+/// Zero-based tiles from a grid sample value
+fn tiles(value: &str) -> Vec<u32> {
+    value.split(',').map(|tile| tile.parse().unwrap()).collect()
+}
+
+fn join_tiles(tiles: &[u32]) -> String {
+    tiles
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn angle(value: &str) -> f64 {
+    value.parse().unwrap()
+}
+
+fn error_json(code: &str) -> Value {
+    json!({ "errorId": 1, "errorCode": code, "errorDescription": "conformance" })
+}
+
+/// Fixture body shared by the createTask/getTaskResult JSON providers
+macro_rules! json_api_fixture {
+    (errors: $errors:expr, solutions: $solutions:expr) => {
+        async fn mount_created(&self, server: &MockServer, task_id: &str) {
+            mount(server, "/createTask", json!({ "errorId": 0, "taskId": task_id })).await;
+        }
+
+        async fn mount_processing(&self, server: &MockServer, _task_id: &str) {
+            mount(
+                server,
+                "/getTaskResult",
+                json!({ "errorId": 0, "status": "processing" }),
+            )
+            .await;
+        }
+
+        async fn mount_solution(
+            &self,
+            server: &MockServer,
+            _task_id: &str,
+            kind: SolutionKind,
+            value: &str,
+        ) -> bool {
+            let solutions: fn(SolutionKind, &str) -> Option<Value> = $solutions;
+            let Some(solution) = solutions(kind, value) else {
+                return false;
+            };
+            let body = json!({
+                "errorId": 0,
+                "status": "ready",
+                "solution": solution,
+            });
+            mount(server, "/getTaskResult", body).await;
+            true
+        }
+
+        async fn mount_error(
+            &self,
+            server: &MockServer,
+            _task_id: &str,
+            scenario: ErrorScenario,
+        ) -> bool {
+            let errors: fn(ErrorScenario) -> &'static str = $errors;
+            let endpoint = if scenario.on_result() {
+                "/getTaskResult"
+            } else {
+                "/createTask"
+            };
+            mount(server, endpoint, error_json(errors(scenario))).await;
+            true
+        }
+    };
+}
+
+// ---------------------------------------------------------------------------
+// Capsolver
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "capsolver")]
+struct CapsolverFixture;
+
+#[cfg(feature = "capsolver")]
+impl ConformanceFixture for CapsolverFixture {
+    type Provider = crate::providers::capsolver::CapsolverProvider;
+
+    fn name(&self) -> &str {
+        "Capsolver"
+    }
+
+    fn provider(&self, url: &url::Url) -> Self::Provider {
+        Self::Provider::with_url(url.clone(), "conformance-key").unwrap()
+    }
+
+    json_api_fixture!(errors: |scenario| {
+        match scenario {
+            ErrorScenario::Transient => "ERROR_RATE_LIMIT",
+            ErrorScenario::InvalidKey => "ERROR_KEY_DENIED_ACCESS",
+            ErrorScenario::ZeroBalance => "ERROR_ZERO_BALANCE",
+            ErrorScenario::Unsolvable => "ERROR_CAPTCHA_UNSOLVABLE",
+        }
+    }, solutions: |kind, value| {
+        match kind {
+            SolutionKind::Grid => Some(json!({ "objects": tiles(value) })),
+            SolutionKind::Rotate => Some(json!({ "angle": angle(value) })),
+            SolutionKind::HCaptcha
+            | SolutionKind::Token
+            | SolutionKind::Tencent
+            | SolutionKind::Lemin
+            | SolutionKind::Capy
+            | SolutionKind::TextQuestion => None,
+            _ => solution_json(kind, value),
+        }
+    });
+
+    async fn mount_ready(
+        &self,
+        server: &MockServer,
+        task_id: &str,
+        kind: SolutionKind,
+        value: &str,
+    ) -> bool {
+        let solution = match kind {
+            SolutionKind::ImageToText | SolutionKind::AudioToText => solution_json(kind, value),
+            SolutionKind::Grid => Some(json!({ "objects": tiles(value) })),
+            SolutionKind::Rotate => Some(json!({ "angle": angle(value) })),
+            _ => None,
+        };
+        let Some(solution) = solution else {
+            return false;
+        };
+        let body = json!({
+            "errorId": 0,
+            "taskId": task_id,
+            "status": "ready",
+            "solution": solution,
+        });
+        mount(server, "/createTask", body).await;
+        true
+    }
+
+    fn solution_value(
+        &self,
+        kind: SolutionKind,
+        solution: crate::providers::capsolver::CapsolverSolution,
+    ) -> Option<String> {
+        use crate::providers::capsolver::CapsolverSolution as S;
+        match (kind, solution) {
+            (SolutionKind::ReCaptcha, S::ReCaptcha(s)) => Some(s.g_recaptcha_response),
+            (SolutionKind::Turnstile, S::Turnstile(s)) => s.token,
+            (SolutionKind::ImageToText, S::ImageToText(s)) => Some(s.text),
+            (SolutionKind::AudioToText, s) => s.try_into_audio_to_text().ok().map(|s| s.text),
+            (SolutionKind::AntiBot, S::AntiBot(s)) => s.cookie("conformance").map(str::to_string),
+            (SolutionKind::Grid, S::GridClassification(s)) => Some(join_tiles(s.tiles())),
+            (SolutionKind::Rotate, S::Rotate(s)) => Some(s.angle().to_string()),
+            _ => None,
+        }
+    }
+
+    fn unsupported_tasks(&self) -> Vec<CaptchaTask> {
+        vec![
+            HCaptcha::new("https://example.com", "site-key").into(),
+            TextQuestion::new("2 + 2?").into(),
+        ]
+    }
+}
+
+#[cfg(feature = "capsolver")]
+#[tokio::test]
+async fn test_capsolver_conformance() {
+    let report = assert_conformance(&CapsolverFixture).await;
+    for check in [
+        "create_task_ready_image_to_text",
+        "create_task_ready_grid",
+        "create_task_ready_rotate",
+        "create_task_ready_audio_to_text",
+        "solution_anti_bot",
+    ] {
+        assert_eq!(
+            report.outcome(check),
+            Some(&CheckOutcome::Passed),
+            "{check}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CapMonster
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "capmonster")]
+struct CapmonsterFixture;
+
+#[cfg(feature = "capmonster")]
+impl ConformanceFixture for CapmonsterFixture {
+    type Provider = crate::providers::capmonster::CapmonsterProvider;
+
+    fn name(&self) -> &str {
+        "CapMonster"
+    }
+
+    fn provider(&self, url: &url::Url) -> Self::Provider {
+        Self::Provider::with_url(url.clone(), "conformance-key").unwrap()
+    }
+
+    json_api_fixture!(errors: |scenario| {
+        match scenario {
+            ErrorScenario::Transient => "ERROR_NO_SLOT_AVAILABLE",
+            ErrorScenario::InvalidKey => "ERROR_KEY_DOES_NOT_EXIST",
+            ErrorScenario::ZeroBalance => "ERROR_ZERO_BALANCE",
+            ErrorScenario::Unsolvable => "ERROR_CAPTCHA_UNSOLVABLE",
+        }
+    }, solutions: |kind, value| {
+        match kind {
+            SolutionKind::Grid => {
+                let selected = tiles(value);
+                let answer: Vec<bool> = (0..9).map(|tile| selected.contains(&tile)).collect();
+                Some(json!({ "answer": answer }))
+            }
+            SolutionKind::Rotate => Some(json!({ "answer": [angle(value)] })),
+            SolutionKind::HCaptcha
+            | SolutionKind::Tencent
+            | SolutionKind::Lemin
+            | SolutionKind::Capy
+            | SolutionKind::AudioToText
+            | SolutionKind::TextQuestion => None,
+            _ => solution_json(kind, value),
+        }
+    });
+
+    fn solution_value(
+        &self,
+        kind: SolutionKind,
+        solution: crate::providers::capmonster::CapmonsterSolution,
+    ) -> Option<String> {
+        use crate::providers::capmonster::CapmonsterSolution as S;
+        match (kind, solution) {
+            (SolutionKind::ReCaptcha, S::ReCaptcha(s)) => Some(s.g_recaptcha_response),
+            (SolutionKind::Turnstile, S::Turnstile(s)) => s.token,
+            (SolutionKind::ImageToText, S::ImageToText(s)) => Some(s.text),
+            (SolutionKind::Token, s) => s.try_into_token().ok().map(|s| s.token),
+            (SolutionKind::AntiBot, S::AntiBot(s)) => s.cookie("conformance").map(str::to_string),
+            (SolutionKind::Grid, S::GridClassification(s)) => Some(join_tiles(s.tiles())),
+            (SolutionKind::Rotate, S::Rotate(s)) => Some(s.angle().to_string()),
+            _ => None,
+        }
+    }
+
+    fn unsupported_tasks(&self) -> Vec<CaptchaTask> {
+        vec![
+            HCaptcha::new("https://example.com", "site-key").into(),
+            TextQuestion::new("2 + 2?").into(),
+        ]
+    }
+}
+
+#[cfg(feature = "capmonster")]
+#[tokio::test]
+async fn test_capmonster_conformance() {
+    let report = assert_conformance(&CapmonsterFixture).await;
+    for check in [
+        "solution_token",
+        "solution_anti_bot",
+        "solution_grid",
+        "solution_rotate",
+    ] {
+        assert_eq!(
+            report.outcome(check),
+            Some(&CheckOutcome::Passed),
+            "{check}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RuCaptcha
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "rucaptcha")]
+struct RucaptchaFixture;
+
+#[cfg(feature = "rucaptcha")]
+impl ConformanceFixture for RucaptchaFixture {
+    type Provider = crate::providers::rucaptcha::RucaptchaProvider;
+
+    fn name(&self) -> &str {
+        "RuCaptcha"
+    }
+
+    fn provider(&self, url: &url::Url) -> Self::Provider {
+        Self::Provider::with_url(url.clone(), "conformance-key").unwrap()
+    }
+
+    json_api_fixture!(errors: |scenario| {
+        match scenario {
+            ErrorScenario::Transient => "ERROR_NO_SLOT_AVAILABLE",
+            ErrorScenario::InvalidKey => "ERROR_KEY_DOES_NOT_EXIST",
+            ErrorScenario::ZeroBalance => "ERROR_ZERO_BALANCE",
+            ErrorScenario::Unsolvable => "ERROR_CAPTCHA_UNSOLVABLE",
+        }
+    }, solutions: |kind, value| {
+        match kind {
+            SolutionKind::Grid => {
+                let click: Vec<u32> = tiles(value).into_iter().map(|tile| tile + 1).collect();
+                Some(json!({ "click": click }))
+            }
+            SolutionKind::Rotate => Some(json!({ "rotate": angle(value) })),
+            SolutionKind::HCaptcha => {
+                Some(json!({ "gRecaptchaResponse": value, "respKey": "E0_conformance" }))
+            }
+            SolutionKind::AudioToText => Some(json!({ "token": value })),
+            SolutionKind::AntiBot => None,
+            _ => solution_json(kind, value),
+        }
+    });
+
+    fn solution_value(
+        &self,
+        kind: SolutionKind,
+        solution: crate::providers::rucaptcha::RucaptchaSolution,
+    ) -> Option<String> {
+        use crate::providers::rucaptcha::RucaptchaSolution as S;
+        match (kind, solution) {
+            (SolutionKind::ReCaptcha, S::ReCaptcha(s)) => Some(s.g_recaptcha_response),
+            (SolutionKind::Turnstile, S::Turnstile(s)) => s.token,
+            (SolutionKind::ImageToText, S::ImageToText(s)) => Some(s.text),
+            (SolutionKind::HCaptcha, s) => {
+                s.try_into_hcaptcha().ok().map(|s| s.g_recaptcha_response)
+            }
+            (SolutionKind::Token, s) => s.try_into_token().ok().map(|s| s.token),
+            (SolutionKind::AudioToText, s) => s.try_into_audio_to_text().ok().map(|s| s.text),
+            (SolutionKind::TextQuestion, s) => s.try_into_text_question().ok().map(|s| s.text),
+            (SolutionKind::Grid, S::GridClassification(s)) => Some(join_tiles(s.tiles())),
+            (SolutionKind::Rotate, S::Rotate(s)) => Some(s.angle().to_string()),
+            (SolutionKind::Tencent, S::Tencent(s)) => Some(s.ticket),
+            (SolutionKind::Lemin, S::Lemin(s)) => Some(s.answer),
+            (SolutionKind::Capy, S::Capy(s)) => Some(s.answer),
+            _ => None,
+        }
+    }
+
+    fn unsupported_tasks(&self) -> Vec<CaptchaTask> {
+        vec![
+            TurnstileChallenge::token("https://example.com", "key", "action", "data", "page", "ua")
+                .into(),
+        ]
+    }
+}
+
+#[cfg(feature = "rucaptcha")]
+#[tokio::test]
+async fn test_rucaptcha_conformance() {
+    let report = assert_conformance(&RucaptchaFixture).await;
+    for check in [
+        "solution_hcaptcha",
+        "solution_token",
+        "solution_grid",
+        "solution_rotate",
+        "solution_tencent",
+        "solution_lemin",
+        "solution_capy",
+        "solution_audio_to_text",
+        "solution_text_question",
+    ] {
+        assert_eq!(
+            report.outcome(check),
+            Some(&CheckOutcome::Passed),
+            "{check}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// The suite catches broken providers
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "mock")]
+mod broken {
+    use super::*;
+    use crate::providers::mock::{MockError, MockOutcome, MockProvider, MockSolution};
+
+    /// Ignores the server: always answers `Ready`, with transient errors
+    /// classified as permanent
+    pub(super) struct BrokenFixture;
+
+    impl ConformanceFixture for BrokenFixture {
+        type Provider = MockProvider;
+
+        fn name(&self) -> &str {
+            "Broken"
+        }
+
+        fn provider(&self, _url: &url::Url) -> MockProvider {
+            let provider = MockProvider::new();
+            for _ in 0..16 {
+                provider.push(MockOutcome::ready("wrong"));
+            }
+            provider.push(MockOutcome::error(MockError::permanent("boom")));
+            provider
+        }
+
+        async fn mount_created(&self, _server: &MockServer, _task_id: &str) {}
+
+        async fn mount_processing(&self, _server: &MockServer, _task_id: &str) {}
+
+        async fn mount_solution(
+            &self,
+            _server: &MockServer,
+            _task_id: &str,
+            _kind: SolutionKind,
+            _value: &str,
+        ) -> bool {
+            true
+        }
+
+        async fn mount_error(
+            &self,
+            _server: &MockServer,
+            _task_id: &str,
+            _scenario: ErrorScenario,
+        ) -> bool {
+            false
+        }
+
+        fn solution_value(&self, _kind: SolutionKind, solution: MockSolution) -> Option<String> {
+            Some(solution.token().to_string())
+        }
+    }
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn test_broken_provider_is_reported() {
+    let report = super::suite::run_conformance(&broken::BrokenFixture).await;
+
+    assert!(!report.is_success());
+    assert!(matches!(
+        report.outcome("create_task_pending"),
+        Some(CheckOutcome::Failed(reason)) if reason.contains("got Ready")
+    ));
+    assert!(matches!(
+        report.outcome("get_task_result_processing"),
+        Some(CheckOutcome::Failed(_))
+    ));
+    assert!(matches!(
+        report.outcome("error_invalid_key"),
+        Some(CheckOutcome::Skipped(_))
+    ));
+    assert!(report.to_string().contains("FAILED  create_task_pending"));
+}
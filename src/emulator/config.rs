@@ -0,0 +1,150 @@
+//! Emulator configuration.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Which provider's JSON protocol the emulator speaks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Flavor {
+    /// Capsolver: string task IDs, `ImageToTextTask` solved in `createTask`
+    Capsolver,
+    /// CapMonster Cloud: numeric task IDs
+    Capmonster,
+    /// RuCaptcha / 2Captcha API v2: numeric task IDs
+    Rucaptcha,
+}
+
+impl Flavor {
+    /// All supported flavors
+    pub const ALL: [Flavor; 3] = [Flavor::Capsolver, Flavor::Capmonster, Flavor::Rucaptcha];
+
+    /// Lowercase name, as accepted by [`FromStr`]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Flavor::Capsolver => "capsolver",
+            Flavor::Capmonster => "capmonster",
+            Flavor::Rucaptcha => "rucaptcha",
+        }
+    }
+}
+
+impl fmt::Display for Flavor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Flavor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Flavor::ALL
+            .into_iter()
+            .find(|flavor| flavor.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                format!("unknown flavor '{s}' (expected capsolver, capmonster or rucaptcha)")
+            })
+    }
+}
+
+/// API endpoint a scripted error is returned from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    /// `POST /createTask`
+    CreateTask,
+    /// `POST /getTaskResult`
+    GetTaskResult,
+}
+
+impl Endpoint {
+    pub(crate) fn from_path(path: &str) -> Option<Self> {
+        match path.trim_end_matches('/') {
+            "/createTask" => Some(Endpoint::CreateTask),
+            "/getTaskResult" => Some(Endpoint::GetTaskResult),
+            _ => None,
+        }
+    }
+}
+
+/// Configuration for an [`Emulator`](super::Emulator)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::emulator::{EmulatorConfig, Endpoint, Flavor};
+/// use std::time::Duration;
+///
+/// let config = EmulatorConfig::new(Flavor::Capmonster)
+///     .with_api_key("test-key")
+///     .with_ready_after_polls(2)
+///     .with_error(Endpoint::CreateTask, "ERROR_NO_SLOT_AVAILABLE");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmulatorConfig {
+    pub(crate) flavor: Flavor,
+    pub(crate) api_key: Option<String>,
+    pub(crate) ready_after_polls: u32,
+    pub(crate) ready_after: Duration,
+    pub(crate) text_answer: String,
+    pub(crate) errors: Vec<(Endpoint, String)>,
+}
+
+impl EmulatorConfig {
+    /// Default answer for image-to-text tasks
+    pub const DEFAULT_TEXT_ANSWER: &'static str = "emulated";
+
+    /// Configuration for `flavor` that accepts any non-empty client key and
+    /// answers "processing" once before each task is ready
+    pub fn new(flavor: Flavor) -> Self {
+        Self {
+            flavor,
+            api_key: None,
+            ready_after_polls: 1,
+            ready_after: Duration::ZERO,
+            text_answer: Self::DEFAULT_TEXT_ANSWER.to_string(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Only accept this client key; others get the flavor's invalid-key error
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Number of "processing" answers before a task is ready
+    pub fn with_ready_after_polls(mut self, polls: u32) -> Self {
+        self.ready_after_polls = polls;
+        self
+    }
+
+    /// Minimum time between task creation and the task being ready
+    ///
+    /// Measured on the tokio clock. Combined with
+    /// [`with_ready_after_polls`](Self::with_ready_after_polls): both must pass.
+    pub fn with_ready_after(mut self, delay: Duration) -> Self {
+        self.ready_after = delay;
+        self
+    }
+
+    /// Answer returned for image-to-text tasks
+    pub fn with_text_answer(mut self, text: impl Into<String>) -> Self {
+        self.text_answer = text.into();
+        self
+    }
+
+    /// Queue an error code for the next request to `endpoint`
+    ///
+    /// Queued errors are returned in order, one per request, after the client
+    /// key is checked.
+    pub fn with_error(mut self, endpoint: Endpoint, code: impl Into<String>) -> Self {
+        self.errors.push((endpoint, code.into()));
+        self
+    }
+
+    /// The protocol flavor
+    pub fn flavor(&self) -> Flavor {
+        self.flavor
+    }
+}
@@ -0,0 +1,144 @@
+//! Minimal HTTP/1.1 transport: one JSON request per connection.
+
+use super::state::EmulatorState;
+use serde_json::Value;
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const MAX_HEAD: usize = 16 * 1024;
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+/// Time a client gets to send its whole request
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Bounds of the pause after a failed `accept()` (e.g. out of file descriptors)
+const MIN_ACCEPT_BACKOFF: Duration = Duration::from_millis(5);
+const MAX_ACCEPT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Accept connections until the task is aborted
+///
+/// Failed accepts are retried after a pause that doubles up to one second,
+/// so a persistent error does not spin the runtime.
+pub(crate) async fn serve(listener: TcpListener, state: Arc<EmulatorState>) {
+    let mut backoff = MIN_ACCEPT_BACKOFF;
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => {
+                backoff = MIN_ACCEPT_BACKOFF;
+                stream
+            }
+            Err(_) => {
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_ACCEPT_BACKOFF);
+                continue;
+            }
+        };
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            // A client hanging up mid-request is not the emulator's problem
+            let _ = handle_connection(stream, &state).await;
+        });
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, state: &EmulatorState) -> io::Result<()> {
+    let request = tokio::time::timeout(READ_TIMEOUT, read_request(&mut stream))
+        .await
+        .map_err(|_| io::Error::from(io::ErrorKind::TimedOut))??;
+    let (status, body) = match request {
+        Some(Request { method, path, body }) if method == "POST" => state.handle(&path, &body),
+        Some(_) => (405, error("method not allowed")),
+        None => (400, error("malformed request")),
+    };
+
+    let body = body.to_string();
+    let head = format!(
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        reason(status),
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+/// Read the request line, headers and a `Content-Length` body
+///
+/// Returns `None` for anything this transport does not understand.
+async fn read_request(stream: &mut TcpStream) -> io::Result<Option<Request>> {
+    let mut buf = Vec::with_capacity(1024);
+    let head_end = loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+        if buf.len() > MAX_HEAD {
+            return Ok(None);
+        }
+        let mut chunk = [0u8; 4096];
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let Ok(head) = std::str::from_utf8(&buf[..head_end]) else {
+        return Ok(None);
+    };
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Ok(None);
+    };
+    let method = method.to_string();
+    let path = target.split('?').next().unwrap_or(target).to_string();
+
+    let mut content_length = 0;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            match value.trim().parse::<usize>() {
+                Ok(len) if len <= MAX_BODY => content_length = len,
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    let mut body = buf.split_off(head_end + 4);
+    while body.len() < content_length {
+        let mut chunk = vec![0u8; (content_length - body.len()).min(64 * 1024)];
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+    body.truncate(content_length);
+
+    Ok(Some(Request { method, path, body }))
+}
+
+fn error(message: &str) -> Value {
+    serde_json::json!({ "errorId": 1, "errorCode": "ERROR_BAD_REQUEST", "errorDescription": message })
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Unknown",
+    }
+}
@@ -0,0 +1,57 @@
+//! # Provider API Emulator
+//!
+//! A local HTTP server that speaks the `createTask`/`getTaskResult` JSON
+//! protocol of Capsolver, CapMonster Cloud or RuCaptcha, so end-to-end tests
+//! can run the real provider implementations offline.
+//!
+//! The emulator:
+//!
+//! - checks the `clientKey` (any non-empty key, or the one configured)
+//! - validates each task against the flavor's rules: known task type (every
+//!   type the in-tree provider for that flavor sends), required fields, base64
+//!   images and proxy fields
+//! - issues task IDs in the flavor's format (strings for Capsolver, numbers
+//!   otherwise)
+//! - answers `processing` until a task's schedule has passed, then `ready`
+//!   with a generated solution in the flavor's shape (e.g. grid tiles as
+//!   Capsolver `objects`, CapMonster `answer` or RuCaptcha `click`)
+//! - returns scripted error codes from either endpoint
+//!
+//! Rejections use the error codes the real service returns for the same
+//! mistake, e.g. `ERROR_KEY_DENIED_ACCESS` on Capsolver and
+//! `ERROR_KEY_DOES_NOT_EXIST` on the others.
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::capmonster::CapmonsterProvider;
+//! use captcha_solvers::emulator::{Emulator, EmulatorConfig, Endpoint, Flavor};
+//! use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ReCaptchaV2};
+//!
+//! let config = EmulatorConfig::new(Flavor::Capmonster)
+//!     .with_api_key("test-key")
+//!     .with_ready_after_polls(2);
+//! let emulator = Emulator::start(config).await?;
+//! emulator.push_error(Endpoint::GetTaskResult, "ERROR_CAPTCHA_UNSOLVABLE");
+//!
+//! let provider = CapmonsterProvider::with_url(emulator.url(), "test-key")?;
+//! let service = CaptchaSolverService::new(provider);
+//! ```
+//!
+//! The `captcha-emulator` binary runs the same server standalone:
+//!
+//! ```bash
+//! cargo run --features emulator --bin captcha-emulator -- --flavor rucaptcha --port 8080
+//! ```
+
+mod config;
+mod http;
+mod rules;
+mod server;
+mod state;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{EmulatorConfig, Endpoint, Flavor};
+pub use server::{Emulator, EmulatorHandle};
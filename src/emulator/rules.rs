@@ -0,0 +1,295 @@
+//! Per-provider task validation rules and error codes.
+
+use super::config::Flavor;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Map, Value};
+
+/// Kind of solution a task type produces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SolutionKind {
+    ReCaptcha,
+    HCaptcha,
+    Turnstile,
+    /// `cf_clearance` cookie (Cloudflare Challenge)
+    Clearance,
+    /// Bare `token` (MTCaptcha, Friendly Captcha, Prosopo and similar widgets)
+    Token,
+    AntiBot,
+    Text,
+    /// Recognized speech; RuCaptcha returns it as `token`
+    Audio,
+    Grid,
+    Rotate,
+    Tencent,
+    Lemin,
+    Capy,
+}
+
+/// Whether a task type takes `proxyType`/`proxyAddress`/`proxyPort`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ProxyRule {
+    Forbidden,
+    Optional,
+    Required,
+}
+
+/// Validation rule for one task type
+#[derive(Debug)]
+pub(crate) struct TaskRule {
+    pub(crate) task_type: &'static str,
+    /// `class` value for task types shared by several captchas (CapMonster)
+    pub(crate) class: Option<&'static str>,
+    pub(crate) kind: SolutionKind,
+    pub(crate) required: &'static [&'static str],
+    pub(crate) proxy: ProxyRule,
+}
+
+const fn rule(
+    task_type: &'static str,
+    kind: SolutionKind,
+    required: &'static [&'static str],
+    proxy: ProxyRule,
+) -> TaskRule {
+    TaskRule {
+        task_type,
+        class: None,
+        kind,
+        required,
+        proxy,
+    }
+}
+
+impl TaskRule {
+    const fn class(mut self, class: &'static str) -> Self {
+        self.class = Some(class);
+        self
+    }
+}
+
+const SITE: &[&str] = &["websiteURL", "websiteKey"];
+const BODY: &[&str] = &["body"];
+const IMAGE: &[&str] = &["image"];
+const AUDIO: &[&str] = &["body", "lang"];
+const PAGE: &[&str] = &["websiteURL"];
+const CUTCAPTCHA: &[&str] = &["websiteURL", "miseryKey", "dataApiKey"];
+const TENCENT: &[&str] = &["websiteURL", "appId"];
+const LEMIN: &[&str] = &["websiteURL", "captchaId", "divId"];
+const KEYCAPTCHA: &[&str] = &[
+    "websiteURL",
+    "s_s_c_user_id",
+    "s_s_c_session_id",
+    "s_s_c_web_server_sign",
+    "s_s_c_web_server_sign2",
+];
+
+use ProxyRule::{Forbidden, Optional, Required};
+use SolutionKind::{
+    AntiBot, Audio, Capy, Clearance, Grid, HCaptcha, Lemin, ReCaptcha, Rotate, Tencent, Text,
+    Token, Turnstile,
+};
+
+const CAPSOLVER_RULES: &[TaskRule] = &[
+    rule("ReCaptchaV2TaskProxyLess", ReCaptcha, SITE, Forbidden),
+    rule("ReCaptchaV2EnterpriseTask", ReCaptcha, SITE, Required),
+    rule(
+        "ReCaptchaV2EnterpriseTaskProxyLess",
+        ReCaptcha,
+        SITE,
+        Forbidden,
+    ),
+    rule("ReCaptchaV3Task", ReCaptcha, SITE, Required),
+    rule("ReCaptchaV3TaskProxyLess", ReCaptcha, SITE, Forbidden),
+    rule("ReCaptchaV3EnterpriseTask", ReCaptcha, SITE, Required),
+    rule(
+        "ReCaptchaV3EnterpriseTaskProxyLess",
+        ReCaptcha,
+        SITE,
+        Forbidden,
+    ),
+    rule("AntiTurnstileTaskProxyLess", Turnstile, SITE, Forbidden),
+    rule("AntiCloudflareTask", Clearance, PAGE, Required),
+    rule("ImageToTextTask", Text, BODY, Forbidden),
+    rule("ReCaptchaV2Classification", Grid, IMAGE, Forbidden),
+    rule("VisionEngine", Rotate, &["module", "image"], Forbidden),
+    rule("AudioRecognition", Audio, AUDIO, Forbidden),
+    rule("AntiImpervaTask", AntiBot, &["websiteUrl"], Required),
+    rule("AntiKasadaTask", AntiBot, &["pageURL"], Required),
+];
+
+const CAPMONSTER_RULES: &[TaskRule] = &[
+    rule("RecaptchaV2Task", ReCaptcha, SITE, Optional),
+    rule("RecaptchaV2EnterpriseTask", ReCaptcha, SITE, Optional),
+    rule("RecaptchaV3TaskProxyless", ReCaptcha, SITE, Forbidden),
+    rule("RecaptchaV3EnterpriseTask", ReCaptcha, SITE, Optional),
+    rule("TurnstileTask", Turnstile, SITE, Optional),
+    rule("ImageToTextTask", Text, BODY, Forbidden),
+    rule("ComplexImageTask", Grid, &[], Forbidden).class("recaptcha"),
+    rule("ComplexImageTask", Rotate, &[], Forbidden).class("recognition"),
+    rule("MTCaptchaTask", Token, SITE, Optional),
+    rule("ProsopoTask", Token, SITE, Optional),
+    rule("CustomTask", Token, SITE, Optional).class("altcha"),
+    rule("CustomTask", AntiBot, PAGE, Required).class("Imperva"),
+    rule("CustomTask", AntiBot, PAGE, Required).class("Kasada"),
+];
+
+const RUCAPTCHA_RULES: &[TaskRule] = &[
+    rule("RecaptchaV2TaskProxyless", ReCaptcha, SITE, Forbidden),
+    rule("RecaptchaV2Task", ReCaptcha, SITE, Required),
+    rule(
+        "RecaptchaV2EnterpriseTaskProxyless",
+        ReCaptcha,
+        SITE,
+        Forbidden,
+    ),
+    rule("RecaptchaV2EnterpriseTask", ReCaptcha, SITE, Required),
+    rule("RecaptchaV3TaskProxyless", ReCaptcha, SITE, Forbidden),
+    rule("TurnstileTaskProxyless", Turnstile, SITE, Forbidden),
+    rule("TurnstileTask", Turnstile, SITE, Required),
+    rule("ImageToTextTask", Text, BODY, Forbidden),
+    rule("TextCaptchaTask", Text, &["comment"], Forbidden),
+    rule("GridTask", Grid, BODY, Forbidden),
+    rule("RotateTask", Rotate, BODY, Forbidden),
+    rule("AudioTask", Audio, AUDIO, Forbidden),
+    rule("MtCaptchaTaskProxyless", Token, SITE, Forbidden),
+    rule("MtCaptchaTask", Token, SITE, Required),
+    rule("FriendlyCaptchaTaskProxyless", Token, SITE, Forbidden),
+    rule("FriendlyCaptchaTask", Token, SITE, Required),
+    rule("CutCaptchaTaskProxyless", Token, CUTCAPTCHA, Forbidden),
+    rule("CutCaptchaTask", Token, CUTCAPTCHA, Required),
+    rule("YandexSmartCaptchaTaskProxyless", Token, SITE, Forbidden),
+    rule("YandexSmartCaptchaTask", Token, SITE, Required),
+    rule("TencentTaskProxyless", Tencent, TENCENT, Forbidden),
+    rule("TencentTask", Tencent, TENCENT, Required),
+    rule("LeminTaskProxyless", Lemin, LEMIN, Forbidden),
+    rule("LeminTask", Lemin, LEMIN, Required),
+    rule("KeyCaptchaTaskProxyless", Token, KEYCAPTCHA, Forbidden),
+    rule("KeyCaptchaTask", Token, KEYCAPTCHA, Required),
+    rule("CapyTaskProxyless", Capy, SITE, Forbidden),
+    rule("CapyTask", Capy, SITE, Required),
+    rule("HCaptchaTaskProxyless", HCaptcha, SITE, Forbidden),
+    rule("HCaptchaTask", HCaptcha, SITE, Required),
+    rule("ProsopoTaskProxyless", Token, SITE, Forbidden),
+    rule("ProsopoTask", Token, SITE, Required),
+    rule("CaptchaFoxTaskProxyless", Token, SITE, Forbidden),
+    rule("CaptchaFoxTask", Token, SITE, Required),
+];
+
+const PROXY_FIELDS: [&str; 3] = ["proxyType", "proxyAddress", "proxyPort"];
+
+/// Reason a request is rejected, mapped to each flavor's error code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Rejection {
+    BadRequest,
+    InvalidKey,
+    UnsupportedTask,
+    InvalidTaskData,
+    UnknownTaskId,
+}
+
+impl Flavor {
+    pub(crate) fn rules(&self) -> &'static [TaskRule] {
+        match self {
+            Flavor::Capsolver => CAPSOLVER_RULES,
+            Flavor::Capmonster => CAPMONSTER_RULES,
+            Flavor::Rucaptcha => RUCAPTCHA_RULES,
+        }
+    }
+
+    /// Error code this flavor returns for `rejection`
+    pub(crate) fn error_code(&self, rejection: Rejection) -> &'static str {
+        match (self, rejection) {
+            (Flavor::Rucaptcha, Rejection::BadRequest | Rejection::InvalidTaskData) => {
+                "ERROR_BAD_PARAMETERS"
+            }
+            (_, Rejection::BadRequest) => "ERROR_BAD_REQUEST",
+            (_, Rejection::InvalidTaskData) => "ERROR_INVALID_TASK_DATA",
+            (Flavor::Capsolver, Rejection::InvalidKey) => "ERROR_KEY_DENIED_ACCESS",
+            (_, Rejection::InvalidKey) => "ERROR_KEY_DOES_NOT_EXIST",
+            (_, Rejection::UnsupportedTask) => "ERROR_TASK_NOT_SUPPORTED",
+            (Flavor::Rucaptcha, Rejection::UnknownTaskId) => "ERROR_NO_SUCH_CAPCHA_ID",
+            (_, Rejection::UnknownTaskId) => "ERROR_TASKID_INVALID",
+        }
+    }
+}
+
+/// Look up the rule for `task` and check its fields
+pub(crate) fn validate(
+    flavor: Flavor,
+    task: &Map<String, Value>,
+) -> Result<&'static TaskRule, (Rejection, String)> {
+    let task_type = task.get("type").and_then(Value::as_str).ok_or((
+        Rejection::InvalidTaskData,
+        "task.type is missing".to_string(),
+    ))?;
+    let class = task.get("class").and_then(Value::as_str);
+    let rule = flavor
+        .rules()
+        .iter()
+        .find(|rule| {
+            rule.task_type == task_type && rule.class.is_none_or(|rule| class == Some(rule))
+        })
+        .ok_or_else(|| {
+            let task_type = match class {
+                Some(class) => format!("{task_type} class {class}"),
+                None => task_type.to_string(),
+            };
+            (
+                Rejection::UnsupportedTask,
+                format!("{task_type} is not supported by {flavor}"),
+            )
+        })?;
+
+    for field in rule.required {
+        let present = task
+            .get(*field)
+            .and_then(Value::as_str)
+            .is_some_and(|value| !value.is_empty());
+        if !present {
+            return Err((Rejection::InvalidTaskData, format!("{field} is required")));
+        }
+    }
+
+    for field in ["body", "image", "imagesBase64"] {
+        let valid = match task.get(field) {
+            Some(Value::String(data)) => STANDARD.decode(data).is_ok(),
+            Some(Value::Array(items)) => {
+                !items.is_empty()
+                    && items.iter().all(|item| {
+                        item.as_str()
+                            .is_some_and(|data| STANDARD.decode(data).is_ok())
+                    })
+            }
+            _ => true,
+        };
+        if !valid {
+            return Err((
+                Rejection::InvalidTaskData,
+                format!("{field} is not valid base64"),
+            ));
+        }
+    }
+
+    let proxy_fields = PROXY_FIELDS
+        .iter()
+        .filter(|f| task.contains_key(**f))
+        .count();
+    match (rule.proxy, proxy_fields) {
+        (Forbidden, 0) | (Optional, 0) => {}
+        (Forbidden, _) => {
+            return Err((
+                Rejection::InvalidTaskData,
+                format!("{task_type} does not take a proxy"),
+            ));
+        }
+        (_, n) if n < PROXY_FIELDS.len() => {
+            return Err((
+                Rejection::InvalidTaskData,
+                "proxyType, proxyAddress and proxyPort are required".to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    Ok(rule)
+}
@@ -0,0 +1,107 @@
+//! Emulator server lifecycle.
+
+use super::config::{EmulatorConfig, Endpoint, Flavor};
+use super::http::serve;
+use super::state::EmulatorState;
+use serde_json::Value;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use url::Url;
+
+/// Local HTTP server speaking a provider's `createTask`/`getTaskResult` protocol
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::capsolver::CapsolverProvider;
+/// use captcha_solvers::emulator::{Emulator, EmulatorConfig, Flavor};
+/// use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, Turnstile};
+///
+/// let emulator = Emulator::start(EmulatorConfig::new(Flavor::Capsolver)).await?;
+/// let provider = CapsolverProvider::with_url(emulator.url(), "any-key")?;
+/// let service = CaptchaSolverService::new(provider);
+///
+/// let solution = service
+///     .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+///     .await?;
+/// ```
+#[derive(Debug)]
+pub struct Emulator;
+
+impl Emulator {
+    /// Start an emulator on an ephemeral port on `127.0.0.1`
+    pub async fn start(config: EmulatorConfig) -> io::Result<EmulatorHandle> {
+        Self::bind(config, SocketAddr::from(([127, 0, 0, 1], 0))).await
+    }
+
+    /// Start an emulator on `addr`
+    pub async fn bind(config: EmulatorConfig, addr: SocketAddr) -> io::Result<EmulatorHandle> {
+        let listener = TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(EmulatorState::new(config));
+        let task = tokio::spawn(serve(listener, Arc::clone(&state)));
+        Ok(EmulatorHandle {
+            addr,
+            state,
+            task: Some(task),
+        })
+    }
+}
+
+/// Running emulator; the server stops when the handle is dropped
+#[derive(Debug)]
+pub struct EmulatorHandle {
+    addr: SocketAddr,
+    state: Arc<EmulatorState>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl EmulatorHandle {
+    /// Base URL to pass to a provider's `with_url`
+    pub fn url(&self) -> Url {
+        Url::parse(&format!("http://{}", self.addr)).expect("socket address is a valid URL")
+    }
+
+    /// Address the server listens on
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The protocol flavor
+    pub fn flavor(&self) -> Flavor {
+        self.state.flavor()
+    }
+
+    /// Queue an error code for the next request to `endpoint`
+    pub fn push_error(&self, endpoint: Endpoint, code: impl Into<String>) {
+        self.state.push_error(endpoint, code.into());
+    }
+
+    /// Task payloads accepted by `createTask`, in order
+    pub fn received_tasks(&self) -> Vec<Value> {
+        self.state.received_tasks()
+    }
+
+    /// Serve until the process exits
+    pub async fn wait(mut self) {
+        if let Some(task) = self.task.take() {
+            let _ = task.await;
+        }
+    }
+
+    /// Stop the server
+    pub fn shutdown(self) {
+        drop(self);
+    }
+}
+
+impl Drop for EmulatorHandle {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Task registry and request handling, independent of the HTTP transport.
+
+use super::config::{EmulatorConfig, Endpoint, Flavor};
+use super::rules::{Rejection, SolutionKind, TaskRule, validate};
+use serde_json::{Map, Value, json};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+use tokio::time::Instant;
+
+/// `userAgent` reported with browser-bound solutions
+const EMULATOR_USER_AGENT: &str = "captcha-emulator";
+
+#[derive(Debug)]
+struct Task {
+    rule: &'static TaskRule,
+    seq: u64,
+    polls: u32,
+    created: Instant,
+}
+
+#[derive(Debug)]
+struct Inner {
+    tasks: HashMap<String, Task>,
+    received: Vec<Value>,
+    errors: VecDeque<(Endpoint, String)>,
+    next_seq: u64,
+}
+
+/// Shared emulator state: the configuration plus the tasks issued so far
+#[derive(Debug)]
+pub(crate) struct EmulatorState {
+    config: EmulatorConfig,
+    inner: Mutex<Inner>,
+}
+
+impl EmulatorState {
+    pub(crate) fn new(config: EmulatorConfig) -> Self {
+        let errors = config.errors.iter().cloned().collect();
+        Self {
+            config,
+            inner: Mutex::new(Inner {
+                tasks: HashMap::new(),
+                received: Vec::new(),
+                errors,
+                next_seq: 0,
+            }),
+        }
+    }
+
+    pub(crate) fn flavor(&self) -> Flavor {
+        self.config.flavor
+    }
+
+    pub(crate) fn push_error(&self, endpoint: Endpoint, code: String) {
+        self.inner().errors.push_back((endpoint, code));
+    }
+
+    pub(crate) fn received_tasks(&self) -> Vec<Value> {
+        self.inner().received.clone()
+    }
+
+    fn inner(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Answer one API request; returns the HTTP status and JSON body
+    pub(crate) fn handle(&self, path: &str, body: &[u8]) -> (u16, Value) {
+        let Some(endpoint) = Endpoint::from_path(path) else {
+            return (
+                404,
+                self.error_body("ERROR_NOT_FOUND", &format!("no route for {path}")),
+            );
+        };
+
+        let request = match serde_json::from_slice::<Value>(body) {
+            Ok(Value::Object(request)) => request,
+            _ => return self.reject(Rejection::BadRequest, "body must be a JSON object"),
+        };
+
+        let key = request.get("clientKey").and_then(Value::as_str);
+        let key_ok = match (&self.config.api_key, key) {
+            (Some(expected), Some(key)) => expected == key,
+            (None, Some(key)) => !key.is_empty(),
+            (_, None) => false,
+        };
+        if !key_ok {
+            return self.reject(Rejection::InvalidKey, "invalid clientKey");
+        }
+
+        if let Some(code) = self.take_error(endpoint) {
+            return (200, self.error_body(&code, "scripted error"));
+        }
+
+        match endpoint {
+            Endpoint::CreateTask => self.create_task(&request),
+            Endpoint::GetTaskResult => self.get_task_result(&request),
+        }
+    }
+
+    fn take_error(&self, endpoint: Endpoint) -> Option<String> {
+        let mut inner = self.inner();
+        let index = inner.errors.iter().position(|(e, _)| *e == endpoint)?;
+        inner.errors.remove(index).map(|(_, code)| code)
+    }
+
+    fn create_task(&self, request: &Map<String, Value>) -> (u16, Value) {
+        let Some(task) = request.get("task").and_then(Value::as_object) else {
+            return self.reject(Rejection::InvalidTaskData, "task is required");
+        };
+        let rule = match validate(self.flavor(), task) {
+            Ok(rule) => rule,
+            Err((rejection, message)) => return self.reject(rejection, &message),
+        };
+
+        let mut inner = self.inner();
+        inner.next_seq += 1;
+        let seq = inner.next_seq;
+        inner.received.push(Value::Object(task.clone()));
+
+        let (key, task_id) = self.task_id(seq);
+        // Capsolver answers recognition tasks synchronously in the createTask response
+        if self.flavor() == Flavor::Capsolver
+            && matches!(
+                rule.kind,
+                SolutionKind::Text
+                    | SolutionKind::Audio
+                    | SolutionKind::Grid
+                    | SolutionKind::Rotate
+            )
+        {
+            return (
+                200,
+                json!({
+                    "errorId": 0,
+                    "taskId": task_id,
+                    "status": "ready",
+                    "solution": self.solution(rule, seq),
+                }),
+            );
+        }
+
+        inner.tasks.insert(
+            key,
+            Task {
+                rule,
+                seq,
+                polls: 0,
+                created: Instant::now(),
+            },
+        );
+        (200, json!({ "errorId": 0, "taskId": task_id }))
+    }
+
+    fn get_task_result(&self, request: &Map<String, Value>) -> (u16, Value) {
+        let task_id = match request.get("taskId") {
+            Some(Value::String(id)) => id.clone(),
+            Some(Value::Number(id)) => id.to_string(),
+            _ => return self.reject(Rejection::UnknownTaskId, "taskId is required"),
+        };
+
+        let mut inner = self.inner();
+        let Some(task) = inner.tasks.get_mut(&task_id) else {
+            return self.reject(Rejection::UnknownTaskId, "task not found");
+        };
+
+        task.polls += 1;
+        let ready = task.polls > self.config.ready_after_polls
+            && task.created.elapsed() >= self.config.ready_after;
+        if !ready {
+            return (200, json!({ "errorId": 0, "status": "processing" }));
+        }
+
+        (
+            200,
+            json!({
+                "errorId": 0,
+                "status": "ready",
+                "solution": self.solution(task.rule, task.seq),
+            }),
+        )
+    }
+
+    /// Registry key and wire value of a new task ID
+    ///
+    /// Capsolver issues opaque string IDs, the others numeric IDs.
+    fn task_id(&self, seq: u64) -> (String, Value) {
+        match self.flavor() {
+            Flavor::Capsolver => {
+                let id = format!("emu-{seq:08x}");
+                (id.clone(), Value::String(id))
+            }
+            Flavor::Capmonster | Flavor::Rucaptcha => {
+                let id = 1_000_000 + seq;
+                (id.to_string(), Value::from(id))
+            }
+        }
+    }
+
+    /// Solution in the shape the flavor returns for the rule's task type
+    ///
+    /// Grid answers select tiles 0 and 3 and rotate answers are 90 degrees.
+    fn solution(&self, rule: &TaskRule, seq: u64) -> Value {
+        let token = format!("emulated-{}-{seq}", self.flavor());
+        let text = &self.config.text_answer;
+        match (rule.kind, self.flavor()) {
+            (SolutionKind::ReCaptcha, _) => json!({ "gRecaptchaResponse": token }),
+            (SolutionKind::HCaptcha, _) => json!({
+                "gRecaptchaResponse": token,
+                "respKey": format!("E0_emulated-{seq}"),
+                "userAgent": EMULATOR_USER_AGENT,
+            }),
+            (SolutionKind::Turnstile, _) => {
+                json!({ "token": token, "userAgent": EMULATOR_USER_AGENT })
+            }
+            (SolutionKind::Clearance, _) => json!({
+                "cookies": { "cf_clearance": token },
+                "userAgent": EMULATOR_USER_AGENT,
+            }),
+            (SolutionKind::Token, _) => json!({ "token": token }),
+            (SolutionKind::AntiBot, _) => json!({
+                "cookies": { "emulated": token },
+                "userAgent": EMULATOR_USER_AGENT,
+            }),
+            (SolutionKind::Text, _) => json!({ "text": text }),
+            (SolutionKind::Audio, Flavor::Rucaptcha) => json!({ "token": text }),
+            (SolutionKind::Audio, _) => json!({ "text": text }),
+            (SolutionKind::Grid, Flavor::Capsolver) => json!({ "objects": [0, 3] }),
+            (SolutionKind::Grid, Flavor::Capmonster) => {
+                json!({ "answer": [true, false, false, true] })
+            }
+            (SolutionKind::Grid, Flavor::Rucaptcha) => json!({ "click": [1, 4] }),
+            (SolutionKind::Rotate, Flavor::Capsolver) => json!({ "angle": 90 }),
+            (SolutionKind::Rotate, Flavor::Capmonster) => json!({ "answer": [90] }),
+            (SolutionKind::Rotate, Flavor::Rucaptcha) => json!({ "rotate": 90 }),
+            (SolutionKind::Tencent, _) => json!({ "ticket": token, "randstr": format!("@{seq}") }),
+            (SolutionKind::Lemin, _) => json!({
+                "answer": token,
+                "challenge_id": format!("emulated-challenge-{seq}"),
+            }),
+            (SolutionKind::Capy, _) => json!({
+                "captchakey": format!("emulated-key-{seq}"),
+                "challengekey": format!("emulated-challenge-{seq}"),
+                "answer": token,
+            }),
+        }
+    }
+
+    fn reject(&self, rejection: Rejection, message: &str) -> (u16, Value) {
+        (
+            200,
+            self.error_body(self.flavor().error_code(rejection), message),
+        )
+    }
+
+    fn error_body(&self, code: &str, message: &str) -> Value {
+        json!({
+            "errorId": 1,
+            "errorCode": code,
+            "errorDescription": message,
+        })
+    }
+}
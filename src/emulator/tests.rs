@@ -0,0 +1,513 @@
+//! End-to-end tests running the real providers against the emulator.
+
+use super::config::{EmulatorConfig, Endpoint, Flavor};
+use super::server::{Emulator, EmulatorHandle};
+use crate::service::{CaptchaSolverService, CaptchaSolverServiceConfig, CaptchaSolverServiceTrait};
+use crate::tasks::{ImageToText, ReCaptchaV2, Turnstile};
+use serde_json::{Value, json};
+use std::time::Duration;
+
+fn config() -> CaptchaSolverServiceConfig {
+    CaptchaSolverServiceConfig::builder()
+        .timeout(Duration::from_secs(10))
+        .poll_interval(Duration::from_millis(20))
+        .build()
+}
+
+async fn post(emulator: &EmulatorHandle, path: &str, body: Value) -> Value {
+    reqwest::Client::new()
+        .post(emulator.url().join(path).unwrap())
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[cfg(feature = "capsolver")]
+#[tokio::test]
+async fn test_capsolver_turnstile_and_image() {
+    use crate::providers::capsolver::CapsolverProvider;
+
+    let emulator = Emulator::start(EmulatorConfig::new(Flavor::Capsolver).with_text_answer("4271"))
+        .await
+        .unwrap();
+    let provider = CapsolverProvider::with_url(emulator.url(), "key").unwrap();
+    let service = CaptchaSolverService::with_config(provider, config());
+
+    let solution = service
+        .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+        .await
+        .unwrap();
+    assert_eq!(
+        solution.into_turnstile().token(),
+        Some("emulated-capsolver-1")
+    );
+
+    let solution = service
+        .solve_captcha(ImageToText::from_bytes(b"png"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_image_to_text().text(), "4271");
+
+    let received = emulator.received_tasks();
+    assert_eq!(received[0]["type"], "AntiTurnstileTaskProxyLess");
+    assert_eq!(received[0]["websiteKey"], "site-key");
+    assert_eq!(received[1]["type"], "ImageToTextTask");
+}
+
+#[cfg(feature = "capsolver")]
+#[tokio::test]
+async fn test_capsolver_recognition_and_anti_bot_tasks() {
+    use crate::providers::capsolver::CapsolverProvider;
+    use crate::tasks::{AudioToText, CloudflareChallenge, GridClassification, Kasada, Rotate};
+    use crate::utils::proxy::ProxyConfig;
+
+    let emulator = Emulator::start(EmulatorConfig::new(Flavor::Capsolver).with_text_answer("42"))
+        .await
+        .unwrap();
+    let provider = CapsolverProvider::with_url(emulator.url(), "key").unwrap();
+    let service = CaptchaSolverService::with_config(provider, config());
+    let proxy = || ProxyConfig::http("10.0.0.1", 3128);
+
+    let task = GridClassification::from_grid_bytes(b"png", 3, 3).unwrap();
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(solution.into_grid_classification().tiles(), [0, 3]);
+
+    let task = Rotate::from_bytes(b"png").with_module("rotate_v1");
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(solution.into_rotate().angle(), 90.0);
+
+    let task = AudioToText::from_bytes(b"ID3\x04\x00", "en").unwrap();
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(solution.into_audio_to_text().text(), "42");
+
+    let task = CloudflareChallenge::new("https://example.com", proxy());
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(
+        solution.into_cloudflare_challenge().cf_clearance(),
+        Some("emulated-capsolver-4")
+    );
+
+    let task = Kasada::new("https://example.com", proxy());
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(
+        solution.into_anti_bot().cookie("emulated"),
+        Some("emulated-capsolver-5")
+    );
+
+    let types: Vec<_> = emulator
+        .received_tasks()
+        .iter()
+        .map(|task| task["type"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "ReCaptchaV2Classification",
+            "VisionEngine",
+            "AudioRecognition",
+            "AntiCloudflareTask",
+            "AntiKasadaTask",
+        ]
+    );
+}
+
+#[cfg(feature = "capmonster")]
+#[tokio::test]
+async fn test_capmonster_recaptcha_after_polls() {
+    use crate::providers::capmonster::CapmonsterProvider;
+
+    let emulator = Emulator::start(
+        EmulatorConfig::new(Flavor::Capmonster)
+            .with_api_key("secret")
+            .with_ready_after_polls(3),
+    )
+    .await
+    .unwrap();
+    let provider = CapmonsterProvider::with_url(emulator.url(), "secret").unwrap();
+    let service = CaptchaSolverService::with_config(provider, config());
+
+    let solution = service
+        .solve_captcha(ReCaptchaV2::new("https://example.com", "site-key"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_recaptcha().token(), "emulated-capmonster-1");
+}
+
+#[cfg(feature = "capmonster")]
+#[tokio::test]
+async fn test_capmonster_rejects_wrong_key() {
+    use crate::errors::RetryableError;
+    use crate::providers::capmonster::CapmonsterProvider;
+
+    let emulator = Emulator::start(EmulatorConfig::new(Flavor::Capmonster).with_api_key("secret"))
+        .await
+        .unwrap();
+    let provider = CapmonsterProvider::with_url(emulator.url(), "wrong").unwrap();
+    let service = CaptchaSolverService::with_config(provider, config());
+
+    let err = service
+        .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("ERROR_KEY_DOES_NOT_EXIST"));
+    assert!(!err.should_retry_operation());
+}
+
+#[cfg(feature = "capmonster")]
+#[tokio::test]
+async fn test_capmonster_complex_image_and_custom_tasks() {
+    use crate::providers::capmonster::CapmonsterProvider;
+    use crate::tasks::{
+        Altcha, CaptchaTask, GridClassification, Imperva, MtCaptcha, Prosopo, Rotate,
+    };
+    use crate::utils::proxy::ProxyConfig;
+
+    let emulator = Emulator::start(EmulatorConfig::new(Flavor::Capmonster))
+        .await
+        .unwrap();
+    let provider = CapmonsterProvider::with_url(emulator.url(), "key").unwrap();
+    let service = CaptchaSolverService::with_config(provider, config());
+
+    let task = GridClassification::from_grid_bytes(b"png", 4, 4).unwrap();
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(solution.into_grid_classification().tiles(), [0, 3]);
+
+    let task = Rotate::from_bytes(b"png").with_module("rotate");
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(solution.into_rotate().angle(), 90.0);
+
+    for (i, task) in [
+        CaptchaTask::from(MtCaptcha::new("https://example.com", "site-key")),
+        Prosopo::new("https://example.com", "site-key").into(),
+        Altcha::new("https://example.com", "https://example.com/challenge").into(),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let solution = service.solve_captcha(task).await.unwrap();
+        let expected = format!("emulated-capmonster-{}", i + 3);
+        assert_eq!(solution.into_token().token(), expected);
+    }
+
+    let task = Imperva::new("https://example.com", ProxyConfig::http("10.0.0.1", 3128))
+        .with_user_agent("Mozilla/5.0")
+        .with_incapsula_script_bytes(b"script")
+        .with_session_cookie("incap_ses_1=abc");
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(
+        solution.into_anti_bot().cookie("emulated"),
+        Some("emulated-capmonster-6")
+    );
+
+    let received = emulator.received_tasks();
+    let classes: Vec<_> = received.iter().map(|task| task["class"].as_str()).collect();
+    assert_eq!(
+        classes,
+        [
+            Some("recaptcha"),
+            Some("recognition"),
+            None,
+            None,
+            Some("altcha"),
+            Some("Imperva"),
+        ]
+    );
+
+    let response = post(
+        &emulator,
+        "createTask",
+        json!({ "clientKey": "key", "task": { "type": "CustomTask", "class": "DataDome" } }),
+    )
+    .await;
+    assert_eq!(response["errorCode"], "ERROR_TASK_NOT_SUPPORTED");
+}
+
+#[cfg(feature = "rucaptcha")]
+#[tokio::test]
+async fn test_rucaptcha_extended_task_types() {
+    use crate::providers::rucaptcha::RucaptchaProvider;
+    use crate::tasks::{
+        AudioToText, Capy, GridClassification, HCaptcha, Lemin, MtCaptcha, Rotate, Tencent,
+        TextQuestion,
+    };
+
+    let emulator = Emulator::start(EmulatorConfig::new(Flavor::Rucaptcha).with_text_answer("42"))
+        .await
+        .unwrap();
+    let provider = RucaptchaProvider::with_url(emulator.url(), "key").unwrap();
+    let service = CaptchaSolverService::with_config(provider, config());
+
+    let task = GridClassification::from_grid_bytes(b"png", 3, 3).unwrap();
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(solution.into_grid_classification().tiles(), [0, 3]);
+
+    let solution = service
+        .solve_captcha(Rotate::from_bytes(b"png"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_rotate().angle(), 90.0);
+
+    let task = AudioToText::from_bytes(b"ID3\x04\x00", "en").unwrap();
+    let solution = service.solve_captcha(task).await.unwrap();
+    assert_eq!(solution.into_audio_to_text().text(), "42");
+
+    let solution = service
+        .solve_captcha(TextQuestion::new("2 + 2?"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_text_question().text(), "42");
+
+    let solution = service
+        .solve_captcha(HCaptcha::new("https://example.com", "site-key"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_hcaptcha().token(), "emulated-rucaptcha-5");
+
+    let solution = service
+        .solve_captcha(MtCaptcha::new("https://example.com", "site-key"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_token().token(), "emulated-rucaptcha-6");
+
+    let solution = service
+        .solve_captcha(Tencent::new("https://example.com", "app-id"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_tencent().ticket(), "emulated-rucaptcha-7");
+
+    let solution = service
+        .solve_captcha(Lemin::new("https://example.com", "captcha-id", "div-id"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_lemin().answer(), "emulated-rucaptcha-8");
+
+    let solution = service
+        .solve_captcha(Capy::new("https://example.com", "site-key"))
+        .await
+        .unwrap();
+    assert_eq!(solution.into_capy().answer(), "emulated-rucaptcha-9");
+
+    let types: Vec<_> = emulator
+        .received_tasks()
+        .iter()
+        .map(|task| task["type"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "GridTask",
+            "RotateTask",
+            "AudioTask",
+            "TextCaptchaTask",
+            "HCaptchaTaskProxyless",
+            "MtCaptchaTaskProxyless",
+            "TencentTaskProxyless",
+            "LeminTaskProxyless",
+            "CapyTaskProxyless",
+        ]
+    );
+}
+
+#[cfg(feature = "rucaptcha")]
+#[tokio::test]
+async fn test_rucaptcha_numeric_ids_and_delay() {
+    use crate::providers::rucaptcha::RucaptchaProvider;
+
+    let emulator = Emulator::start(
+        EmulatorConfig::new(Flavor::Rucaptcha).with_ready_after(Duration::from_millis(150)),
+    )
+    .await
+    .unwrap();
+    let provider = RucaptchaProvider::with_url(emulator.url(), "key").unwrap();
+    let service = CaptchaSolverService::with_config(provider, config());
+
+    let started = std::time::Instant::now();
+    let solution = service
+        .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+        .await
+        .unwrap();
+    assert_eq!(
+        solution.into_turnstile().token(),
+        Some("emulated-rucaptcha-1")
+    );
+    assert!(started.elapsed() >= Duration::from_millis(150));
+
+    let created = post(
+        &emulator,
+        "createTask",
+        json!({ "clientKey": "key", "task": { "type": "ImageToTextTask", "body": "cG5n" } }),
+    )
+    .await;
+    assert_eq!(created["taskId"], 1_000_002);
+}
+
+#[cfg(feature = "rucaptcha")]
+#[tokio::test]
+async fn test_scripted_errors_and_retry() {
+    use crate::errors::RetryableError;
+    use crate::providers::CaptchaRetryableProvider;
+    use crate::providers::rucaptcha::RucaptchaProvider;
+    use crate::utils::retry::RetryConfig;
+
+    let emulator = Emulator::start(
+        EmulatorConfig::new(Flavor::Rucaptcha)
+            .with_error(Endpoint::CreateTask, "ERROR_NO_SLOT_AVAILABLE"),
+    )
+    .await
+    .unwrap();
+    let provider = RucaptchaProvider::with_url(emulator.url(), "key").unwrap();
+    let retry = RetryConfig::default().with_min_delay(Duration::from_millis(10));
+    let service = CaptchaSolverService::with_config(
+        CaptchaRetryableProvider::with_config(provider, retry),
+        config(),
+    );
+
+    let solution = service
+        .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+        .await
+        .unwrap();
+    assert_eq!(
+        solution.into_turnstile().token(),
+        Some("emulated-rucaptcha-1")
+    );
+
+    emulator.push_error(Endpoint::GetTaskResult, "ERROR_CAPTCHA_UNSOLVABLE");
+    let err = service
+        .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Captcha unsolvable"), "{err}");
+    assert!(err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_payload_validation() {
+    let emulator = Emulator::start(EmulatorConfig::new(Flavor::Rucaptcha))
+        .await
+        .unwrap();
+    let create = |task: Value| json!({ "clientKey": "key", "task": task });
+
+    let cases = [
+        (
+            create(
+                json!({ "type": "AntiTurnstileTaskProxyLess", "websiteURL": "u", "websiteKey": "k" }),
+            ),
+            "ERROR_TASK_NOT_SUPPORTED",
+        ),
+        (
+            create(json!({ "type": "TurnstileTaskProxyless", "websiteURL": "u" })),
+            "ERROR_BAD_PARAMETERS",
+        ),
+        (
+            create(json!({ "type": "TurnstileTask", "websiteURL": "u", "websiteKey": "k" })),
+            "ERROR_BAD_PARAMETERS",
+        ),
+        (
+            create(json!({ "type": "ImageToTextTask", "body": "not base64!" })),
+            "ERROR_BAD_PARAMETERS",
+        ),
+        (
+            create(json!({ "type": "TencentTaskProxyless", "websiteURL": "u" })),
+            "ERROR_BAD_PARAMETERS",
+        ),
+        (
+            create(json!({ "type": "AudioTask", "body": "SUQz" })),
+            "ERROR_BAD_PARAMETERS",
+        ),
+        (
+            json!({ "task": { "type": "ImageToTextTask", "body": "cG5n" } }),
+            "ERROR_KEY_DOES_NOT_EXIST",
+        ),
+    ];
+    for (body, code) in cases {
+        let response = post(&emulator, "createTask", body.clone()).await;
+        assert_eq!(response["errorId"], 1, "{body}");
+        assert_eq!(response["errorCode"], code, "{body}");
+    }
+    assert!(emulator.received_tasks().is_empty());
+
+    let response = post(
+        &emulator,
+        "getTaskResult",
+        json!({ "clientKey": "key", "taskId": 42 }),
+    )
+    .await;
+    assert_eq!(response["errorCode"], "ERROR_NO_SUCH_CAPCHA_ID");
+}
+
+#[tokio::test]
+async fn test_processing_then_ready_over_http() {
+    let emulator = Emulator::start(
+        EmulatorConfig::new(Flavor::Capsolver)
+            .with_api_key("key")
+            .with_ready_after_polls(2),
+    )
+    .await
+    .unwrap();
+
+    let task = json!({
+        "type": "ReCaptchaV2EnterpriseTask",
+        "websiteURL": "https://example.com",
+        "websiteKey": "site-key",
+        "proxyType": "http",
+        "proxyAddress": "1.2.3.4",
+        "proxyPort": 8080,
+    });
+    let created = post(
+        &emulator,
+        "createTask",
+        json!({ "clientKey": "key", "task": task }),
+    )
+    .await;
+    assert_eq!(created["errorId"], 0);
+    let task_id = created["taskId"].as_str().unwrap().to_string();
+
+    let poll = json!({ "clientKey": "key", "taskId": task_id });
+    for _ in 0..2 {
+        let response = post(&emulator, "getTaskResult", poll.clone()).await;
+        assert_eq!(response["status"], "processing");
+    }
+    let response = post(&emulator, "getTaskResult", poll).await;
+    assert_eq!(response["status"], "ready");
+    assert_eq!(
+        response["solution"]["gRecaptchaResponse"],
+        "emulated-capsolver-1"
+    );
+
+    let response = post(&emulator, "unknown", json!({})).await;
+    assert_eq!(response["errorCode"], "ERROR_NOT_FOUND");
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_stalled_request_is_dropped() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let emulator = Emulator::start(EmulatorConfig::new(Flavor::Capsolver))
+        .await
+        .unwrap();
+    let mut stream = tokio::net::TcpStream::connect(emulator.addr())
+        .await
+        .unwrap();
+    stream
+        .write_all(b"POST /createTask HTTP/1.1\r\n")
+        .await
+        .unwrap();
+
+    // The paused clock jumps to the read timeout, which closes the connection
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).await.unwrap();
+    assert!(response.is_empty());
+}
+
+#[test]
+fn test_flavor_from_str() {
+    assert_eq!("CapMonster".parse::<Flavor>(), Ok(Flavor::Capmonster));
+    assert!("anticaptcha".parse::<Flavor>().is_err());
+    for flavor in Flavor::ALL {
+        assert_eq!(flavor.to_string().parse::<Flavor>(), Ok(flavor));
+    }
+}
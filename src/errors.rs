@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fmt;
 use thiserror::Error;
 
@@ -23,7 +24,9 @@ pub struct UnsupportedTaskError {
     /// The task type that is not supported
     pub task_type: &'static str,
     /// The provider name that doesn't support this task
-    pub provider: &'static str,
+    ///
+    /// A `&'static str` before 0.4.0; owned for configured provider names.
+    pub provider: Cow<'static, str>,
     /// Optional list of unsupported fields (when the task type is supported
     /// but specific field combinations are not).
     pub unsupported_fields: Vec<&'static str>,
@@ -31,10 +34,10 @@ pub struct UnsupportedTaskError {
 
 impl UnsupportedTaskError {
     /// Create a new unsupported task error.
-    pub fn new(task_type: &'static str, provider: &'static str) -> Self {
+    pub fn new(task_type: &'static str, provider: impl Into<Cow<'static, str>>) -> Self {
         Self {
             task_type,
-            provider,
+            provider: provider.into(),
             unsupported_fields: Vec::new(),
         }
     }
@@ -42,7 +45,7 @@ impl UnsupportedTaskError {
     /// Create an error for a supported task type with unsupported field combinations.
     pub fn unsupported_fields(
         task_type: &'static str,
-        provider: &'static str,
+        provider: impl Into<Cow<'static, str>>,
         fields: Vec<&'static str>,
     ) -> Self {
         debug_assert!(
@@ -51,10 +54,27 @@ impl UnsupportedTaskError {
         );
         Self {
             task_type,
-            provider,
+            provider: provider.into(),
             unsupported_fields: fields,
         }
     }
+
+    /// Attribute the error to a different provider name.
+    ///
+    /// Useful for providers that share another provider's task conversions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use captcha_solvers::UnsupportedTaskError;
+    ///
+    /// let error = UnsupportedTaskError::new("Altcha", "RuCaptcha").with_provider("2Captcha");
+    /// assert_eq!(error.provider, "2Captcha");
+    /// ```
+    pub fn with_provider(mut self, provider: impl Into<Cow<'static, str>>) -> Self {
+        self.provider = provider.into();
+        self
+    }
 }
 
 impl fmt::Display for UnsupportedTaskError {
@@ -141,4 +161,36 @@ pub trait RetryableError {
     fn should_retry_operation(&self) -> bool {
         self.is_retryable()
     }
+
+    /// Returns the API key problem behind this error, if any.
+    ///
+    /// Used by [`KeyPoolProvider`](crate::KeyPoolProvider) to take a key out
+    /// of rotation. Default implementation returns `None`.
+    fn key_fault(&self) -> Option<KeyFault> {
+        None
+    }
+}
+
+/// Why an API key cannot currently be used.
+///
+/// Reported by [`RetryableError::key_fault`] for errors tied to the key
+/// rather than to the task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyFault {
+    /// The key does not exist or was denied access
+    Invalid,
+    /// The account behind the key has no funds
+    ZeroBalance,
+    /// The key is temporarily blocked (e.g. too many bad requests)
+    TemporarilyBlocked,
+}
+
+impl fmt::Display for KeyFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyFault::Invalid => write!(f, "invalid key"),
+            KeyFault::ZeroBalance => write!(f, "zero balance"),
+            KeyFault::TemporarilyBlocked => write!(f, "temporarily blocked"),
+        }
+    }
 }
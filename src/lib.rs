@@ -13,6 +13,12 @@
 //! | Capsolver | `capsolver` (default) | <https://capsolver.com> |
 //! | CapMonster Cloud | `capmonster` (default) | <https://capmonster.cloud> |
 //! | RuCaptcha | `rucaptcha` (default) | <https://rucaptcha.com> |
+//! | Anti-Captcha | `anticaptcha` | <https://anti-captcha.com> |
+//! | 2Captcha | `twocaptcha` | <https://2captcha.com> |
+//! | Legacy `in.php`/`res.php` | `legacy` | Any 2Captcha-compatible service |
+//! | Generic `createTask` | `generic` | Any Capsolver/CapMonster-compatible service |
+//! | DeathByCaptcha | `deathbycaptcha` | <https://deathbycaptcha.com> |
+//! | Local OCR | `localocr` | In-process template matching, no external service |
 //!
 //! ## Supported Captcha Types
 //!
@@ -25,6 +31,31 @@
 //! | [`TurnstileWaitRoom`] | Cloudflare Waiting Room bypass | CapMonster |
 //! | [`CloudflareChallenge`] | Full page challenge bypass | Capsolver |
 //! | [`ImageToText`] | Image captcha OCR recognition | All |
+//! | [`GridClassification`] | Grid image tile selection | Capsolver, CapMonster, RuCaptcha |
+//! | [`Rotate`] | Rotate-the-image captcha | Capsolver, CapMonster, RuCaptcha |
+//! | [`AudioToText`] | Audio captcha speech recognition | Capsolver, RuCaptcha |
+//! | [`MtCaptcha`] | MTCaptcha widget | CapMonster, RuCaptcha |
+//! | [`FriendlyCaptcha`] | Friendly Captcha widget | RuCaptcha |
+//! | [`Cutcaptcha`] | Cutcaptcha widget | RuCaptcha |
+//! | [`YandexSmartCaptcha`] | Yandex SmartCaptcha | RuCaptcha |
+//! | [`Tencent`] | Tencent captcha (TCaptcha) | RuCaptcha |
+//! | [`Lemin`] | Lemin Cropped puzzle captcha | RuCaptcha |
+//! | [`KeyCaptcha`] | KeyCaptcha puzzle captcha | RuCaptcha |
+//! | [`Capy`] | Capy puzzle captcha | RuCaptcha |
+//! | [`Imperva`] | Imperva (Incapsula) anti-bot bypass | Capsolver, CapMonster |
+//! | [`Kasada`] | Kasada anti-bot bypass | Capsolver, CapMonster |
+//! | [`Prosopo`] | Prosopo Procaptcha widget | CapMonster, RuCaptcha |
+//! | [`CaptchaFox`] | CaptchaFox widget | RuCaptcha |
+//! | [`Altcha`] | Altcha widget | CapMonster |
+//! | [`TextQuestion`] | Plain-text question captcha | RuCaptcha |
+//! | [`HCaptcha`] | hCaptcha widget | RuCaptcha, Anti-Captcha |
+//!
+//! 2Captcha shares RuCaptcha's backend and supports the same task types.
+//! The legacy `in.php`/`res.php` provider supports ReCaptcha V2/V3, Turnstile
+//! and ImageToText. The generic `createTask` provider supports the task kinds
+//! mapped by its profile (ReCaptcha V2/V3, Turnstile, ImageToText and HCaptcha).
+//! DeathByCaptcha supports ReCaptcha V2/V3, Turnstile and ImageToText. The local
+//! OCR provider solves simple ImageToText captchas in-process.
 //!
 //! ## Quick Start
 //!
@@ -267,7 +298,10 @@
 //! CaptchaRetryableProvider<P>  (optional retry wrapper)
 //!         │
 //!         ▼
-//!     Provider          (trait: CapsolverProvider, CapmonsterProvider, RucaptchaProvider)
+//! KeyPoolProvider<P>           (optional multi-key wrapper)
+//!         │
+//!         ▼
+//!     Provider          (trait: CapsolverProvider, CapmonsterProvider, RucaptchaProvider, AnticaptchaProvider, TwocaptchaProvider, LegacyProvider, GenericProvider, DeathbycaptchaProvider, LocalOcrProvider)
 //! ```
 //!
 //! ## Features
@@ -275,6 +309,19 @@
 //! - `capsolver` - Capsolver provider support (enabled by default)
 //! - `capmonster` - CapMonster Cloud provider support (enabled by default)
 //! - `rucaptcha` - RuCaptcha provider support (enabled by default)
+//! - `anticaptcha` - Anti-Captcha provider support
+//! - `twocaptcha` - 2Captcha provider support, reusing the RuCaptcha client
+//! - `legacy` - Classic `in.php`/`res.php` provider for 2Captcha-compatible services
+//! - `generic` - Configurable provider for `createTask`/`getTaskResult` API clones
+//! - `deathbycaptcha` - DeathByCaptcha provider (enables reqwest `multipart`)
+//! - `localocr` - Local template-matching OCR provider for simple image captchas
+//! - `mock` - Scriptable in-memory provider for unit-testing code that uses the service
+//! - `chaos` - Fault-injection wrapper for testing retry and timeout settings
+//! - `emulator` - Local Capsolver/CapMonster/RuCaptcha API emulator and `captcha-emulator` binary
+//! - `cassette` - Record/replay HTTP middleware for deterministic provider tests
+//! - `conformance` - Conformance test-kit for custom `Provider` implementations
+//! - `config` - Solver configuration from environment variables and TOML/JSON files
+//!   (needs at least one of `capsolver`, `capmonster`, `rucaptcha`, `anticaptcha`)
 //! - `tracing` - OpenTelemetry tracing instrumentation (enabled by default)
 //! - `metrics` - OpenTelemetry metrics support
 
@@ -314,15 +361,103 @@ pub mod rucaptcha {
     pub use crate::providers::rucaptcha::*;
 }
 
+#[cfg(feature = "anticaptcha")]
+pub mod anticaptcha {
+    //! Anti-Captcha provider implementation.
+    //!
+    //! See [`AnticaptchaProvider`] for usage details.
+    pub use crate::providers::anticaptcha::*;
+}
+
+#[cfg(feature = "twocaptcha")]
+pub mod twocaptcha {
+    //! 2Captcha provider implementation.
+    //!
+    //! See [`TwocaptchaProvider`] for usage details.
+    pub use crate::providers::twocaptcha::*;
+}
+
+#[cfg(feature = "legacy")]
+pub mod legacy {
+    //! Legacy `in.php`/`res.php` provider implementation.
+    //!
+    //! See [`LegacyProvider`] for usage details.
+    pub use crate::providers::legacy::*;
+}
+
+#[cfg(feature = "generic")]
+pub mod generic {
+    //! Generic `createTask`-compatible provider implementation.
+    //!
+    //! See [`GenericProvider`] and [`GenericProfile`] for usage details.
+    pub use crate::providers::generic::*;
+}
+
+#[cfg(feature = "deathbycaptcha")]
+pub mod deathbycaptcha {
+    //! DeathByCaptcha provider implementation.
+    //!
+    //! See [`DeathbycaptchaProvider`] for usage details.
+    pub use crate::providers::deathbycaptcha::*;
+}
+
+#[cfg(feature = "localocr")]
+pub mod localocr {
+    //! Local OCR provider implementation.
+    //!
+    //! See [`LocalOcrProvider`] and [`TemplateSet`] for usage details.
+    pub use crate::providers::localocr::*;
+}
+
+#[cfg(feature = "mock")]
+pub mod mock {
+    //! Scriptable in-memory provider for tests.
+    //!
+    //! See [`MockProvider`] and [`MockOutcome`] for usage details.
+    pub use crate::providers::mock::*;
+}
+
+#[cfg(feature = "chaos")]
+pub mod chaos {
+    //! Fault-injection wrapper for resilience tests.
+    //!
+    //! See [`ChaosProvider`] for usage details.
+    pub use crate::providers::chaos::*;
+}
+
+#[cfg(feature = "emulator")]
+pub mod emulator;
+
+#[cfg(feature = "cassette")]
+pub mod cassette;
+
+#[cfg(feature = "conformance")]
+pub mod conformance;
+
+// Without a createTask provider there is nothing to configure
+#[cfg(all(
+    feature = "config",
+    any(
+        feature = "capsolver",
+        feature = "capmonster",
+        feature = "rucaptcha",
+        feature = "anticaptcha"
+    )
+))]
+pub mod config;
+
 // ============================================================================
 // Public API - Core Types
 // ============================================================================
 
 // Error handling
-pub use errors::{RetryableError, UnsupportedTaskError};
+pub use errors::{KeyFault, RetryableError, UnsupportedTaskError};
 
 // Provider abstraction
-pub use providers::{CaptchaRetryableProvider, OnRetryCallback, Provider, TaskCreationOutcome};
+pub use providers::{
+    CaptchaRetryableProvider, KeyPoolError, KeyPoolProvider, KeySelection, KeyStatus,
+    OnRetryCallback, Provider, TaskCreationOutcome,
+};
 
 // Service
 pub use service::{
@@ -339,8 +474,11 @@ pub use tokio_util::sync::CancellationToken;
 // ============================================================================
 
 pub use tasks::{
-    CaptchaTask, CloudflareChallenge, ImageToText, ReCaptchaV2, ReCaptchaV3, Turnstile,
-    TurnstileChallenge, TurnstileChallengeMode, TurnstileWaitRoom,
+    Altcha, AudioError, AudioFormat, AudioToText, CaptchaFox, CaptchaTask, Capy,
+    CloudflareChallenge, Cutcaptcha, FriendlyCaptcha, GridClassification, GridError, GridImages,
+    HCaptcha, ImageToText, Imperva, Kasada, KeyCaptcha, Lemin, MAX_AUDIO_SIZE, MtCaptcha, Prosopo,
+    ReCaptchaV2, ReCaptchaV3, Rotate, RotateError, Tencent, TextQuestion, Turnstile,
+    TurnstileChallenge, TurnstileChallengeMode, TurnstileWaitRoom, YandexSmartCaptcha,
 };
 
 // ============================================================================
@@ -348,8 +486,10 @@ pub use tasks::{
 // ============================================================================
 
 pub use solutions::{
-    CloudflareChallengeSolution, ImageToTextSolution, ProviderSolution, ReCaptchaSolution,
-    TurnstileSolution,
+    AntiBotSolution, AudioToTextSolution, CapySolution, CloudflareChallengeSolution,
+    GridClassificationSolution, HCaptchaSolution, ImageToTextSolution, LeminSolution,
+    ProviderSolution, ReCaptchaSolution, RotateSolution, TencentSolution, TextQuestionSolution,
+    TokenSolution, TurnstileSolution,
 };
 
 // ============================================================================
@@ -0,0 +1,254 @@
+use crate::errors::{KeyFault, RetryableError, UnsupportedTaskError};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AnticaptchaError {
+    #[error("Failed to build HTTP client: {0}")]
+    BuildHttpClient(#[source] reqwest::Error),
+
+    #[error("HTTP request failed: {0}")]
+    HttpRequest(#[from] reqwest_middleware::Error),
+
+    #[error("Failed to parse response: {0}")]
+    ParseResponse(#[source] reqwest::Error),
+
+    #[error("Anti-Captcha API error: {0}")]
+    Api(#[source] AnticaptchaApiError),
+
+    #[error("{0}")]
+    UnsupportedTask(#[source] UnsupportedTaskError),
+
+    #[error("Invalid task data: {0}")]
+    InvalidTaskData(String),
+}
+
+pub type Result<T> = std::result::Result<T, AnticaptchaError>;
+
+impl RetryableError for AnticaptchaError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            AnticaptchaError::HttpRequest(_) => true,
+            AnticaptchaError::Api(error) => error.error_code.is_retryable(),
+            AnticaptchaError::BuildHttpClient(_)
+            | AnticaptchaError::ParseResponse(_)
+            | AnticaptchaError::UnsupportedTask(_)
+            | AnticaptchaError::InvalidTaskData(_) => false,
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            AnticaptchaError::HttpRequest(_) => true,
+            AnticaptchaError::Api(error) => error.error_code.should_retry_operation(),
+            AnticaptchaError::BuildHttpClient(_)
+            | AnticaptchaError::ParseResponse(_)
+            | AnticaptchaError::UnsupportedTask(_)
+            | AnticaptchaError::InvalidTaskData(_) => false,
+        }
+    }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            AnticaptchaError::Api(error) => error.error_code.key_fault(),
+            _ => None,
+        }
+    }
+}
+
+/// Known Anti-Captcha API error codes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnticaptchaErrorCode {
+    // Retryable/transient
+    NoSlotAvailable,
+    IpBlocked,
+
+    // Account/configuration
+    KeyDoesNotExist,
+    ZeroBalance,
+    IpNotAllowed,
+    AccountSuspended,
+
+    // Task-level
+    NoSuchMethod,
+    TaskAbsent,
+    TaskNotSupported,
+    ZeroCaptchaFilesize,
+    TooBigCaptchaFilesize,
+    ImageTypeNotSupported,
+    IncorrectSessionData,
+    NoSuchCaptchaId,
+    CaptchaUnsolvable,
+    RecaptchaTimeout,
+    RecaptchaInvalidSitekey,
+    RecaptchaInvalidDomain,
+    TokenExpired,
+
+    // Proxy
+    ProxyConnectRefused,
+    ProxyConnectTimeout,
+    ProxyReadTimeout,
+    ProxyBanned,
+    ProxyTransparent,
+
+    // Unknown
+    Other(String),
+}
+
+impl AnticaptchaErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::NoSlotAvailable => "ERROR_NO_SLOT_AVAILABLE",
+            Self::IpBlocked => "ERROR_IP_BLOCKED",
+            Self::KeyDoesNotExist => "ERROR_KEY_DOES_NOT_EXIST",
+            Self::ZeroBalance => "ERROR_ZERO_BALANCE",
+            Self::IpNotAllowed => "ERROR_IP_NOT_ALLOWED",
+            Self::AccountSuspended => "ERROR_ACCOUNT_SUSPENDED",
+            Self::NoSuchMethod => "ERROR_NO_SUCH_METHOD",
+            Self::TaskAbsent => "ERROR_TASK_ABSENT",
+            Self::TaskNotSupported => "ERROR_TASK_NOT_SUPPORTED",
+            Self::ZeroCaptchaFilesize => "ERROR_ZERO_CAPTCHA_FILESIZE",
+            Self::TooBigCaptchaFilesize => "ERROR_TOO_BIG_CAPTCHA_FILESIZE",
+            Self::ImageTypeNotSupported => "ERROR_IMAGE_TYPE_NOT_SUPPORTED",
+            Self::IncorrectSessionData => "ERROR_INCORRECT_SESSION_DATA",
+            Self::NoSuchCaptchaId => "ERROR_NO_SUCH_CAPCHA_ID",
+            Self::CaptchaUnsolvable => "ERROR_CAPTCHA_UNSOLVABLE",
+            Self::RecaptchaTimeout => "ERROR_RECAPTCHA_TIMEOUT",
+            Self::RecaptchaInvalidSitekey => "ERROR_RECAPTCHA_INVALID_SITEKEY",
+            Self::RecaptchaInvalidDomain => "ERROR_RECAPTCHA_INVALID_DOMAIN",
+            Self::TokenExpired => "ERROR_TOKEN_EXPIRED",
+            Self::ProxyConnectRefused => "ERROR_PROXY_CONNECT_REFUSED",
+            Self::ProxyConnectTimeout => "ERROR_PROXY_CONNECT_TIMEOUT",
+            Self::ProxyReadTimeout => "ERROR_PROXY_READ_TIMEOUT",
+            Self::ProxyBanned => "ERROR_PROXY_BANNED",
+            Self::ProxyTransparent => "ERROR_PROXY_TRANSPARENT",
+            Self::Other(code) => code.as_str(),
+        }
+    }
+
+    fn from_str(s: &str) -> Self {
+        match s {
+            "ERROR_NO_SLOT_AVAILABLE" => Self::NoSlotAvailable,
+            "ERROR_IP_BLOCKED" => Self::IpBlocked,
+            "ERROR_KEY_DOES_NOT_EXIST" => Self::KeyDoesNotExist,
+            "ERROR_ZERO_BALANCE" => Self::ZeroBalance,
+            "ERROR_IP_NOT_ALLOWED" => Self::IpNotAllowed,
+            "ERROR_ACCOUNT_SUSPENDED" => Self::AccountSuspended,
+            "ERROR_NO_SUCH_METHOD" => Self::NoSuchMethod,
+            "ERROR_TASK_ABSENT" => Self::TaskAbsent,
+            "ERROR_TASK_NOT_SUPPORTED" => Self::TaskNotSupported,
+            "ERROR_ZERO_CAPTCHA_FILESIZE" => Self::ZeroCaptchaFilesize,
+            "ERROR_TOO_BIG_CAPTCHA_FILESIZE" => Self::TooBigCaptchaFilesize,
+            "ERROR_IMAGE_TYPE_NOT_SUPPORTED" => Self::ImageTypeNotSupported,
+            "ERROR_INCORRECT_SESSION_DATA" => Self::IncorrectSessionData,
+            // Anti-Captcha spells this code without the "T"
+            "ERROR_NO_SUCH_CAPCHA_ID" | "ERROR_NO_SUCH_CAPTCHA_ID" => Self::NoSuchCaptchaId,
+            "ERROR_CAPTCHA_UNSOLVABLE" => Self::CaptchaUnsolvable,
+            "ERROR_RECAPTCHA_TIMEOUT" => Self::RecaptchaTimeout,
+            "ERROR_RECAPTCHA_INVALID_SITEKEY" => Self::RecaptchaInvalidSitekey,
+            "ERROR_RECAPTCHA_INVALID_DOMAIN" => Self::RecaptchaInvalidDomain,
+            "ERROR_TOKEN_EXPIRED" => Self::TokenExpired,
+            "ERROR_PROXY_CONNECT_REFUSED" => Self::ProxyConnectRefused,
+            "ERROR_PROXY_CONNECT_TIMEOUT" => Self::ProxyConnectTimeout,
+            "ERROR_PROXY_READ_TIMEOUT" => Self::ProxyReadTimeout,
+            "ERROR_PROXY_BANNED" => Self::ProxyBanned,
+            "ERROR_PROXY_TRANSPARENT" => Self::ProxyTransparent,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::NoSlotAvailable | Self::IpBlocked)
+    }
+
+    /// Returns the API key problem this code reports, if any
+    pub fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            Self::KeyDoesNotExist | Self::AccountSuspended => Some(KeyFault::Invalid),
+            Self::ZeroBalance => Some(KeyFault::ZeroBalance),
+            Self::IpBlocked => Some(KeyFault::TemporarilyBlocked),
+            _ => None,
+        }
+    }
+
+    pub fn should_retry_operation(&self) -> bool {
+        match self {
+            Self::NoSlotAvailable
+            | Self::IpBlocked
+            | Self::NoSuchCaptchaId
+            | Self::CaptchaUnsolvable
+            | Self::RecaptchaTimeout
+            | Self::TokenExpired => true,
+            Self::KeyDoesNotExist
+            | Self::ZeroBalance
+            | Self::IpNotAllowed
+            | Self::AccountSuspended
+            | Self::NoSuchMethod
+            | Self::TaskAbsent
+            | Self::TaskNotSupported
+            | Self::ZeroCaptchaFilesize
+            | Self::TooBigCaptchaFilesize
+            | Self::ImageTypeNotSupported
+            | Self::IncorrectSessionData
+            | Self::RecaptchaInvalidSitekey
+            | Self::RecaptchaInvalidDomain
+            | Self::ProxyConnectRefused
+            | Self::ProxyConnectTimeout
+            | Self::ProxyReadTimeout
+            | Self::ProxyBanned
+            | Self::ProxyTransparent
+            | Self::Other(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for AnticaptchaErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for AnticaptchaErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for AnticaptchaErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from_str(&s))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnticaptchaApiError {
+    pub error_id: u32,
+    pub error_code: AnticaptchaErrorCode,
+    #[serde(default)]
+    pub error_description: Option<String>,
+}
+
+impl fmt::Display for AnticaptchaApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Anti-Captcha Error [{}]: {} - {}",
+            self.error_id,
+            self.error_code,
+            self.error_description
+                .as_deref()
+                .unwrap_or("No description")
+        )
+    }
+}
+
+impl std::error::Error for AnticaptchaApiError {}
@@ -0,0 +1,110 @@
+//! # Anti-Captcha Provider
+//!
+//! Implementation of captcha solver provider for [Anti-Captcha](https://anti-captcha.com).
+//!
+//! Anti-Captcha speaks the same `createTask`/`getTaskResult` JSON protocol as
+//! CapMonster Cloud and solves with human workers, which makes it a good fit
+//! for image captchas.
+//!
+//! ## Supported Captcha Types
+//!
+//! | Type | Task Type | Proxy Required |
+//! |------|-----------|----------------|
+//! | ReCaptcha V2 | [`ReCaptchaV2`](crate::ReCaptchaV2) | No |
+//! | ReCaptcha V2 Invisible | [`ReCaptchaV2`](crate::ReCaptchaV2) with `.invisible()` | No |
+//! | ReCaptcha V2 Enterprise | [`ReCaptchaV2`](crate::ReCaptchaV2) with `.enterprise()` | No |
+//! | ReCaptcha V3 | [`ReCaptchaV3`](crate::ReCaptchaV3) | No (proxy not supported) |
+//! | ReCaptcha V3 Enterprise | [`ReCaptchaV3`](crate::ReCaptchaV3) with `.enterprise()` | No (proxy not supported) |
+//! | Cloudflare Turnstile | [`Turnstile`](crate::Turnstile) | No |
+//! | Image to Text | [`ImageToText`](crate::ImageToText) | No |
+//! | hCaptcha | [`HCaptcha`](crate::HCaptcha) | No |
+//!
+//! All other task types are rejected with [`UnsupportedTaskError`](crate::UnsupportedTaskError).
+//!
+//! **Notes**:
+//! - ReCaptcha V3 `minScore` must be 0.3, 0.7 or 0.9 (defaults to 0.9).
+//! - ReCaptcha V2 `user_agent` and `cookies` are only sent together with a proxy.
+//! - Image to Text supports `numeric` values 0-2 only; recognition modules are not supported.
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::anticaptcha::AnticaptchaProvider;
+//! use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ImageToText};
+//!
+//! let provider = AnticaptchaProvider::new("your_api_key")?;
+//! let service = CaptchaSolverService::new(provider);
+//!
+//! let task = ImageToText::from_base64("iVBORw0KGgoAAAANSUhEUgAA...").case_sensitive();
+//! let solution = service.solve_captcha(task).await?;
+//! println!("Recognized: {}", solution.into_image_to_text().text());
+//! ```
+//!
+//! ## Provider Configuration
+//!
+//! ```rust,ignore
+//! use captcha_solvers::anticaptcha::AnticaptchaProvider;
+//! use url::Url;
+//!
+//! // Simple: default API URL
+//! let provider = AnticaptchaProvider::new("api_key")?;
+//!
+//! // Custom URL
+//! let provider = AnticaptchaProvider::with_url(
+//!     Url::parse("https://api.anti-captcha.com")?,
+//!     "api_key"
+//! )?;
+//!
+//! // Full builder
+//! let provider = AnticaptchaProvider::builder("api_key")
+//!     .url(custom_url)
+//!     .http_client(custom_middleware_client)
+//!     .build()?;
+//! ```
+//!
+//! ## Solution Types
+//!
+//! Each captcha type returns a specific solution:
+//!
+//! - **ReCaptcha V2/V3**: [`ReCaptchaSolution`] with `token()` method
+//! - **Turnstile**: [`TurnstileSolution`] with `token()` method (or [`TokenSolution`] via `into_token()`)
+//! - **Image to Text**: [`ImageToTextSolution`] with `text()` method
+//! - **hCaptcha**: [`HCaptchaSolution`] with `token()` and `resp_key()` methods
+//!
+//! ## Error Handling
+//!
+//! Errors are categorized as retryable or permanent:
+//!
+//! ```rust,ignore
+//! use captcha_solvers::RetryableError;
+//! use captcha_solvers::anticaptcha::AnticaptchaError;
+//!
+//! match result {
+//!     Err(e) if e.is_retryable() => { /* retry later */ }
+//!     Err(e) => { /* permanent error, check API key or task data */ }
+//!     Ok(solution) => { /* success */ }
+//! }
+//! ```
+
+mod errors;
+mod provider;
+mod response;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+// Errors
+pub use errors::{AnticaptchaApiError, AnticaptchaError, AnticaptchaErrorCode};
+
+// Provider
+pub use provider::{AnticaptchaProvider, AnticaptchaProviderBuilder, DEFAULT_API_URL};
+
+// Solutions
+pub use types::{
+    AnticaptchaSolution, HCaptchaSolution, ImageToTextSolution, ReCaptchaSolution, TokenSolution,
+    TurnstileSolution,
+};
+
+// Re-export proxy types for convenience (also available at crate root)
+pub use crate::utils::proxy::{ProxyConfig, ProxyType};
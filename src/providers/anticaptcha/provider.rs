@@ -0,0 +1,275 @@
+//! Anti-Captcha provider implementation.
+
+use super::errors::{AnticaptchaError, Result};
+use super::response::AnticaptchaResponse;
+use super::types::{
+    AnticaptchaSolution, AnticaptchaTask, CreateTaskData, CreateTaskRequest, GetTaskData,
+    GetTaskResultRequest,
+};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use secrecy::{ExposeSecret, SecretString};
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+
+#[cfg(feature = "tracing")]
+use crate::utils::span_status::{record_error, set_span_ok};
+#[cfg(feature = "tracing")]
+use tracing::Span;
+
+/// Default Anti-Captcha API URL.
+pub const DEFAULT_API_URL: &str = "https://api.anti-captcha.com";
+
+/// API endpoint paths.
+const CREATE_TASK_PATH: &str = "createTask";
+const GET_TASK_RESULT_PATH: &str = "getTaskResult";
+
+/// reCAPTCHA V3 scores accepted by Anti-Captcha.
+const MIN_SCORES: [f32; 3] = [0.3, 0.7, 0.9];
+
+/// Anti-Captcha provider implementation.
+#[derive(Clone)]
+pub struct AnticaptchaProvider {
+    http_client: ClientWithMiddleware,
+    api_key: SecretString,
+    url: Url,
+}
+
+impl Debug for AnticaptchaProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnticaptchaProvider")
+            .field("url", &self.url)
+            .field("api_key", &crate::utils::REDACTED)
+            .finish()
+    }
+}
+
+/// Builder for configuring a [`AnticaptchaProvider`].
+pub struct AnticaptchaProviderBuilder {
+    api_key: String,
+    url: Option<Url>,
+    http_client: Option<ClientWithMiddleware>,
+}
+
+impl AnticaptchaProviderBuilder {
+    /// Create a new builder with API key.
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            url: None,
+            http_client: None,
+        }
+    }
+
+    /// Set a custom API URL.
+    pub fn url(mut self, url: Url) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Set custom middleware HTTP client.
+    pub fn http_client(mut self, client: ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Build provider instance.
+    pub fn build(self) -> Result<AnticaptchaProvider> {
+        let url = self
+            .url
+            .unwrap_or_else(|| Url::parse(DEFAULT_API_URL).expect("Invalid default URL"));
+
+        let http_client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let client = reqwest::Client::builder()
+                    .build()
+                    .map_err(AnticaptchaError::BuildHttpClient)?;
+                ClientBuilder::new(client).build()
+            }
+        };
+
+        Ok(AnticaptchaProvider {
+            http_client,
+            api_key: SecretString::from(self.api_key),
+            url,
+        })
+    }
+}
+
+impl AnticaptchaProvider {
+    /// Create provider with default API URL.
+    pub fn new(api_key: impl Into<String>) -> Result<Self> {
+        Self::builder(api_key).build()
+    }
+
+    /// Create provider with custom URL.
+    pub fn with_url(url: Url, api_key: impl Into<String>) -> Result<Self> {
+        Self::builder(api_key).url(url).build()
+    }
+
+    /// Create provider builder.
+    pub fn builder(api_key: impl Into<String>) -> AnticaptchaProviderBuilder {
+        AnticaptchaProviderBuilder::new(api_key)
+    }
+
+    /// Get base URL.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Get API key.
+    fn api_key(&self) -> &str {
+        self.api_key.expose_secret()
+    }
+
+    /// Send POST request to provider API.
+    async fn post<Req: serde::Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        request: &Req,
+    ) -> Result<Res> {
+        let mut url = self.url.clone();
+        url.set_path(path);
+
+        let response = self
+            .http_client
+            .post(url)
+            .json(request)
+            .send()
+            .await
+            .map_err(AnticaptchaError::HttpRequest)?;
+
+        response
+            .json()
+            .await
+            .map_err(AnticaptchaError::ParseResponse)
+    }
+
+    fn validate_task(task: &AnticaptchaTask) -> Result<()> {
+        if let AnticaptchaTask::RecaptchaV3TaskProxyless { min_score, .. } = task
+            && !MIN_SCORES.contains(min_score)
+        {
+            return Err(AnticaptchaError::InvalidTaskData(
+                "minScore must be one of 0.3, 0.7 or 0.9".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Create captcha task (internal).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task_internal",
+            target = "captcha.anticaptcha",
+            skip_all,
+            fields(task_id = tracing::field::Empty)
+        )
+    )]
+    async fn create_task_internal(&self, task: AnticaptchaTask) -> Result<TaskId> {
+        Self::validate_task(&task)?;
+
+        let request = CreateTaskRequest {
+            client_key: self.api_key(),
+            task: &task,
+        };
+
+        let response: AnticaptchaResponse<CreateTaskData> =
+            self.post(CREATE_TASK_PATH, &request).await?;
+        let data = response.into_result().map_err(AnticaptchaError::Api)?;
+        let task_id = TaskId::from(data.task_id);
+
+        #[cfg(feature = "tracing")]
+        Span::current().record("task_id", task_id.as_ref());
+
+        Ok(task_id)
+    }
+
+    /// Get task result (internal).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result_internal",
+            target = "captcha.anticaptcha",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result_internal<T: DeserializeOwned + Debug>(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Option<T>> {
+        let request = GetTaskResultRequest {
+            client_key: self.api_key(),
+            task_id: task_id.as_ref(),
+        };
+
+        let response: AnticaptchaResponse<GetTaskData<T>> =
+            self.post(GET_TASK_RESULT_PATH, &request).await?;
+
+        let data = response.into_result().map_err(AnticaptchaError::Api)?;
+
+        #[cfg(feature = "tracing")]
+        if data.solution.is_some() {
+            set_span_ok();
+        }
+
+        Ok(data.solution)
+    }
+}
+
+impl Provider for AnticaptchaProvider {
+    type Solution = AnticaptchaSolution;
+    type Error = AnticaptchaError;
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task",
+            target = "captcha.anticaptcha",
+            skip_all,
+            fields(captcha.task_type)
+        )
+    )]
+    async fn create_task(&self, task: CaptchaTask) -> Result<TaskCreationOutcome<Self::Solution>> {
+        #[cfg(feature = "tracing")]
+        Span::current().record("captcha.task_type", task.to_string());
+
+        let internal_task: AnticaptchaTask =
+            task.try_into().map_err(AnticaptchaError::UnsupportedTask)?;
+        let result = self.create_task_internal(internal_task).await;
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => set_span_ok(),
+            Err(e) => record_error(e, "Anticaptcha"),
+        }
+
+        result.map(TaskCreationOutcome::Pending)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result",
+            target = "captcha.anticaptcha",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result(&self, task_id: &TaskId) -> Result<Option<Self::Solution>> {
+        let result = self.get_task_result_internal(task_id).await;
+
+        #[cfg(feature = "tracing")]
+        if let Err(ref e) = result {
+            record_error(e, "Anticaptcha");
+        }
+
+        result
+    }
+}
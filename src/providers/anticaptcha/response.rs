@@ -0,0 +1,23 @@
+//! Response parsing for the Anti-Captcha API.
+
+use super::errors::AnticaptchaApiError;
+use crate::impl_api_response_deserialize;
+
+/// Anti-Captcha API response wrapper
+#[derive(Debug)]
+pub enum AnticaptchaResponse<T> {
+    Success(T),
+    Error(AnticaptchaApiError),
+}
+
+impl<T> AnticaptchaResponse<T> {
+    /// Convert to Result for convenient use with `?`.
+    pub fn into_result(self) -> Result<T, AnticaptchaApiError> {
+        match self {
+            Self::Success(data) => Ok(data),
+            Self::Error(err) => Err(err),
+        }
+    }
+}
+
+impl_api_response_deserialize!(AnticaptchaResponse, AnticaptchaApiError);
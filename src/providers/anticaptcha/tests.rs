@@ -0,0 +1,327 @@
+//! Tests for the Anti-Captcha provider.
+
+use super::errors::{AnticaptchaError, AnticaptchaErrorCode};
+use super::provider::AnticaptchaProvider;
+use super::response::AnticaptchaResponse;
+use super::types::{AnticaptchaSolution, CreateTaskData, GetTaskData};
+use crate::errors::{KeyFault, RetryableError};
+use crate::providers::traits::Provider;
+use crate::tasks::{ImageToText, Prosopo, ReCaptchaV3, Turnstile};
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct TestSolution {
+    #[serde(rename = "gRecaptchaResponse")]
+    g_recaptcha_response: String,
+}
+
+fn mock_provider(server: &MockServer) -> AnticaptchaProvider {
+    AnticaptchaProvider::builder("test_api_key")
+        .url(Url::parse(&server.uri()).unwrap())
+        .build()
+        .unwrap()
+}
+
+async fn mock_create_task(server: &MockServer, response: Value) {
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response))
+        .mount(server)
+        .await;
+}
+
+async fn mock_get_task_result(server: &MockServer, response: Value) {
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response))
+        .mount(server)
+        .await;
+}
+
+fn success_create_task_response(task_id: u64) -> Value {
+    json!({
+        "errorId": 0,
+        "taskId": task_id,
+    })
+}
+
+fn error_response(error_code: &str, description: &str) -> Value {
+    json!({
+        "errorId": 1,
+        "errorCode": error_code,
+        "errorDescription": description,
+    })
+}
+
+fn ready_solution_response(solution: Value) -> Value {
+    json!({
+        "errorId": 0,
+        "status": "ready",
+        "solution": solution,
+        "cost": "0.000700",
+    })
+}
+
+fn processing_response() -> Value {
+    json!({
+        "errorId": 0,
+        "status": "processing",
+    })
+}
+
+#[tokio::test]
+async fn test_create_task_success() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "clientKey": "test_api_key",
+            "task": {
+                "type": "ImageToTextTask",
+                "body": "aGVsbG8=",
+                "case": true,
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(success_create_task_response(7654321)),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = ImageToText::from_base64("aGVsbG8=").case_sensitive();
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "7654321");
+}
+
+#[tokio::test]
+async fn test_create_task_turnstile() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "task": {
+                "type": "TurnstileTaskProxyless",
+                "websiteURL": "https://example.com",
+                "websiteKey": "site-key",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(success_create_task_response(1)))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "1");
+}
+
+#[tokio::test]
+async fn test_create_task_api_error() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        error_response(
+            "ERROR_KEY_DOES_NOT_EXIST",
+            "Account authorization key not found in the system",
+        ),
+    )
+    .await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    match err {
+        AnticaptchaError::Api(ref error) => {
+            assert_eq!(error.error_code, AnticaptchaErrorCode::KeyDoesNotExist);
+        }
+        _ => panic!("Expected Api error"),
+    }
+    assert!(!err.is_retryable());
+    assert!(!err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_create_task_no_slot_is_retryable() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        error_response("ERROR_NO_SLOT_AVAILABLE", "No idle workers"),
+    )
+    .await;
+
+    let provider = mock_provider(&server);
+    let task = ImageToText::from_base64("aGVsbG8=");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert!(err.is_retryable());
+    assert!(err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_create_task_invalid_min_score() {
+    let provider = AnticaptchaProvider::new("test_api_key").unwrap();
+    let task = ReCaptchaV3::new("https://example.com", "site-key").with_min_score(0.5);
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(err, AnticaptchaError::InvalidTaskData(_)));
+}
+
+#[tokio::test]
+async fn test_create_task_prosopo_unsupported() {
+    let provider = AnticaptchaProvider::new("test_api_key").unwrap();
+    let task = Prosopo::new("https://example.com", "site-key");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(err, AnticaptchaError::UnsupportedTask(_)));
+}
+
+#[tokio::test]
+async fn test_get_task_result_ready() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .and(body_partial_json(json!({
+            "clientKey": "test_api_key",
+            "taskId": 7654321,
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(ready_solution_response(json!({
+                "text": "deditur",
+                "url": "http://61.39.233.233/1/147220556452507.jpg"
+            }))),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("7654321");
+
+    let solution: Option<AnticaptchaSolution> = provider.get_task_result(&task_id).await.unwrap();
+    let solution = solution.unwrap();
+    assert_eq!(solution.into_image_to_text().text(), "deditur");
+}
+
+#[tokio::test]
+async fn test_get_task_result_processing() {
+    let server = MockServer::start().await;
+    mock_get_task_result(&server, processing_response()).await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("7654321");
+
+    let solution: Option<AnticaptchaSolution> = provider.get_task_result(&task_id).await.unwrap();
+    assert!(solution.is_none());
+}
+
+#[tokio::test]
+async fn test_get_task_result_unsolvable() {
+    let server = MockServer::start().await;
+    mock_get_task_result(
+        &server,
+        error_response("ERROR_CAPTCHA_UNSOLVABLE", "Captcha could not be solved"),
+    )
+    .await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("7654321");
+
+    let err = provider
+        .get_task_result(&task_id)
+        .await
+        .map(|_: Option<AnticaptchaSolution>| ())
+        .unwrap_err();
+    assert!(!err.is_retryable());
+    assert!(err.should_retry_operation());
+}
+
+#[test]
+fn test_error_code_key_fault() {
+    assert_eq!(
+        AnticaptchaErrorCode::KeyDoesNotExist.key_fault(),
+        Some(KeyFault::Invalid)
+    );
+    assert_eq!(
+        AnticaptchaErrorCode::ZeroBalance.key_fault(),
+        Some(KeyFault::ZeroBalance)
+    );
+    assert_eq!(
+        AnticaptchaErrorCode::AccountSuspended.key_fault(),
+        Some(KeyFault::Invalid)
+    );
+    assert_eq!(
+        AnticaptchaErrorCode::IpBlocked.key_fault(),
+        Some(KeyFault::TemporarilyBlocked)
+    );
+    assert_eq!(AnticaptchaErrorCode::NoSlotAvailable.key_fault(), None);
+    assert_eq!(AnticaptchaErrorCode::CaptchaUnsolvable.key_fault(), None);
+}
+
+#[test]
+fn test_builder_default_url() {
+    let provider = AnticaptchaProvider::new("test-key").unwrap();
+    assert_eq!(provider.url().as_str(), "https://api.anti-captcha.com/");
+}
+
+#[test]
+fn test_builder_custom_url() {
+    let custom_url = Url::parse("https://custom.example.com").unwrap();
+    let provider = AnticaptchaProvider::builder("test-key")
+        .url(custom_url.clone())
+        .build()
+        .unwrap();
+    assert_eq!(*provider.url(), custom_url);
+}
+
+#[test]
+fn test_response_deserialization_success() {
+    let json = r#"{
+        "errorId": 0,
+        "taskId": 7654321
+    }"#;
+
+    let response: AnticaptchaResponse<CreateTaskData> = serde_json::from_str(json).unwrap();
+    let data = response.into_result().expect("expected success response");
+    assert_eq!(data.task_id, "7654321");
+}
+
+#[test]
+fn test_response_deserialization_error() {
+    let json = r#"{
+        "errorId": 16,
+        "errorCode": "ERROR_NO_SUCH_CAPCHA_ID",
+        "errorDescription": "Task you are requesting does not exist"
+    }"#;
+
+    let response: AnticaptchaResponse<CreateTaskData> = serde_json::from_str(json).unwrap();
+    let error = response.into_result().expect_err("expected error response");
+    assert_eq!(error.error_code, AnticaptchaErrorCode::NoSuchCaptchaId);
+}
+
+#[test]
+fn test_response_get_task_ready() {
+    let json = r#"{
+        "errorId": 0,
+        "status": "ready",
+        "solution": {"gRecaptchaResponse": "token"}
+    }"#;
+
+    let response: AnticaptchaResponse<GetTaskData<TestSolution>> =
+        serde_json::from_str(json).unwrap();
+    let data = response.into_result().expect("expected success response");
+    assert_eq!(data.status, "ready");
+    assert_eq!(
+        data.solution
+            .expect("expected solution")
+            .g_recaptcha_response,
+        "token"
+    );
+}
@@ -0,0 +1,871 @@
+//! Task and solution types for the Anti-Captcha API.
+
+use crate::errors::UnsupportedTaskError;
+use crate::utils::proxy::RucaptchaProxyFields;
+use crate::utils::serde_helpers::{
+    deserialize_string_or_number, serialize_string_as_number_if_possible,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+// ============================================================================
+// Task Types
+// ============================================================================
+
+/// Anti-Captcha task types for API request payloads.
+///
+/// Anti-Captcha uses separate task types for proxied and proxyless solving.
+/// Its proxy format matches RuCaptcha's (`http` covers both HTTP and HTTPS),
+/// so proxy fields are serialized as [`RucaptchaProxyFields`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum AnticaptchaTask {
+    // -------------------------------------------------------------------------
+    // ReCaptcha V2
+    // -------------------------------------------------------------------------
+    /// ReCaptcha V2 using service's built-in proxy
+    RecaptchaV2TaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(
+            rename = "recaptchaDataSValue",
+            skip_serializing_if = "Option::is_none"
+        )]
+        recaptcha_data_s_value: Option<String>,
+        #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
+        is_invisible: Option<bool>,
+    },
+
+    /// ReCaptcha V2 with custom proxy
+    RecaptchaV2Task {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(
+            rename = "recaptchaDataSValue",
+            skip_serializing_if = "Option::is_none"
+        )]
+        recaptcha_data_s_value: Option<String>,
+        #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
+        is_invisible: Option<bool>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cookies: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // ReCaptcha V2 Enterprise
+    // -------------------------------------------------------------------------
+    /// ReCaptcha V2 Enterprise using service's built-in proxy
+    RecaptchaV2EnterpriseTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, serde_json::Value>>,
+        #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
+        api_domain: Option<String>,
+    },
+
+    /// ReCaptcha V2 Enterprise with custom proxy
+    RecaptchaV2EnterpriseTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, serde_json::Value>>,
+        #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
+        api_domain: Option<String>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cookies: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // ReCaptcha V3 (standard and enterprise, proxyless only)
+    // -------------------------------------------------------------------------
+    /// ReCaptcha V3 using service's built-in proxy
+    RecaptchaV3TaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        /// Required worker score: 0.3, 0.7 or 0.9
+        #[serde(rename = "minScore")]
+        min_score: f32,
+        #[serde(rename = "pageAction", skip_serializing_if = "Option::is_none")]
+        page_action: Option<String>,
+        #[serde(rename = "isEnterprise", skip_serializing_if = "Option::is_none")]
+        is_enterprise: Option<bool>,
+        #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
+        api_domain: Option<String>,
+    },
+
+    // -------------------------------------------------------------------------
+    // Cloudflare Turnstile
+    // -------------------------------------------------------------------------
+    /// Cloudflare Turnstile using service's built-in proxy
+    TurnstileTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        action: Option<String>,
+        #[serde(rename = "turnstileCData", skip_serializing_if = "Option::is_none")]
+        turnstile_cdata: Option<String>,
+    },
+
+    /// Cloudflare Turnstile with custom proxy
+    TurnstileTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        action: Option<String>,
+        #[serde(rename = "turnstileCData", skip_serializing_if = "Option::is_none")]
+        turnstile_cdata: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // Image to Text
+    // -------------------------------------------------------------------------
+    /// Image to text recognition by human workers
+    ImageToTextTask {
+        /// Base64 encoded image content (no newlines, no data:image prefix)
+        body: String,
+        /// Require answer with spaces (multiple words)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        phrase: Option<bool>,
+        /// Case-sensitive answer
+        #[serde(rename = "case", skip_serializing_if = "Option::is_none")]
+        case_sensitive: Option<bool>,
+        /// Numeric constraint: 0=any, 1=numbers only, 2=letters only
+        #[serde(skip_serializing_if = "Option::is_none")]
+        numeric: Option<u8>,
+        /// Math expression to calculate
+        #[serde(skip_serializing_if = "Option::is_none")]
+        math: Option<bool>,
+        /// Minimum answer length
+        #[serde(rename = "minLength", skip_serializing_if = "Option::is_none")]
+        min_length: Option<u32>,
+        /// Maximum answer length
+        #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
+        max_length: Option<u32>,
+        /// Additional instruction text for workers
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+        /// Page the image was taken from (used for worker statistics)
+        #[serde(rename = "websiteURL", skip_serializing_if = "Option::is_none")]
+        website_url: Option<String>,
+    },
+
+    // -------------------------------------------------------------------------
+    // hCaptcha
+    // -------------------------------------------------------------------------
+    /// hCaptcha using service's built-in proxy
+    HCaptchaTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
+        is_invisible: Option<bool>,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, serde_json::Value>>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+    },
+
+    /// hCaptcha with custom proxy
+    HCaptchaTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
+        is_invisible: Option<bool>,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, serde_json::Value>>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+}
+
+impl Display for AnticaptchaTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecaptchaV2TaskProxyless { .. } | Self::RecaptchaV2Task { .. } => {
+                write!(f, "ReCaptchaV2")
+            }
+            Self::RecaptchaV2EnterpriseTaskProxyless { .. }
+            | Self::RecaptchaV2EnterpriseTask { .. } => write!(f, "ReCaptchaV2Enterprise"),
+            Self::RecaptchaV3TaskProxyless {
+                is_enterprise: Some(true),
+                ..
+            } => write!(f, "ReCaptchaV3Enterprise"),
+            Self::RecaptchaV3TaskProxyless { .. } => write!(f, "ReCaptchaV3"),
+            Self::TurnstileTaskProxyless { .. } | Self::TurnstileTask { .. } => {
+                write!(f, "Turnstile")
+            }
+            Self::ImageToTextTask { .. } => write!(f, "ImageToText"),
+            Self::HCaptchaTaskProxyless { .. } | Self::HCaptchaTask { .. } => {
+                write!(f, "HCaptcha")
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Solution Types
+// ============================================================================
+
+// Re-export shared solution types for convenience.
+pub use crate::solutions::{
+    HCaptchaSolution, ImageToTextSolution, ReCaptchaSolution, TokenSolution, TurnstileSolution,
+};
+
+/// Anti-Captcha solution types.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum AnticaptchaSolution {
+    /// Image to text solution (must be first for untagged deserialization priority —
+    /// `ImageToTextSolution` has a unique `text` field)
+    ImageToText(ImageToTextSolution),
+    /// ReCaptcha or hCaptcha solution (same shape; see `try_into_hcaptcha`)
+    ReCaptcha(ReCaptchaSolution),
+    Turnstile(TurnstileSolution),
+}
+
+impl crate::solutions::ProviderSolution for AnticaptchaSolution {}
+
+impl AnticaptchaSolution {
+    /// Try to extract ReCaptcha solution (returns reference).
+    pub fn as_recaptcha(&self) -> Option<&ReCaptchaSolution> {
+        match self {
+            Self::ReCaptcha(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract ReCaptcha solution (consumes self).
+    pub fn try_into_recaptcha(self) -> Result<ReCaptchaSolution, Box<Self>> {
+        match self {
+            Self::ReCaptcha(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract ReCaptcha solution, panics if not ReCaptcha.
+    pub fn into_recaptcha(self) -> ReCaptchaSolution {
+        self.try_into_recaptcha()
+            .expect("Expected ReCaptcha solution")
+    }
+
+    /// Try to extract Turnstile solution (returns reference).
+    pub fn as_turnstile(&self) -> Option<&TurnstileSolution> {
+        match self {
+            Self::Turnstile(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract Turnstile solution (consumes self).
+    pub fn try_into_turnstile(self) -> Result<TurnstileSolution, Box<Self>> {
+        match self {
+            Self::Turnstile(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract Turnstile solution, panics if not Turnstile.
+    pub fn into_turnstile(self) -> TurnstileSolution {
+        self.try_into_turnstile()
+            .expect("Expected Turnstile solution")
+    }
+
+    /// Try to extract ImageToText solution (returns reference).
+    pub fn as_image_to_text(&self) -> Option<&ImageToTextSolution> {
+        match self {
+            Self::ImageToText(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract ImageToText solution (consumes self).
+    pub fn try_into_image_to_text(self) -> Result<ImageToTextSolution, Box<Self>> {
+        match self {
+            Self::ImageToText(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract ImageToText solution, panics if not ImageToText.
+    pub fn into_image_to_text(self) -> ImageToTextSolution {
+        self.try_into_image_to_text()
+            .expect("Expected ImageToText solution")
+    }
+
+    /// Try to extract hCaptcha solution (consumes self).
+    ///
+    /// hCaptcha results deserialize as [`ReCaptcha`](Self::ReCaptcha), which
+    /// has the same shape; call this for hCaptcha tasks.
+    pub fn try_into_hcaptcha(self) -> Result<HCaptchaSolution, Box<Self>> {
+        match self {
+            Self::ReCaptcha(solution) => Ok(solution.into()),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract hCaptcha solution, panics if not hCaptcha.
+    pub fn into_hcaptcha(self) -> HCaptchaSolution {
+        self.try_into_hcaptcha()
+            .expect("Expected HCaptcha solution")
+    }
+
+    /// Try to extract generic token solution (consumes self).
+    ///
+    /// Token-only responses deserialize as the Turnstile variant.
+    pub fn try_into_token(self) -> Result<TokenSolution, Box<Self>> {
+        match self {
+            Self::Turnstile(solution) => TokenSolution::try_from(solution)
+                .map_err(|solution| Box::new(Self::Turnstile(solution))),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract generic token solution, panics if no token is present.
+    pub fn into_token(self) -> TokenSolution {
+        self.try_into_token().expect("Expected token solution")
+    }
+}
+
+// ============================================================================
+// Internal Types (Request/Response)
+// ============================================================================
+
+/// Response data from createTask endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateTaskData {
+    #[serde(deserialize_with = "deserialize_string_or_number")]
+    pub task_id: String,
+}
+
+/// Response data from getTaskResult endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetTaskData<T> {
+    #[allow(dead_code)]
+    pub status: String,
+    pub solution: Option<T>,
+}
+
+/// Request payload for creating a task.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateTaskRequest<'a> {
+    pub(crate) client_key: &'a str,
+    pub(crate) task: &'a AnticaptchaTask,
+}
+
+/// Request payload for getting task result.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetTaskResultRequest<'a> {
+    pub(crate) client_key: &'a str,
+    #[serde(serialize_with = "serialize_string_as_number_if_possible")]
+    pub(crate) task_id: &'a str,
+}
+
+// ============================================================================
+// From/TryFrom implementations for shared task types
+// ============================================================================
+
+impl TryFrom<crate::tasks::ReCaptchaV2> for AnticaptchaTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::ReCaptchaV2) -> Result<Self, Self::Error> {
+        let is_invisible = if task.is_invisible { Some(true) } else { None };
+        let enterprise_payload = task.enterprise_payload;
+
+        let mut unsupported = Vec::new();
+        if task.page_action.is_some() {
+            unsupported.push("page_action");
+        }
+        if task.is_session {
+            unsupported.push("is_session");
+        }
+        if task.is_session_v3 {
+            unsupported.push("is_session_v3");
+        }
+        if task.anchor.is_some() {
+            unsupported.push("anchor");
+        }
+        if task.reload.is_some() {
+            unsupported.push("reload");
+        }
+        // User agent and cookies are only accepted together with a proxy
+        if task.proxy.is_none() {
+            if task.user_agent.is_some() {
+                unsupported.push("user_agent");
+            }
+            if task.cookies.is_some() {
+                unsupported.push("cookies");
+            }
+        }
+        if task.is_enterprise {
+            if task.recaptcha_data_s_value.is_some() {
+                unsupported.push("recaptcha_data_s_value");
+            }
+            if task.is_invisible {
+                unsupported.push("is_invisible");
+            }
+        } else {
+            if task.api_domain.is_some() {
+                unsupported.push("api_domain");
+            }
+            if enterprise_payload.is_some() {
+                unsupported.push("enterprise_payload");
+            }
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "ReCaptchaV2",
+                "Anti-Captcha",
+                unsupported,
+            ));
+        }
+
+        match (task.is_enterprise, task.proxy) {
+            (true, Some(proxy)) => Ok(Self::RecaptchaV2EnterpriseTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                enterprise_payload,
+                api_domain: task.api_domain,
+                user_agent: task.user_agent,
+                cookies: task.cookies,
+                proxy: proxy.into_rucaptcha_fields(),
+            }),
+            (true, None) => Ok(Self::RecaptchaV2EnterpriseTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                enterprise_payload,
+                api_domain: task.api_domain,
+            }),
+            (false, Some(proxy)) => Ok(Self::RecaptchaV2Task {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                recaptcha_data_s_value: task.recaptcha_data_s_value,
+                is_invisible,
+                user_agent: task.user_agent,
+                cookies: task.cookies,
+                proxy: proxy.into_rucaptcha_fields(),
+            }),
+            (false, None) => Ok(Self::RecaptchaV2TaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                recaptcha_data_s_value: task.recaptcha_data_s_value,
+                is_invisible,
+            }),
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::ReCaptchaV3> for AnticaptchaTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::ReCaptchaV3) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.proxy.is_some() {
+            unsupported.push("proxy");
+        }
+        if task.enterprise_payload.is_some() {
+            unsupported.push("enterprise_payload");
+        }
+        if task.is_session {
+            unsupported.push("is_session");
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "ReCaptchaV3",
+                "Anti-Captcha",
+                unsupported,
+            ));
+        }
+
+        // Anti-Captcha requires minScore, default to 0.9 if not specified
+        let min_score = task.min_score.unwrap_or(0.9);
+        let is_enterprise = if task.is_enterprise { Some(true) } else { None };
+
+        Ok(Self::RecaptchaV3TaskProxyless {
+            website_url: task.website_url,
+            website_key: task.website_key,
+            min_score,
+            page_action: task.page_action,
+            is_enterprise,
+            api_domain: task.api_domain,
+        })
+    }
+}
+
+impl TryFrom<crate::tasks::Turnstile> for AnticaptchaTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::Turnstile) -> Result<Self, Self::Error> {
+        if task.pagedata.is_some() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "Turnstile",
+                "Anti-Captcha",
+                vec!["pagedata"],
+            ));
+        }
+
+        match task.proxy {
+            Some(proxy) => Ok(Self::TurnstileTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                action: task.action,
+                turnstile_cdata: task.cdata,
+                proxy: proxy.into_rucaptcha_fields(),
+            }),
+            None => Ok(Self::TurnstileTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                action: task.action,
+                turnstile_cdata: task.cdata,
+            }),
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::ImageToText> for AnticaptchaTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::ImageToText) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        // Anti-Captcha only knows "numbers only" (1) and "letters only" (2)
+        if task.numeric > 2 {
+            unsupported.push("numeric");
+        }
+        if task.module.is_some() {
+            unsupported.push("module");
+        }
+        if task.img_instructions.is_some() {
+            unsupported.push("img_instructions");
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "ImageToText",
+                "Anti-Captcha",
+                unsupported,
+            ));
+        }
+
+        Ok(Self::ImageToTextTask {
+            body: task.body,
+            phrase: if task.phrase { Some(true) } else { None },
+            case_sensitive: if task.case_sensitive {
+                Some(true)
+            } else {
+                None
+            },
+            numeric: if task.numeric != 0 {
+                Some(task.numeric)
+            } else {
+                None
+            },
+            math: if task.math { Some(true) } else { None },
+            min_length: if task.min_length > 0 {
+                Some(task.min_length)
+            } else {
+                None
+            },
+            max_length: if task.max_length > 0 {
+                Some(task.max_length)
+            } else {
+                None
+            },
+            comment: task.comment,
+            website_url: task.website_url,
+        })
+    }
+}
+
+impl From<crate::tasks::HCaptcha> for AnticaptchaTask {
+    fn from(task: crate::tasks::HCaptcha) -> Self {
+        let is_invisible = if task.is_invisible { Some(true) } else { None };
+        let enterprise_payload = task.enterprise_payload;
+
+        match task.proxy {
+            Some(proxy) => Self::HCaptchaTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                is_invisible,
+                enterprise_payload,
+                user_agent: task.user_agent,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::HCaptchaTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                is_invisible,
+                enterprise_payload,
+                user_agent: task.user_agent,
+            },
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::CaptchaTask> for AnticaptchaTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::CaptchaTask) -> Result<Self, Self::Error> {
+        match task {
+            crate::tasks::CaptchaTask::ReCaptchaV2(t) => t.try_into(),
+            crate::tasks::CaptchaTask::ReCaptchaV3(t) => t.try_into(),
+            crate::tasks::CaptchaTask::Turnstile(t) => t.try_into(),
+            crate::tasks::CaptchaTask::ImageToText(t) => t.try_into(),
+            crate::tasks::CaptchaTask::HCaptcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::TurnstileChallenge(_) => Err(UnsupportedTaskError::new(
+                "TurnstileChallenge",
+                "Anti-Captcha",
+            )),
+            crate::tasks::CaptchaTask::TurnstileWaitRoom(_) => Err(UnsupportedTaskError::new(
+                "TurnstileWaitRoom",
+                "Anti-Captcha",
+            )),
+            crate::tasks::CaptchaTask::CloudflareChallenge(_) => Err(UnsupportedTaskError::new(
+                "CloudflareChallenge",
+                "Anti-Captcha",
+            )),
+            crate::tasks::CaptchaTask::GridClassification(_) => Err(UnsupportedTaskError::new(
+                "GridClassification",
+                "Anti-Captcha",
+            )),
+            crate::tasks::CaptchaTask::Rotate(_) => {
+                Err(UnsupportedTaskError::new("Rotate", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::AudioToText(_) => {
+                Err(UnsupportedTaskError::new("AudioToText", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::MtCaptcha(_) => {
+                Err(UnsupportedTaskError::new("MtCaptcha", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::FriendlyCaptcha(_) => {
+                Err(UnsupportedTaskError::new("FriendlyCaptcha", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::Cutcaptcha(_) => {
+                Err(UnsupportedTaskError::new("Cutcaptcha", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::YandexSmartCaptcha(_) => Err(UnsupportedTaskError::new(
+                "YandexSmartCaptcha",
+                "Anti-Captcha",
+            )),
+            crate::tasks::CaptchaTask::Tencent(_) => {
+                Err(UnsupportedTaskError::new("Tencent", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::Lemin(_) => {
+                Err(UnsupportedTaskError::new("Lemin", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::KeyCaptcha(_) => {
+                Err(UnsupportedTaskError::new("KeyCaptcha", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::Capy(_) => {
+                Err(UnsupportedTaskError::new("Capy", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::Imperva(_) => {
+                Err(UnsupportedTaskError::new("Imperva", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::Kasada(_) => {
+                Err(UnsupportedTaskError::new("Kasada", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::Prosopo(_) => {
+                Err(UnsupportedTaskError::new("Prosopo", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::CaptchaFox(_) => {
+                Err(UnsupportedTaskError::new("CaptchaFox", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::Altcha(_) => {
+                Err(UnsupportedTaskError::new("Altcha", "Anti-Captcha"))
+            }
+            crate::tasks::CaptchaTask::TextQuestion(_) => {
+                Err(UnsupportedTaskError::new("TextQuestion", "Anti-Captcha"))
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tasks::{HCaptcha, ImageToText, ReCaptchaV2, ReCaptchaV3, Turnstile};
+    use crate::utils::proxy::ProxyConfig;
+
+    #[test]
+    fn test_recaptcha_v2_proxyless_serialization() {
+        let task: AnticaptchaTask = ReCaptchaV2::new("https://example.com", "site-key")
+            .invisible()
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "RecaptchaV2TaskProxyless");
+        assert_eq!(json["websiteURL"], "https://example.com");
+        assert_eq!(json["isInvisible"], true);
+    }
+
+    #[test]
+    fn test_recaptcha_v2_with_proxy_serialization() {
+        let task: AnticaptchaTask = ReCaptchaV2::new("https://example.com", "site-key")
+            .with_user_agent("Mozilla/5.0")
+            .with_proxy(ProxyConfig::https("1.2.3.4", 8080))
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "RecaptchaV2Task");
+        assert_eq!(json["userAgent"], "Mozilla/5.0");
+        assert_eq!(json["proxyType"], "http");
+        assert_eq!(json["proxyPort"], 8080);
+    }
+
+    #[test]
+    fn test_recaptcha_v2_enterprise_serialization() {
+        let task: AnticaptchaTask = ReCaptchaV2::new("https://example.com", "site-key")
+            .enterprise()
+            .with_api_domain("www.recaptcha.net")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "RecaptchaV2EnterpriseTaskProxyless");
+        assert_eq!(json["apiDomain"], "www.recaptcha.net");
+    }
+
+    #[test]
+    fn test_recaptcha_v2_rejects_proxyless_user_agent() {
+        let task = ReCaptchaV2::new("https://example.com", "site-key")
+            .with_user_agent("Mozilla/5.0")
+            .with_action("verify");
+        let result: Result<AnticaptchaTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert!(err.unsupported_fields.contains(&"user_agent"));
+        assert!(err.unsupported_fields.contains(&"page_action"));
+    }
+
+    #[test]
+    fn test_recaptcha_v3_serialization() {
+        let task: AnticaptchaTask = ReCaptchaV3::new("https://example.com", "site-key")
+            .enterprise()
+            .with_min_score(0.3)
+            .with_action("login")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "RecaptchaV3TaskProxyless");
+        assert_eq!(json["isEnterprise"], true);
+        assert_eq!(json["pageAction"], "login");
+        assert!((json["minScore"].as_f64().unwrap() - 0.3).abs() < 1e-6);
+        assert_eq!(task.to_string(), "ReCaptchaV3Enterprise");
+    }
+
+    #[test]
+    fn test_recaptcha_v3_rejects_proxy() {
+        let task = ReCaptchaV3::new("https://example.com", "site-key")
+            .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+        let result: Result<AnticaptchaTask, _> = task.try_into();
+        assert!(result.unwrap_err().unsupported_fields.contains(&"proxy"));
+    }
+
+    #[test]
+    fn test_turnstile_serialization() {
+        let task: AnticaptchaTask = Turnstile::new("https://example.com", "site-key")
+            .with_action("login")
+            .with_cdata("cdata")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "TurnstileTaskProxyless");
+        assert_eq!(json["action"], "login");
+        assert_eq!(json["turnstileCData"], "cdata");
+    }
+
+    #[test]
+    fn test_image_to_text_serialization() {
+        let task: AnticaptchaTask = ImageToText::from_base64("aGVsbG8=")
+            .case_sensitive()
+            .with_numeric(1)
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "ImageToTextTask");
+        assert_eq!(json["body"], "aGVsbG8=");
+        assert_eq!(json["case"], true);
+        assert_eq!(json["numeric"], 1);
+        assert!(json.get("phrase").is_none());
+    }
+
+    #[test]
+    fn test_image_to_text_rejects_unsupported_fields() {
+        let task = ImageToText::from_base64("aGVsbG8=")
+            .with_numeric(4)
+            .with_module("common");
+        let result: Result<AnticaptchaTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert!(err.unsupported_fields.contains(&"numeric"));
+        assert!(err.unsupported_fields.contains(&"module"));
+    }
+
+    #[test]
+    fn test_hcaptcha_with_proxy_serialization() {
+        let task: AnticaptchaTask = HCaptcha::new("https://example.com", "site-key")
+            .with_rqdata("rq")
+            .with_proxy(ProxyConfig::socks5("1.2.3.4", 1080))
+            .into();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "HCaptchaTask");
+        assert_eq!(json["enterprisePayload"]["rqdata"], "rq");
+        assert_eq!(json["proxyType"], "socks5");
+    }
+
+    #[test]
+    fn test_solution_deserialization() {
+        let json = r#"{"text": "deditur", "url": "http://61.39.233.233/1/147220556452507.jpg"}"#;
+        let solution: AnticaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_image_to_text().text(), "deditur");
+
+        let json = r#"{"gRecaptchaResponse": "P1_eyJ0eXAi", "respKey": "E0_eyJ0eXAi"}"#;
+        let solution: AnticaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_hcaptcha().resp_key(), Some("E0_eyJ0eXAi"));
+
+        let json = r#"{"gRecaptchaResponse": "3AHJ_VuvYIBNBW5yyv0zRYJ75VkOKvhKj9_xGBJKnQimF72rfoq3Iy-DyGHMwLAo6a3"}"#;
+        let solution: AnticaptchaSolution = serde_json::from_str(json).unwrap();
+        assert!(solution.as_recaptcha().is_some());
+
+        let json = r#"{"token": "0.vtJqmZnvobaUzK2i2PyKaSqHELYtBZfRoPwMvLMdA81WL", "userAgent": "Mozilla/5.0"}"#;
+        let solution: AnticaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            solution.into_token().token(),
+            "0.vtJqmZnvobaUzK2i2PyKaSqHELYtBZfRoPwMvLMdA81WL"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::errors::{RetryableError, UnsupportedTaskError};
+use crate::errors::{KeyFault, RetryableError, UnsupportedTaskError};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use thiserror::Error;
@@ -48,6 +48,13 @@ impl RetryableError for CapmonsterError {
             | CapmonsterError::InvalidTaskData(_) => false,
         }
     }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            CapmonsterError::Api(error) => error.error_code.key_fault(),
+            _ => None,
+        }
+    }
 }
 
 /// Known CapMonster API error codes.
@@ -139,6 +146,16 @@ impl CapmonsterErrorCode {
         )
     }
 
+    /// Returns the API key problem this code reports, if any
+    pub fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            Self::KeyDoesNotExist | Self::KeyDeniedAccess => Some(KeyFault::Invalid),
+            Self::ZeroBalance => Some(KeyFault::ZeroBalance),
+            Self::KeyTempBlocked => Some(KeyFault::TemporarilyBlocked),
+            _ => None,
+        }
+    }
+
     pub fn should_retry_operation(&self) -> bool {
         match self {
             Self::ServiceUnavailable
@@ -15,8 +15,19 @@
 //! | Turnstile Challenge | [`TurnstileChallenge`](crate::TurnstileChallenge) | Depends on mode |
 //! | Turnstile Wait Room | [`TurnstileWaitRoom`](crate::TurnstileWaitRoom) | Yes |
 //! | Image to Text | [`ImageToText`](crate::ImageToText) | No |
-//!
-//! **Note**: [`CloudflareChallenge`](crate::CloudflareChallenge) is not supported by CapMonster.
+//! | Grid Classification | [`GridClassification`](crate::GridClassification) | No |
+//! | Rotate | [`Rotate`](crate::Rotate) | No |
+//! | MTCaptcha | [`MtCaptcha`](crate::MtCaptcha) | No |
+//! | Imperva (Incapsula) | [`Imperva`](crate::Imperva) | Yes |
+//! | Kasada | [`Kasada`](crate::Kasada) | Yes |
+//! | Prosopo Procaptcha | [`Prosopo`](crate::Prosopo) | No |
+//! | Altcha | [`Altcha`](crate::Altcha) | No |
+//!
+//! **Note**: [`CloudflareChallenge`](crate::CloudflareChallenge), [`AudioToText`](crate::AudioToText),
+//! [`FriendlyCaptcha`](crate::FriendlyCaptcha), [`Cutcaptcha`](crate::Cutcaptcha), [`YandexSmartCaptcha`](crate::YandexSmartCaptcha)
+//! [`Tencent`](crate::Tencent), [`Lemin`](crate::Lemin), [`KeyCaptcha`](crate::KeyCaptcha)
+//! [`Capy`](crate::Capy), [`CaptchaFox`](crate::CaptchaFox), [`TextQuestion`](crate::TextQuestion)
+//! and [`HCaptcha`](crate::HCaptcha) are not supported by CapMonster.
 //! For `cf_clearance` use cases, use [`TurnstileChallenge::cf_clearance()`](crate::TurnstileChallenge::cf_clearance)
 //! which maps to CapMonster's `TurnstileTask` with `cloudflareTaskType = "cf_clearance"`.
 //!
@@ -126,6 +137,8 @@
 //! - **ReCaptcha V2/V3**: [`ReCaptchaSolution`] with `token()` method
 //! - **Turnstile / Challenge / WaitRoom**: [`TurnstileSolution`] with `token()` and `cf_clearance()` methods
 //! - **Image to Text**: [`ImageToTextSolution`] with `text()` method
+//! - **MTCaptcha / Prosopo / Altcha**: [`TokenSolution`] with `token()` method
+//! - **Imperva / Kasada**: [`AntiBotSolution`] with `cookies`, `headers` and `apply()`
 //!
 //! ## Error Handling
 //!
@@ -157,7 +170,10 @@ pub use errors::{CapmonsterApiError, CapmonsterError, CapmonsterErrorCode};
 pub use provider::{CapmonsterProvider, CapmonsterProviderBuilder, DEFAULT_API_URL};
 
 // Solutions
-pub use types::{CapmonsterSolution, ImageToTextSolution, ReCaptchaSolution, TurnstileSolution};
+pub use types::{
+    AntiBotSolution, CapmonsterSolution, GridClassificationSolution, ImageToTextSolution,
+    ReCaptchaSolution, RotateSolution, TokenSolution, TurnstileSolution,
+};
 
 // Re-export proxy types for convenience (also available at crate root)
 pub use crate::utils::proxy::{ProxyConfig, ProxyType};
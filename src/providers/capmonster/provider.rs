@@ -172,35 +172,56 @@ impl CapmonsterProvider {
                 proxy,
                 ..
             } => match task_type.as_str() {
-                "token" => {
+                "token"
                     if page_action.is_none()
                         || data.is_none()
                         || page_data.is_none()
-                        || user_agent.is_none()
-                    {
-                        return Err(CapmonsterError::InvalidTaskData(
-                            "Turnstile challenge token mode requires pageAction, data, pageData and userAgent".to_string(),
-                        ));
-                    }
+                        || user_agent.is_none() =>
+                {
+                    return Err(CapmonsterError::InvalidTaskData(
+                        "Turnstile challenge token mode requires pageAction, data, pageData and userAgent".to_string(),
+                    ));
                 }
-                "cf_clearance" => {
-                    if html_page_base64.is_none() || user_agent.is_none() || proxy.is_none() {
-                        return Err(CapmonsterError::InvalidTaskData(
-                            "Turnstile challenge cf_clearance mode requires htmlPageBase64, userAgent and proxy"
-                                .to_string(),
-                        ));
-                    }
+                "cf_clearance"
+                    if html_page_base64.is_none() || user_agent.is_none() || proxy.is_none() =>
+                {
+                    return Err(CapmonsterError::InvalidTaskData(
+                        "Turnstile challenge cf_clearance mode requires htmlPageBase64, userAgent and proxy"
+                            .to_string(),
+                    ));
                 }
-                "wait_room" => {
-                    if html_page_base64.is_none() || user_agent.is_none() || proxy.is_none() {
-                        return Err(CapmonsterError::InvalidTaskData(
-                            "Turnstile wait_room mode requires htmlPageBase64, userAgent and proxy"
-                                .to_string(),
-                        ));
-                    }
+                "wait_room"
+                    if html_page_base64.is_none() || user_agent.is_none() || proxy.is_none() =>
+                {
+                    return Err(CapmonsterError::InvalidTaskData(
+                        "Turnstile wait_room mode requires htmlPageBase64, userAgent and proxy"
+                            .to_string(),
+                    ));
                 }
                 _ => {}
             },
+            CustomTask {
+                class,
+                user_agent,
+                metadata,
+                ..
+            } if class == "Imperva"
+                && (user_agent.is_none()
+                    || metadata.incapsula_script_base64.is_none()
+                    || metadata.incapsula_session_cookie.is_none()) =>
+            {
+                return Err(CapmonsterError::InvalidTaskData(
+                    "Imperva task requires userAgent, incapsulaScriptBase64 and incapsulaSessionCookie"
+                        .to_string(),
+                ));
+            }
+            ComplexImageTask {
+                class, metadata, ..
+            } if class == "recognition" && metadata.task.is_none() => {
+                return Err(CapmonsterError::InvalidTaskData(
+                    "Recognition task requires a module (metadata Task)".to_string(),
+                ));
+            }
             _ => {}
         }
 
@@ -5,12 +5,15 @@ use super::provider::CapmonsterProvider;
 use super::response::CapmonsterResponse;
 use super::types::{CapmonsterSolution, CreateTaskData, GetTaskData};
 use crate::providers::traits::Provider;
-use crate::tasks::{Turnstile, TurnstileChallenge, TurnstileChallengeMode};
+use crate::tasks::{
+    Altcha, CaptchaFox, HCaptcha, Imperva, Prosopo, Rotate, Turnstile, TurnstileChallenge,
+    TurnstileChallengeMode,
+};
 use crate::utils::types::TaskId;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_partial_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -134,6 +137,93 @@ async fn test_create_task_invalid_token_challenge_data() {
     assert!(matches!(err, CapmonsterError::InvalidTaskData(_)));
 }
 
+#[tokio::test]
+async fn test_create_task_rotate_requires_module() {
+    let provider = CapmonsterProvider::new("test_api_key").unwrap();
+    let task = Rotate::from_base64("image");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(err, CapmonsterError::InvalidTaskData(_)));
+}
+
+#[tokio::test]
+async fn test_create_task_imperva_requires_script_and_cookie() {
+    let provider = CapmonsterProvider::new("test_api_key").unwrap();
+    let task = Imperva::new(
+        "https://example.com",
+        crate::utils::proxy::ProxyConfig::http("1.2.3.4", 8080),
+    )
+    .with_user_agent("Mozilla/5.0");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(err, CapmonsterError::InvalidTaskData(_)));
+}
+
+#[tokio::test]
+async fn test_create_task_prosopo() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "task": {
+                "type": "ProsopoTask",
+                "websiteURL": "https://example.com",
+                "websiteKey": "5EZVvs",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(success_create_task_response("1")))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = Prosopo::new("https://example.com", "5EZVvs");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "1");
+}
+
+#[tokio::test]
+async fn test_create_task_altcha_custom_task() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "task": {
+                "type": "CustomTask",
+                "class": "altcha",
+                "websiteURL": "https://example.com",
+                "websiteKey": "https://example.com/challenge",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(success_create_task_response("2")))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = Altcha::new("https://example.com", "https://example.com/challenge");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "2");
+}
+
+#[tokio::test]
+async fn test_create_task_captcha_fox_unsupported() {
+    let provider = CapmonsterProvider::new("test_api_key").unwrap();
+    let task = CaptchaFox::new("https://example.com", "sk_key");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(err, CapmonsterError::UnsupportedTask(_)));
+}
+
+#[tokio::test]
+async fn test_create_task_hcaptcha_unsupported() {
+    let provider = CapmonsterProvider::new("test_api_key").unwrap();
+    let task = HCaptcha::new("https://example.com", "site-key");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(err, CapmonsterError::UnsupportedTask(_)));
+}
+
 #[tokio::test]
 async fn test_get_task_result_ready() {
     let server = MockServer::start().await;
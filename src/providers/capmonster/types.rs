@@ -125,6 +125,88 @@ pub enum CapmonsterTask {
         #[serde(rename = "CapMonsterModule", skip_serializing_if = "Option::is_none")]
         module: Option<String>,
     },
+
+    // -------------------------------------------------------------------------
+    // Complex Image (Grid Classification)
+    // -------------------------------------------------------------------------
+    ComplexImageTask {
+        class: String,
+        #[serde(rename = "imagesBase64")]
+        images_base64: Vec<String>,
+        metadata: ComplexImageMetadata,
+        #[serde(rename = "websiteUrl", skip_serializing_if = "Option::is_none")]
+        website_url: Option<String>,
+    },
+
+    // -------------------------------------------------------------------------
+    // MTCaptcha
+    // -------------------------------------------------------------------------
+    #[serde(rename = "MTCaptchaTask")]
+    MtCaptchaTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<ApiProxyFields>,
+    },
+
+    // -------------------------------------------------------------------------
+    // Prosopo Procaptcha
+    // -------------------------------------------------------------------------
+    ProsopoTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<ApiProxyFields>,
+    },
+
+    // -------------------------------------------------------------------------
+    // Custom Task (Imperva / Kasada / Altcha)
+    // -------------------------------------------------------------------------
+    CustomTask {
+        class: String,
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey", skip_serializing_if = "Option::is_none")]
+        website_key: Option<String>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        metadata: CustomTaskMetadata,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<ApiProxyFields>,
+    },
+}
+
+/// Metadata for `CustomTask` (Imperva class).
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomTaskMetadata {
+    /// Base64 encoded Incapsula script content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incapsula_script_base64: Option<String>,
+    /// Incapsula session cookie (`incap_ses_*=...`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incapsula_session_cookie: Option<String>,
+    /// URL of the reese84 script endpoint
+    #[serde(rename = "reese84UrlEndpoint", skip_serializing_if = "Option::is_none")]
+    pub reese84_url_endpoint: Option<String>,
+}
+
+/// Metadata for `ComplexImageTask` (reCAPTCHA and recognition classes).
+#[derive(Debug, Clone, Serialize)]
+pub struct ComplexImageMetadata {
+    /// Grid size ("4x4", "3x3" or "1x1" for individual tiles, reCAPTCHA class only)
+    #[serde(rename = "Grid", skip_serializing_if = "Option::is_none")]
+    pub grid: Option<String>,
+    /// Task text (e.g., "Click on traffic lights") or recognition task name
+    #[serde(rename = "Task", skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+    /// Task object ID (e.g., "/m/015qff")
+    #[serde(rename = "TaskDefinition", skip_serializing_if = "Option::is_none")]
+    pub task_definition: Option<String>,
 }
 
 impl Display for CapmonsterTask {
@@ -149,6 +231,12 @@ impl Display for CapmonsterTask {
             },
             Self::TurnstileTask { .. } => write!(f, "Turnstile"),
             Self::ImageToTextTask { .. } => write!(f, "ImageToText"),
+            Self::ComplexImageTask { class, .. } if class == "recognition" => write!(f, "Rotate"),
+            Self::ComplexImageTask { .. } => write!(f, "GridClassification"),
+            Self::MtCaptchaTask { .. } => write!(f, "MtCaptcha"),
+            Self::ProsopoTask { .. } => write!(f, "Prosopo"),
+            Self::CustomTask { class, .. } if class == "altcha" => write!(f, "Altcha"),
+            Self::CustomTask { class, .. } => write!(f, "{class}"),
         }
     }
 }
@@ -158,7 +246,10 @@ impl Display for CapmonsterTask {
 // ============================================================================
 
 // Re-export shared solution types for convenience.
-pub use crate::solutions::{ImageToTextSolution, ReCaptchaSolution, TurnstileSolution};
+pub use crate::solutions::{
+    AntiBotSolution, GridClassificationSolution, ImageToTextSolution, ReCaptchaSolution,
+    RotateSolution, TokenSolution, TurnstileSolution,
+};
 
 /// CapMonster solution types.
 #[derive(Debug, Clone, Deserialize)]
@@ -167,7 +258,11 @@ pub enum CapmonsterSolution {
     /// Image to text solution (must be first for untagged deserialization priority —
     /// `ImageToTextSolution` has a unique `text` field)
     ImageToText(ImageToTextSolution),
+    GridClassification(GridClassificationSolution),
+    Rotate(RotateSolution),
     ReCaptcha(ReCaptchaSolution),
+    /// Tried before Turnstile; `AntiBotSolution` rejects Turnstile-shaped payloads
+    AntiBot(AntiBotSolution),
     Turnstile(TurnstileSolution),
 }
 
@@ -239,6 +334,86 @@ impl CapmonsterSolution {
         self.try_into_image_to_text()
             .expect("Expected ImageToText solution")
     }
+
+    /// Try to extract GridClassification solution (returns reference).
+    pub fn as_grid_classification(&self) -> Option<&GridClassificationSolution> {
+        match self {
+            Self::GridClassification(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract GridClassification solution (consumes self).
+    pub fn try_into_grid_classification(self) -> Result<GridClassificationSolution, Box<Self>> {
+        match self {
+            Self::GridClassification(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract GridClassification solution, panics if not GridClassification.
+    pub fn into_grid_classification(self) -> GridClassificationSolution {
+        self.try_into_grid_classification()
+            .expect("Expected GridClassification solution")
+    }
+
+    /// Try to extract Rotate solution (returns reference).
+    pub fn as_rotate(&self) -> Option<&RotateSolution> {
+        match self {
+            Self::Rotate(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract Rotate solution (consumes self).
+    pub fn try_into_rotate(self) -> Result<RotateSolution, Box<Self>> {
+        match self {
+            Self::Rotate(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract Rotate solution, panics if not Rotate.
+    pub fn into_rotate(self) -> RotateSolution {
+        self.try_into_rotate().expect("Expected Rotate solution")
+    }
+
+    /// Try to extract AntiBot solution (returns reference).
+    pub fn as_anti_bot(&self) -> Option<&AntiBotSolution> {
+        match self {
+            Self::AntiBot(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract AntiBot solution (consumes self).
+    pub fn try_into_anti_bot(self) -> Result<AntiBotSolution, Box<Self>> {
+        match self {
+            Self::AntiBot(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract AntiBot solution, panics if not AntiBot.
+    pub fn into_anti_bot(self) -> AntiBotSolution {
+        self.try_into_anti_bot().expect("Expected AntiBot solution")
+    }
+
+    /// Try to extract generic token solution (consumes self).
+    ///
+    /// Token-only responses deserialize as the Turnstile variant.
+    pub fn try_into_token(self) -> Result<TokenSolution, Box<Self>> {
+        match self {
+            Self::Turnstile(solution) => TokenSolution::try_from(solution)
+                .map_err(|solution| Box::new(Self::Turnstile(solution))),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract generic token solution, panics if no token is present.
+    pub fn into_token(self) -> TokenSolution {
+        self.try_into_token().expect("Expected token solution")
+    }
 }
 
 // ============================================================================
@@ -290,8 +465,21 @@ impl TryFrom<crate::tasks::ReCaptchaV2> for CapmonsterTask {
         let is_invisible = if task.is_invisible { Some(true) } else { None };
         let proxy = task.proxy.map(|p| p.into_api_proxy_fields());
 
+        let mut unsupported = Vec::new();
+        if task.is_session {
+            unsupported.push("is_session");
+        }
+        if task.is_session_v3 {
+            unsupported.push("is_session_v3");
+        }
+        if task.anchor.is_some() {
+            unsupported.push("anchor");
+        }
+        if task.reload.is_some() {
+            unsupported.push("reload");
+        }
+
         if task.is_enterprise {
-            let mut unsupported = Vec::new();
             if task.recaptcha_data_s_value.is_some() {
                 unsupported.push("recaptcha_data_s_value");
             }
@@ -315,7 +503,6 @@ impl TryFrom<crate::tasks::ReCaptchaV2> for CapmonsterTask {
                 proxy,
             })
         } else {
-            let mut unsupported = Vec::new();
             if task.page_action.is_some() {
                 unsupported.push("page_action");
             }
@@ -360,6 +547,9 @@ impl TryFrom<crate::tasks::ReCaptchaV3> for CapmonsterTask {
         if task.enterprise_payload.is_some() {
             unsupported.push("enterprise_payload");
         }
+        if task.is_session {
+            unsupported.push("is_session");
+        }
         if !unsupported.is_empty() {
             return Err(UnsupportedTaskError::unsupported_fields(
                 "ReCaptchaV3",
@@ -484,6 +674,183 @@ impl TryFrom<crate::tasks::ImageToText> for CapmonsterTask {
     }
 }
 
+impl TryFrom<crate::tasks::GridClassification> for CapmonsterTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::GridClassification) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        let (images_base64, grid) = match task.images {
+            crate::tasks::GridImages::Grid(image) => {
+                if task.rows != task.columns || !(3..=4).contains(&task.rows) {
+                    unsupported.push("rows");
+                    unsupported.push("columns");
+                }
+                (vec![image], format!("{}x{}", task.rows, task.columns))
+            }
+            crate::tasks::GridImages::Tiles(tiles) => (tiles, "1x1".to_string()),
+        };
+        if task.website_key.is_some() {
+            unsupported.push("website_key");
+        }
+        if task.img_instructions.is_some() {
+            unsupported.push("img_instructions");
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "GridClassification",
+                "CapMonster",
+                unsupported,
+            ));
+        }
+
+        // Object IDs such as "/m/015qff" go to TaskDefinition, plain text to Task
+        let (task_text, task_definition) = match task.question {
+            Some(question) if question.starts_with("/m/") => (None, Some(question)),
+            question => (question, None),
+        };
+
+        Ok(Self::ComplexImageTask {
+            class: "recaptcha".to_string(),
+            images_base64,
+            metadata: ComplexImageMetadata {
+                grid: Some(grid),
+                task: task_text,
+                task_definition,
+            },
+            website_url: task.website_url,
+        })
+    }
+}
+
+impl TryFrom<crate::tasks::Rotate> for CapmonsterTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::Rotate) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.angle_step.is_some() {
+            unsupported.push("angle_step");
+        }
+        if task.comment.is_some() {
+            unsupported.push("comment");
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "Rotate",
+                "CapMonster",
+                unsupported,
+            ));
+        }
+
+        Ok(Self::ComplexImageTask {
+            class: "recognition".to_string(),
+            images_base64: vec![task.body],
+            metadata: ComplexImageMetadata {
+                grid: None,
+                task: task.module,
+                task_definition: None,
+            },
+            website_url: None,
+        })
+    }
+}
+
+impl From<crate::tasks::MtCaptcha> for CapmonsterTask {
+    fn from(task: crate::tasks::MtCaptcha) -> Self {
+        Self::MtCaptchaTask {
+            website_url: task.website_url,
+            website_key: task.website_key,
+            proxy: task.proxy.map(|p| p.into_api_proxy_fields()),
+        }
+    }
+}
+
+impl From<crate::tasks::Prosopo> for CapmonsterTask {
+    fn from(task: crate::tasks::Prosopo) -> Self {
+        Self::ProsopoTask {
+            website_url: task.website_url,
+            website_key: task.website_key,
+            proxy: task.proxy.map(|p| p.into_api_proxy_fields()),
+        }
+    }
+}
+
+impl From<crate::tasks::Altcha> for CapmonsterTask {
+    fn from(task: crate::tasks::Altcha) -> Self {
+        Self::CustomTask {
+            class: "altcha".to_string(),
+            website_url: task.website_url,
+            website_key: Some(task.website_key),
+            user_agent: None,
+            metadata: CustomTaskMetadata::default(),
+            proxy: task.proxy.map(|p| p.into_api_proxy_fields()),
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::Imperva> for CapmonsterTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::Imperva) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.utmvc {
+            unsupported.push("utmvc");
+        }
+        if task.reese84 {
+            unsupported.push("reese84");
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "Imperva",
+                "CapMonster",
+                unsupported,
+            ));
+        }
+
+        Ok(Self::CustomTask {
+            class: "Imperva".to_string(),
+            website_url: task.website_url,
+            website_key: None,
+            user_agent: task.user_agent,
+            metadata: CustomTaskMetadata {
+                incapsula_script_base64: task.incapsula_script,
+                incapsula_session_cookie: task.session_cookie,
+                reese84_url_endpoint: task.reese84_script_url,
+            },
+            proxy: Some(task.proxy.into_api_proxy_fields()),
+        })
+    }
+}
+
+impl TryFrom<crate::tasks::Kasada> for CapmonsterTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::Kasada) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.cd {
+            unsupported.push("cd");
+        }
+        if task.only_cd {
+            unsupported.push("only_cd");
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "Kasada",
+                "CapMonster",
+                unsupported,
+            ));
+        }
+
+        Ok(Self::CustomTask {
+            class: "Kasada".to_string(),
+            website_url: task.website_url,
+            website_key: None,
+            user_agent: task.user_agent,
+            metadata: CustomTaskMetadata::default(),
+            proxy: Some(task.proxy.into_api_proxy_fields()),
+        })
+    }
+}
+
 impl TryFrom<crate::tasks::CaptchaTask> for CapmonsterTask {
     type Error = UnsupportedTaskError;
 
@@ -499,6 +866,47 @@ impl TryFrom<crate::tasks::CaptchaTask> for CapmonsterTask {
                 "CapMonster",
             )),
             crate::tasks::CaptchaTask::ImageToText(t) => t.try_into(),
+            crate::tasks::CaptchaTask::GridClassification(t) => t.try_into(),
+            crate::tasks::CaptchaTask::Rotate(t) => t.try_into(),
+            crate::tasks::CaptchaTask::AudioToText(_) => {
+                Err(UnsupportedTaskError::new("AudioToText", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::MtCaptcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Imperva(t) => t.try_into(),
+            crate::tasks::CaptchaTask::Kasada(t) => t.try_into(),
+            crate::tasks::CaptchaTask::Prosopo(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Altcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::HCaptcha(_) => {
+                Err(UnsupportedTaskError::new("HCaptcha", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::TextQuestion(_) => {
+                Err(UnsupportedTaskError::new("TextQuestion", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::CaptchaFox(_) => {
+                Err(UnsupportedTaskError::new("CaptchaFox", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::FriendlyCaptcha(_) => {
+                Err(UnsupportedTaskError::new("FriendlyCaptcha", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::Cutcaptcha(_) => {
+                Err(UnsupportedTaskError::new("Cutcaptcha", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::YandexSmartCaptcha(_) => Err(UnsupportedTaskError::new(
+                "YandexSmartCaptcha",
+                "CapMonster",
+            )),
+            crate::tasks::CaptchaTask::Tencent(_) => {
+                Err(UnsupportedTaskError::new("Tencent", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::Lemin(_) => {
+                Err(UnsupportedTaskError::new("Lemin", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::KeyCaptcha(_) => {
+                Err(UnsupportedTaskError::new("KeyCaptcha", "CapMonster"))
+            }
+            crate::tasks::CaptchaTask::Capy(_) => {
+                Err(UnsupportedTaskError::new("Capy", "CapMonster"))
+            }
         }
     }
 }
@@ -511,7 +919,8 @@ impl TryFrom<crate::tasks::CaptchaTask> for CapmonsterTask {
 mod tests {
     use super::*;
     use crate::tasks::{
-        ImageToText, ReCaptchaV2, ReCaptchaV3, Turnstile, TurnstileChallenge, TurnstileWaitRoom,
+        GridClassification, ImageToText, ReCaptchaV2, ReCaptchaV3, Rotate, Turnstile,
+        TurnstileChallenge, TurnstileWaitRoom,
     };
     use crate::utils::proxy::ProxyConfig;
 
@@ -536,6 +945,27 @@ mod tests {
         assert!(json.contains("RecaptchaV2EnterpriseTask"));
     }
 
+    #[test]
+    fn test_recaptcha_rejects_session_options() {
+        let task = ReCaptchaV2::new("https://example.com", "key")
+            .enterprise()
+            .session()
+            .with_anchor("anchor-data");
+        let result: Result<CapmonsterTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert!(err.unsupported_fields.contains(&"is_session"));
+        assert!(err.unsupported_fields.contains(&"anchor"));
+
+        let task = ReCaptchaV3::new("https://example.com", "key").session();
+        let result: Result<CapmonsterTask, _> = task.try_into();
+        assert!(
+            result
+                .unwrap_err()
+                .unsupported_fields
+                .contains(&"is_session")
+        );
+    }
+
     #[test]
     fn test_recaptcha_v2_rejects_enterprise_fields_on_non_enterprise() {
         let task = ReCaptchaV2::new("https://example.com", "key")
@@ -711,4 +1141,146 @@ mod tests {
         assert!(err.unsupported_fields.contains(&"numeric"));
         assert!(err.unsupported_fields.contains(&"min_length"));
     }
+
+    #[test]
+    fn test_grid_classification_serialization() {
+        let task: CapmonsterTask = GridClassification::from_grid_base64("grid", 3, 3)
+            .unwrap()
+            .with_question("Click on traffic lights")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"ComplexImageTask\""));
+        assert!(json.contains("\"class\":\"recaptcha\""));
+        assert!(json.contains("\"imagesBase64\":[\"grid\"]"));
+        assert!(json.contains("\"Grid\":\"3x3\""));
+        assert!(json.contains("\"Task\":\"Click on traffic lights\""));
+        assert_eq!(task.to_string(), "GridClassification");
+    }
+
+    #[test]
+    fn test_grid_classification_tiles_serialization() {
+        let task: CapmonsterTask =
+            GridClassification::from_tiles_base64(vec!["a", "b", "c", "d"], 2, 2)
+                .unwrap()
+                .with_question("/m/015qff")
+                .try_into()
+                .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"imagesBase64\":[\"a\",\"b\",\"c\",\"d\"]"));
+        assert!(json.contains("\"Grid\":\"1x1\""));
+        assert!(json.contains("\"TaskDefinition\":\"/m/015qff\""));
+    }
+
+    #[test]
+    fn test_grid_classification_rejects_unsupported_grid() {
+        let task = GridClassification::from_grid_base64("grid", 2, 5).unwrap();
+        let result: Result<CapmonsterTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert!(err.unsupported_fields.contains(&"rows"));
+        assert!(err.unsupported_fields.contains(&"columns"));
+    }
+
+    #[test]
+    fn test_grid_classification_solution_deserialization() {
+        let json = r#"{"answer": [true, false, false, true]}"#;
+        let solution: CapmonsterSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_grid_classification().tiles(), &[0, 3]);
+    }
+
+    #[test]
+    fn test_rotate_serialization() {
+        let task: CapmonsterTask = Rotate::from_base64("image")
+            .with_module("oocl_rotate_new")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"ComplexImageTask\""));
+        assert!(json.contains("\"class\":\"recognition\""));
+        assert!(json.contains("\"Task\":\"oocl_rotate_new\""));
+        assert!(!json.contains("Grid"));
+        assert_eq!(task.to_string(), "Rotate");
+    }
+
+    #[test]
+    fn test_rotate_rejects_worker_fields() {
+        let task = Rotate::from_base64("image").with_angle_step(15).unwrap();
+        let result: Result<CapmonsterTask, _> = task.try_into();
+        assert!(
+            result
+                .unwrap_err()
+                .unsupported_fields
+                .contains(&"angle_step")
+        );
+    }
+
+    #[test]
+    fn test_rotate_solution_deserialization() {
+        let json = r#"{"answer": [135]}"#;
+        let solution: CapmonsterSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_rotate().angle(), 135.0);
+    }
+
+    #[test]
+    fn test_mtcaptcha_serialization() {
+        use crate::tasks::MtCaptcha;
+        let task: CapmonsterTask = MtCaptcha::new("https://example.com", "MTPublic-key").into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"MTCaptchaTask\""));
+        assert!(json.contains("\"websiteKey\":\"MTPublic-key\""));
+        assert!(!json.contains("proxyAddress"));
+        assert_eq!(task.to_string(), "MtCaptcha");
+    }
+
+    #[test]
+    fn test_friendly_captcha_and_cutcaptcha_unsupported() {
+        use crate::tasks::{CaptchaTask, Cutcaptcha, FriendlyCaptcha};
+        let task = CaptchaTask::from(FriendlyCaptcha::new("https://example.com", "key"));
+        assert!(CapmonsterTask::try_from(task).is_err());
+        let task = CaptchaTask::from(Cutcaptcha::new("https://example.com", "misery", "api"));
+        assert!(CapmonsterTask::try_from(task).is_err());
+    }
+
+    #[test]
+    fn test_imperva_serialization() {
+        use crate::tasks::Imperva;
+        let task: CapmonsterTask =
+            Imperva::new("https://example.com", ProxyConfig::http("1.2.3.4", 8080))
+                .with_user_agent("Mozilla/5.0")
+                .with_incapsula_script("dmFyIHg7")
+                .with_session_cookie("incap_ses_1=abc")
+                .try_into()
+                .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"CustomTask\""));
+        assert!(json.contains("\"class\":\"Imperva\""));
+        assert!(json.contains("\"incapsulaScriptBase64\":\"dmFyIHg7\""));
+        assert!(json.contains("\"incapsulaSessionCookie\":\"incap_ses_1=abc\""));
+        assert!(json.contains("proxyAddress"));
+        assert_eq!(task.to_string(), "Imperva");
+    }
+
+    #[test]
+    fn test_kasada_serialization() {
+        use crate::tasks::Kasada;
+        let task: CapmonsterTask =
+            Kasada::new("https://example.com", ProxyConfig::http("1.2.3.4", 8080))
+                .try_into()
+                .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"class\":\"Kasada\""));
+        assert!(json.contains("\"metadata\":{}"));
+        assert_eq!(task.to_string(), "Kasada");
+
+        let task = Kasada::new("https://example.com", ProxyConfig::http("1.2.3.4", 8080)).with_cd();
+        let result: Result<CapmonsterTask, _> = task.try_into();
+        assert!(result.unwrap_err().unsupported_fields.contains(&"cd"));
+    }
+
+    #[test]
+    fn test_anti_bot_solution_deserialization() {
+        let json = r#"{"domains": {"example.com": {"cookies": {"___utmvc": "utm"}}}}"#;
+        let solution: CapmonsterSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_anti_bot().cookie("___utmvc"), Some("utm"));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::errors::{RetryableError, UnsupportedTaskError};
+use crate::errors::{KeyFault, RetryableError, UnsupportedTaskError};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use thiserror::Error;
@@ -43,6 +43,13 @@ impl RetryableError for CapsolverError {
             | CapsolverError::UnsupportedTask(_) => false,
         }
     }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            CapsolverError::Api(error) => error.error_code.key_fault(),
+            _ => None,
+        }
+    }
 }
 
 /// Known Capsolver error codes.
@@ -165,6 +172,16 @@ impl CapsolverErrorCode {
         )
     }
 
+    /// Returns the API key problem this code reports, if any
+    pub fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            Self::KeyDeniedAccess => Some(KeyFault::Invalid),
+            Self::ZeroBalance => Some(KeyFault::ZeroBalance),
+            Self::KeyTempBlocked => Some(KeyFault::TemporarilyBlocked),
+            _ => None,
+        }
+    }
+
     /// Returns true if a fresh solve operation (new task) might succeed
     pub fn should_retry_operation(&self) -> bool {
         match self {
@@ -14,9 +14,19 @@
 //! | Cloudflare Turnstile | [`Turnstile`](crate::Turnstile) | No |
 //! | Cloudflare Challenge | [`CloudflareChallenge`](crate::CloudflareChallenge) | Yes |
 //! | Image to Text | [`ImageToText`](crate::ImageToText) | No |
+//! | Grid Classification | [`GridClassification`](crate::GridClassification) | No |
+//! | Rotate | [`Rotate`](crate::Rotate) | No |
+//! | Audio to Text | [`AudioToText`](crate::AudioToText) | No |
+//! | Imperva (Incapsula) | [`Imperva`](crate::Imperva) | Yes |
+//! | Kasada | [`Kasada`](crate::Kasada) | Yes |
 //!
 //! **Note**: [`TurnstileChallenge`](crate::TurnstileChallenge) and
-//! [`TurnstileWaitRoom`](crate::TurnstileWaitRoom) are not supported by Capsolver.
+//! [`TurnstileWaitRoom`](crate::TurnstileWaitRoom), [`MtCaptcha`](crate::MtCaptcha),
+//! [`FriendlyCaptcha`](crate::FriendlyCaptcha), [`Cutcaptcha`](crate::Cutcaptcha), [`YandexSmartCaptcha`](crate::YandexSmartCaptcha)
+//! [`Tencent`](crate::Tencent), [`Lemin`](crate::Lemin), [`KeyCaptcha`](crate::KeyCaptcha)
+//! [`Capy`](crate::Capy), [`Prosopo`](crate::Prosopo), [`CaptchaFox`](crate::CaptchaFox)
+//! [`Altcha`](crate::Altcha), [`TextQuestion`](crate::TextQuestion) and [`HCaptcha`](crate::HCaptcha)
+//! are not supported by Capsolver.
 //!
 //! ## Quick Start
 //!
@@ -68,6 +78,8 @@
 //! - **Turnstile**: [`TurnstileSolution`] with `token()` method
 //! - **Cloudflare Challenge**: [`CloudflareChallengeSolution`] with `token()` and `cf_clearance()` methods
 //! - **Image to Text**: [`ImageToTextSolution`] with `text()` method
+//! - **Audio to Text**: [`AudioToTextSolution`] with `text()` method
+//! - **Imperva / Kasada**: [`AntiBotSolution`] with `cookies`, `headers` and `apply()`
 //!
 //! ## Error Handling
 //!
@@ -100,7 +112,8 @@ pub use provider::{CapsolverProvider, CapsolverProviderBuilder, DEFAULT_API_URL}
 
 // Solutions (public API)
 pub use types::{
-    CapsolverSolution, CloudflareChallengeSolution, ImageToTextSolution, ReCaptchaSolution,
+    AntiBotSolution, AudioToTextSolution, CapsolverSolution, CloudflareChallengeSolution,
+    GridClassificationSolution, ImageToTextSolution, ReCaptchaSolution, RotateSolution,
     TurnstileSolution,
 };
 
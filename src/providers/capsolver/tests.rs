@@ -4,8 +4,9 @@ use super::errors::{CapsolverError, CapsolverErrorCode};
 use super::provider::CapsolverProvider;
 use super::response::CapsolverResponse;
 use super::types::{CapsolverSolution, CreateTaskData, GetTaskData};
+use crate::errors::KeyFault;
 use crate::providers::traits::Provider;
-use crate::tasks::Turnstile;
+use crate::tasks::{Altcha, CaptchaFox, HCaptcha, Prosopo, Turnstile};
 use crate::utils::types::TaskId;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
@@ -191,6 +192,41 @@ async fn test_get_task_result_api_error() {
     }
 }
 
+#[tokio::test]
+async fn test_create_task_widget_tokens_unsupported() {
+    let provider = CapsolverProvider::new("test_api_key").unwrap();
+
+    let err = provider
+        .create_task(Prosopo::new("https://example.com", "key").into())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CapsolverError::UnsupportedTask(_)));
+
+    let err = provider
+        .create_task(CaptchaFox::new("https://example.com", "sk_key").into())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CapsolverError::UnsupportedTask(_)));
+
+    let err = provider
+        .create_task(Altcha::new("https://example.com", "https://example.com/challenge").into())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CapsolverError::UnsupportedTask(_)));
+}
+
+#[tokio::test]
+async fn test_create_task_hcaptcha_unsupported() {
+    let provider = CapsolverProvider::new("test_api_key").unwrap();
+    let task = HCaptcha::new("https://example.com", "site-key");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    match err {
+        CapsolverError::UnsupportedTask(error) => assert_eq!(error.task_type, "HCaptcha"),
+        _ => panic!("Expected UnsupportedTask error"),
+    }
+}
+
 // =============================================================================
 // Builder Tests
 // =============================================================================
@@ -231,6 +267,24 @@ fn test_error_code_retryability() {
     assert!(!CapsolverErrorCode::Other("SOME_NEW_ERROR".to_string()).is_retryable());
 }
 
+#[test]
+fn test_error_code_key_fault() {
+    assert_eq!(
+        CapsolverErrorCode::KeyDeniedAccess.key_fault(),
+        Some(KeyFault::Invalid)
+    );
+    assert_eq!(
+        CapsolverErrorCode::ZeroBalance.key_fault(),
+        Some(KeyFault::ZeroBalance)
+    );
+    assert_eq!(
+        CapsolverErrorCode::KeyTempBlocked.key_fault(),
+        Some(KeyFault::TemporarilyBlocked)
+    );
+    assert_eq!(CapsolverErrorCode::RateLimit.key_fault(), None);
+    assert_eq!(CapsolverErrorCode::InvalidTaskData.key_fault(), None);
+}
+
 // =============================================================================
 // Response Deserialization Tests
 // =============================================================================
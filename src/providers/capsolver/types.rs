@@ -31,6 +31,10 @@ pub enum CapsolverTask {
         is_invisible: Option<bool>,
         #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
         api_domain: Option<String>,
+        #[serde(rename = "isSession", skip_serializing_if = "Option::is_none")]
+        is_session: Option<bool>,
+        #[serde(rename = "isSessionV3", skip_serializing_if = "Option::is_none")]
+        is_session_v3: Option<bool>,
     },
 
     /// ReCaptcha V2 Enterprise requiring custom proxy
@@ -47,6 +51,14 @@ pub enum CapsolverTask {
         is_invisible: Option<bool>,
         #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
         api_domain: Option<String>,
+        #[serde(rename = "isSession", skip_serializing_if = "Option::is_none")]
+        is_session: Option<bool>,
+        #[serde(rename = "isSessionV3", skip_serializing_if = "Option::is_none")]
+        is_session_v3: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        anchor: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reload: Option<String>,
         #[serde(flatten)]
         proxy: ApiProxyFields,
     },
@@ -65,6 +77,14 @@ pub enum CapsolverTask {
         is_invisible: Option<bool>,
         #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
         api_domain: Option<String>,
+        #[serde(rename = "isSession", skip_serializing_if = "Option::is_none")]
+        is_session: Option<bool>,
+        #[serde(rename = "isSessionV3", skip_serializing_if = "Option::is_none")]
+        is_session_v3: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        anchor: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reload: Option<String>,
     },
 
     // -------------------------------------------------------------------------
@@ -80,6 +100,8 @@ pub enum CapsolverTask {
         page_action: Option<String>,
         #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
         api_domain: Option<String>,
+        #[serde(rename = "isSession", skip_serializing_if = "Option::is_none")]
+        is_session: Option<bool>,
         #[serde(flatten)]
         proxy: ApiProxyFields,
     },
@@ -94,6 +116,8 @@ pub enum CapsolverTask {
         page_action: Option<String>,
         #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
         api_domain: Option<String>,
+        #[serde(rename = "isSession", skip_serializing_if = "Option::is_none")]
+        is_session: Option<bool>,
     },
 
     /// ReCaptcha V3 Enterprise requiring custom proxy
@@ -108,6 +132,8 @@ pub enum CapsolverTask {
         enterprise_payload: Option<HashMap<String, serde_json::Value>>,
         #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
         api_domain: Option<String>,
+        #[serde(rename = "isSession", skip_serializing_if = "Option::is_none")]
+        is_session: Option<bool>,
         #[serde(flatten)]
         proxy: ApiProxyFields,
     },
@@ -124,6 +150,8 @@ pub enum CapsolverTask {
         enterprise_payload: Option<HashMap<String, serde_json::Value>>,
         #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
         api_domain: Option<String>,
+        #[serde(rename = "isSession", skip_serializing_if = "Option::is_none")]
+        is_session: Option<bool>,
     },
 
     // -------------------------------------------------------------------------
@@ -170,6 +198,84 @@ pub enum CapsolverTask {
         #[serde(skip_serializing_if = "Option::is_none")]
         module: Option<String>,
     },
+
+    // -------------------------------------------------------------------------
+    // Grid Classification
+    // -------------------------------------------------------------------------
+    /// reCAPTCHA V2 grid image classification
+    ReCaptchaV2Classification {
+        /// Page source URL to improve accuracy
+        #[serde(rename = "websiteURL", skip_serializing_if = "Option::is_none")]
+        website_url: Option<String>,
+        /// reCAPTCHA site key to improve accuracy
+        #[serde(rename = "websiteKey", skip_serializing_if = "Option::is_none")]
+        website_key: Option<String>,
+        /// Base64 encoded grid image
+        image: String,
+        /// Object name or ID (e.g., "/m/04_sv")
+        #[serde(skip_serializing_if = "Option::is_none")]
+        question: Option<String>,
+    },
+
+    // -------------------------------------------------------------------------
+    // Vision Engine
+    // -------------------------------------------------------------------------
+    /// Vision engine recognition (rotate, slider, etc.)
+    VisionEngine {
+        /// Recognition module (e.g., "rotate_v1")
+        module: String,
+        /// Base64 encoded image content
+        image: String,
+    },
+
+    // -------------------------------------------------------------------------
+    // Audio Recognition
+    // -------------------------------------------------------------------------
+    /// Audio captcha speech recognition
+    AudioRecognition {
+        /// Base64 encoded MP3 or WAV audio content
+        body: String,
+        /// Language code of the audio (e.g., "en")
+        lang: String,
+    },
+
+    // -------------------------------------------------------------------------
+    // Anti-bot (Imperva / Kasada)
+    // -------------------------------------------------------------------------
+    /// Imperva (Incapsula) bypass (requires proxy)
+    AntiImpervaTask {
+        #[serde(rename = "websiteUrl")]
+        website_url: String,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        /// Solve the `___utmvc` cookie
+        #[serde(skip_serializing_if = "Option::is_none")]
+        utmvc: Option<bool>,
+        /// Solve the `reese84` cookie
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reese84: Option<bool>,
+        /// URL of the reese84 script
+        #[serde(rename = "reeseScriptUrl", skip_serializing_if = "Option::is_none")]
+        reese_script_url: Option<String>,
+        #[serde(flatten)]
+        proxy: ApiProxyFields,
+    },
+
+    /// Kasada bypass (requires proxy)
+    AntiKasadaTask {
+        #[serde(rename = "pageURL")]
+        page_url: String,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        /// Also solve the `x-kpsdk-cd` header
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cd: Option<bool>,
+        /// Only solve the `x-kpsdk-cd` header
+        #[serde(rename = "onlyCD", skip_serializing_if = "Option::is_none")]
+        only_cd: Option<bool>,
+        #[serde(flatten)]
+        proxy: ApiProxyFields,
+    },
 }
 
 /// Metadata for Turnstile captcha
@@ -200,6 +306,11 @@ impl Display for CapsolverTask {
             Self::AntiTurnstileTaskProxyLess { .. } => write!(f, "Turnstile"),
             Self::AntiCloudflareTask { .. } => write!(f, "CloudflareChallenge"),
             Self::ImageToTextTask { .. } => write!(f, "ImageToText"),
+            Self::ReCaptchaV2Classification { .. } => write!(f, "GridClassification"),
+            Self::VisionEngine { .. } => write!(f, "Rotate"),
+            Self::AudioRecognition { .. } => write!(f, "AudioToText"),
+            Self::AntiImpervaTask { .. } => write!(f, "Imperva"),
+            Self::AntiKasadaTask { .. } => write!(f, "Kasada"),
         }
     }
 }
@@ -210,7 +321,8 @@ impl Display for CapsolverTask {
 
 // Re-export shared solution types for convenience
 pub use crate::solutions::{
-    CloudflareChallengeSolution, ImageToTextSolution, ReCaptchaSolution, TurnstileSolution,
+    AntiBotSolution, AudioToTextSolution, CloudflareChallengeSolution, GridClassificationSolution,
+    ImageToTextSolution, ReCaptchaSolution, RotateSolution, TurnstileSolution,
 };
 
 /// Capsolver solution types
@@ -219,8 +331,17 @@ pub use crate::solutions::{
 pub enum CapsolverSolution {
     /// Image to text solution (must be first for untagged deserialization priority)
     ImageToText(ImageToTextSolution),
+    /// Grid classification solution (selected tiles)
+    GridClassification(GridClassificationSolution),
+    /// Rotate solution (angle in degrees)
+    Rotate(RotateSolution),
     /// ReCaptcha solution (V2 or V3)
     ReCaptcha(ReCaptchaSolution),
+    /// Imperva or Kasada anti-bot solution (cookies/headers)
+    ///
+    /// Tried before Turnstile, which accepts any payload with a token or
+    /// clearance cookie; `AntiBotSolution` rejects Turnstile-shaped payloads.
+    AntiBot(AntiBotSolution),
     /// Turnstile or Cloudflare Challenge solution
     Turnstile(TurnstileSolution),
 }
@@ -326,6 +447,112 @@ impl CapsolverSolution {
         self.try_into_image_to_text()
             .expect("Expected ImageToText solution")
     }
+
+    /// Try to extract AudioToText solution (returns reference)
+    ///
+    /// Audio captchas share the [`ImageToTextSolution`] variant.
+    pub fn as_audio_to_text(&self) -> Option<&AudioToTextSolution> {
+        self.as_image_to_text()
+    }
+
+    /// Try to extract AudioToText solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a text solution, or `Err(self)` otherwise.
+    pub fn try_into_audio_to_text(self) -> Result<AudioToTextSolution, Box<Self>> {
+        self.try_into_image_to_text()
+    }
+
+    /// Extract AudioToText solution, panics if not a text solution
+    ///
+    /// # Panics
+    /// Panics if the solution is not a text solution.
+    /// Use `try_into_audio_to_text()` for a non-panicking alternative.
+    pub fn into_audio_to_text(self) -> AudioToTextSolution {
+        self.try_into_audio_to_text()
+            .expect("Expected AudioToText solution")
+    }
+
+    /// Try to extract GridClassification solution (returns reference)
+    pub fn as_grid_classification(&self) -> Option<&GridClassificationSolution> {
+        match self {
+            Self::GridClassification(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract GridClassification solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a GridClassification solution, or `Err(self)` otherwise.
+    pub fn try_into_grid_classification(self) -> Result<GridClassificationSolution, Box<Self>> {
+        match self {
+            Self::GridClassification(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract GridClassification solution, panics if not GridClassification
+    ///
+    /// # Panics
+    /// Panics if the solution is not a GridClassification solution.
+    /// Use `try_into_grid_classification()` for a non-panicking alternative.
+    pub fn into_grid_classification(self) -> GridClassificationSolution {
+        self.try_into_grid_classification()
+            .expect("Expected GridClassification solution")
+    }
+
+    /// Try to extract Rotate solution (returns reference)
+    pub fn as_rotate(&self) -> Option<&RotateSolution> {
+        match self {
+            Self::Rotate(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract Rotate solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a Rotate solution, or `Err(self)` otherwise.
+    pub fn try_into_rotate(self) -> Result<RotateSolution, Box<Self>> {
+        match self {
+            Self::Rotate(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract Rotate solution, panics if not Rotate
+    ///
+    /// # Panics
+    /// Panics if the solution is not a Rotate solution.
+    /// Use `try_into_rotate()` for a non-panicking alternative.
+    pub fn into_rotate(self) -> RotateSolution {
+        self.try_into_rotate().expect("Expected Rotate solution")
+    }
+
+    /// Try to extract AntiBot solution (returns reference)
+    pub fn as_anti_bot(&self) -> Option<&AntiBotSolution> {
+        match self {
+            Self::AntiBot(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract AntiBot solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is an AntiBot solution, or `Err(self)` otherwise.
+    pub fn try_into_anti_bot(self) -> Result<AntiBotSolution, Box<Self>> {
+        match self {
+            Self::AntiBot(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract AntiBot solution, panics if not AntiBot
+    ///
+    /// # Panics
+    /// Panics if the solution is not an AntiBot solution.
+    /// Use `try_into_anti_bot()` for a non-panicking alternative.
+    pub fn into_anti_bot(self) -> AntiBotSolution {
+        self.try_into_anti_bot().expect("Expected AntiBot solution")
+    }
 }
 
 // ============================================================================
@@ -378,7 +605,30 @@ impl TryFrom<crate::tasks::ReCaptchaV2> for CapsolverTask {
     type Error = crate::errors::UnsupportedTaskError;
 
     fn try_from(task: crate::tasks::ReCaptchaV2) -> Result<Self, Self::Error> {
+        if !task.is_enterprise {
+            // Capsolver does not support V2 non-enterprise with proxy or anchor/reload payloads
+            let mut unsupported = Vec::new();
+            if task.proxy.is_some() {
+                unsupported.push("proxy");
+            }
+            if task.anchor.is_some() {
+                unsupported.push("anchor");
+            }
+            if task.reload.is_some() {
+                unsupported.push("reload");
+            }
+            if !unsupported.is_empty() {
+                return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+                    "ReCaptchaV2",
+                    "Capsolver",
+                    unsupported,
+                ));
+            }
+        }
+
         let is_invisible = if task.is_invisible { Some(true) } else { None };
+        let is_session = if task.is_session { Some(true) } else { None };
+        let is_session_v3 = if task.is_session_v3 { Some(true) } else { None };
 
         match (task.is_enterprise, task.proxy) {
             // Enterprise with proxy
@@ -389,6 +639,10 @@ impl TryFrom<crate::tasks::ReCaptchaV2> for CapsolverTask {
                 enterprise_payload: task.enterprise_payload,
                 is_invisible,
                 api_domain: task.api_domain,
+                is_session,
+                is_session_v3,
+                anchor: task.anchor,
+                reload: task.reload,
                 proxy: proxy.into_api_proxy_fields(),
             }),
             // Enterprise without proxy
@@ -399,21 +653,21 @@ impl TryFrom<crate::tasks::ReCaptchaV2> for CapsolverTask {
                 enterprise_payload: task.enterprise_payload,
                 is_invisible,
                 api_domain: task.api_domain,
+                is_session,
+                is_session_v3,
+                anchor: task.anchor,
+                reload: task.reload,
             }),
-            // Standard with proxy — Capsolver does not support V2 non-enterprise with proxy
-            (false, Some(_)) => Err(crate::errors::UnsupportedTaskError::unsupported_fields(
-                "ReCaptchaV2",
-                "Capsolver",
-                vec!["proxy"],
-            )),
-            // Standard without proxy
-            (false, None) => Ok(Self::ReCaptchaV2TaskProxyLess {
+            // Standard (proxy is rejected above)
+            (false, _) => Ok(Self::ReCaptchaV2TaskProxyLess {
                 website_url: task.website_url,
                 website_key: task.website_key,
                 page_action: task.page_action,
                 recaptcha_data_s_value: task.recaptcha_data_s_value,
                 is_invisible,
                 api_domain: task.api_domain,
+                is_session,
+                is_session_v3,
             }),
         }
     }
@@ -431,6 +685,8 @@ impl TryFrom<crate::tasks::ReCaptchaV3> for CapsolverTask {
             ));
         }
 
+        let is_session = if task.is_session { Some(true) } else { None };
+
         match (task.is_enterprise, task.proxy) {
             // Enterprise with proxy
             (true, Some(proxy)) => Ok(Self::ReCaptchaV3EnterpriseTask {
@@ -439,6 +695,7 @@ impl TryFrom<crate::tasks::ReCaptchaV3> for CapsolverTask {
                 page_action: task.page_action,
                 enterprise_payload: task.enterprise_payload,
                 api_domain: task.api_domain,
+                is_session,
                 proxy: proxy.into_api_proxy_fields(),
             }),
             // Enterprise without proxy
@@ -448,6 +705,7 @@ impl TryFrom<crate::tasks::ReCaptchaV3> for CapsolverTask {
                 page_action: task.page_action,
                 enterprise_payload: task.enterprise_payload,
                 api_domain: task.api_domain,
+                is_session,
             }),
             // Standard with proxy
             (false, Some(proxy)) => Ok(Self::ReCaptchaV3Task {
@@ -455,6 +713,7 @@ impl TryFrom<crate::tasks::ReCaptchaV3> for CapsolverTask {
                 website_key: task.website_key,
                 page_action: task.page_action,
                 api_domain: task.api_domain,
+                is_session,
                 proxy: proxy.into_api_proxy_fields(),
             }),
             // Standard without proxy
@@ -463,6 +722,7 @@ impl TryFrom<crate::tasks::ReCaptchaV3> for CapsolverTask {
                 website_key: task.website_key,
                 page_action: task.page_action,
                 api_domain: task.api_domain,
+                is_session,
             }),
         }
     }
@@ -560,6 +820,115 @@ impl TryFrom<crate::tasks::ImageToText> for CapsolverTask {
     }
 }
 
+impl TryFrom<crate::tasks::GridClassification> for CapsolverTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::GridClassification) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        let image = match task.images {
+            crate::tasks::GridImages::Grid(image) => image,
+            crate::tasks::GridImages::Tiles(_) => {
+                unsupported.push("tiles");
+                String::new()
+            }
+        };
+        if task.img_instructions.is_some() {
+            unsupported.push("img_instructions");
+        }
+        if !unsupported.is_empty() {
+            return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+                "GridClassification",
+                "Capsolver",
+                unsupported,
+            ));
+        }
+
+        Ok(Self::ReCaptchaV2Classification {
+            website_url: task.website_url,
+            website_key: task.website_key,
+            image,
+            question: task.question,
+        })
+    }
+}
+
+impl TryFrom<crate::tasks::Rotate> for CapsolverTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::Rotate) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.angle_step.is_some() {
+            unsupported.push("angle_step");
+        }
+        if task.comment.is_some() {
+            unsupported.push("comment");
+        }
+        if !unsupported.is_empty() {
+            return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+                "Rotate",
+                "Capsolver",
+                unsupported,
+            ));
+        }
+
+        Ok(Self::VisionEngine {
+            module: task.module.unwrap_or_else(|| "rotate_v1".to_string()),
+            image: task.body,
+        })
+    }
+}
+
+impl From<crate::tasks::AudioToText> for CapsolverTask {
+    fn from(task: crate::tasks::AudioToText) -> Self {
+        Self::AudioRecognition {
+            body: task.body,
+            lang: task.lang,
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::Imperva> for CapsolverTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::Imperva) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.incapsula_script.is_some() {
+            unsupported.push("incapsula_script");
+        }
+        if task.session_cookie.is_some() {
+            unsupported.push("session_cookie");
+        }
+        if !unsupported.is_empty() {
+            return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+                "Imperva",
+                "Capsolver",
+                unsupported,
+            ));
+        }
+
+        Ok(Self::AntiImpervaTask {
+            website_url: task.website_url,
+            user_agent: task.user_agent,
+            utmvc: task.utmvc.then_some(true),
+            reese84: task.reese84.then_some(true),
+            reese_script_url: task.reese84_script_url,
+            proxy: task.proxy.into_api_proxy_fields(),
+        })
+    }
+}
+
+impl From<crate::tasks::Kasada> for CapsolverTask {
+    fn from(task: crate::tasks::Kasada) -> Self {
+        Self::AntiKasadaTask {
+            page_url: task.website_url,
+            user_agent: task.user_agent,
+            cd: task.cd.then_some(true),
+            only_cd: task.only_cd.then_some(true),
+            proxy: task.proxy.into_api_proxy_fields(),
+        }
+    }
+}
+
 impl TryFrom<crate::tasks::CaptchaTask> for CapsolverTask {
     type Error = crate::errors::UnsupportedTaskError;
 
@@ -570,6 +939,55 @@ impl TryFrom<crate::tasks::CaptchaTask> for CapsolverTask {
             crate::tasks::CaptchaTask::Turnstile(t) => t.try_into(),
             crate::tasks::CaptchaTask::CloudflareChallenge(t) => Ok(t.into()),
             crate::tasks::CaptchaTask::ImageToText(t) => t.try_into(),
+            crate::tasks::CaptchaTask::GridClassification(t) => t.try_into(),
+            crate::tasks::CaptchaTask::Rotate(t) => t.try_into(),
+            crate::tasks::CaptchaTask::AudioToText(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Imperva(t) => t.try_into(),
+            crate::tasks::CaptchaTask::Kasada(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::MtCaptcha(_) => Err(
+                crate::errors::UnsupportedTaskError::new("MtCaptcha", "Capsolver"),
+            ),
+            crate::tasks::CaptchaTask::FriendlyCaptcha(_) => Err(
+                crate::errors::UnsupportedTaskError::new("FriendlyCaptcha", "Capsolver"),
+            ),
+            crate::tasks::CaptchaTask::Cutcaptcha(_) => Err(
+                crate::errors::UnsupportedTaskError::new("Cutcaptcha", "Capsolver"),
+            ),
+            crate::tasks::CaptchaTask::YandexSmartCaptcha(_) => Err(
+                crate::errors::UnsupportedTaskError::new("YandexSmartCaptcha", "Capsolver"),
+            ),
+            crate::tasks::CaptchaTask::Tencent(_) => Err(crate::errors::UnsupportedTaskError::new(
+                "Tencent",
+                "Capsolver",
+            )),
+            crate::tasks::CaptchaTask::Lemin(_) => Err(crate::errors::UnsupportedTaskError::new(
+                "Lemin",
+                "Capsolver",
+            )),
+            crate::tasks::CaptchaTask::KeyCaptcha(_) => Err(
+                crate::errors::UnsupportedTaskError::new("KeyCaptcha", "Capsolver"),
+            ),
+            crate::tasks::CaptchaTask::Capy(_) => Err(crate::errors::UnsupportedTaskError::new(
+                "Capy",
+                "Capsolver",
+            )),
+            crate::tasks::CaptchaTask::Prosopo(_) => Err(crate::errors::UnsupportedTaskError::new(
+                "Prosopo",
+                "Capsolver",
+            )),
+            crate::tasks::CaptchaTask::CaptchaFox(_) => Err(
+                crate::errors::UnsupportedTaskError::new("CaptchaFox", "Capsolver"),
+            ),
+            crate::tasks::CaptchaTask::Altcha(_) => Err(crate::errors::UnsupportedTaskError::new(
+                "Altcha",
+                "Capsolver",
+            )),
+            crate::tasks::CaptchaTask::HCaptcha(_) => Err(
+                crate::errors::UnsupportedTaskError::new("HCaptcha", "Capsolver"),
+            ),
+            crate::tasks::CaptchaTask::TextQuestion(_) => Err(
+                crate::errors::UnsupportedTaskError::new("TextQuestion", "Capsolver"),
+            ),
             crate::tasks::CaptchaTask::TurnstileChallenge(_) => Err(
                 crate::errors::UnsupportedTaskError::new("TurnstileChallenge", "Capsolver"),
             ),
@@ -842,6 +1260,54 @@ mod tests {
         assert!(err.unsupported_fields.contains(&"numeric"));
     }
 
+    #[test]
+    fn test_recaptcha_v2_session_serialization() {
+        let task: CapsolverTask = ReCaptchaV2::new("https://example.com", "key")
+            .session()
+            .session_v3()
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["isSession"], true);
+        assert_eq!(json["isSessionV3"], true);
+    }
+
+    #[test]
+    fn test_recaptcha_v2_enterprise_anchor_reload_serialization() {
+        let task: CapsolverTask = ReCaptchaV2::new("https://example.com", "key")
+            .enterprise()
+            .with_anchor("anchor-data")
+            .with_reload("reload-data")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "ReCaptchaV2EnterpriseTaskProxyLess");
+        assert_eq!(json["anchor"], "anchor-data");
+        assert_eq!(json["reload"], "reload-data");
+        assert!(json.get("isSession").is_none());
+    }
+
+    #[test]
+    fn test_recaptcha_v2_anchor_rejected_on_non_enterprise() {
+        let task = ReCaptchaV2::new("https://example.com", "key")
+            .with_anchor("anchor-data")
+            .with_reload("reload-data");
+        let result: Result<CapsolverTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert!(err.unsupported_fields.contains(&"anchor"));
+        assert!(err.unsupported_fields.contains(&"reload"));
+    }
+
+    #[test]
+    fn test_recaptcha_v3_session_serialization() {
+        let task: CapsolverTask = ReCaptchaV3::new("https://example.com", "key")
+            .session()
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["isSession"], true);
+    }
+
     #[test]
     fn test_recaptcha_v3_rejects_min_score() {
         let task = ReCaptchaV3::new("https://example.com", "key").with_min_score(0.9);
@@ -850,4 +1316,156 @@ mod tests {
         let err = result.unwrap_err();
         assert!(err.unsupported_fields.contains(&"min_score"));
     }
+
+    #[test]
+    fn test_grid_classification_serialization() {
+        use crate::tasks::GridClassification;
+        let task: CapsolverTask = GridClassification::from_grid_base64("grid", 3, 3)
+            .unwrap()
+            .with_question("/m/04_sv")
+            .with_website_key("site-key")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"ReCaptchaV2Classification\""));
+        assert!(json.contains("\"image\":\"grid\""));
+        assert!(json.contains("\"question\":\"/m/04_sv\""));
+        assert!(json.contains("\"websiteKey\":\"site-key\""));
+        assert!(!json.contains("rows"));
+        assert_eq!(task.to_string(), "GridClassification");
+    }
+
+    #[test]
+    fn test_grid_classification_rejects_tiles() {
+        use crate::tasks::GridClassification;
+        let task = GridClassification::from_tiles_base64(vec!["a", "b", "c", "d"], 2, 2).unwrap();
+        let result: Result<CapsolverTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert_eq!(err.task_type, "GridClassification");
+        assert!(err.unsupported_fields.contains(&"tiles"));
+    }
+
+    #[test]
+    fn test_grid_classification_solution_deserialization() {
+        let json = r#"{"objects": [0, 2], "type": "multi"}"#;
+        let solution: CapsolverSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_grid_classification().tiles(), &[0, 2]);
+    }
+
+    #[test]
+    fn test_rotate_serialization() {
+        use crate::tasks::Rotate;
+        let task: CapsolverTask = Rotate::from_base64("image").try_into().unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"VisionEngine\""));
+        assert!(json.contains("\"module\":\"rotate_v1\""));
+        assert!(json.contains("\"image\":\"image\""));
+        assert_eq!(task.to_string(), "Rotate");
+    }
+
+    #[test]
+    fn test_rotate_rejects_worker_fields() {
+        use crate::tasks::Rotate;
+        let task = Rotate::from_base64("image")
+            .with_angle_step(15)
+            .unwrap()
+            .with_comment("Rotate upright");
+        let result: Result<CapsolverTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert!(err.unsupported_fields.contains(&"angle_step"));
+        assert!(err.unsupported_fields.contains(&"comment"));
+    }
+
+    #[test]
+    fn test_rotate_solution_deserialization() {
+        let json = r#"{"angle": 45}"#;
+        let solution: CapsolverSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_rotate().angle(), 45.0);
+    }
+
+    #[test]
+    fn test_audio_to_text_serialization() {
+        use crate::tasks::AudioToText;
+        let task: CapsolverTask = AudioToText::from_base64("SUQzBAA=", "de").into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"AudioRecognition\""));
+        assert!(json.contains("\"body\":\"SUQzBAA=\""));
+        assert!(json.contains("\"lang\":\"de\""));
+        assert_eq!(task.to_string(), "AudioToText");
+    }
+
+    #[test]
+    fn test_widget_captchas_unsupported() {
+        use crate::tasks::{CaptchaTask, Cutcaptcha, FriendlyCaptcha, MtCaptcha};
+        for task in [
+            CaptchaTask::from(MtCaptcha::new("https://example.com", "key")),
+            CaptchaTask::from(FriendlyCaptcha::new("https://example.com", "key")),
+            CaptchaTask::from(Cutcaptcha::new("https://example.com", "misery", "api")),
+        ] {
+            assert!(CapsolverTask::try_from(task).is_err());
+        }
+    }
+
+    #[test]
+    fn test_imperva_serialization() {
+        use crate::tasks::Imperva;
+        let task: CapsolverTask =
+            Imperva::new("https://example.com", ProxyConfig::http("1.2.3.4", 8080))
+                .with_reese84()
+                .with_reese84_script_url("https://example.com/reese.js")
+                .try_into()
+                .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"AntiImpervaTask\""));
+        assert!(json.contains("\"reese84\":true"));
+        assert!(!json.contains("utmvc"));
+        assert!(json.contains("\"reeseScriptUrl\":\"https://example.com/reese.js\""));
+        assert!(json.contains("proxyAddress"));
+        assert_eq!(task.to_string(), "Imperva");
+    }
+
+    #[test]
+    fn test_imperva_rejects_capmonster_fields() {
+        use crate::tasks::Imperva;
+        let task = Imperva::new("https://example.com", ProxyConfig::http("1.2.3.4", 8080))
+            .with_session_cookie("incap_ses_1=abc");
+        let result: Result<CapsolverTask, _> = task.try_into();
+        assert!(
+            result
+                .unwrap_err()
+                .unsupported_fields
+                .contains(&"session_cookie")
+        );
+    }
+
+    #[test]
+    fn test_kasada_serialization() {
+        use crate::tasks::Kasada;
+        let task: CapsolverTask =
+            Kasada::new("https://example.com", ProxyConfig::http("1.2.3.4", 8080))
+                .with_cd()
+                .into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"AntiKasadaTask\""));
+        assert!(json.contains("\"pageURL\":\"https://example.com\""));
+        assert!(json.contains("\"cd\":true"));
+        assert!(!json.contains("onlyCD"));
+        assert_eq!(task.to_string(), "Kasada");
+    }
+
+    #[test]
+    fn test_anti_bot_solution_deserialization() {
+        let json = r#"{"x-kpsdk-ct": "0Hs1a", "x-kpsdk-cd": "cd", "user-agent": "Mozilla/5.0"}"#;
+        let solution: CapsolverSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_anti_bot().header("x-kpsdk-ct"), Some("0Hs1a"));
+
+        // Cookie-only Imperva payloads must not be taken for Turnstile
+        let json = r#"{"cookies": {"reese84": "r84"}, "userAgent": "Mozilla/5.0"}"#;
+        let solution: CapsolverSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_anti_bot().cookie("reese84"), Some("r84"));
+
+        let json = r#"{"cookies": {"cf_clearance": "clearance"}, "userAgent": "Mozilla/5.0"}"#;
+        let solution: CapsolverSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_turnstile().cf_clearance(), Some("clearance"));
+    }
 }
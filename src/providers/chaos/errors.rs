@@ -0,0 +1,64 @@
+//! Error types for the fault-injection wrapper.
+
+use crate::errors::{KeyFault, RetryableError};
+use thiserror::Error;
+
+/// Error returned by [`ChaosProvider`](super::ChaosProvider)
+///
+/// Either an error injected by the wrapper or one returned by the wrapped
+/// provider, which keeps its own [`RetryableError`] answers.
+#[derive(Debug, Error)]
+pub enum ChaosError<E> {
+    /// Injected transient failure (retried by the service and
+    /// [`CaptchaRetryableProvider`](crate::CaptchaRetryableProvider))
+    #[error("Injected transient error")]
+    Transient,
+
+    /// Injected permanent failure (never retried)
+    #[error("Injected permanent error")]
+    Permanent,
+
+    /// Error from the wrapped provider
+    #[error(transparent)]
+    Inner(E),
+}
+
+impl<E> ChaosError<E> {
+    /// Returns `true` if the error was injected by the wrapper
+    pub fn is_injected(&self) -> bool {
+        !matches!(self, ChaosError::Inner(_))
+    }
+
+    /// Returns the wrapped provider's error, if any
+    pub fn into_inner(self) -> Option<E> {
+        match self {
+            ChaosError::Inner(e) => Some(e),
+            ChaosError::Transient | ChaosError::Permanent => None,
+        }
+    }
+}
+
+impl<E: RetryableError> RetryableError for ChaosError<E> {
+    fn is_retryable(&self) -> bool {
+        match self {
+            ChaosError::Transient => true,
+            ChaosError::Permanent => false,
+            ChaosError::Inner(e) => e.is_retryable(),
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            ChaosError::Transient => true,
+            ChaosError::Permanent => false,
+            ChaosError::Inner(e) => e.should_retry_operation(),
+        }
+    }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            ChaosError::Inner(e) => e.key_fault(),
+            ChaosError::Transient | ChaosError::Permanent => None,
+        }
+    }
+}
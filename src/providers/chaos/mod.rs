@@ -0,0 +1,60 @@
+//! # Fault-Injection Wrapper
+//!
+//! [`ChaosProvider`] wraps any [`Provider`](crate::Provider) and injects
+//! failures around it, to exercise retry, polling and timeout settings in CI
+//! without network access. Pair it with the `mock` provider for fully
+//! offline chaos tests.
+//!
+//! | Fault | Setting | Effect |
+//! |-------|---------|--------|
+//! | Latency | [`ChaosProvider::with_latency`] | Sleep before any call |
+//! | Slow polls | [`ChaosProvider::with_slow_polls`] | Sleep before `get_task_result` |
+//! | Transient errors | [`ChaosProvider::with_transient_errors`] | [`ChaosError::Transient`], retryable |
+//! | Permanent errors | [`ChaosProvider::with_permanent_errors`] | [`ChaosError::Permanent`], not retried |
+//! | Dropped solutions | [`ChaosProvider::with_dropped_solutions`] | The task never reports its solution |
+//!
+//! Rates are probabilities in `[0, 1]` drawn from a seedable RNG
+//! ([`ChaosProvider::with_seed`]). For deterministic sequences, queue
+//! [`Fault`]s with [`ChaosProvider::with_script`].
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::chaos::{ChaosProvider, Fault};
+//! use captcha_solvers::mock::{MockOutcome, MockProvider};
+//! use captcha_solvers::{
+//!     CaptchaRetryableProvider, CaptchaSolverService, CaptchaSolverServiceTrait, Turnstile,
+//! };
+//!
+//! #[tokio::test(start_paused = true)]
+//! async fn survives_transient_errors() {
+//!     let mock = MockProvider::new().then(MockOutcome::pending(2, "token"));
+//!     let chaos = ChaosProvider::new(mock)
+//!         .with_script([Fault::TransientError, Fault::Pass, Fault::TransientError]);
+//!     let service = CaptchaSolverService::new(CaptchaRetryableProvider::new(chaos.clone()));
+//!
+//!     let solution = service
+//!         .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+//!         .await
+//!         .unwrap();
+//!     assert_eq!(solution.token(), "token");
+//!     assert_eq!(chaos.stats().transient_errors, 2);
+//! }
+//! ```
+
+mod errors;
+mod provider;
+mod rng;
+mod types;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests;
+
+// Errors
+pub use errors::ChaosError;
+
+// Provider
+pub use provider::ChaosProvider;
+
+// Types
+pub use types::{ChaosStats, Fault};
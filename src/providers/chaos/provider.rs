@@ -0,0 +1,283 @@
+//! Fault-injecting provider wrapper implementation.
+
+use super::errors::ChaosError;
+use super::rng::SplitMix64;
+use super::types::{ChaosStats, DelayRate, Fault};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+#[cfg(feature = "tracing")]
+use tracing::debug;
+
+/// Which provider call a fault is drawn for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Call {
+    CreateTask,
+    GetTaskResult,
+}
+
+#[derive(Debug)]
+struct State {
+    rng: SplitMix64,
+    script: VecDeque<Fault>,
+    dropped: HashSet<TaskId>,
+    stats: ChaosStats,
+}
+
+/// Wrapper that injects latency, errors and lost solutions around any [`Provider`]
+///
+/// Each call either consumes the next scripted [`Fault`] or draws one from the
+/// configured rates, so at most one fault applies per call. Injected errors
+/// are [`ChaosError::Transient`] (retryable) or [`ChaosError::Permanent`];
+/// errors from the wrapped provider pass through as [`ChaosError::Inner`].
+///
+/// Delays use `tokio::time::sleep`, so tests running on a paused clock
+/// complete instantly. Clones share the RNG, the script and the counters.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::chaos::ChaosProvider;
+/// use captcha_solvers::{CaptchaRetryableProvider, CaptchaSolverService};
+/// use std::time::Duration;
+///
+/// let chaos = ChaosProvider::new(inner)
+///     .with_seed(42)
+///     .with_transient_errors(0.2)
+///     .with_latency(0.5, Duration::from_millis(100), Duration::from_secs(2))
+///     .with_slow_polls(0.3, Duration::from_secs(5), Duration::from_secs(15))
+///     .with_dropped_solutions(0.05);
+///
+/// let service = CaptchaSolverService::new(CaptchaRetryableProvider::new(chaos.clone()));
+/// // ... run the solves under test, then inspect chaos.stats()
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChaosProvider<P> {
+    inner: P,
+    latency: DelayRate,
+    slow_polls: DelayRate,
+    transient_rate: f64,
+    permanent_rate: f64,
+    drop_rate: f64,
+    state: Arc<Mutex<State>>,
+}
+
+impl<P: Provider> ChaosProvider<P> {
+    /// Wrap a provider with all fault rates at zero and a random seed
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            latency: DelayRate::NONE,
+            slow_polls: DelayRate::NONE,
+            transient_rate: 0.0,
+            permanent_rate: 0.0,
+            drop_rate: 0.0,
+            state: Arc::new(Mutex::new(State {
+                rng: SplitMix64::from_entropy(),
+                script: VecDeque::new(),
+                dropped: HashSet::new(),
+                stats: ChaosStats::default(),
+            })),
+        }
+    }
+
+    /// Seed the RNG so the same configuration injects the same faults
+    pub fn with_seed(self, seed: u64) -> Self {
+        self.state().rng = SplitMix64::new(seed);
+        self
+    }
+
+    /// Delay a fraction of all calls by a duration in `[min, max]`
+    pub fn with_latency(mut self, rate: f64, min: Duration, max: Duration) -> Self {
+        self.latency = DelayRate {
+            rate: clamp_rate(rate),
+            min,
+            max,
+        };
+        self
+    }
+
+    /// Delay a fraction of `get_task_result` calls by a duration in `[min, max]`
+    ///
+    /// Drawn before the general latency, so a slow poll replaces it.
+    pub fn with_slow_polls(mut self, rate: f64, min: Duration, max: Duration) -> Self {
+        self.slow_polls = DelayRate {
+            rate: clamp_rate(rate),
+            min,
+            max,
+        };
+        self
+    }
+
+    /// Fail a fraction of calls with [`ChaosError::Transient`]
+    pub fn with_transient_errors(mut self, rate: f64) -> Self {
+        self.transient_rate = clamp_rate(rate);
+        self
+    }
+
+    /// Fail a fraction of calls with [`ChaosError::Permanent`]
+    pub fn with_permanent_errors(mut self, rate: f64) -> Self {
+        self.permanent_rate = clamp_rate(rate);
+        self
+    }
+
+    /// Discard the solution of a fraction of calls (see [`Fault::DropSolution`])
+    pub fn with_dropped_solutions(mut self, rate: f64) -> Self {
+        self.drop_rate = clamp_rate(rate);
+        self
+    }
+
+    /// Queue faults for the next calls, ahead of the random rates
+    pub fn with_script(self, faults: impl IntoIterator<Item = Fault>) -> Self {
+        self.push_faults(faults);
+        self
+    }
+
+    /// Queue faults on a shared clone, e.g. after it was moved into a service
+    pub fn push_faults(&self, faults: impl IntoIterator<Item = Fault>) {
+        self.state().script.extend(faults);
+    }
+
+    /// Counters of the faults injected so far
+    pub fn stats(&self) -> ChaosStats {
+        self.state().stats
+    }
+
+    /// Get reference to the inner provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Pick the fault for one call and count it
+    fn next_fault(&self, call: Call) -> Fault {
+        let mut state = self.state();
+        let fault = match state.script.pop_front() {
+            Some(fault) => fault,
+            None => self.draw(&mut state.rng, call),
+        };
+
+        match fault {
+            Fault::Pass => state.stats.passed += 1,
+            Fault::Delay(_) => state.stats.delayed += 1,
+            Fault::TransientError => state.stats.transient_errors += 1,
+            Fault::PermanentError => state.stats.permanent_errors += 1,
+            // Counted once a solution is actually discarded
+            Fault::DropSolution => {}
+        }
+
+        #[cfg(feature = "tracing")]
+        if fault != Fault::Pass {
+            debug!(?fault, ?call, "Injecting fault");
+        }
+
+        fault
+    }
+
+    fn draw(&self, rng: &mut SplitMix64, call: Call) -> Fault {
+        // Draw every chance on each call so one rate does not shift the others
+        let permanent = rng.chance(self.permanent_rate);
+        let transient = rng.chance(self.transient_rate);
+        let drop = rng.chance(self.drop_rate);
+        let slow = call == Call::GetTaskResult && rng.chance(self.slow_polls.rate);
+        let slow_delay = rng.duration(self.slow_polls.min, self.slow_polls.max);
+        let latency = rng.chance(self.latency.rate);
+        let latency_delay = rng.duration(self.latency.min, self.latency.max);
+
+        if permanent {
+            Fault::PermanentError
+        } else if transient {
+            Fault::TransientError
+        } else if drop {
+            Fault::DropSolution
+        } else if slow {
+            Fault::Delay(slow_delay)
+        } else if latency {
+            Fault::Delay(latency_delay)
+        } else {
+            Fault::Pass
+        }
+    }
+
+    fn drop_task(&self, task_id: &TaskId) {
+        let mut state = self.state();
+        state.dropped.insert(task_id.clone());
+        state.stats.dropped_solutions += 1;
+    }
+}
+
+impl<P: Provider> Provider for ChaosProvider<P> {
+    type Solution = P::Solution;
+    type Error = ChaosError<P::Error>;
+
+    async fn create_task(
+        &self,
+        task: CaptchaTask,
+    ) -> Result<TaskCreationOutcome<Self::Solution>, Self::Error> {
+        let fault = self.next_fault(Call::CreateTask);
+        match fault {
+            Fault::TransientError => return Err(ChaosError::Transient),
+            Fault::PermanentError => return Err(ChaosError::Permanent),
+            Fault::Delay(delay) => tokio::time::sleep(delay).await,
+            Fault::Pass | Fault::DropSolution => {}
+        }
+
+        let outcome = self
+            .inner
+            .create_task(task)
+            .await
+            .map_err(ChaosError::Inner)?;
+
+        if fault != Fault::DropSolution {
+            return Ok(outcome);
+        }
+        // Dropping a pending task loses the solution its polls would return
+        let task_id = outcome.task_id().clone();
+        self.drop_task(&task_id);
+        Ok(TaskCreationOutcome::Pending(task_id))
+    }
+
+    async fn get_task_result(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Option<Self::Solution>, Self::Error> {
+        if self.state().dropped.contains(task_id) {
+            return Ok(None);
+        }
+
+        let fault = self.next_fault(Call::GetTaskResult);
+        match fault {
+            Fault::TransientError => return Err(ChaosError::Transient),
+            Fault::PermanentError => return Err(ChaosError::Permanent),
+            Fault::Delay(delay) => tokio::time::sleep(delay).await,
+            Fault::Pass | Fault::DropSolution => {}
+        }
+
+        let solution = self
+            .inner
+            .get_task_result(task_id)
+            .await
+            .map_err(ChaosError::Inner)?;
+
+        if fault == Fault::DropSolution && solution.is_some() {
+            self.drop_task(task_id);
+            return Ok(None);
+        }
+        Ok(solution)
+    }
+}
+
+fn clamp_rate(rate: f64) -> f64 {
+    if rate.is_nan() {
+        0.0
+    } else {
+        rate.clamp(0.0, 1.0)
+    }
+}
@@ -0,0 +1,55 @@
+//! Small seedable RNG so fault sequences are reproducible without extra dependencies.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// SplitMix64 generator
+///
+/// Not suitable for anything but picking faults; the only requirement is that
+/// the same seed always yields the same sequence.
+#[derive(Debug, Clone)]
+pub(super) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(super) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Seed from the process's hash randomness
+    pub(super) fn from_entropy() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(0x5eed);
+        Self::new(hasher.finish())
+    }
+
+    pub(super) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in `[0, 1)`
+    pub(super) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns `true` with probability `rate`
+    ///
+    /// Always draws, so enabling one fault does not shift the sequence of others.
+    pub(super) fn chance(&mut self, rate: f64) -> bool {
+        self.next_f64() < rate
+    }
+
+    /// Uniform duration in `[min, max]`
+    pub(super) fn duration(&mut self, min: Duration, max: Duration) -> Duration {
+        if max <= min {
+            return min;
+        }
+        min + (max - min).mul_f64(self.next_f64())
+    }
+}
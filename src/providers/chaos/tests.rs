@@ -0,0 +1,233 @@
+//! Tests for the fault-injection wrapper.
+
+use super::errors::ChaosError;
+use super::provider::ChaosProvider;
+use super::types::{ChaosStats, Fault};
+use crate::errors::RetryableError;
+use crate::providers::CaptchaRetryableProvider;
+use crate::providers::mock::{MockError, MockOutcome, MockProvider};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::service::{CaptchaSolverService, CaptchaSolverServiceConfig, CaptchaSolverServiceTrait};
+use crate::tasks::Turnstile;
+use crate::utils::retry::RetryConfig;
+use std::time::Duration;
+
+fn task() -> Turnstile {
+    Turnstile::new("https://example.com", "site-key")
+}
+
+fn config() -> CaptchaSolverServiceConfig {
+    CaptchaSolverServiceConfig::builder()
+        .timeout(Duration::from_secs(120))
+        .poll_interval(Duration::from_secs(5))
+        .build()
+}
+
+fn retrying<P: Provider>(provider: P) -> CaptchaRetryableProvider<P> {
+    let retry = RetryConfig::default()
+        .with_max_retries(5)
+        .with_min_delay(Duration::from_millis(100));
+    CaptchaRetryableProvider::with_config(provider, retry)
+}
+
+#[tokio::test]
+async fn test_pass_through() {
+    let mock = MockProvider::new().then(MockOutcome::ready("token"));
+    let chaos = ChaosProvider::new(mock.clone());
+
+    let outcome = chaos.create_task(task().into()).await.unwrap();
+    assert_eq!(outcome.into_solution().unwrap().token(), "token");
+    assert_eq!(chaos.stats().passed, 1);
+    assert_eq!(mock.received_tasks().len(), 1);
+}
+
+#[tokio::test]
+async fn test_injected_errors_skip_inner_provider() {
+    let mock = MockProvider::new();
+    let chaos = ChaosProvider::new(mock.clone())
+        .with_script([Fault::TransientError, Fault::PermanentError]);
+
+    let err = chaos.create_task(task().into()).await.unwrap_err();
+    assert!(matches!(err, ChaosError::Transient));
+    assert!(err.is_retryable() && err.should_retry_operation());
+    assert!(err.is_injected());
+
+    let err = chaos.create_task(task().into()).await.unwrap_err();
+    assert!(matches!(err, ChaosError::Permanent));
+    assert!(!err.is_retryable() && !err.should_retry_operation());
+
+    assert!(mock.received_tasks().is_empty());
+}
+
+#[tokio::test]
+async fn test_inner_errors_keep_classification() {
+    let mock = MockProvider::new().then(MockOutcome::error(MockError::scripted(
+        "unsolvable",
+        false,
+        true,
+    )));
+    let chaos = ChaosProvider::new(mock);
+
+    let err = chaos.create_task(task().into()).await.unwrap_err();
+    assert!(!err.is_injected());
+    assert!(!err.is_retryable());
+    assert!(err.should_retry_operation());
+    assert_eq!(
+        err.into_inner().unwrap().to_string(),
+        "Scripted error: unsolvable"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retryable_provider_recovers_from_transient_errors() {
+    let mock = MockProvider::new().then(MockOutcome::pending(1, "token"));
+    let chaos = ChaosProvider::new(mock.clone()).with_script([
+        Fault::TransientError,
+        Fault::TransientError,
+        Fault::Pass,
+        Fault::TransientError,
+    ]);
+    let service = CaptchaSolverService::with_config(retrying(chaos.clone()), config());
+
+    let solution = service.solve_captcha(task()).await.unwrap();
+    assert_eq!(solution.token(), "token");
+    assert_eq!(chaos.stats().transient_errors, 3);
+    assert_eq!(mock.received_tasks().len(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_permanent_poll_error_fails_solve() {
+    let mock = MockProvider::new().then(MockOutcome::pending(5, "token"));
+    let chaos = ChaosProvider::new(mock).with_script([Fault::Pass, Fault::PermanentError]);
+    let service = CaptchaSolverService::with_config(retrying(chaos), config());
+
+    let err = service.solve_captcha(task()).await.unwrap_err();
+    assert!(!err.should_retry_operation());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dropped_ready_solution_times_out() {
+    let mock = MockProvider::new().then(MockOutcome::ready("token"));
+    let chaos = ChaosProvider::new(mock.clone()).with_script([Fault::DropSolution]);
+
+    let outcome = chaos.create_task(task().into()).await.unwrap();
+    assert!(matches!(outcome, TaskCreationOutcome::Pending(_)));
+
+    let service = CaptchaSolverService::with_config(chaos.clone(), config());
+    mock.push(MockOutcome::ready("second"));
+    chaos.push_faults([Fault::DropSolution]);
+    let err = service.solve_captcha(task()).await.unwrap_err();
+    assert!(err.is_timeout());
+    assert_eq!(chaos.stats().dropped_solutions, 2);
+    // Polls for dropped tasks never reach the wrapped provider
+    assert_eq!(mock.poll_count(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dropped_poll_solution() {
+    let mock = MockProvider::new().then(MockOutcome::pending(1, "token"));
+    let chaos = ChaosProvider::new(mock.clone()).with_script([
+        Fault::Pass,
+        Fault::Pass,
+        Fault::DropSolution,
+    ]);
+
+    let id = chaos
+        .create_task(task().into())
+        .await
+        .unwrap()
+        .task_id()
+        .clone();
+    assert!(chaos.get_task_result(&id).await.unwrap().is_none());
+    assert!(chaos.get_task_result(&id).await.unwrap().is_none());
+    assert!(chaos.get_task_result(&id).await.unwrap().is_none());
+    assert_eq!(mock.poll_count(), 2);
+    assert_eq!(chaos.stats().dropped_solutions, 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_slow_polls_advance_clock() {
+    let mock = MockProvider::new().then(MockOutcome::pending(2, "token"));
+    let chaos = ChaosProvider::new(mock).with_slow_polls(
+        1.0,
+        Duration::from_secs(10),
+        Duration::from_secs(10),
+    );
+
+    let started = tokio::time::Instant::now();
+    let id = chaos
+        .create_task(task().into())
+        .await
+        .unwrap()
+        .task_id()
+        .clone();
+    assert_eq!(tokio::time::Instant::now(), started);
+
+    while chaos.get_task_result(&id).await.unwrap().is_none() {}
+    assert_eq!(started.elapsed(), Duration::from_secs(30));
+    assert_eq!(chaos.stats().delayed, 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_slow_polls_hit_service_timeout() {
+    let mock = MockProvider::new().then(MockOutcome::pending(10, "token"));
+    let chaos = ChaosProvider::new(mock).with_slow_polls(
+        1.0,
+        Duration::from_secs(20),
+        Duration::from_secs(20),
+    );
+    let service = CaptchaSolverService::with_config(chaos, config());
+
+    let err = service.solve_captcha(task()).await.unwrap_err();
+    assert!(err.is_timeout());
+}
+
+async fn run_seeded(seed: u64) -> (ChaosStats, Vec<bool>) {
+    let mock = MockProvider::new();
+    for _ in 0..50 {
+        mock.push(MockOutcome::ready("token"));
+    }
+    let chaos = ChaosProvider::new(mock)
+        .with_seed(seed)
+        .with_transient_errors(0.3)
+        .with_permanent_errors(0.1)
+        .with_latency(0.5, Duration::from_millis(10), Duration::from_millis(500));
+
+    let mut results = Vec::new();
+    for _ in 0..50 {
+        results.push(chaos.create_task(task().into()).await.is_ok());
+    }
+    (chaos.stats(), results)
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_seeded_rates_are_reproducible() {
+    let (stats, results) = run_seeded(7).await;
+    assert_eq!(run_seeded(7).await, (stats, results.clone()));
+    assert_ne!(run_seeded(8).await.1, results);
+
+    let total = stats.passed
+        + stats.delayed
+        + stats.transient_errors
+        + stats.permanent_errors
+        + stats.dropped_solutions;
+    assert_eq!(total, 50);
+    assert!(stats.transient_errors > 0 && stats.permanent_errors > 0 && stats.delayed > 0);
+    assert_eq!(
+        results.iter().filter(|ok| !**ok).count(),
+        stats.transient_errors + stats.permanent_errors
+    );
+}
+
+#[tokio::test]
+async fn test_rates_are_clamped() {
+    let chaos = ChaosProvider::new(MockProvider::new())
+        .with_seed(1)
+        .with_permanent_errors(f64::NAN)
+        .with_transient_errors(3.0);
+
+    for _ in 0..10 {
+        let err = chaos.create_task(task().into()).await.unwrap_err();
+        assert!(matches!(err, ChaosError::Transient));
+    }
+}
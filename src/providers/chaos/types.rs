@@ -0,0 +1,62 @@
+//! Fault descriptions and counters for the fault-injection wrapper.
+
+use std::time::Duration;
+
+/// A single fault applied to one provider call
+///
+/// Queue faults with [`ChaosProvider::with_script`](super::ChaosProvider::with_script)
+/// to drive a deterministic sequence; each `create_task` or `get_task_result`
+/// call consumes one entry before the random rates are consulted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// Forward the call unchanged
+    Pass,
+
+    /// Sleep before forwarding the call
+    Delay(Duration),
+
+    /// Fail with [`ChaosError::Transient`](super::ChaosError::Transient)
+    /// without calling the wrapped provider
+    TransientError,
+
+    /// Fail with [`ChaosError::Permanent`](super::ChaosError::Permanent)
+    /// without calling the wrapped provider
+    PermanentError,
+
+    /// Forward the call, then discard the solution it returns
+    ///
+    /// A dropped task answers "not ready" to every later poll, as if the
+    /// result was lost on the way back.
+    DropSolution,
+}
+
+/// Counters of the faults injected so far
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChaosStats {
+    /// Calls forwarded without any fault
+    pub passed: usize,
+    /// Calls delayed by latency or slow polls
+    pub delayed: usize,
+    /// Injected transient errors
+    pub transient_errors: usize,
+    /// Injected permanent errors
+    pub permanent_errors: usize,
+    /// Solutions discarded
+    pub dropped_solutions: usize,
+}
+
+/// A fault rate paired with the delay range it draws from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct DelayRate {
+    pub(super) rate: f64,
+    pub(super) min: Duration,
+    pub(super) max: Duration,
+}
+
+impl DelayRate {
+    pub(super) const NONE: Self = Self {
+        rate: 0.0,
+        min: Duration::ZERO,
+        max: Duration::ZERO,
+    };
+}
@@ -0,0 +1,175 @@
+//! Error types for the DeathByCaptcha provider.
+//!
+//! DeathByCaptcha reports errors as a short kebab-case `error` string
+//! (`not-logged-in`, `insufficient-funds`, ...) together with an HTTP status
+//! code, which is mapped into [`DeathbycaptchaErrorCode`].
+
+use crate::errors::{KeyFault, RetryableError, UnsupportedTaskError};
+use reqwest::Url;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeathbycaptchaError {
+    #[error("Failed to build HTTP client: {0}")]
+    BuildHttpClient(#[source] reqwest::Error),
+
+    #[error("URL cannot be used as a base: {0}")]
+    InvalidUrl(Url),
+
+    #[error("HTTP request failed: {0}")]
+    HttpRequest(#[from] reqwest_middleware::Error),
+
+    #[error("Failed to parse response: {0}")]
+    ParseResponse(#[source] reqwest::Error),
+
+    #[error("Unexpected response (HTTP {status}): {body}")]
+    UnexpectedResponse { status: u16, body: String },
+
+    #[error("Upload response did not include a captcha id")]
+    MissingCaptchaId,
+
+    #[error("Invalid base64 image: {0}")]
+    InvalidImage(#[source] base64::DecodeError),
+
+    #[error("DeathByCaptcha API error: {0}")]
+    Api(DeathbycaptchaErrorCode),
+
+    #[error("Captcha could not be solved")]
+    Unsolvable,
+
+    #[error("{0}")]
+    UnsupportedTask(#[source] UnsupportedTaskError),
+}
+
+pub type Result<T> = std::result::Result<T, DeathbycaptchaError>;
+
+impl RetryableError for DeathbycaptchaError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            DeathbycaptchaError::HttpRequest(_) => true,
+            DeathbycaptchaError::UnexpectedResponse { status, .. } => *status >= 500,
+            DeathbycaptchaError::Api(code) => code.is_retryable(),
+            DeathbycaptchaError::BuildHttpClient(_)
+            | DeathbycaptchaError::InvalidUrl(_)
+            | DeathbycaptchaError::ParseResponse(_)
+            | DeathbycaptchaError::MissingCaptchaId
+            | DeathbycaptchaError::InvalidImage(_)
+            | DeathbycaptchaError::Unsolvable
+            | DeathbycaptchaError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            DeathbycaptchaError::HttpRequest(_) | DeathbycaptchaError::Unsolvable => true,
+            DeathbycaptchaError::UnexpectedResponse { status, .. } => *status >= 500,
+            DeathbycaptchaError::Api(code) => code.should_retry_operation(),
+            DeathbycaptchaError::BuildHttpClient(_)
+            | DeathbycaptchaError::InvalidUrl(_)
+            | DeathbycaptchaError::ParseResponse(_)
+            | DeathbycaptchaError::MissingCaptchaId
+            | DeathbycaptchaError::InvalidImage(_)
+            | DeathbycaptchaError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            DeathbycaptchaError::Api(code) => code.key_fault(),
+            _ => None,
+        }
+    }
+}
+
+/// Known DeathByCaptcha `error` values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeathbycaptchaErrorCode {
+    // Retryable/transient
+    ServiceOverload,
+
+    // Account/configuration
+    NotLoggedIn,
+    Banned,
+    InsufficientFunds,
+
+    // Task submission
+    InvalidCaptcha,
+
+    // Unknown
+    Other(String),
+}
+
+impl DeathbycaptchaErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ServiceOverload => "service-overload",
+            Self::NotLoggedIn => "not-logged-in",
+            Self::Banned => "banned",
+            Self::InsufficientFunds => "insufficient-funds",
+            Self::InvalidCaptcha => "invalid-captcha",
+            Self::Other(code) => code.as_str(),
+        }
+    }
+
+    pub(crate) fn from_str(s: &str) -> Self {
+        match s {
+            "service-overload" => Self::ServiceOverload,
+            "not-logged-in" => Self::NotLoggedIn,
+            "banned" => Self::Banned,
+            "insufficient-funds" => Self::InsufficientFunds,
+            "invalid-captcha" => Self::InvalidCaptcha,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::ServiceOverload)
+    }
+
+    /// Returns the account problem this code reports, if any
+    pub fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            Self::NotLoggedIn | Self::Banned => Some(KeyFault::Invalid),
+            Self::InsufficientFunds => Some(KeyFault::ZeroBalance),
+            _ => None,
+        }
+    }
+
+    pub fn should_retry_operation(&self) -> bool {
+        match self {
+            Self::ServiceOverload => true,
+            Self::NotLoggedIn
+            | Self::Banned
+            | Self::InsufficientFunds
+            | Self::InvalidCaptcha
+            | Self::Other(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for DeathbycaptchaErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for DeathbycaptchaErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeathbycaptchaErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from_str(&s))
+    }
+}
@@ -0,0 +1,85 @@
+//! # DeathByCaptcha Provider
+//!
+//! Implementation of captcha solver provider for [DeathByCaptcha](https://deathbycaptcha.com),
+//! a long-standing human-solver service with its own HTTP API.
+//!
+//! Captchas are uploaded as `multipart/form-data`: images as a `captchafile`
+//! part, token captchas as `token_params`/`turnstile_params` JSON. Results are
+//! polled from `/captcha/<id>`.
+//!
+//! ## Supported Captcha Types
+//!
+//! | Type | Task Type | `type` | Proxy Supported |
+//! |------|-----------|--------|-----------------|
+//! | Image to Text | [`ImageToText`](crate::ImageToText) | `0` | No |
+//! | ReCaptcha V2 | [`ReCaptchaV2`](crate::ReCaptchaV2) | `4` | HTTP only |
+//! | ReCaptcha V3 | [`ReCaptchaV3`](crate::ReCaptchaV3) | `5` | HTTP only |
+//! | Cloudflare Turnstile | [`Turnstile`](crate::Turnstile) | `12` | HTTP only |
+//!
+//! Image captchas are sent without solving hints: `ImageToText` options such as
+//! `case_sensitive` or `min_length` are rejected, as are all other task types,
+//! with [`UnsupportedTaskError`](crate::UnsupportedTaskError).
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::deathbycaptcha::DeathbycaptchaProvider;
+//! use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ImageToText};
+//!
+//! let provider = DeathbycaptchaProvider::new("username", "password")?;
+//! // or: DeathbycaptchaProvider::with_authtoken("authtoken")?
+//! let service = CaptchaSolverService::new(provider);
+//!
+//! let task = ImageToText::from_base64(base64_image);
+//! let solution = service.solve_captcha(task).await?;
+//! println!("Text: {}", solution.into_image_to_text().text());
+//! ```
+//!
+//! ## Solution Types
+//!
+//! Every captcha is answered with a plain `text` field, so every task yields a
+//! [`DeathbycaptchaSolution`]. Convert it into the shared type you expect:
+//!
+//! - **ReCaptcha V2/V3**: `into_recaptcha()` → [`ReCaptchaSolution`]
+//! - **Turnstile**: `into_turnstile()` → [`TurnstileSolution`] (or `into_token()` → [`TokenSolution`])
+//! - **Image to Text**: `into_image_to_text()` → [`ImageToTextSolution`]
+//!
+//! ## Error Handling
+//!
+//! `error` strings are mapped into [`DeathbycaptchaErrorCode`] and classified
+//! as retryable or permanent:
+//!
+//! ```rust,ignore
+//! use captcha_solvers::RetryableError;
+//! use captcha_solvers::deathbycaptcha::{DeathbycaptchaError, DeathbycaptchaErrorCode};
+//!
+//! match result {
+//!     Err(DeathbycaptchaError::Api(DeathbycaptchaErrorCode::InsufficientFunds)) => { /* top up */ }
+//!     Err(e) if e.is_retryable() => { /* service-overload, 5xx responses, network errors */ }
+//!     Err(e) => { /* permanent error, check credentials or task data */ }
+//!     Ok(solution) => { /* success */ }
+//! }
+//! ```
+
+mod errors;
+mod provider;
+mod response;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+// Errors
+pub use errors::{DeathbycaptchaError, DeathbycaptchaErrorCode};
+
+// Provider
+pub use provider::{DEFAULT_API_URL, DeathbycaptchaProvider, DeathbycaptchaProviderBuilder};
+
+// Solutions
+pub use crate::solutions::{
+    ImageToTextSolution, ReCaptchaSolution, TokenSolution, TurnstileSolution,
+};
+pub use types::DeathbycaptchaSolution;
+
+// Re-export proxy types for convenience (also available at crate root)
+pub use crate::utils::proxy::{ProxyConfig, ProxyType};
@@ -0,0 +1,407 @@
+//! DeathByCaptcha provider implementation.
+
+use super::errors::{DeathbycaptchaError, Result};
+use super::response::CaptchaResponse;
+use super::types::{DeathbycaptchaSolution, DeathbycaptchaTask};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use reqwest::Url;
+use reqwest::header::ACCEPT;
+use reqwest::multipart::{Form, Part};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, RequestBuilder};
+use secrecy::{ExposeSecret, SecretString};
+use std::fmt::Debug;
+
+#[cfg(feature = "tracing")]
+use crate::utils::span_status::{record_error, set_span_ok};
+#[cfg(feature = "tracing")]
+use tracing::Span;
+
+/// Default DeathByCaptcha API URL
+pub const DEFAULT_API_URL: &str = "https://api.dbcapi.me/api";
+
+/// API endpoint path
+const CAPTCHA_PATH: &str = "captcha";
+
+/// Account credentials sent with every upload.
+#[derive(Clone)]
+enum Credentials {
+    Password {
+        username: String,
+        password: SecretString,
+    },
+    Authtoken(SecretString),
+}
+
+impl Credentials {
+    /// Add the credential fields to an upload form.
+    fn apply(&self, form: Form) -> Form {
+        match self {
+            Self::Password { username, password } => form
+                .text("username", username.clone())
+                .text("password", password.expose_secret().to_string()),
+            Self::Authtoken(token) => form.text("authtoken", token.expose_secret().to_string()),
+        }
+    }
+}
+
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Password { username, .. } => f
+                .debug_struct("Password")
+                .field("username", username)
+                .field("password", &crate::utils::REDACTED)
+                .finish(),
+            Self::Authtoken(_) => f
+                .debug_tuple("Authtoken")
+                .field(&crate::utils::REDACTED)
+                .finish(),
+        }
+    }
+}
+
+/// Provider for the DeathByCaptcha HTTP API
+///
+/// Captchas are uploaded as `multipart/form-data` to `/captcha`: images as a
+/// `captchafile` part, reCAPTCHA and Turnstile as `token_params` and
+/// `turnstile_params` JSON. Results are polled from `/captcha/<id>`.
+///
+/// Authenticate with a username and password, or with an API `authtoken`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::{
+///     CaptchaSolverService, CaptchaSolverServiceTrait, ReCaptchaV2,
+///     deathbycaptcha::DeathbycaptchaProvider,
+/// };
+///
+/// let provider = DeathbycaptchaProvider::new("username", "password")?;
+/// let service = CaptchaSolverService::new(provider);
+///
+/// let task = ReCaptchaV2::new("https://example.com", "site_key");
+/// let solution = service.solve_captcha(task).await?;
+/// println!("Token: {}", solution.into_recaptcha().token());
+/// ```
+#[derive(Clone)]
+pub struct DeathbycaptchaProvider {
+    http_client: ClientWithMiddleware,
+    credentials: Credentials,
+    url: Url,
+}
+
+impl Debug for DeathbycaptchaProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeathbycaptchaProvider")
+            .field("url", &self.url)
+            .field("credentials", &self.credentials)
+            .finish()
+    }
+}
+
+/// Builder for configuring a [`DeathbycaptchaProvider`]
+///
+/// Provides a fluent API for constructing providers with custom settings.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::deathbycaptcha::DeathbycaptchaProvider;
+///
+/// let provider = DeathbycaptchaProvider::builder("username", "password")
+///     .http_client(custom_client)
+///     .build()?;
+/// ```
+pub struct DeathbycaptchaProviderBuilder {
+    credentials: Credentials,
+    url: Option<Url>,
+    http_client: Option<ClientWithMiddleware>,
+}
+
+impl DeathbycaptchaProviderBuilder {
+    /// Create a new builder authenticating with a username and password
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::with_credentials(Credentials::Password {
+            username: username.into(),
+            password: SecretString::from(password.into()),
+        })
+    }
+
+    /// Create a new builder authenticating with an API `authtoken`
+    pub fn with_authtoken(authtoken: impl Into<String>) -> Self {
+        Self::with_credentials(Credentials::Authtoken(SecretString::from(authtoken.into())))
+    }
+
+    fn with_credentials(credentials: Credentials) -> Self {
+        Self {
+            credentials,
+            url: None,
+            http_client: None,
+        }
+    }
+
+    /// Set a custom API URL
+    ///
+    /// Default: `https://api.dbcapi.me/api`
+    pub fn url(mut self, url: Url) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Set a custom HTTP client with middleware
+    ///
+    /// Use this when you need custom middleware (e.g., tracing, retry, rate limiting).
+    pub fn http_client(mut self, client: ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Build the [`DeathbycaptchaProvider`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL cannot be a base (e.g. `mailto:`) or the
+    /// HTTP client cannot be built.
+    pub fn build(self) -> Result<DeathbycaptchaProvider> {
+        let url = self
+            .url
+            .unwrap_or_else(|| Url::parse(DEFAULT_API_URL).expect("Invalid default URL"));
+        if url.cannot_be_a_base() {
+            return Err(DeathbycaptchaError::InvalidUrl(url));
+        }
+
+        let http_client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let client = reqwest::Client::builder()
+                    .build()
+                    .map_err(DeathbycaptchaError::BuildHttpClient)?;
+                ClientBuilder::new(client).build()
+            }
+        };
+
+        Ok(DeathbycaptchaProvider {
+            http_client,
+            credentials: self.credentials,
+            url,
+        })
+    }
+}
+
+impl DeathbycaptchaProvider {
+    /// Create a new DeathByCaptcha provider with the default API URL
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Your DeathByCaptcha username
+    /// * `password` - Your DeathByCaptcha password
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Result<Self> {
+        Self::builder(username, password).build()
+    }
+
+    /// Create a new DeathByCaptcha provider authenticating with an API `authtoken`
+    pub fn with_authtoken(authtoken: impl Into<String>) -> Result<Self> {
+        DeathbycaptchaProviderBuilder::with_authtoken(authtoken).build()
+    }
+
+    /// Create a builder for configuring the provider
+    ///
+    /// Use [`DeathbycaptchaProviderBuilder::with_authtoken`] to configure an
+    /// `authtoken` instead of a username and password.
+    pub fn builder(
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> DeathbycaptchaProviderBuilder {
+        DeathbycaptchaProviderBuilder::new(username, password)
+    }
+
+    /// Get the API URL
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Resolve an endpoint relative to the base URL, keeping any base path.
+    fn endpoint(&self, segments: &[&str]) -> Url {
+        let mut url = self.url.clone();
+        url.path_segments_mut()
+            .expect("checked in build()")
+            .pop_if_empty()
+            .extend(segments);
+        url
+    }
+
+    /// Build the multipart upload form for a task.
+    fn upload_form(&self, task: DeathbycaptchaTask) -> Result<Form> {
+        let form = self
+            .credentials
+            .apply(Form::new())
+            .text("type", task.captcha_type().to_string());
+
+        let form = match task {
+            DeathbycaptchaTask::Image { body } => {
+                let image = STANDARD
+                    .decode(body.trim())
+                    .map_err(DeathbycaptchaError::InvalidImage)?;
+                form.part("captchafile", Part::bytes(image).file_name("captcha"))
+            }
+            DeathbycaptchaTask::ReCaptchaV2(params) | DeathbycaptchaTask::ReCaptchaV3(params) => {
+                form.text(
+                    "token_params",
+                    serde_json::to_string(&params).expect("token_params are serializable"),
+                )
+            }
+            DeathbycaptchaTask::Turnstile(params) => form.text(
+                "turnstile_params",
+                serde_json::to_string(&params).expect("turnstile_params are serializable"),
+            ),
+        };
+
+        Ok(form)
+    }
+
+    /// Send a request and parse the captcha status response.
+    async fn send(&self, request: RequestBuilder) -> Result<CaptchaResponse> {
+        let response = request
+            .header(ACCEPT, "application/json")
+            .send()
+            .await
+            .map_err(DeathbycaptchaError::HttpRequest)?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(DeathbycaptchaError::ParseResponse)?;
+        CaptchaResponse::parse(status, &body)
+    }
+
+    /// Create a captcha solving task (internal)
+    ///
+    /// Returns `TaskCreationOutcome::Ready` if the upload was answered with a
+    /// solved text, otherwise returns `TaskCreationOutcome::Pending`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task_internal",
+            target = "captcha.deathbycaptcha",
+            skip_all,
+            fields(task_id = tracing::field::Empty)
+        )
+    )]
+    async fn create_task_internal(
+        &self,
+        task: DeathbycaptchaTask,
+    ) -> Result<TaskCreationOutcome<DeathbycaptchaSolution>> {
+        let form = self.upload_form(task)?;
+        let response = self
+            .send(
+                self.http_client
+                    .post(self.endpoint(&[CAPTCHA_PATH]))
+                    .multipart(form),
+            )
+            .await?;
+
+        let captcha_id = response
+            .captcha_id()
+            .ok_or(DeathbycaptchaError::MissingCaptchaId)?;
+        let task_id = TaskId::from(captcha_id.to_string());
+
+        #[cfg(feature = "tracing")]
+        Span::current().record("task_id", task_id.as_ref());
+
+        match response.into_result()? {
+            Some(text) => Ok(TaskCreationOutcome::Ready {
+                task_id,
+                solution: DeathbycaptchaSolution { text },
+            }),
+            None => Ok(TaskCreationOutcome::Pending(task_id)),
+        }
+    }
+
+    /// Get the result of a captcha task (internal)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result_internal",
+            target = "captcha.deathbycaptcha",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result_internal(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Option<DeathbycaptchaSolution>> {
+        let response = self
+            .send(
+                self.http_client
+                    .get(self.endpoint(&[CAPTCHA_PATH, task_id.as_ref()])),
+            )
+            .await?;
+        let solution = response
+            .into_result()?
+            .map(|text| DeathbycaptchaSolution { text });
+
+        #[cfg(feature = "tracing")]
+        if solution.is_some() {
+            set_span_ok();
+        }
+
+        Ok(solution)
+    }
+}
+
+impl Provider for DeathbycaptchaProvider {
+    type Solution = DeathbycaptchaSolution;
+    type Error = DeathbycaptchaError;
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task",
+            target = "captcha.deathbycaptcha",
+            skip_all,
+            fields(captcha.task_type)
+        )
+    )]
+    async fn create_task(&self, task: CaptchaTask) -> Result<TaskCreationOutcome<Self::Solution>> {
+        #[cfg(feature = "tracing")]
+        Span::current().record("captcha.task_type", task.to_string());
+
+        let internal_task: DeathbycaptchaTask = task
+            .try_into()
+            .map_err(DeathbycaptchaError::UnsupportedTask)?;
+        let result = self.create_task_internal(internal_task).await;
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => set_span_ok(),
+            Err(e) => record_error(e, "DeathByCaptcha"),
+        }
+
+        result
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result",
+            target = "captcha.deathbycaptcha",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result(&self, task_id: &TaskId) -> Result<Option<Self::Solution>> {
+        let result = self.get_task_result_internal(task_id).await;
+
+        #[cfg(feature = "tracing")]
+        if let Err(ref e) = result {
+            record_error(e, "DeathByCaptcha");
+        }
+
+        result
+    }
+}
@@ -0,0 +1,67 @@
+//! Response parsing for the DeathByCaptcha API.
+//!
+//! Uploads and polls both answer with a captcha status object:
+//! `{"status": 0, "captcha": 123, "is_correct": true, "text": "..."}`.
+//! Failures carry an `error` string and a non-2xx HTTP status instead.
+
+use super::errors::{DeathbycaptchaError, DeathbycaptchaErrorCode, Result};
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+/// Captcha status object
+#[derive(Debug, Default, PartialEq, Deserialize)]
+pub struct CaptchaResponse {
+    /// Numeric captcha ID (`0` or missing if nothing was uploaded)
+    #[serde(default)]
+    pub captcha: Option<u64>,
+    /// `false` once the captcha has been given up on
+    #[serde(default)]
+    pub is_correct: Option<bool>,
+    /// Solved text, empty or `null` while in progress
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Error string on failures
+    #[serde(default)]
+    pub error: Option<DeathbycaptchaErrorCode>,
+}
+
+impl CaptchaResponse {
+    /// Parse a response body together with its HTTP status.
+    ///
+    /// An `error` string is reported as [`DeathbycaptchaError::Api`]. Bodies
+    /// that are not a status object map `503 Service Unavailable` to
+    /// [`DeathbycaptchaErrorCode::ServiceOverload`] (the documented overload
+    /// status) and anything else to [`DeathbycaptchaError::UnexpectedResponse`].
+    pub fn parse(status: StatusCode, body: &str) -> Result<Self> {
+        match serde_json::from_str::<Self>(body) {
+            Ok(Self {
+                error: Some(code), ..
+            }) => Err(DeathbycaptchaError::Api(code)),
+            Ok(response) if status.is_success() => Ok(response),
+            _ if status == StatusCode::SERVICE_UNAVAILABLE => Err(DeathbycaptchaError::Api(
+                DeathbycaptchaErrorCode::ServiceOverload,
+            )),
+            _ => Err(DeathbycaptchaError::UnexpectedResponse {
+                status: status.as_u16(),
+                body: body.trim().to_string(),
+            }),
+        }
+    }
+
+    /// Get the captcha ID, if one was assigned
+    pub fn captcha_id(&self) -> Option<u64> {
+        self.captcha.filter(|id| *id != 0)
+    }
+
+    /// Convert to Result, mapping a captcha in progress to `None`.
+    ///
+    /// An empty answer with `is_correct: false` means the captcha could not
+    /// be solved and is reported as [`DeathbycaptchaError::Unsolvable`].
+    pub fn into_result(self) -> Result<Option<String>> {
+        match self.text.filter(|text| !text.is_empty()) {
+            Some(text) => Ok(Some(text)),
+            None if self.is_correct == Some(false) => Err(DeathbycaptchaError::Unsolvable),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! Tests for the DeathByCaptcha provider.
+
+use super::errors::{DeathbycaptchaError, DeathbycaptchaErrorCode};
+use super::provider::{DeathbycaptchaProvider, DeathbycaptchaProviderBuilder};
+use super::response::CaptchaResponse;
+use super::types::DeathbycaptchaSolution;
+use crate::errors::{KeyFault, RetryableError};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::{Altcha, ImageToText, ReCaptchaV2, Turnstile};
+use crate::utils::types::TaskId;
+use reqwest::{StatusCode, Url};
+use serde_json::json;
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn mock_provider(server: &MockServer) -> DeathbycaptchaProvider {
+    DeathbycaptchaProvider::builder("user", "secret")
+        .url(Url::parse(&format!("{}/api", server.uri())).unwrap())
+        .build()
+        .unwrap()
+}
+
+fn status(captcha: u64, text: &str, is_correct: bool) -> serde_json::Value {
+    json!({
+        "status": 0,
+        "captcha": captcha,
+        "is_correct": is_correct,
+        "text": text,
+    })
+}
+
+#[tokio::test]
+async fn test_create_task_recaptcha() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/captcha"))
+        .and(header("accept", "application/json"))
+        .and(body_string_contains("name=\"username\"\r\n\r\nuser"))
+        .and(body_string_contains("name=\"password\"\r\n\r\nsecret"))
+        .and(body_string_contains("name=\"type\"\r\n\r\n4"))
+        .and(body_string_contains(
+            r#"{"googlekey":"site-key","pageurl":"https://example.com"}"#,
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status(1234, "", true)))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = ReCaptchaV2::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert!(matches!(outcome, TaskCreationOutcome::Pending(_)));
+    assert_eq!(outcome.task_id().as_ref(), "1234");
+}
+
+#[tokio::test]
+async fn test_create_task_image_upload() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/captcha"))
+        .and(body_string_contains(
+            "name=\"captchafile\"; filename=\"captcha\"",
+        ))
+        .and(body_string_contains("hello"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status(1, "", true)))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = ImageToText::from_base64("aGVsbG8=");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "1");
+}
+
+#[tokio::test]
+async fn test_create_task_invalid_image() {
+    let provider = DeathbycaptchaProvider::new("user", "secret").unwrap();
+    let task = ImageToText::from_base64("not base64!");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(err, DeathbycaptchaError::InvalidImage(_)));
+    assert!(!err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_create_task_authtoken() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/captcha"))
+        .and(body_string_contains("name=\"authtoken\"\r\n\r\ntoken-123"))
+        .and(body_string_contains("name=\"type\"\r\n\r\n12"))
+        .and(body_string_contains("turnstile_params"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status(7, "", true)))
+        .mount(&server)
+        .await;
+
+    let provider = DeathbycaptchaProviderBuilder::with_authtoken("token-123")
+        .url(Url::parse(&format!("{}/api", server.uri())).unwrap())
+        .build()
+        .unwrap();
+    let task = Turnstile::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "7");
+}
+
+#[tokio::test]
+async fn test_create_task_insufficient_funds() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/captcha"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .set_body_json(json!({"status": 255, "error": "insufficient-funds"})),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        DeathbycaptchaError::Api(DeathbycaptchaErrorCode::InsufficientFunds)
+    ));
+    assert!(!err.is_retryable());
+    assert!(!err.should_retry_operation());
+    assert_eq!(err.key_fault(), Some(KeyFault::ZeroBalance));
+}
+
+#[tokio::test]
+async fn test_create_task_missing_captcha_id() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/captcha"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"status": 0})))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert!(matches!(err, DeathbycaptchaError::MissingCaptchaId));
+    assert!(!err.is_retryable());
+}
+
+#[test]
+fn test_error_code_key_fault() {
+    for code in ["not-logged-in", "banned"] {
+        let code = DeathbycaptchaErrorCode::from_str(code);
+        assert_eq!(code.key_fault(), Some(KeyFault::Invalid));
+    }
+    assert_eq!(
+        DeathbycaptchaErrorCode::InsufficientFunds.key_fault(),
+        Some(KeyFault::ZeroBalance)
+    );
+    assert_eq!(DeathbycaptchaErrorCode::ServiceOverload.key_fault(), None);
+}
+
+#[tokio::test]
+async fn test_create_task_service_overload_is_retryable() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/captcha"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable"))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        DeathbycaptchaError::Api(DeathbycaptchaErrorCode::ServiceOverload)
+    ));
+    assert!(err.is_retryable());
+    assert!(err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_create_task_unsupported() {
+    let provider = DeathbycaptchaProvider::new("user", "secret").unwrap();
+    let task = Altcha::new("https://example.com", "https://example.com/challenge");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    match err {
+        DeathbycaptchaError::UnsupportedTask(error) => {
+            assert_eq!(error.task_type, "Altcha");
+            assert_eq!(error.provider, "DeathByCaptcha");
+        }
+        _ => panic!("Expected UnsupportedTask error"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_task_result_ready() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/captcha/1234"))
+        .and(header("accept", "application/json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status(1234, "03AGdBq2", true)))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("1234");
+
+    let solution: Option<DeathbycaptchaSolution> =
+        provider.get_task_result(&task_id).await.unwrap();
+    assert_eq!(solution.unwrap().into_recaptcha().token(), "03AGdBq2");
+}
+
+#[tokio::test]
+async fn test_get_task_result_not_ready() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/captcha/1234"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status(1234, "", true)))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let solution = provider
+        .get_task_result(&TaskId::from("1234"))
+        .await
+        .unwrap();
+    assert!(solution.is_none());
+}
+
+#[tokio::test]
+async fn test_get_task_result_unsolvable() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/captcha/1234"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(status(1234, "", false)))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let err = provider
+        .get_task_result(&TaskId::from("1234"))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, DeathbycaptchaError::Unsolvable));
+    assert!(!err.is_retryable());
+    assert!(err.should_retry_operation());
+}
+
+#[test]
+fn test_builder_default_url() {
+    let provider = DeathbycaptchaProvider::with_authtoken("token").unwrap();
+    assert_eq!(provider.url().as_str(), "https://api.dbcapi.me/api");
+}
+
+#[test]
+fn test_builder_rejects_cannot_be_a_base_url() {
+    let url = Url::parse("mailto:solver@example.com").unwrap();
+    let err = DeathbycaptchaProvider::builder("user", "secret")
+        .url(url)
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, DeathbycaptchaError::InvalidUrl(_)));
+    assert!(!err.is_retryable());
+}
+
+#[test]
+fn test_debug_redacts_credentials() {
+    let provider = DeathbycaptchaProvider::new("user", "secret").unwrap();
+    let debug = format!("{provider:?}");
+    assert!(debug.contains("user"));
+    assert!(!debug.contains("secret"));
+}
+
+#[test]
+fn test_response_parse() {
+    assert_eq!(
+        CaptchaResponse::parse(StatusCode::OK, r#"{"captcha": 1, "text": null}"#).unwrap(),
+        CaptchaResponse {
+            captcha: Some(1),
+            ..Default::default()
+        }
+    );
+    assert!(matches!(
+        CaptchaResponse::parse(StatusCode::FORBIDDEN, r#"{"error": "not-logged-in"}"#),
+        Err(DeathbycaptchaError::Api(
+            DeathbycaptchaErrorCode::NotLoggedIn
+        ))
+    ));
+    assert!(matches!(
+        CaptchaResponse::parse(StatusCode::OK, r#"{"error": "something-new"}"#),
+        Err(DeathbycaptchaError::Api(DeathbycaptchaErrorCode::Other(_)))
+    ));
+    assert!(matches!(
+        CaptchaResponse::parse(StatusCode::BAD_GATEWAY, "<html>Bad Gateway</html>"),
+        Err(DeathbycaptchaError::UnexpectedResponse { status: 502, .. })
+    ));
+}
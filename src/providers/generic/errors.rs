@@ -0,0 +1,148 @@
+//! Error types for the generic `createTask` provider.
+//!
+//! Clones of the `createTask` API share the `errorId`/`errorCode` response shape
+//! but not the set of codes, so codes are kept as strings and classified at
+//! runtime by the [`GenericProfile`](super::GenericProfile) error table.
+
+use crate::errors::{KeyFault, RetryableError, UnsupportedTaskError};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt;
+use thiserror::Error;
+
+/// Errors returned by the generic `createTask` provider
+#[derive(Debug, Error)]
+pub enum GenericError {
+    #[error("Failed to build HTTP client: {0}")]
+    BuildHttpClient(#[source] reqwest::Error),
+
+    #[error("URL cannot be used as a base: {0}")]
+    InvalidUrl(Url),
+
+    #[error("HTTP request failed: {0}")]
+    HttpRequest(#[from] reqwest_middleware::Error),
+
+    #[error("Failed to parse response: {0}")]
+    ParseResponse(#[source] reqwest::Error),
+
+    #[error("API error: {0}")]
+    Api(#[source] GenericApiError),
+
+    #[error("{0}")]
+    UnsupportedTask(#[source] UnsupportedTaskError),
+}
+
+pub type Result<T> = std::result::Result<T, GenericError>;
+
+impl RetryableError for GenericError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            GenericError::HttpRequest(_) => true,
+            GenericError::Api(error) => error.class.is_retryable(),
+            GenericError::BuildHttpClient(_)
+            | GenericError::InvalidUrl(_)
+            | GenericError::ParseResponse(_)
+            | GenericError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            GenericError::HttpRequest(_) => true,
+            GenericError::Api(error) => error.class.should_retry_operation(),
+            GenericError::BuildHttpClient(_)
+            | GenericError::InvalidUrl(_)
+            | GenericError::ParseResponse(_)
+            | GenericError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            GenericError::Api(error) => error.class.key_fault(),
+            _ => None,
+        }
+    }
+}
+
+/// Retry classification for an API error code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// Transient service-side condition: retry the same request
+    /// (e.g. `ERROR_NO_SLOT_AVAILABLE`).
+    Transient,
+    /// The task failed but a new task may succeed
+    /// (e.g. `ERROR_CAPTCHA_UNSOLVABLE`).
+    RetryOperation,
+    /// Configuration or account problem: do not retry.
+    #[default]
+    Permanent,
+    /// The API key cannot currently be used (e.g. `ERROR_ZERO_BALANCE`).
+    ///
+    /// Only [`KeyFault::TemporarilyBlocked`] is retried.
+    KeyFault(KeyFault),
+}
+
+impl ErrorClass {
+    /// Returns true if the same request should be retried
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::Transient | Self::KeyFault(KeyFault::TemporarilyBlocked)
+        )
+    }
+
+    /// Returns true if a fresh solve operation (new task) might succeed
+    pub fn should_retry_operation(self) -> bool {
+        matches!(
+            self,
+            Self::Transient | Self::RetryOperation | Self::KeyFault(KeyFault::TemporarilyBlocked)
+        )
+    }
+
+    /// Returns the API key problem this class reports, if any
+    pub fn key_fault(self) -> Option<KeyFault> {
+        match self {
+            Self::KeyFault(fault) => Some(fault),
+            _ => None,
+        }
+    }
+}
+
+/// Generic API error response
+///
+/// `provider` and `class` are filled in from the provider's
+/// [`GenericProfile`](super::GenericProfile) after parsing.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenericApiError {
+    pub error_id: u32,
+    #[serde(default)]
+    pub error_code: String,
+    #[serde(default)]
+    pub error_description: Option<String>,
+    /// Provider name from the profile
+    #[serde(skip)]
+    pub provider: Cow<'static, str>,
+    /// Retry classification of `error_code`
+    #[serde(skip)]
+    pub class: ErrorClass,
+}
+
+impl fmt::Display for GenericApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} Error [{}]: {} - {}",
+            self.provider,
+            self.error_id,
+            self.error_code,
+            self.error_description
+                .as_deref()
+                .unwrap_or("No description")
+        )
+    }
+}
+
+impl std::error::Error for GenericApiError {}
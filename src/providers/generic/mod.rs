@@ -0,0 +1,104 @@
+//! # Generic `createTask` Provider
+//!
+//! Configurable provider for the many cheaper services (NextCaptcha, CapGuru
+//! and others) that clone the Capsolver/CapMonster `createTask`/`getTaskResult`
+//! JSON API with small differences in task type names.
+//!
+//! Instead of one module per clone, a [`GenericProfile`] describes the service:
+//!
+//! - a table mapping [`GenericTaskKind`]s to the service's task `type` names
+//!   (with an optional separate name for custom-proxy tasks),
+//! - field renames applied to the canonical Anti-Captcha/CapMonster field names,
+//! - an error-code table classifying codes as [`ErrorClass::Transient`],
+//!   [`ErrorClass::RetryOperation`], [`ErrorClass::Permanent`] or
+//!   [`ErrorClass::KeyFault`].
+//!
+//! ## Supported Captcha Types
+//!
+//! | Type | Task Kind | Standard `type` names |
+//! |------|-----------|-----------------------|
+//! | ReCaptcha V2 | [`GenericTaskKind::ReCaptchaV2`] | `RecaptchaV2TaskProxyless` / `RecaptchaV2Task` |
+//! | ReCaptcha V2 Enterprise | [`GenericTaskKind::ReCaptchaV2Enterprise`] | `RecaptchaV2EnterpriseTaskProxyless` / `RecaptchaV2EnterpriseTask` |
+//! | ReCaptcha V3 | [`GenericTaskKind::ReCaptchaV3`] | `RecaptchaV3TaskProxyless` |
+//! | ReCaptcha V3 Enterprise | [`GenericTaskKind::ReCaptchaV3Enterprise`] | — (map it explicitly) |
+//! | Cloudflare Turnstile | [`GenericTaskKind::Turnstile`] | `TurnstileTaskProxyless` / `TurnstileTask` |
+//! | Image to Text | [`GenericTaskKind::ImageToText`] | `ImageToTextTask` |
+//! | hCaptcha | [`GenericTaskKind::HCaptcha`] | `HCaptchaTaskProxyless` / `HCaptchaTask` |
+//!
+//! Task kinds the profile does not map, and all other task types, are rejected
+//! with [`UnsupportedTaskError`](crate::UnsupportedTaskError) naming the profile.
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::generic::{ErrorClass, GenericProfile, GenericProvider, GenericTaskKind};
+//! use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, Turnstile};
+//! use url::Url;
+//!
+//! let profile = GenericProfile::new("ExampleCaptcha")
+//!     .with_standard_task_types()
+//!     .task_type_with_proxy(GenericTaskKind::Turnstile, "AntiTurnstileTaskProxyLess", "AntiTurnstileTask")
+//!     .rename_field("data", "cData")
+//!     .error_class("ERROR_RATE_LIMIT", ErrorClass::Transient);
+//!
+//! let provider = GenericProvider::new(Url::parse("https://api.example-captcha.com")?, profile, "api_key")?;
+//! let service = CaptchaSolverService::new(provider);
+//!
+//! let solution = service.solve_captcha(Turnstile::new("https://example.com", "site_key")).await?;
+//! println!("Token: {:?}", solution.into_turnstile().token());
+//! ```
+//!
+//! ## Solution Types
+//!
+//! Solutions are parsed into the shared solution types, like the other providers:
+//!
+//! - **ReCaptcha V2/V3**: [`ReCaptchaSolution`] with `token()` method
+//! - **Turnstile**: [`TurnstileSolution`] with `token()` method (or [`TokenSolution`] via `into_token()`)
+//! - **Image to Text**: [`ImageToTextSolution`] with `text()` method
+//! - **hCaptcha**: [`HCaptchaSolution`] with `token()` and `resp_key()` methods
+//!
+//! ## Error Handling
+//!
+//! API errors carry the raw `errorCode` string and its [`ErrorClass`] from the
+//! profile; unknown codes are treated as permanent. Codes classified as
+//! [`ErrorClass::KeyFault`] are reported by
+//! [`RetryableError::key_fault`](crate::RetryableError::key_fault):
+//!
+//! ```rust,ignore
+//! use captcha_solvers::RetryableError;
+//! use captcha_solvers::generic::GenericError;
+//!
+//! match result {
+//!     Err(GenericError::Api(e)) if e.error_code == "ERROR_ZERO_BALANCE" => { /* top up */ }
+//!     Err(e) if e.is_retryable() => { /* retry later */ }
+//!     Err(e) => { /* permanent error, check API key or task data */ }
+//!     Ok(solution) => { /* success */ }
+//! }
+//! ```
+
+mod errors;
+mod profile;
+mod provider;
+mod response;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+// Errors
+pub use errors::{ErrorClass, GenericApiError, GenericError};
+
+// Profile
+pub use profile::{GenericProfile, GenericTaskKind, GenericTaskType};
+
+// Provider
+pub use provider::{GenericProvider, GenericProviderBuilder};
+
+// Solutions
+pub use types::{
+    GenericSolution, HCaptchaSolution, ImageToTextSolution, ReCaptchaSolution, TokenSolution,
+    TurnstileSolution,
+};
+
+// Re-export proxy types for convenience (also available at crate root)
+pub use crate::utils::proxy::{ProxyConfig, ProxyType};
@@ -0,0 +1,262 @@
+//! Configuration tables describing a `createTask`-compatible service.
+
+use super::errors::ErrorClass;
+use crate::errors::KeyFault;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Task kinds the generic provider knows how to serialize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GenericTaskKind {
+    /// reCAPTCHA v2 without enterprise payload
+    ReCaptchaV2,
+    /// reCAPTCHA v2 Enterprise
+    ReCaptchaV2Enterprise,
+    /// reCAPTCHA v3 without enterprise payload
+    ReCaptchaV3,
+    /// reCAPTCHA v3 Enterprise
+    ReCaptchaV3Enterprise,
+    /// Cloudflare Turnstile
+    Turnstile,
+    /// Image recognition
+    ImageToText,
+    /// hCaptcha
+    HCaptcha,
+}
+
+impl GenericTaskKind {
+    /// Kind name used in `UnsupportedTaskError` messages
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReCaptchaV2 => "ReCaptchaV2",
+            Self::ReCaptchaV2Enterprise => "ReCaptchaV2Enterprise",
+            Self::ReCaptchaV3 => "ReCaptchaV3",
+            Self::ReCaptchaV3Enterprise => "ReCaptchaV3Enterprise",
+            Self::Turnstile => "Turnstile",
+            Self::ImageToText => "ImageToText",
+            Self::HCaptcha => "HCaptcha",
+        }
+    }
+}
+
+impl fmt::Display for GenericTaskKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `type` names used for a task kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenericTaskType {
+    /// Type name for tasks solved with the service's own proxies
+    pub proxyless: String,
+    /// Type name for tasks with a custom proxy (`None` if proxies are not accepted)
+    pub proxy: Option<String>,
+}
+
+/// Description of a `createTask`/`getTaskResult` JSON API clone.
+///
+/// A profile maps [`GenericTaskKind`]s to the service's task `type` names,
+/// renames fields where the service deviates from the canonical names, and
+/// classifies its error codes for retrying.
+///
+/// Tasks are serialized with the Anti-Captcha/CapMonster field names
+/// (`websiteURL`, `websiteKey`, `isInvisible`, `minScore`, `proxyType`, ...).
+/// [`rename_field`](Self::rename_field) applies to every task.
+///
+/// # Example
+///
+/// ```rust
+/// use captcha_solvers::generic::{ErrorClass, GenericProfile, GenericTaskKind};
+///
+/// let profile = GenericProfile::new("ExampleCaptcha")
+///     .with_standard_task_types()
+///     .task_type(GenericTaskKind::ReCaptchaV3, "ReCaptchaV3TaskProxyLess")
+///     .rename_field("websiteURL", "websiteUrl")
+///     .error_class("ERROR_RATE_LIMIT", ErrorClass::Transient);
+///
+/// assert_eq!(profile.name(), "ExampleCaptcha");
+/// assert_eq!(profile.classify("ERROR_RATE_LIMIT"), ErrorClass::Transient);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GenericProfile {
+    name: Cow<'static, str>,
+    task_types: HashMap<GenericTaskKind, GenericTaskType>,
+    field_renames: HashMap<String, String>,
+    error_classes: HashMap<String, ErrorClass>,
+}
+
+/// Error codes shared by most `createTask` clones
+const DEFAULT_ERROR_CLASSES: &[(&str, ErrorClass)] = &[
+    ("ERROR_NO_SLOT_AVAILABLE", ErrorClass::Transient),
+    ("ERROR_TOO_MUCH_REQUESTS", ErrorClass::Transient),
+    ("ERROR_SERVICE_UNAVAILABLE", ErrorClass::Transient),
+    // Capsolver spells this code without the second "I"
+    ("ERROR_SERVICE_UNAVALIABLE", ErrorClass::Transient),
+    ("ERROR_CAPTCHA_UNSOLVABLE", ErrorClass::RetryOperation),
+    ("ERROR_RECAPTCHA_TIMEOUT", ErrorClass::RetryOperation),
+    ("ERROR_TOKEN_EXPIRED", ErrorClass::RetryOperation),
+    ("ERROR_NO_SUCH_CAPCHA_ID", ErrorClass::RetryOperation),
+    ("ERROR_NO_SUCH_CAPTCHA_ID", ErrorClass::RetryOperation),
+    (
+        "ERROR_KEY_DOES_NOT_EXIST",
+        ErrorClass::KeyFault(KeyFault::Invalid),
+    ),
+    (
+        "ERROR_KEY_DENIED_ACCESS",
+        ErrorClass::KeyFault(KeyFault::Invalid),
+    ),
+    (
+        "ERROR_ZERO_BALANCE",
+        ErrorClass::KeyFault(KeyFault::ZeroBalance),
+    ),
+    (
+        "ERROR_KEY_TEMP_BLOCKED",
+        ErrorClass::KeyFault(KeyFault::TemporarilyBlocked),
+    ),
+    (
+        "ERROR_IP_BLOCKED",
+        ErrorClass::KeyFault(KeyFault::TemporarilyBlocked),
+    ),
+];
+
+/// Task type names used by Anti-Captcha, CapMonster and most of their clones
+const STANDARD_TASK_TYPES: &[(GenericTaskKind, &str, Option<&str>)] = &[
+    (
+        GenericTaskKind::ReCaptchaV2,
+        "RecaptchaV2TaskProxyless",
+        Some("RecaptchaV2Task"),
+    ),
+    (
+        GenericTaskKind::ReCaptchaV2Enterprise,
+        "RecaptchaV2EnterpriseTaskProxyless",
+        Some("RecaptchaV2EnterpriseTask"),
+    ),
+    (
+        GenericTaskKind::ReCaptchaV3,
+        "RecaptchaV3TaskProxyless",
+        None,
+    ),
+    (
+        GenericTaskKind::Turnstile,
+        "TurnstileTaskProxyless",
+        Some("TurnstileTask"),
+    ),
+    (GenericTaskKind::ImageToText, "ImageToTextTask", None),
+    (
+        GenericTaskKind::HCaptcha,
+        "HCaptchaTaskProxyless",
+        Some("HCaptchaTask"),
+    ),
+];
+
+impl GenericProfile {
+    /// Create an empty profile with the default error classification.
+    ///
+    /// No task kinds are mapped yet; add them with
+    /// [`with_standard_task_types`](Self::with_standard_task_types),
+    /// [`task_type`](Self::task_type) or
+    /// [`task_type_with_proxy`](Self::task_type_with_proxy).
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            task_types: HashMap::new(),
+            field_renames: HashMap::new(),
+            error_classes: DEFAULT_ERROR_CLASSES
+                .iter()
+                .map(|(code, class)| (code.to_string(), *class))
+                .collect(),
+        }
+    }
+
+    /// Map the task kinds to the Anti-Captcha/CapMonster `type` names
+    /// (`RecaptchaV2TaskProxyless`, `TurnstileTask`, `ImageToTextTask`, ...).
+    pub fn with_standard_task_types(mut self) -> Self {
+        for (kind, proxyless, proxy) in STANDARD_TASK_TYPES {
+            self.task_types.insert(
+                *kind,
+                GenericTaskType {
+                    proxyless: proxyless.to_string(),
+                    proxy: proxy.map(str::to_string),
+                },
+            );
+        }
+        self
+    }
+
+    /// Map a task kind to a proxyless `type` name (custom proxies are rejected).
+    pub fn task_type(mut self, kind: GenericTaskKind, proxyless: impl Into<String>) -> Self {
+        self.task_types.insert(
+            kind,
+            GenericTaskType {
+                proxyless: proxyless.into(),
+                proxy: None,
+            },
+        );
+        self
+    }
+
+    /// Map a task kind to its proxyless and custom-proxy `type` names.
+    pub fn task_type_with_proxy(
+        mut self,
+        kind: GenericTaskKind,
+        proxyless: impl Into<String>,
+        proxy: impl Into<String>,
+    ) -> Self {
+        self.task_types.insert(
+            kind,
+            GenericTaskType {
+                proxyless: proxyless.into(),
+                proxy: Some(proxy.into()),
+            },
+        );
+        self
+    }
+
+    /// Remove a task kind so it is rejected as unsupported.
+    pub fn without_task_type(mut self, kind: GenericTaskKind) -> Self {
+        self.task_types.remove(&kind);
+        self
+    }
+
+    /// Rename a task field (e.g. `websiteURL` → `websiteUrl`).
+    pub fn rename_field(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.field_renames.insert(from.into(), to.into());
+        self
+    }
+
+    /// Classify an error code, overriding the default classification.
+    pub fn error_class(mut self, code: impl Into<String>, class: ErrorClass) -> Self {
+        self.error_classes.insert(code.into(), class);
+        self
+    }
+
+    /// Provider name used in errors and tracing
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Provider name for errors that outlive the profile
+    pub(crate) fn error_name(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+
+    /// Get the `type` names mapped for a task kind
+    pub fn task_type_names(&self, kind: GenericTaskKind) -> Option<&GenericTaskType> {
+        self.task_types.get(&kind)
+    }
+
+    /// Get the renamed name of a task field
+    pub fn field_name<'a>(&'a self, field: &'a str) -> &'a str {
+        self.field_renames
+            .get(field)
+            .map(String::as_str)
+            .unwrap_or(field)
+    }
+
+    /// Classify an error code; unknown codes are [`ErrorClass::Permanent`].
+    pub fn classify(&self, code: &str) -> ErrorClass {
+        self.error_classes.get(code).copied().unwrap_or_default()
+    }
+}
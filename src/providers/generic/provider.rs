@@ -0,0 +1,329 @@
+//! Generic `createTask`-compatible provider implementation.
+
+use super::errors::{GenericApiError, GenericError, Result};
+use super::profile::GenericProfile;
+use super::response::GenericResponse;
+use super::types::{
+    CreateTaskData, CreateTaskRequest, GenericSolution, GetTaskData, GetTaskResultRequest,
+    build_task,
+};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use secrecy::{ExposeSecret, SecretString};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+#[cfg(feature = "tracing")]
+use crate::utils::span_status::{record_error, set_span_ok};
+#[cfg(feature = "tracing")]
+use tracing::Span;
+
+/// API endpoint paths
+const CREATE_TASK_PATH: &str = "createTask";
+const GET_TASK_RESULT_PATH: &str = "getTaskResult";
+
+/// Provider for services cloning the `createTask`/`getTaskResult` JSON API
+///
+/// The service is described by a [`GenericProfile`]: which task `type` names
+/// it uses, which fields it names differently and how its error codes should
+/// be retried. The endpoint paths are appended to the base URL, so services
+/// hosted under a sub-path work as well.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::{
+///     CaptchaSolverService, CaptchaSolverServiceTrait, ReCaptchaV2,
+///     generic::{GenericProfile, GenericProvider},
+/// };
+/// use url::Url;
+///
+/// let profile = GenericProfile::new("ExampleCaptcha").with_standard_task_types();
+/// let provider = GenericProvider::new(
+///     Url::parse("https://api.example-captcha.com")?,
+///     profile,
+///     "api_key",
+/// )?;
+/// let service = CaptchaSolverService::new(provider);
+///
+/// let task = ReCaptchaV2::new("https://example.com", "site_key");
+/// let solution = service.solve_captcha(task).await?;
+/// println!("Token: {}", solution.into_recaptcha().token());
+/// ```
+#[derive(Clone)]
+pub struct GenericProvider {
+    http_client: ClientWithMiddleware,
+    api_key: SecretString,
+    url: Url,
+    profile: Arc<GenericProfile>,
+}
+
+impl Debug for GenericProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GenericProvider")
+            .field("name", &self.profile.name())
+            .field("url", &self.url)
+            .field("api_key", &crate::utils::REDACTED)
+            .finish()
+    }
+}
+
+/// Builder for configuring a [`GenericProvider`]
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::generic::{GenericProfile, GenericProvider};
+/// use url::Url;
+///
+/// let provider = GenericProvider::builder(url, profile, "your-api-key")
+///     .http_client(custom_client)
+///     .build()?;
+/// ```
+pub struct GenericProviderBuilder {
+    api_key: String,
+    url: Url,
+    profile: GenericProfile,
+    http_client: Option<ClientWithMiddleware>,
+}
+
+impl GenericProviderBuilder {
+    /// Create a new builder for the service at `url` described by `profile`
+    pub fn new(url: Url, profile: GenericProfile, api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            url,
+            profile,
+            http_client: None,
+        }
+    }
+
+    /// Set a custom HTTP client with middleware
+    ///
+    /// Use this when you need custom middleware (e.g., tracing, retry, rate limiting).
+    pub fn http_client(mut self, client: ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Build the [`GenericProvider`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL cannot be a base (e.g. `mailto:`) or the
+    /// HTTP client cannot be built.
+    pub fn build(self) -> Result<GenericProvider> {
+        if self.url.cannot_be_a_base() {
+            return Err(GenericError::InvalidUrl(self.url));
+        }
+
+        let http_client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let client = reqwest::Client::builder()
+                    .build()
+                    .map_err(GenericError::BuildHttpClient)?;
+                ClientBuilder::new(client).build()
+            }
+        };
+
+        Ok(GenericProvider {
+            http_client,
+            api_key: SecretString::from(self.api_key),
+            url: self.url,
+            profile: Arc::new(self.profile),
+        })
+    }
+}
+
+impl GenericProvider {
+    /// Create a new generic provider
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the service (`createTask`/`getTaskResult` are resolved against it)
+    /// * `profile` - Task type, field and error code tables for the service
+    /// * `api_key` - Your API key
+    pub fn new(url: Url, profile: GenericProfile, api_key: impl Into<String>) -> Result<Self> {
+        Self::builder(url, profile, api_key).build()
+    }
+
+    /// Create a builder for configuring the provider
+    ///
+    /// Use this for advanced configuration options like custom HTTP clients.
+    pub fn builder(
+        url: Url,
+        profile: GenericProfile,
+        api_key: impl Into<String>,
+    ) -> GenericProviderBuilder {
+        GenericProviderBuilder::new(url, profile, api_key)
+    }
+
+    /// Get the base URL
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Get the service profile
+    pub fn profile(&self) -> &GenericProfile {
+        &self.profile
+    }
+
+    /// Get the API key (exposed for request building).
+    fn api_key(&self) -> &str {
+        self.api_key.expose_secret()
+    }
+
+    /// Attach the profile's name and retry classification to an API error.
+    fn classify(&self, mut error: GenericApiError) -> GenericError {
+        error.provider = self.profile.error_name();
+        error.class = self.profile.classify(&error.error_code);
+        GenericError::Api(error)
+    }
+
+    /// Send a POST request to the API.
+    async fn post<Req: serde::Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        request: &Req,
+    ) -> Result<Res> {
+        let mut url = self.url.clone();
+        url.path_segments_mut()
+            .expect("checked in build()")
+            .pop_if_empty()
+            .push(path);
+
+        let response = self
+            .http_client
+            .post(url)
+            .json(request)
+            .send()
+            .await
+            .map_err(GenericError::HttpRequest)?;
+
+        response.json().await.map_err(GenericError::ParseResponse)
+    }
+
+    /// Create a captcha solving task (internal)
+    ///
+    /// Returns `TaskCreationOutcome::Ready` if the solution is included in the response,
+    /// otherwise returns `TaskCreationOutcome::Pending`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task_internal",
+            target = "captcha.generic",
+            skip_all,
+            fields(task_id = tracing::field::Empty)
+        )
+    )]
+    async fn create_task_internal(
+        &self,
+        task: Value,
+    ) -> Result<TaskCreationOutcome<GenericSolution>> {
+        let request = CreateTaskRequest {
+            client_key: self.api_key(),
+            task: &task,
+        };
+
+        let response: GenericResponse<CreateTaskData> =
+            self.post(CREATE_TASK_PATH, &request).await?;
+        let data = response.into_result().map_err(|e| self.classify(e))?;
+        let task_id = TaskId::from(data.task_id);
+
+        #[cfg(feature = "tracing")]
+        Span::current().record("task_id", task_id.as_ref());
+
+        match data.solution {
+            Some(solution) => Ok(TaskCreationOutcome::Ready { task_id, solution }),
+            None => Ok(TaskCreationOutcome::Pending(task_id)),
+        }
+    }
+
+    /// Get the result of a captcha task (internal)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result_internal",
+            target = "captcha.generic",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result_internal<T: DeserializeOwned + Debug>(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Option<T>> {
+        let request = GetTaskResultRequest {
+            client_key: self.api_key(),
+            task_id: task_id.as_ref(),
+        };
+
+        let response: GenericResponse<GetTaskData<T>> =
+            self.post(GET_TASK_RESULT_PATH, &request).await?;
+        let data = response.into_result().map_err(|e| self.classify(e))?;
+
+        #[cfg(feature = "tracing")]
+        if data.solution.is_some() {
+            set_span_ok();
+        }
+
+        Ok(data.solution)
+    }
+}
+
+impl Provider for GenericProvider {
+    type Solution = GenericSolution;
+    type Error = GenericError;
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task",
+            target = "captcha.generic",
+            skip_all,
+            fields(captcha.task_type)
+        )
+    )]
+    async fn create_task(&self, task: CaptchaTask) -> Result<TaskCreationOutcome<Self::Solution>> {
+        #[cfg(feature = "tracing")]
+        Span::current().record("captcha.task_type", task.to_string());
+
+        let internal_task =
+            build_task(task, &self.profile).map_err(GenericError::UnsupportedTask)?;
+        let result = self.create_task_internal(internal_task).await;
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => set_span_ok(),
+            Err(e) => record_error(e, self.profile.name()),
+        }
+
+        result
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result",
+            target = "captcha.generic",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result(&self, task_id: &TaskId) -> Result<Option<Self::Solution>> {
+        let result = self.get_task_result_internal(task_id).await;
+
+        #[cfg(feature = "tracing")]
+        if let Err(ref e) = result {
+            record_error(e, self.profile.name());
+        }
+
+        result
+    }
+}
@@ -0,0 +1,23 @@
+//! Response parsing for `createTask`-compatible APIs.
+
+use super::errors::GenericApiError;
+use crate::impl_api_response_deserialize;
+
+/// Generic API response wrapper
+#[derive(Debug)]
+pub enum GenericResponse<T> {
+    Success(T),
+    Error(GenericApiError),
+}
+
+impl<T> GenericResponse<T> {
+    /// Convert to Result for convenient use with `?`.
+    pub fn into_result(self) -> Result<T, GenericApiError> {
+        match self {
+            Self::Success(data) => Ok(data),
+            Self::Error(err) => Err(err),
+        }
+    }
+}
+
+impl_api_response_deserialize!(GenericResponse, GenericApiError);
@@ -0,0 +1,337 @@
+//! Tests for the generic `createTask` provider.
+
+use super::errors::{ErrorClass, GenericError};
+use super::profile::{GenericProfile, GenericTaskKind};
+use super::provider::GenericProvider;
+use super::types::GenericSolution;
+use crate::errors::{KeyFault, RetryableError};
+use crate::providers::traits::Provider;
+use crate::tasks::{Altcha, ImageToText, ReCaptchaV2, Turnstile};
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use serde_json::{Value, json};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn profile() -> GenericProfile {
+    GenericProfile::new("ExampleCaptcha").with_standard_task_types()
+}
+
+fn mock_provider(server: &MockServer, profile: GenericProfile) -> GenericProvider {
+    GenericProvider::new(Url::parse(&server.uri()).unwrap(), profile, "test_api_key").unwrap()
+}
+
+#[test]
+fn test_builder_rejects_cannot_be_a_base_url() {
+    let url = Url::parse("mailto:solver@example.com").unwrap();
+    let err = GenericProvider::new(url, profile(), "test-key").unwrap_err();
+    assert!(matches!(err, GenericError::InvalidUrl(_)));
+    assert!(!err.is_retryable());
+}
+
+async fn mock_create_task(server: &MockServer, response: Value) {
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response))
+        .mount(server)
+        .await;
+}
+
+async fn mock_get_task_result(server: &MockServer, response: Value) {
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_create_task_success() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "clientKey": "test_api_key",
+            "task": {
+                "type": "RecaptchaV2TaskProxyless",
+                "websiteURL": "https://example.com",
+                "websiteKey": "site-key",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "taskId": 7654321,
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server, profile());
+    let task = ReCaptchaV2::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert!(outcome.is_pending());
+    assert_eq!(outcome.task_id().as_ref(), "7654321");
+}
+
+#[tokio::test]
+async fn test_create_task_uses_profile_tables() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "task": {
+                "type": "AntiTurnstileTaskProxyLess",
+                "websiteUrl": "https://example.com",
+                "websiteKey": "site-key",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "taskId": "a1b2c3",
+        })))
+        .mount(&server)
+        .await;
+
+    let profile = profile()
+        .task_type(GenericTaskKind::Turnstile, "AntiTurnstileTaskProxyLess")
+        .rename_field("websiteURL", "websiteUrl");
+    let provider = mock_provider(&server, profile);
+    let task = Turnstile::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "a1b2c3");
+}
+
+#[tokio::test]
+async fn test_create_task_ready_immediately() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        json!({
+            "errorId": 0,
+            "taskId": "1",
+            "status": "ready",
+            "solution": {"text": "44795sds"},
+        }),
+    )
+    .await;
+
+    let provider = mock_provider(&server, profile());
+    let task = ImageToText::from_base64("aGVsbG8=");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    let solution = outcome.into_solution().expect("expected ready solution");
+    assert_eq!(solution.into_image_to_text().text(), "44795sds");
+}
+
+#[tokio::test]
+async fn test_create_task_error_classification() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        json!({
+            "errorId": 1,
+            "errorCode": "ERROR_RATE_LIMIT",
+            "errorDescription": "Slow down",
+        }),
+    )
+    .await;
+
+    let provider = mock_provider(
+        &server,
+        profile().error_class("ERROR_RATE_LIMIT", ErrorClass::Transient),
+    );
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    match &err {
+        GenericError::Api(error) => {
+            assert_eq!(error.error_code, "ERROR_RATE_LIMIT");
+            assert_eq!(error.class, ErrorClass::Transient);
+        }
+        _ => panic!("Expected Api error"),
+    }
+    assert!(
+        err.to_string()
+            .starts_with("API error: ExampleCaptcha Error [1]: ERROR_RATE_LIMIT")
+    );
+    assert!(err.is_retryable());
+    assert!(err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_create_task_unknown_error_is_permanent() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        json!({"errorId": 1, "errorCode": "ERROR_SOMETHING_NEW"}),
+    )
+    .await;
+
+    let provider = mock_provider(&server, profile());
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert!(!err.is_retryable());
+    assert!(!err.should_retry_operation());
+    assert_eq!(err.key_fault(), None);
+}
+
+#[tokio::test]
+async fn test_create_task_key_fault() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        json!({"errorId": 1, "errorCode": "ERROR_KEY_DOES_NOT_EXIST"}),
+    )
+    .await;
+
+    let provider = mock_provider(&server, profile());
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert_eq!(err.key_fault(), Some(KeyFault::Invalid));
+    assert!(!err.is_retryable());
+    assert!(!err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_profile_maps_code_to_key_fault() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        json!({"errorId": 1, "errorCode": "ERROR_ACCOUNT_FROZEN"}),
+    )
+    .await;
+
+    let provider = mock_provider(
+        &server,
+        profile().error_class(
+            "ERROR_ACCOUNT_FROZEN",
+            ErrorClass::KeyFault(KeyFault::TemporarilyBlocked),
+        ),
+    );
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert_eq!(err.key_fault(), Some(KeyFault::TemporarilyBlocked));
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_create_task_unsupported_names_profile() {
+    let server = MockServer::start().await;
+    let provider = mock_provider(&server, profile());
+    let task = Altcha::new("https://example.com", "https://example.com/challenge");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    match err {
+        GenericError::UnsupportedTask(error) => {
+            assert_eq!(error.task_type, "Altcha");
+            assert_eq!(error.provider, "ExampleCaptcha");
+        }
+        _ => panic!("Expected UnsupportedTask error"),
+    }
+}
+
+#[tokio::test]
+async fn test_profile_name_from_runtime_string() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        json!({"errorId": 1, "errorCode": "ERROR_KEY_DOES_NOT_EXIST"}),
+    )
+    .await;
+
+    // e.g. a name read from a config file
+    let name = String::from("ConfiguredCaptcha");
+    let profile = GenericProfile::new(name).with_standard_task_types();
+    let provider = mock_provider(&server, profile);
+
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(err.to_string().contains("ConfiguredCaptcha Error [1]"));
+
+    let task = Altcha::new("https://example.com", "https://example.com/challenge");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(
+        err,
+        GenericError::UnsupportedTask(ref error) if error.provider == "ConfiguredCaptcha"
+    ));
+}
+
+#[tokio::test]
+async fn test_get_task_result_ready() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .and(body_partial_json(json!({
+            "clientKey": "test_api_key",
+            "taskId": 7654321,
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "status": "ready",
+            "solution": {"gRecaptchaResponse": "recaptcha-token"},
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server, profile());
+    let task_id = TaskId::from("7654321");
+
+    let solution: Option<GenericSolution> = provider.get_task_result(&task_id).await.unwrap();
+    assert_eq!(
+        solution.unwrap().into_recaptcha().token(),
+        "recaptcha-token"
+    );
+}
+
+#[tokio::test]
+async fn test_get_task_result_processing() {
+    let server = MockServer::start().await;
+    mock_get_task_result(&server, json!({"errorId": 0, "status": "processing"})).await;
+
+    let provider = mock_provider(&server, profile());
+    let task_id = TaskId::from("7654321");
+
+    let solution = provider.get_task_result(&task_id).await.unwrap();
+    assert!(solution.is_none());
+}
+
+#[tokio::test]
+async fn test_get_task_result_unsolvable() {
+    let server = MockServer::start().await;
+    mock_get_task_result(
+        &server,
+        json!({"errorId": 12, "errorCode": "ERROR_CAPTCHA_UNSOLVABLE"}),
+    )
+    .await;
+
+    let provider = mock_provider(&server, profile());
+    let task_id = TaskId::from("7654321");
+
+    let err = provider.get_task_result(&task_id).await.unwrap_err();
+    assert!(!err.is_retryable());
+    assert!(err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_base_url_with_path() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/createTask"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "taskId": 42,
+        })))
+        .mount(&server)
+        .await;
+
+    let url = Url::parse(&format!("{}/api/", server.uri())).unwrap();
+    let provider = GenericProvider::new(url, profile(), "test_api_key").unwrap();
+    let task = Turnstile::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "42");
+}
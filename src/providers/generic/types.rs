@@ -0,0 +1,576 @@
+//! Task and solution types for `createTask`-compatible APIs.
+
+use super::profile::{GenericProfile, GenericTaskKind};
+use crate::errors::UnsupportedTaskError;
+use crate::utils::proxy::{ApiProxyFields, ProxyConfig};
+use crate::utils::serde_helpers::{
+    deserialize_string_or_number, serialize_string_as_number_if_possible,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+// ============================================================================
+// Task Types
+// ============================================================================
+
+/// Task fields with the canonical (Anti-Captcha/CapMonster) names.
+///
+/// The `type` field comes from the profile and is added by [`build_task`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub(crate) enum GenericTaskPayload {
+    ReCaptchaV2 {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(
+            rename = "recaptchaDataSValue",
+            skip_serializing_if = "Option::is_none"
+        )]
+        recaptcha_data_s_value: Option<String>,
+        #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
+        is_invisible: Option<bool>,
+        #[serde(rename = "pageAction", skip_serializing_if = "Option::is_none")]
+        page_action: Option<String>,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, Value>>,
+        #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
+        api_domain: Option<String>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cookies: Option<String>,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<ApiProxyFields>,
+    },
+
+    ReCaptchaV3 {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "pageAction", skip_serializing_if = "Option::is_none")]
+        page_action: Option<String>,
+        #[serde(rename = "minScore", skip_serializing_if = "Option::is_none")]
+        min_score: Option<f32>,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, Value>>,
+        #[serde(rename = "apiDomain", skip_serializing_if = "Option::is_none")]
+        api_domain: Option<String>,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<ApiProxyFields>,
+    },
+
+    Turnstile {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        action: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pagedata: Option<String>,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<ApiProxyFields>,
+    },
+
+    ImageToText {
+        body: String,
+        #[serde(rename = "websiteURL", skip_serializing_if = "Option::is_none")]
+        website_url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        module: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        phrase: Option<bool>,
+        #[serde(rename = "case", skip_serializing_if = "Option::is_none")]
+        case_sensitive: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        numeric: Option<u8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        math: Option<bool>,
+        #[serde(rename = "minLength", skip_serializing_if = "Option::is_none")]
+        min_length: Option<u32>,
+        #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
+        max_length: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+        #[serde(rename = "imgInstructions", skip_serializing_if = "Option::is_none")]
+        img_instructions: Option<String>,
+    },
+
+    HCaptcha {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
+        is_invisible: Option<bool>,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, Value>>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<ApiProxyFields>,
+    },
+}
+
+/// Convert a shared task into a `createTask` task object for the profile.
+///
+/// # Errors
+///
+/// Returns [`UnsupportedTaskError`] if the profile does not map the task kind,
+/// if a proxy is set but the profile has no proxy `type` name for the kind,
+/// or if the task uses fields the canonical payload cannot express.
+pub(crate) fn build_task(
+    task: crate::tasks::CaptchaTask,
+    profile: &GenericProfile,
+) -> Result<Value, UnsupportedTaskError> {
+    let (kind, payload, proxy) = into_payload(task, profile.error_name())?;
+
+    let names = profile
+        .task_type_names(kind)
+        .ok_or_else(|| UnsupportedTaskError::new(kind.as_str(), profile.error_name()))?;
+    let type_name = match (proxy, &names.proxy) {
+        (false, _) => &names.proxyless,
+        (true, Some(name)) => name,
+        (true, None) => {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                kind.as_str(),
+                profile.error_name(),
+                vec!["proxy"],
+            ));
+        }
+    };
+
+    let fields = match serde_json::to_value(&payload) {
+        Ok(Value::Object(fields)) => fields,
+        _ => {
+            return Err(UnsupportedTaskError::new(
+                kind.as_str(),
+                profile.error_name(),
+            ));
+        }
+    };
+
+    let mut object = Map::with_capacity(fields.len() + 1);
+    object.insert("type".to_string(), Value::String(type_name.clone()));
+    for (field, value) in fields {
+        object.insert(profile.field_name(&field).to_string(), value);
+    }
+
+    Ok(Value::Object(object))
+}
+
+/// Split a shared task into its kind, canonical payload and whether a proxy is set.
+fn into_payload(
+    task: crate::tasks::CaptchaTask,
+    provider: Cow<'static, str>,
+) -> Result<(GenericTaskKind, GenericTaskPayload, bool), UnsupportedTaskError> {
+    use crate::tasks::CaptchaTask;
+
+    let proxy_fields = |proxy: Option<ProxyConfig>| proxy.map(ProxyConfig::into_api_proxy_fields);
+
+    match task {
+        CaptchaTask::ReCaptchaV2(task) => {
+            let mut unsupported = Vec::new();
+            if task.is_session {
+                unsupported.push("is_session");
+            }
+            if task.is_session_v3 {
+                unsupported.push("is_session_v3");
+            }
+            if task.anchor.is_some() {
+                unsupported.push("anchor");
+            }
+            if task.reload.is_some() {
+                unsupported.push("reload");
+            }
+            if !unsupported.is_empty() {
+                return Err(UnsupportedTaskError::unsupported_fields(
+                    "ReCaptchaV2",
+                    provider,
+                    unsupported,
+                ));
+            }
+
+            let kind = if task.is_enterprise {
+                GenericTaskKind::ReCaptchaV2Enterprise
+            } else {
+                GenericTaskKind::ReCaptchaV2
+            };
+            let has_proxy = task.proxy.is_some();
+            let payload = GenericTaskPayload::ReCaptchaV2 {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                recaptcha_data_s_value: task.recaptcha_data_s_value,
+                is_invisible: task.is_invisible.then_some(true),
+                page_action: task.page_action,
+                enterprise_payload: task.enterprise_payload,
+                api_domain: task.api_domain,
+                user_agent: task.user_agent,
+                cookies: task.cookies,
+                proxy: proxy_fields(task.proxy),
+            };
+            Ok((kind, payload, has_proxy))
+        }
+        CaptchaTask::ReCaptchaV3(task) => {
+            if task.is_session {
+                return Err(UnsupportedTaskError::unsupported_fields(
+                    "ReCaptchaV3",
+                    provider,
+                    vec!["is_session"],
+                ));
+            }
+
+            let kind = if task.is_enterprise {
+                GenericTaskKind::ReCaptchaV3Enterprise
+            } else {
+                GenericTaskKind::ReCaptchaV3
+            };
+            let has_proxy = task.proxy.is_some();
+            let payload = GenericTaskPayload::ReCaptchaV3 {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                page_action: task.page_action,
+                min_score: task.min_score,
+                enterprise_payload: task.enterprise_payload,
+                api_domain: task.api_domain,
+                proxy: proxy_fields(task.proxy),
+            };
+            Ok((kind, payload, has_proxy))
+        }
+        CaptchaTask::Turnstile(task) => {
+            let has_proxy = task.proxy.is_some();
+            let payload = GenericTaskPayload::Turnstile {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                action: task.action,
+                data: task.cdata,
+                pagedata: task.pagedata,
+                proxy: proxy_fields(task.proxy),
+            };
+            Ok((GenericTaskKind::Turnstile, payload, has_proxy))
+        }
+        CaptchaTask::ImageToText(task) => {
+            let payload = GenericTaskPayload::ImageToText {
+                body: task.body,
+                website_url: task.website_url,
+                module: task.module,
+                phrase: task.phrase.then_some(true),
+                case_sensitive: task.case_sensitive.then_some(true),
+                numeric: (task.numeric != 0).then_some(task.numeric),
+                math: task.math.then_some(true),
+                min_length: (task.min_length > 0).then_some(task.min_length),
+                max_length: (task.max_length > 0).then_some(task.max_length),
+                comment: task.comment,
+                img_instructions: task.img_instructions,
+            };
+            Ok((GenericTaskKind::ImageToText, payload, false))
+        }
+        CaptchaTask::HCaptcha(task) => {
+            let has_proxy = task.proxy.is_some();
+            let payload = GenericTaskPayload::HCaptcha {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                is_invisible: task.is_invisible.then_some(true),
+                enterprise_payload: task.enterprise_payload,
+                user_agent: task.user_agent,
+                proxy: proxy_fields(task.proxy),
+            };
+            Ok((GenericTaskKind::HCaptcha, payload, has_proxy))
+        }
+        CaptchaTask::TurnstileChallenge(_) => {
+            Err(UnsupportedTaskError::new("TurnstileChallenge", provider))
+        }
+        CaptchaTask::TurnstileWaitRoom(_) => {
+            Err(UnsupportedTaskError::new("TurnstileWaitRoom", provider))
+        }
+        CaptchaTask::CloudflareChallenge(_) => {
+            Err(UnsupportedTaskError::new("CloudflareChallenge", provider))
+        }
+        CaptchaTask::GridClassification(_) => {
+            Err(UnsupportedTaskError::new("GridClassification", provider))
+        }
+        CaptchaTask::Rotate(_) => Err(UnsupportedTaskError::new("Rotate", provider)),
+        CaptchaTask::AudioToText(_) => Err(UnsupportedTaskError::new("AudioToText", provider)),
+        CaptchaTask::MtCaptcha(_) => Err(UnsupportedTaskError::new("MtCaptcha", provider)),
+        CaptchaTask::FriendlyCaptcha(_) => {
+            Err(UnsupportedTaskError::new("FriendlyCaptcha", provider))
+        }
+        CaptchaTask::Cutcaptcha(_) => Err(UnsupportedTaskError::new("Cutcaptcha", provider)),
+        CaptchaTask::YandexSmartCaptcha(_) => {
+            Err(UnsupportedTaskError::new("YandexSmartCaptcha", provider))
+        }
+        CaptchaTask::Tencent(_) => Err(UnsupportedTaskError::new("Tencent", provider)),
+        CaptchaTask::Lemin(_) => Err(UnsupportedTaskError::new("Lemin", provider)),
+        CaptchaTask::KeyCaptcha(_) => Err(UnsupportedTaskError::new("KeyCaptcha", provider)),
+        CaptchaTask::Capy(_) => Err(UnsupportedTaskError::new("Capy", provider)),
+        CaptchaTask::Imperva(_) => Err(UnsupportedTaskError::new("Imperva", provider)),
+        CaptchaTask::Kasada(_) => Err(UnsupportedTaskError::new("Kasada", provider)),
+        CaptchaTask::Prosopo(_) => Err(UnsupportedTaskError::new("Prosopo", provider)),
+        CaptchaTask::CaptchaFox(_) => Err(UnsupportedTaskError::new("CaptchaFox", provider)),
+        CaptchaTask::Altcha(_) => Err(UnsupportedTaskError::new("Altcha", provider)),
+        CaptchaTask::TextQuestion(_) => Err(UnsupportedTaskError::new("TextQuestion", provider)),
+    }
+}
+
+// ============================================================================
+// Solution Types
+// ============================================================================
+
+// Re-export shared solution types for convenience.
+pub use crate::solutions::{
+    HCaptchaSolution, ImageToTextSolution, ReCaptchaSolution, TokenSolution, TurnstileSolution,
+};
+
+/// Generic `createTask` solution types.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum GenericSolution {
+    /// Image to text solution (must be first for untagged deserialization priority —
+    /// `ImageToTextSolution` has a unique `text` field)
+    ImageToText(ImageToTextSolution),
+    /// ReCaptcha or hCaptcha solution (same shape; see `try_into_hcaptcha`)
+    ReCaptcha(ReCaptchaSolution),
+    Turnstile(TurnstileSolution),
+}
+
+impl crate::solutions::ProviderSolution for GenericSolution {}
+
+impl GenericSolution {
+    /// Try to extract ReCaptcha solution (returns reference).
+    pub fn as_recaptcha(&self) -> Option<&ReCaptchaSolution> {
+        match self {
+            Self::ReCaptcha(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract ReCaptcha solution (consumes self).
+    pub fn try_into_recaptcha(self) -> Result<ReCaptchaSolution, Box<Self>> {
+        match self {
+            Self::ReCaptcha(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract ReCaptcha solution, panics if not ReCaptcha.
+    pub fn into_recaptcha(self) -> ReCaptchaSolution {
+        self.try_into_recaptcha()
+            .expect("Expected ReCaptcha solution")
+    }
+
+    /// Try to extract Turnstile solution (returns reference).
+    pub fn as_turnstile(&self) -> Option<&TurnstileSolution> {
+        match self {
+            Self::Turnstile(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract Turnstile solution (consumes self).
+    pub fn try_into_turnstile(self) -> Result<TurnstileSolution, Box<Self>> {
+        match self {
+            Self::Turnstile(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract Turnstile solution, panics if not Turnstile.
+    pub fn into_turnstile(self) -> TurnstileSolution {
+        self.try_into_turnstile()
+            .expect("Expected Turnstile solution")
+    }
+
+    /// Try to extract image to text solution (returns reference).
+    pub fn as_image_to_text(&self) -> Option<&ImageToTextSolution> {
+        match self {
+            Self::ImageToText(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract image to text solution (consumes self).
+    pub fn try_into_image_to_text(self) -> Result<ImageToTextSolution, Box<Self>> {
+        match self {
+            Self::ImageToText(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract image to text solution, panics if not ImageToText.
+    pub fn into_image_to_text(self) -> ImageToTextSolution {
+        self.try_into_image_to_text()
+            .expect("Expected ImageToText solution")
+    }
+
+    /// Try to extract hCaptcha solution (consumes self).
+    ///
+    /// hCaptcha results deserialize as [`ReCaptcha`](Self::ReCaptcha), which
+    /// has the same shape; call this for hCaptcha tasks.
+    pub fn try_into_hcaptcha(self) -> Result<HCaptchaSolution, Box<Self>> {
+        match self {
+            Self::ReCaptcha(solution) => Ok(solution.into()),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract hCaptcha solution, panics if not hCaptcha.
+    pub fn into_hcaptcha(self) -> HCaptchaSolution {
+        self.try_into_hcaptcha()
+            .expect("Expected HCaptcha solution")
+    }
+
+    /// Try to extract generic token solution (consumes self).
+    ///
+    /// Token-only responses deserialize as the Turnstile variant.
+    pub fn try_into_token(self) -> Result<TokenSolution, Box<Self>> {
+        match self {
+            Self::Turnstile(solution) => TokenSolution::try_from(solution)
+                .map_err(|solution| Box::new(Self::Turnstile(solution))),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract generic token solution, panics if no token is present.
+    pub fn into_token(self) -> TokenSolution {
+        self.try_into_token().expect("Expected token solution")
+    }
+}
+
+// ============================================================================
+// Internal Types (Request/Response)
+// ============================================================================
+
+/// Response data from createTask endpoint.
+///
+/// Some services return the solution of synchronous tasks (like ImageToText)
+/// directly in the createTask response.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateTaskData {
+    #[serde(deserialize_with = "deserialize_string_or_number")]
+    pub task_id: String,
+    #[serde(default)]
+    pub solution: Option<GenericSolution>,
+}
+
+/// Response data from getTaskResult endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetTaskData<T> {
+    #[allow(dead_code)]
+    pub status: String,
+    pub solution: Option<T>,
+}
+
+/// Request payload for creating a task.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateTaskRequest<'a> {
+    pub(crate) client_key: &'a str,
+    pub(crate) task: &'a Value,
+}
+
+/// Request payload for getting task result.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetTaskResultRequest<'a> {
+    pub(crate) client_key: &'a str,
+    #[serde(serialize_with = "serialize_string_as_number_if_possible")]
+    pub(crate) task_id: &'a str,
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tasks::{ImageToText, ReCaptchaV2, ReCaptchaV3, Rotate, Turnstile};
+
+    fn profile() -> GenericProfile {
+        GenericProfile::new("Example").with_standard_task_types()
+    }
+
+    #[test]
+    fn test_recaptcha_v2_proxyless() {
+        let task = ReCaptchaV2::new("https://example.com", "site-key").invisible();
+        let json = build_task(task.into(), &profile()).unwrap();
+        assert_eq!(json["type"], "RecaptchaV2TaskProxyless");
+        assert_eq!(json["websiteURL"], "https://example.com");
+        assert_eq!(json["websiteKey"], "site-key");
+        assert_eq!(json["isInvisible"], true);
+        assert!(json.get("proxyType").is_none());
+    }
+
+    #[test]
+    fn test_recaptcha_v2_enterprise_with_proxy() {
+        let task = ReCaptchaV2::new("https://example.com", "site-key")
+            .enterprise()
+            .with_proxy(ProxyConfig::socks5("1.2.3.4", 1080).with_auth("user", "pass"));
+        let json = build_task(task.into(), &profile()).unwrap();
+        assert_eq!(json["type"], "RecaptchaV2EnterpriseTask");
+        assert_eq!(json["proxyType"], "socks5");
+        assert_eq!(json["proxyAddress"], "1.2.3.4");
+        assert_eq!(json["proxyPort"], 1080);
+        assert_eq!(json["proxyLogin"], "user");
+    }
+
+    #[test]
+    fn test_proxy_without_proxy_type_name() {
+        let task = ReCaptchaV3::new("https://example.com", "site-key")
+            .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+        let err = build_task(task.into(), &profile()).unwrap_err();
+        assert_eq!(err.task_type, "ReCaptchaV3");
+        assert_eq!(err.provider, "Example");
+        assert_eq!(err.unsupported_fields, vec!["proxy"]);
+    }
+
+    #[test]
+    fn test_field_renames() {
+        let profile = profile()
+            .rename_field("websiteURL", "websiteUrl")
+            .rename_field("data", "cData");
+        let task = Turnstile::new("https://example.com", "site-key").with_cdata("token-data");
+        let json = build_task(task.into(), &profile).unwrap();
+        assert_eq!(json["type"], "TurnstileTaskProxyless");
+        assert_eq!(json["websiteUrl"], "https://example.com");
+        assert_eq!(json["cData"], "token-data");
+        assert!(json.get("websiteURL").is_none());
+    }
+
+    #[test]
+    fn test_custom_task_type() {
+        let profile = GenericProfile::new("Example")
+            .task_type(GenericTaskKind::ImageToText, "ImageToTextTaskOCR");
+        let task = ImageToText::from_base64("aGVsbG8=").with_min_length(4);
+        let json = build_task(task.into(), &profile).unwrap();
+        assert_eq!(json["type"], "ImageToTextTaskOCR");
+        assert_eq!(json["body"], "aGVsbG8=");
+        assert_eq!(json["minLength"], 4);
+    }
+
+    #[test]
+    fn test_unmapped_kind_unsupported() {
+        let profile = profile().without_task_type(GenericTaskKind::Turnstile);
+        let task = Turnstile::new("https://example.com", "site-key");
+        let err = build_task(task.into(), &profile).unwrap_err();
+        assert_eq!(err.task_type, "Turnstile");
+        assert!(err.unsupported_fields.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_task_unsupported() {
+        let task = Rotate::from_base64("aGVsbG8=");
+        let err = build_task(task.into(), &profile()).unwrap_err();
+        assert_eq!(err.task_type, "Rotate");
+        assert_eq!(err.provider, "Example");
+    }
+}
@@ -0,0 +1,62 @@
+//! Error types for the key pool wrapper.
+
+use crate::errors::{KeyFault, RetryableError};
+use crate::utils::types::TaskId;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Error returned by [`KeyPoolProvider`](super::KeyPoolProvider)
+#[derive(Debug, Error)]
+pub enum KeyPoolError<E> {
+    /// Every key is quarantined
+    ///
+    /// `retry_after` is the time until the first key leaves quarantine, or
+    /// `None` if all keys are invalid and need [`release`](super::KeyPoolProvider::release).
+    #[error("No API key available in the pool")]
+    NoAvailableKey {
+        /// Time until a key becomes usable again
+        retry_after: Option<Duration>,
+    },
+
+    /// The task ID was not issued by this pool
+    #[error("Task ID '{0}' was not issued by this key pool")]
+    UnknownTask(TaskId),
+
+    /// Error from the provider of one key
+    #[error(transparent)]
+    Inner(E),
+}
+
+impl<E> KeyPoolError<E> {
+    /// Returns the wrapped provider's error, if any
+    pub fn into_inner(self) -> Option<E> {
+        match self {
+            KeyPoolError::Inner(e) => Some(e),
+            KeyPoolError::NoAvailableKey { .. } | KeyPoolError::UnknownTask(_) => None,
+        }
+    }
+}
+
+impl<E: RetryableError> RetryableError for KeyPoolError<E> {
+    fn is_retryable(&self) -> bool {
+        match self {
+            KeyPoolError::Inner(e) => e.is_retryable(),
+            KeyPoolError::NoAvailableKey { .. } | KeyPoolError::UnknownTask(_) => false,
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            KeyPoolError::NoAvailableKey { retry_after } => retry_after.is_some(),
+            KeyPoolError::UnknownTask(_) => false,
+            KeyPoolError::Inner(e) => e.should_retry_operation(),
+        }
+    }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            KeyPoolError::Inner(e) => e.key_fault(),
+            KeyPoolError::NoAvailableKey { .. } | KeyPoolError::UnknownTask(_) => None,
+        }
+    }
+}
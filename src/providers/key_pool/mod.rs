@@ -0,0 +1,45 @@
+//! # Key Pool Wrapper
+//!
+//! [`KeyPoolProvider`] spreads tasks over several API keys of the same
+//! provider, to share rate limits and balance between accounts.
+//!
+//! - Keys are picked round-robin or least-used ([`KeySelection`]).
+//! - Task IDs carry the key index, so results are polled with the key that
+//!   created the task.
+//! - Keys rejected as invalid, out of funds or temporarily blocked are
+//!   quarantined automatically (see [`RetryableError::key_fault`](crate::RetryableError::key_fault));
+//!   the task moves on to the next key.
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::capsolver::CapsolverProvider;
+//! use captcha_solvers::{
+//!     CaptchaRetryableProvider, CaptchaSolverService, CaptchaSolverServiceTrait, KeyPoolProvider,
+//! };
+//!
+//! let pool = KeyPoolProvider::new(["CAP-key-1", "CAP-key-2", "CAP-key-3"], CapsolverProvider::new)?;
+//! let service = CaptchaSolverService::new(CaptchaRetryableProvider::new(pool.clone()));
+//!
+//! let solution = service.solve_captcha(task).await?;
+//!
+//! for key in pool.keys() {
+//!     println!("{} {} tasks, fault: {:?}", key.hint, key.tasks_submitted, key.fault);
+//! }
+//! ```
+
+mod errors;
+mod provider;
+mod types;
+
+#[cfg(all(test, feature = "capsolver"))]
+mod tests;
+
+// Errors
+pub use errors::KeyPoolError;
+
+// Provider
+pub use provider::KeyPoolProvider;
+
+// Types
+pub use types::{KeySelection, KeyStatus};
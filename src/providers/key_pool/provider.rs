@@ -0,0 +1,301 @@
+//! Multi-key provider wrapper implementation.
+
+use super::errors::KeyPoolError;
+use super::types::{KeySelection, KeyStatus, hint};
+use crate::errors::{KeyFault, RetryableError};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[cfg(feature = "tracing")]
+use tracing::debug;
+
+/// Default quarantine for keys with no funds
+const DEFAULT_BALANCE_COOLDOWN: Duration = Duration::from_secs(10 * 60);
+
+/// Default quarantine for temporarily blocked keys
+const DEFAULT_BLOCK_COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default)]
+struct KeyState {
+    tasks_submitted: usize,
+    fault: Option<KeyFault>,
+    /// End of the quarantine; `None` with a fault means until released
+    until: Option<Instant>,
+}
+
+impl KeyState {
+    /// Clear an expired quarantine and report whether the key is usable
+    fn refresh(&mut self, now: Instant) -> bool {
+        if let Some(until) = self.until
+            && until <= now
+        {
+            self.fault = None;
+            self.until = None;
+        }
+        self.fault.is_none()
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    keys: Vec<KeyState>,
+    cursor: usize,
+}
+
+/// Wrapper that spreads tasks over several API keys of one provider
+///
+/// Holds one provider instance per key. New tasks go to the next key picked
+/// by the [`KeySelection`]; the returned [`TaskId`] is prefixed with the
+/// key's index (`"{index}:{task_id}"`) so `get_task_result` polls with the
+/// key that created the task.
+///
+/// Errors whose [`RetryableError::key_fault`] is set quarantine the key:
+///
+/// | Fault | Quarantine |
+/// |-------|------------|
+/// | [`KeyFault::Invalid`] | Until [`release`](Self::release) |
+/// | [`KeyFault::ZeroBalance`] | [`with_balance_cooldown`](Self::with_balance_cooldown), 10 minutes by default |
+/// | [`KeyFault::TemporarilyBlocked`] | [`with_block_cooldown`](Self::with_block_cooldown), 60 seconds by default |
+///
+/// A `create_task` rejected for a key fault moves on to the next available
+/// key. Clones share the key states.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::capsolver::CapsolverProvider;
+/// use captcha_solvers::{CaptchaRetryableProvider, CaptchaSolverService, KeyPoolProvider, KeySelection};
+///
+/// let pool = KeyPoolProvider::new(["CAP-key-1", "CAP-key-2"], CapsolverProvider::new)?
+///     .with_selection(KeySelection::LeastUsed);
+///
+/// let service = CaptchaSolverService::new(CaptchaRetryableProvider::new(pool.clone()));
+/// // ... later, inspect pool.keys() for quarantined keys
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyPoolProvider<P> {
+    providers: Arc<[P]>,
+    hints: Arc<[String]>,
+    selection: KeySelection,
+    balance_cooldown: Duration,
+    block_cooldown: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+impl<P: Provider> KeyPoolProvider<P> {
+    /// Build one provider per key with `factory`
+    ///
+    /// An empty pool fails every task with [`KeyPoolError::NoAvailableKey`].
+    pub fn new<I, K, F, E>(keys: I, mut factory: F) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+        F: FnMut(&str) -> Result<P, E>,
+    {
+        let mut providers = Vec::new();
+        let mut hints = Vec::new();
+        for key in keys {
+            let key = key.as_ref();
+            providers.push(factory(key)?);
+            hints.push(hint(key));
+        }
+
+        let keys = providers.iter().map(|_| KeyState::default()).collect();
+        Ok(Self {
+            providers: providers.into(),
+            hints: hints.into(),
+            selection: KeySelection::default(),
+            balance_cooldown: DEFAULT_BALANCE_COOLDOWN,
+            block_cooldown: DEFAULT_BLOCK_COOLDOWN,
+            state: Arc::new(Mutex::new(State { keys, cursor: 0 })),
+        })
+    }
+
+    /// Set how keys are picked for new tasks
+    pub fn with_selection(mut self, selection: KeySelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Set how long a key with no funds stays out of rotation
+    pub fn with_balance_cooldown(mut self, cooldown: Duration) -> Self {
+        self.balance_cooldown = cooldown;
+        self
+    }
+
+    /// Set how long a temporarily blocked key stays out of rotation
+    pub fn with_block_cooldown(mut self, cooldown: Duration) -> Self {
+        self.block_cooldown = cooldown;
+        self
+    }
+
+    /// Status of every key, in pool order
+    pub fn keys(&self) -> Vec<KeyStatus> {
+        let now = Instant::now();
+        let mut state = self.state();
+        state
+            .keys
+            .iter_mut()
+            .enumerate()
+            .map(|(index, key)| {
+                key.refresh(now);
+                KeyStatus {
+                    index,
+                    hint: self.hints[index].clone(),
+                    tasks_submitted: key.tasks_submitted,
+                    fault: key.fault,
+                    retry_after: key.until.map(|until| until - now),
+                }
+            })
+            .collect()
+    }
+
+    /// Put a quarantined key back into rotation
+    ///
+    /// Invalid keys stay quarantined until released; use this after topping
+    /// up or replacing the key on the provider's side.
+    pub fn release(&self, index: usize) {
+        if let Some(key) = self.state().keys.get_mut(index) {
+            key.fault = None;
+            key.until = None;
+        }
+    }
+
+    /// Get the provider of the key at `index`
+    pub fn provider(&self, index: usize) -> Option<&P> {
+        self.providers.get(index)
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Pick an available key not in `tried` and count the submission
+    fn acquire(&self, tried: &[usize]) -> Result<usize, Option<Duration>> {
+        let now = Instant::now();
+        let mut state = self.state();
+        let count = state.keys.len();
+
+        let mut usable = Vec::with_capacity(count);
+        for (index, key) in state.keys.iter_mut().enumerate() {
+            if key.refresh(now) && !tried.contains(&index) {
+                usable.push(index);
+            }
+        }
+
+        let picked = match self.selection {
+            KeySelection::RoundRobin => (0..count)
+                .map(|offset| (state.cursor + offset) % count)
+                .find(|index| usable.contains(index)),
+            KeySelection::LeastUsed => usable
+                .iter()
+                .copied()
+                .min_by_key(|&index| state.keys[index].tasks_submitted),
+        };
+
+        match picked {
+            Some(index) => {
+                state.cursor = (index + 1) % count;
+                state.keys[index].tasks_submitted += 1;
+                Ok(index)
+            }
+            None => Err(state
+                .keys
+                .iter()
+                .filter_map(|key| key.until)
+                .min()
+                .map(|until| until - now)),
+        }
+    }
+
+    /// Take a key out of rotation if `err` blames it
+    fn quarantine<E: RetryableError>(&self, index: usize, err: &E) -> bool {
+        let Some(fault) = err.key_fault() else {
+            return false;
+        };
+        let until = match fault {
+            KeyFault::Invalid => None,
+            KeyFault::ZeroBalance => Some(Instant::now() + self.balance_cooldown),
+            KeyFault::TemporarilyBlocked => Some(Instant::now() + self.block_cooldown),
+        };
+
+        #[cfg(feature = "tracing")]
+        debug!(key = index, hint = %self.hints[index], %fault, "Quarantining API key");
+
+        let mut state = self.state();
+        let key = &mut state.keys[index];
+        key.fault = Some(fault);
+        key.until = until;
+        true
+    }
+}
+
+impl<P: Provider> Provider for KeyPoolProvider<P> {
+    type Solution = P::Solution;
+    type Error = KeyPoolError<P::Error>;
+
+    async fn create_task(
+        &self,
+        task: CaptchaTask,
+    ) -> Result<TaskCreationOutcome<Self::Solution>, Self::Error> {
+        let mut tried = Vec::new();
+        let mut last_error = None;
+
+        loop {
+            let index = match self.acquire(&tried) {
+                Ok(index) => index,
+                Err(retry_after) => {
+                    return Err(match last_error {
+                        Some(err) => KeyPoolError::Inner(err),
+                        None => KeyPoolError::NoAvailableKey { retry_after },
+                    });
+                }
+            };
+            tried.push(index);
+
+            match self.providers[index].create_task(task.clone()).await {
+                Ok(TaskCreationOutcome::Pending(task_id)) => {
+                    return Ok(TaskCreationOutcome::Pending(encode(index, &task_id)));
+                }
+                Ok(TaskCreationOutcome::Ready { task_id, solution }) => {
+                    return Ok(TaskCreationOutcome::Ready {
+                        task_id: encode(index, &task_id),
+                        solution,
+                    });
+                }
+                Err(err) if self.quarantine(index, &err) => last_error = Some(err),
+                Err(err) => return Err(KeyPoolError::Inner(err)),
+            }
+        }
+    }
+
+    async fn get_task_result(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Option<Self::Solution>, Self::Error> {
+        let (index, inner_id) = decode(task_id)
+            .filter(|(index, _)| *index < self.providers.len())
+            .ok_or_else(|| KeyPoolError::UnknownTask(task_id.clone()))?;
+
+        self.providers[index]
+            .get_task_result(&inner_id)
+            .await
+            .map_err(|err| {
+                self.quarantine(index, &err);
+                KeyPoolError::Inner(err)
+            })
+    }
+}
+
+fn encode(index: usize, task_id: &TaskId) -> TaskId {
+    TaskId::from(format!("{index}:{task_id}"))
+}
+
+fn decode(task_id: &TaskId) -> Option<(usize, TaskId)> {
+    let (index, inner) = task_id.as_ref().split_once(':')?;
+    Some((index.parse().ok()?, TaskId::from(inner)))
+}
@@ -0,0 +1,212 @@
+//! Tests for the key pool wrapper.
+
+use super::errors::KeyPoolError;
+use super::provider::KeyPoolProvider;
+use super::types::KeySelection;
+use crate::errors::{KeyFault, RetryableError};
+use crate::providers::capsolver::{CapsolverError, CapsolverProvider};
+use crate::providers::traits::Provider;
+use crate::tasks::Turnstile;
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use serde_json::{Value, json};
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const KEYS: [&str; 3] = ["key-alpha-0001", "key-bravo-0002", "key-charlie-0003"];
+
+fn task() -> Turnstile {
+    Turnstile::new("https://example.com", "site-key")
+}
+
+fn pool(server: &MockServer, keys: &[&str]) -> KeyPoolProvider<CapsolverProvider> {
+    let url = Url::parse(&server.uri()).unwrap();
+    KeyPoolProvider::new(keys, |key| CapsolverProvider::with_url(url.clone(), key)).unwrap()
+}
+
+fn created(task_id: &str) -> Value {
+    json!({ "errorId": 0, "taskId": task_id })
+}
+
+fn api_error(code: &str) -> Value {
+    json!({ "errorId": 1, "errorCode": code, "errorDescription": code })
+}
+
+/// Answer `createTask` for one key
+async fn mount_create(server: &MockServer, key: &str, response: Value, times: Option<u64>) {
+    let mock = Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({ "clientKey": key })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response));
+    match times {
+        Some(n) => mock.up_to_n_times(n).mount(server).await,
+        None => mock.mount(server).await,
+    }
+}
+
+async fn create(pool: &KeyPoolProvider<CapsolverProvider>) -> String {
+    let outcome = pool.create_task(task().into()).await.unwrap();
+    outcome.task_id().to_string()
+}
+
+#[tokio::test]
+async fn test_round_robin_polls_with_creating_key() {
+    let server = MockServer::start().await;
+    for (i, key) in KEYS.iter().enumerate() {
+        mount_create(&server, key, created(&format!("task-{i}")), None).await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .and(body_partial_json(
+            json!({ "clientKey": KEYS[1], "taskId": "task-1" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "status": "ready",
+            "solution": { "token": "solved" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let pool = pool(&server, &KEYS);
+    let ids = [
+        create(&pool).await,
+        create(&pool).await,
+        create(&pool).await,
+        create(&pool).await,
+    ];
+    assert_eq!(ids, ["0:task-0", "1:task-1", "2:task-2", "0:task-0"]);
+
+    let solution = pool.get_task_result(&TaskId::from("1:task-1")).await;
+    assert_eq!(
+        solution.unwrap().unwrap().as_turnstile().unwrap().token(),
+        Some("solved")
+    );
+
+    let counts: Vec<_> = pool.keys().iter().map(|k| k.tasks_submitted).collect();
+    assert_eq!(counts, [2, 1, 1]);
+}
+
+#[tokio::test]
+async fn test_key_fault_quarantines_and_fails_over() {
+    let server = MockServer::start().await;
+    mount_create(&server, KEYS[0], api_error("ERROR_ZERO_BALANCE"), Some(1)).await;
+    mount_create(&server, KEYS[1], created("task-1"), None).await;
+
+    let pool = pool(&server, &KEYS[..2]).with_balance_cooldown(Duration::from_secs(300));
+    assert_eq!(create(&pool).await, "1:task-1");
+    assert_eq!(create(&pool).await, "1:task-1");
+
+    let keys = pool.keys();
+    assert_eq!(keys[0].fault, Some(KeyFault::ZeroBalance));
+    assert!(
+        keys[0]
+            .retry_after
+            .is_some_and(|d| d <= Duration::from_secs(300))
+    );
+    assert_eq!(keys[0].tasks_submitted, 1);
+    assert_eq!(keys[0].hint, "...0001");
+    assert!(keys[1].is_available());
+}
+
+#[tokio::test]
+async fn test_no_available_key() {
+    let server = MockServer::start().await;
+    mount_create(&server, KEYS[0], api_error("ERROR_KEY_DENIED_ACCESS"), None).await;
+    mount_create(&server, KEYS[1], api_error("ERROR_KEY_TEMP_BLOCKED"), None).await;
+
+    let pool = pool(&server, &KEYS[..2]);
+
+    // Both keys tried; the last key's error is returned
+    let err = pool.create_task(task().into()).await.unwrap_err();
+    assert_eq!(err.key_fault(), Some(KeyFault::TemporarilyBlocked));
+    assert!(matches!(err.into_inner(), Some(CapsolverError::Api(_))));
+
+    let err = pool.create_task(task().into()).await.unwrap_err();
+    let KeyPoolError::NoAvailableKey { retry_after } = err else {
+        panic!("expected NoAvailableKey, got {err:?}");
+    };
+    assert!(retry_after.is_some_and(|d| d <= Duration::from_secs(60)));
+    assert!(!err.is_retryable() && err.should_retry_operation());
+
+    // Invalid keys never come back on their own
+    pool.release(1);
+    let _ = pool.create_task(task().into()).await;
+    let err = pool.create_task(task().into()).await.unwrap_err();
+    assert!(matches!(
+        err,
+        KeyPoolError::NoAvailableKey {
+            retry_after: Some(_)
+        }
+    ));
+    let keys = pool.keys();
+    assert_eq!(keys[0].fault, Some(KeyFault::Invalid));
+    assert_eq!(keys[0].retry_after, None);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_cooldown_expires() {
+    let server = MockServer::start().await;
+    mount_create(
+        &server,
+        KEYS[0],
+        api_error("ERROR_KEY_TEMP_BLOCKED"),
+        Some(1),
+    )
+    .await;
+    mount_create(&server, KEYS[0], created("task-0"), None).await;
+
+    let pool = pool(&server, &KEYS[..1]).with_block_cooldown(Duration::from_secs(30));
+    assert!(pool.create_task(task().into()).await.is_err());
+    assert!(!pool.keys()[0].is_available());
+
+    tokio::time::pause();
+    tokio::time::advance(Duration::from_secs(31)).await;
+    tokio::time::resume();
+
+    assert!(pool.keys()[0].is_available());
+    assert_eq!(create(&pool).await, "0:task-0");
+}
+
+#[tokio::test]
+async fn test_least_used_selection() {
+    let mut next_ids = Vec::new();
+    for selection in [KeySelection::RoundRobin, KeySelection::LeastUsed] {
+        let server = MockServer::start().await;
+        mount_create(
+            &server,
+            KEYS[0],
+            api_error("ERROR_KEY_DENIED_ACCESS"),
+            Some(1),
+        )
+        .await;
+        mount_create(&server, KEYS[0], created("task-0"), None).await;
+        mount_create(&server, KEYS[1], created("task-1"), None).await;
+
+        // Key 0 is rejected once and stays out until released
+        let pool = pool(&server, &KEYS[..2]).with_selection(selection);
+        assert_eq!(create(&pool).await, "1:task-1");
+        assert_eq!(create(&pool).await, "1:task-1");
+        pool.release(0);
+        assert_eq!(create(&pool).await, "0:task-0");
+        next_ids.push(create(&pool).await);
+    }
+
+    // Round-robin alternates; least-used lets key 0 catch up first
+    assert_eq!(next_ids, ["1:task-1", "0:task-0"]);
+}
+
+#[tokio::test]
+async fn test_unknown_task_id() {
+    let server = MockServer::start().await;
+    let pool = pool(&server, &KEYS[..2]);
+
+    for id in ["task-1", "x:task-1", "2:task-1"] {
+        let err = pool.get_task_result(&TaskId::from(id)).await.unwrap_err();
+        assert!(matches!(err, KeyPoolError::UnknownTask(ref t) if t.as_ref() == id));
+    }
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
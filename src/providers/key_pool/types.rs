@@ -0,0 +1,47 @@
+//! Selection strategies and key status for the key pool wrapper.
+
+use crate::errors::KeyFault;
+use std::time::Duration;
+
+/// How [`KeyPoolProvider`](super::KeyPoolProvider) picks a key for a new task
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeySelection {
+    /// Cycle through the keys in order
+    #[default]
+    RoundRobin,
+
+    /// Pick the key that submitted the fewest tasks (lowest index on ties)
+    LeastUsed,
+}
+
+/// Snapshot of one key in the pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyStatus {
+    /// Position of the key in the pool (the prefix of its task IDs)
+    pub index: usize,
+    /// Redacted key, e.g. `"...f3a9"`
+    pub hint: String,
+    /// `createTask` calls sent with this key
+    pub tasks_submitted: usize,
+    /// Why the key is quarantined, if it is
+    pub fault: Option<KeyFault>,
+    /// Time left in quarantine; `None` for available or invalid keys
+    pub retry_after: Option<Duration>,
+}
+
+impl KeyStatus {
+    /// Returns `true` if the key can take new tasks
+    pub fn is_available(&self) -> bool {
+        self.fault.is_none()
+    }
+}
+
+/// Redact a key down to its last four characters
+pub(super) fn hint(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("...{tail}")
+}
@@ -0,0 +1,259 @@
+//! Error types for the legacy `in.php`/`res.php` provider.
+//!
+//! The legacy protocol reports errors as bare text codes (`ERROR_ZERO_BALANCE`,
+//! `MAX_USER_TURN`, ...) instead of JSON objects, so [`LegacyErrorCode`] is the
+//! whole API error.
+
+use crate::errors::{KeyFault, RetryableError, UnsupportedTaskError};
+use reqwest::Url;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LegacyError {
+    #[error("Failed to build HTTP client: {0}")]
+    BuildHttpClient(#[source] reqwest::Error),
+
+    #[error("URL cannot be used as a base: {0}")]
+    InvalidUrl(Url),
+
+    #[error("HTTP request failed: {0}")]
+    HttpRequest(#[from] reqwest_middleware::Error),
+
+    #[error("Failed to parse response: {0}")]
+    ParseResponse(#[source] reqwest::Error),
+
+    #[error("Unexpected response: {0}")]
+    UnexpectedResponse(String),
+
+    #[error("Legacy API error: {0}")]
+    Api(LegacyErrorCode),
+
+    #[error("{0}")]
+    UnsupportedTask(#[source] UnsupportedTaskError),
+}
+
+pub type Result<T> = std::result::Result<T, LegacyError>;
+
+impl RetryableError for LegacyError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            LegacyError::HttpRequest(_) => true,
+            LegacyError::Api(code) => code.is_retryable(),
+            LegacyError::BuildHttpClient(_)
+            | LegacyError::InvalidUrl(_)
+            | LegacyError::ParseResponse(_)
+            | LegacyError::UnexpectedResponse(_)
+            | LegacyError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            LegacyError::HttpRequest(_) => true,
+            LegacyError::Api(code) => code.should_retry_operation(),
+            LegacyError::BuildHttpClient(_)
+            | LegacyError::InvalidUrl(_)
+            | LegacyError::ParseResponse(_)
+            | LegacyError::UnexpectedResponse(_)
+            | LegacyError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            LegacyError::Api(code) => code.key_fault(),
+            _ => None,
+        }
+    }
+}
+
+/// Known `in.php`/`res.php` error codes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LegacyErrorCode {
+    // Retryable/transient
+    NoSlotAvailable,
+    MaxUserTurn,
+    IpBanned,
+
+    // Account/configuration
+    WrongUserKey,
+    KeyDoesNotExist,
+    ZeroBalance,
+    IpNotAllowed,
+
+    // Task submission (in.php)
+    PageUrl,
+    ZeroCaptchaFilesize,
+    TooBigCaptchaFilesize,
+    WrongFileExtension,
+    ImageTypeNotSupported,
+    Upload,
+    CaptchaImageBlocked,
+    TooManyBadImages,
+    BadTokenOrPageUrl,
+    GoogleKey,
+    WrongGoogleKey,
+    SiteKey,
+    BadParameters,
+    BadProxy,
+
+    // Result polling (res.php)
+    CaptchaUnsolvable,
+    WrongIdFormat,
+    WrongCaptchaId,
+    BadDuplicates,
+    TokenExpired,
+    EmptyAction,
+    ProxyConnectionFailed,
+
+    // Unknown
+    Other(String),
+}
+
+impl LegacyErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::NoSlotAvailable => "ERROR_NO_SLOT_AVAILABLE",
+            Self::MaxUserTurn => "MAX_USER_TURN",
+            Self::IpBanned => "IP_BANNED",
+            Self::WrongUserKey => "ERROR_WRONG_USER_KEY",
+            Self::KeyDoesNotExist => "ERROR_KEY_DOES_NOT_EXIST",
+            Self::ZeroBalance => "ERROR_ZERO_BALANCE",
+            Self::IpNotAllowed => "ERROR_IP_NOT_ALLOWED",
+            Self::PageUrl => "ERROR_PAGEURL",
+            Self::ZeroCaptchaFilesize => "ERROR_ZERO_CAPTCHA_FILESIZE",
+            Self::TooBigCaptchaFilesize => "ERROR_TOO_BIG_CAPTCHA_FILESIZE",
+            Self::WrongFileExtension => "ERROR_WRONG_FILE_EXTENSION",
+            Self::ImageTypeNotSupported => "ERROR_IMAGE_TYPE_NOT_SUPPORTED",
+            Self::Upload => "ERROR_UPLOAD",
+            Self::CaptchaImageBlocked => "ERROR_CAPTCHAIMAGE_BLOCKED",
+            Self::TooManyBadImages => "TOO_MANY_BAD_IMAGES",
+            Self::BadTokenOrPageUrl => "ERROR_BAD_TOKEN_OR_PAGEURL",
+            Self::GoogleKey => "ERROR_GOOGLEKEY",
+            Self::WrongGoogleKey => "ERROR_WRONG_GOOGLEKEY",
+            Self::SiteKey => "ERROR_SITEKEY",
+            Self::BadParameters => "ERROR_BAD_PARAMETERS",
+            Self::BadProxy => "ERROR_BAD_PROXY",
+            Self::CaptchaUnsolvable => "ERROR_CAPTCHA_UNSOLVABLE",
+            Self::WrongIdFormat => "ERROR_WRONG_ID_FORMAT",
+            Self::WrongCaptchaId => "ERROR_WRONG_CAPTCHA_ID",
+            Self::BadDuplicates => "ERROR_BAD_DUPLICATES",
+            Self::TokenExpired => "ERROR_TOKEN_EXPIRED",
+            Self::EmptyAction => "ERROR_EMPTY_ACTION",
+            Self::ProxyConnectionFailed => "ERROR_PROXY_CONNECTION_FAILED",
+            Self::Other(code) => code.as_str(),
+        }
+    }
+
+    pub(crate) fn from_str(s: &str) -> Self {
+        match s {
+            "ERROR_NO_SLOT_AVAILABLE" => Self::NoSlotAvailable,
+            "MAX_USER_TURN" => Self::MaxUserTurn,
+            "IP_BANNED" => Self::IpBanned,
+            "ERROR_WRONG_USER_KEY" => Self::WrongUserKey,
+            "ERROR_KEY_DOES_NOT_EXIST" => Self::KeyDoesNotExist,
+            "ERROR_ZERO_BALANCE" => Self::ZeroBalance,
+            "ERROR_IP_NOT_ALLOWED" => Self::IpNotAllowed,
+            "ERROR_PAGEURL" => Self::PageUrl,
+            "ERROR_ZERO_CAPTCHA_FILESIZE" => Self::ZeroCaptchaFilesize,
+            "ERROR_TOO_BIG_CAPTCHA_FILESIZE" => Self::TooBigCaptchaFilesize,
+            "ERROR_WRONG_FILE_EXTENSION" => Self::WrongFileExtension,
+            "ERROR_IMAGE_TYPE_NOT_SUPPORTED" => Self::ImageTypeNotSupported,
+            "ERROR_UPLOAD" => Self::Upload,
+            "ERROR_CAPTCHAIMAGE_BLOCKED" => Self::CaptchaImageBlocked,
+            "TOO_MANY_BAD_IMAGES" => Self::TooManyBadImages,
+            "ERROR_BAD_TOKEN_OR_PAGEURL" => Self::BadTokenOrPageUrl,
+            "ERROR_GOOGLEKEY" => Self::GoogleKey,
+            "ERROR_WRONG_GOOGLEKEY" => Self::WrongGoogleKey,
+            "ERROR_SITEKEY" => Self::SiteKey,
+            "ERROR_BAD_PARAMETERS" => Self::BadParameters,
+            "ERROR_BAD_PROXY" => Self::BadProxy,
+            "ERROR_CAPTCHA_UNSOLVABLE" => Self::CaptchaUnsolvable,
+            "ERROR_WRONG_ID_FORMAT" => Self::WrongIdFormat,
+            "ERROR_WRONG_CAPTCHA_ID" => Self::WrongCaptchaId,
+            "ERROR_BAD_DUPLICATES" => Self::BadDuplicates,
+            "ERROR_TOKEN_EXPIRED" => Self::TokenExpired,
+            "ERROR_EMPTY_ACTION" => Self::EmptyAction,
+            "ERROR_PROXY_CONNECTION_FAILED" => Self::ProxyConnectionFailed,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::NoSlotAvailable | Self::MaxUserTurn | Self::IpBanned
+        )
+    }
+
+    /// Returns the API key problem this code reports, if any
+    pub fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            Self::WrongUserKey | Self::KeyDoesNotExist => Some(KeyFault::Invalid),
+            Self::ZeroBalance => Some(KeyFault::ZeroBalance),
+            Self::IpBanned | Self::MaxUserTurn => Some(KeyFault::TemporarilyBlocked),
+            _ => None,
+        }
+    }
+
+    pub fn should_retry_operation(&self) -> bool {
+        match self {
+            Self::NoSlotAvailable
+            | Self::MaxUserTurn
+            | Self::IpBanned
+            | Self::CaptchaUnsolvable
+            | Self::BadDuplicates
+            | Self::TokenExpired
+            | Self::ProxyConnectionFailed => true,
+            Self::WrongUserKey
+            | Self::KeyDoesNotExist
+            | Self::ZeroBalance
+            | Self::IpNotAllowed
+            | Self::PageUrl
+            | Self::ZeroCaptchaFilesize
+            | Self::TooBigCaptchaFilesize
+            | Self::WrongFileExtension
+            | Self::ImageTypeNotSupported
+            | Self::Upload
+            | Self::CaptchaImageBlocked
+            | Self::TooManyBadImages
+            | Self::BadTokenOrPageUrl
+            | Self::GoogleKey
+            | Self::WrongGoogleKey
+            | Self::SiteKey
+            | Self::BadParameters
+            | Self::BadProxy
+            | Self::WrongIdFormat
+            | Self::WrongCaptchaId
+            | Self::EmptyAction
+            | Self::Other(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for LegacyErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for LegacyErrorCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LegacyErrorCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::from_str(&s))
+    }
+}
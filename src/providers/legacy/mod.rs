@@ -0,0 +1,85 @@
+//! # Legacy `in.php`/`res.php` Provider
+//!
+//! Implementation of captcha solver provider for services exposing the classic
+//! 2Captcha-style form API: tasks are submitted to `in.php` and polled from
+//! `res.php`, with plain-text `OK|<value>`, `CAPCHA_NOT_READY` and error code
+//! responses.
+//!
+//! Many smaller and self-hosted solver services, as well as older
+//! 2Captcha/RuCaptcha accounts, only speak this protocol. Point the provider at
+//! any of them by setting its base URL.
+//!
+//! ## Supported Captcha Types
+//!
+//! | Type | Task Type | `method` | Proxy Supported |
+//! |------|-----------|----------|-----------------|
+//! | ReCaptcha V2 | [`ReCaptchaV2`](crate::ReCaptchaV2) | `userrecaptcha` | Yes |
+//! | ReCaptcha V3 | [`ReCaptchaV3`](crate::ReCaptchaV3) | `userrecaptcha` (`version=v3`) | No |
+//! | Cloudflare Turnstile | [`Turnstile`](crate::Turnstile) | `turnstile` | Yes |
+//! | Image to Text | [`ImageToText`](crate::ImageToText) | `base64` | No |
+//!
+//! All other task types are rejected with [`UnsupportedTaskError`](crate::UnsupportedTaskError).
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::legacy::LegacyProvider;
+//! use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ReCaptchaV2};
+//! use url::Url;
+//!
+//! let provider = LegacyProvider::with_url(Url::parse("https://solver.example.com")?, "api_key")?;
+//! let service = CaptchaSolverService::new(provider);
+//!
+//! let task = ReCaptchaV2::new("https://example.com", "site_key");
+//! let solution = service.solve_captcha(task).await?;
+//! println!("Token: {}", solution.into_recaptcha().token());
+//! ```
+//!
+//! ## Solution Types
+//!
+//! `res.php` returns a bare answer string, so every task yields a
+//! [`LegacySolution`]. Convert it into the shared type you expect:
+//!
+//! - **ReCaptcha V2/V3**: `into_recaptcha()` → [`ReCaptchaSolution`]
+//! - **Turnstile**: `into_turnstile()` → [`TurnstileSolution`] (or `into_token()` → [`TokenSolution`])
+//! - **Image to Text**: `into_image_to_text()` → [`ImageToTextSolution`]
+//!
+//! ## Error Handling
+//!
+//! Text error codes are mapped into [`LegacyErrorCode`] and classified as
+//! retryable or permanent:
+//!
+//! ```rust,ignore
+//! use captcha_solvers::RetryableError;
+//! use captcha_solvers::legacy::{LegacyError, LegacyErrorCode};
+//!
+//! match result {
+//!     Err(LegacyError::Api(LegacyErrorCode::ZeroBalance)) => { /* top up */ }
+//!     Err(e) if e.is_retryable() => { /* ERROR_NO_SLOT_AVAILABLE, MAX_USER_TURN, ... */ }
+//!     Err(e) => { /* permanent error, check API key or task data */ }
+//!     Ok(solution) => { /* success */ }
+//! }
+//! ```
+
+mod errors;
+mod provider;
+mod response;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+// Errors
+pub use errors::{LegacyError, LegacyErrorCode};
+
+// Provider
+pub use provider::{DEFAULT_API_URL, LegacyProvider, LegacyProviderBuilder};
+
+// Solutions
+pub use crate::solutions::{
+    ImageToTextSolution, ReCaptchaSolution, TokenSolution, TurnstileSolution,
+};
+pub use types::LegacySolution;
+
+// Re-export proxy types for convenience (also available at crate root)
+pub use crate::utils::proxy::{ProxyConfig, ProxyType};
@@ -0,0 +1,324 @@
+//! Legacy `in.php`/`res.php` provider implementation.
+
+use super::errors::{LegacyError, Result};
+use super::response::LegacyResponse;
+use super::types::{InRequest, LegacySolution, LegacyTask, ResRequest};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, RequestBuilder};
+use secrecy::{ExposeSecret, SecretString};
+use std::fmt::Debug;
+
+#[cfg(feature = "tracing")]
+use crate::utils::span_status::{record_error, set_span_ok};
+#[cfg(feature = "tracing")]
+use tracing::Span;
+
+/// Default legacy API URL (2Captcha's classic endpoints)
+pub const DEFAULT_API_URL: &str = "https://2captcha.com";
+
+/// API endpoint paths
+const IN_PATH: &str = "in.php";
+const RES_PATH: &str = "res.php";
+
+/// Provider for services exposing the classic `in.php`/`res.php` API
+///
+/// Tasks are submitted as form-encoded `in.php` requests and polled with
+/// `res.php?action=get`. Any compatible service can be targeted by setting
+/// its base URL; the endpoint paths are appended to it, so services hosted
+/// under a sub-path (e.g. `https://solver.example.com/api/`) work as well.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::{
+///     CaptchaSolverService, CaptchaSolverServiceTrait, ImageToText,
+///     legacy::LegacyProvider,
+/// };
+/// use url::Url;
+///
+/// let provider = LegacyProvider::with_url(
+///     Url::parse("https://solver.example.com")?,
+///     "api_key",
+/// )?;
+/// let service = CaptchaSolverService::new(provider);
+///
+/// let task = ImageToText::from_base64(base64_image);
+/// let solution = service.solve_captcha(task).await?;
+/// println!("Text: {}", solution.into_image_to_text().text());
+/// ```
+#[derive(Clone)]
+pub struct LegacyProvider {
+    http_client: ClientWithMiddleware,
+    api_key: SecretString,
+    url: Url,
+}
+
+impl Debug for LegacyProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LegacyProvider")
+            .field("url", &self.url)
+            .field("api_key", &crate::utils::REDACTED)
+            .finish()
+    }
+}
+
+/// Builder for configuring a [`LegacyProvider`]
+///
+/// Provides a fluent API for constructing providers with custom settings.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::legacy::LegacyProvider;
+/// use url::Url;
+///
+/// let provider = LegacyProvider::builder("your-api-key")
+///     .url(Url::parse("https://solver.example.com").unwrap())
+///     .http_client(custom_client)
+///     .build()?;
+/// ```
+pub struct LegacyProviderBuilder {
+    api_key: String,
+    url: Option<Url>,
+    http_client: Option<ClientWithMiddleware>,
+}
+
+impl LegacyProviderBuilder {
+    /// Create a new builder with the given API key
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            url: None,
+            http_client: None,
+        }
+    }
+
+    /// Set the base URL of the compatible service
+    ///
+    /// `in.php` and `res.php` are resolved relative to this URL.
+    ///
+    /// Default: `https://2captcha.com`
+    pub fn url(mut self, url: Url) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Set a custom HTTP client with middleware
+    ///
+    /// Use this when you need custom middleware (e.g., tracing, retry, rate limiting).
+    pub fn http_client(mut self, client: ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Build the [`LegacyProvider`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL cannot be a base (e.g. `mailto:`) or the
+    /// HTTP client cannot be built.
+    pub fn build(self) -> Result<LegacyProvider> {
+        let url = self
+            .url
+            .unwrap_or_else(|| Url::parse(DEFAULT_API_URL).expect("Invalid default URL"));
+        if url.cannot_be_a_base() {
+            return Err(LegacyError::InvalidUrl(url));
+        }
+
+        let http_client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let client = reqwest::Client::builder()
+                    .build()
+                    .map_err(LegacyError::BuildHttpClient)?;
+                ClientBuilder::new(client).build()
+            }
+        };
+
+        Ok(LegacyProvider {
+            http_client,
+            api_key: SecretString::from(self.api_key),
+            url,
+        })
+    }
+}
+
+impl LegacyProvider {
+    /// Create a new legacy provider with the default API URL
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Your API key
+    pub fn new(api_key: impl Into<String>) -> Result<Self> {
+        Self::builder(api_key).build()
+    }
+
+    /// Create a new legacy provider for a compatible service
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL of the service (`in.php`/`res.php` are resolved against it)
+    /// * `api_key` - Your API key
+    pub fn with_url(url: Url, api_key: impl Into<String>) -> Result<Self> {
+        Self::builder(api_key).url(url).build()
+    }
+
+    /// Create a builder for configuring the provider
+    ///
+    /// Use this for advanced configuration options like custom HTTP clients.
+    pub fn builder(api_key: impl Into<String>) -> LegacyProviderBuilder {
+        LegacyProviderBuilder::new(api_key)
+    }
+
+    /// Get the base URL
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Get the API key (exposed for request building).
+    fn api_key(&self) -> &str {
+        self.api_key.expose_secret()
+    }
+
+    /// Resolve an endpoint relative to the base URL, keeping any base path.
+    fn endpoint(&self, path: &str) -> Url {
+        let mut url = self.url.clone();
+        url.path_segments_mut()
+            .expect("checked in build()")
+            .pop_if_empty()
+            .push(path);
+        url
+    }
+
+    /// Send a request and parse the plain-text response.
+    async fn send(&self, request: RequestBuilder) -> Result<LegacyResponse> {
+        let response = request.send().await.map_err(LegacyError::HttpRequest)?;
+        let body = response.text().await.map_err(LegacyError::ParseResponse)?;
+        LegacyResponse::parse(&body)
+    }
+
+    /// Create a captcha solving task (internal)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task_internal",
+            target = "captcha.legacy",
+            skip_all,
+            fields(task_id = tracing::field::Empty)
+        )
+    )]
+    async fn create_task_internal(&self, task: LegacyTask) -> Result<TaskId> {
+        let request = InRequest {
+            key: self.api_key(),
+            task: &task,
+        };
+
+        let response = self
+            .send(self.http_client.post(self.endpoint(IN_PATH)).form(&request))
+            .await?;
+
+        let task_id = match response {
+            LegacyResponse::Ok(id) => TaskId::from(id),
+            LegacyResponse::Error(code) => return Err(LegacyError::Api(code)),
+            LegacyResponse::NotReady => {
+                return Err(LegacyError::UnexpectedResponse(
+                    "CAPCHA_NOT_READY".to_string(),
+                ));
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        Span::current().record("task_id", task_id.as_ref());
+
+        Ok(task_id)
+    }
+
+    /// Get the result of a captcha task (internal)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result_internal",
+            target = "captcha.legacy",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result_internal(&self, task_id: &TaskId) -> Result<Option<LegacySolution>> {
+        let request = ResRequest {
+            key: self.api_key(),
+            action: "get",
+            id: task_id.as_ref(),
+        };
+
+        let response = self
+            .send(
+                self.http_client
+                    .get(self.endpoint(RES_PATH))
+                    .query(&request),
+            )
+            .await?;
+        let solution = response
+            .into_result()?
+            .map(|answer| LegacySolution { answer });
+
+        #[cfg(feature = "tracing")]
+        if solution.is_some() {
+            set_span_ok();
+        }
+
+        Ok(solution)
+    }
+}
+
+impl Provider for LegacyProvider {
+    type Solution = LegacySolution;
+    type Error = LegacyError;
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task",
+            target = "captcha.legacy",
+            skip_all,
+            fields(captcha.task_type)
+        )
+    )]
+    async fn create_task(&self, task: CaptchaTask) -> Result<TaskCreationOutcome<Self::Solution>> {
+        #[cfg(feature = "tracing")]
+        Span::current().record("captcha.task_type", task.to_string());
+
+        let internal_task: LegacyTask = task.try_into().map_err(LegacyError::UnsupportedTask)?;
+        let result = self.create_task_internal(internal_task).await;
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => set_span_ok(),
+            Err(e) => record_error(e, "Legacy"),
+        }
+
+        result.map(TaskCreationOutcome::Pending)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result",
+            target = "captcha.legacy",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result(&self, task_id: &TaskId) -> Result<Option<Self::Solution>> {
+        let result = self.get_task_result_internal(task_id).await;
+
+        #[cfg(feature = "tracing")]
+        if let Err(ref e) = result {
+            record_error(e, "Legacy");
+        }
+
+        result
+    }
+}
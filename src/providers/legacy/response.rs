@@ -0,0 +1,57 @@
+//! Response parsing for the legacy `in.php`/`res.php` API.
+//!
+//! Both endpoints answer with plain text: `OK|<value>` on success,
+//! `CAPCHA_NOT_READY` while a task is still being solved, or a bare error code.
+
+use super::errors::{LegacyError, LegacyErrorCode, Result};
+
+/// Status text returned by `res.php` while the task is in progress
+/// (the misspelling is part of the protocol)
+const NOT_READY: &str = "CAPCHA_NOT_READY";
+
+/// Plain-text legacy API response
+#[derive(Debug, PartialEq)]
+pub enum LegacyResponse {
+    /// `OK|<value>`: the task ID from `in.php` or the answer from `res.php`
+    Ok(String),
+    /// `CAPCHA_NOT_READY`
+    NotReady,
+    /// An error code such as `ERROR_ZERO_BALANCE` or `MAX_USER_TURN`
+    Error(LegacyErrorCode),
+}
+
+impl LegacyResponse {
+    /// Parse a response body.
+    ///
+    /// Bodies that are neither `OK|...`, `CAPCHA_NOT_READY` nor an error code
+    /// (for example an HTML error page from a proxy) are reported as
+    /// [`LegacyError::UnexpectedResponse`].
+    pub fn parse(body: &str) -> Result<Self> {
+        let body = body.trim();
+
+        if let Some(value) = body.strip_prefix("OK|") {
+            return Ok(Self::Ok(value.to_string()));
+        }
+        if body == NOT_READY {
+            return Ok(Self::NotReady);
+        }
+
+        // Some services append a description: `ERROR_WRONG_USER_KEY|description`
+        let code = body.split('|').next().unwrap_or_default();
+        match LegacyErrorCode::from_str(code) {
+            LegacyErrorCode::Other(code) if !code.starts_with("ERROR") => {
+                Err(LegacyError::UnexpectedResponse(body.to_string()))
+            }
+            code => Ok(Self::Error(code)),
+        }
+    }
+
+    /// Convert to Result, mapping `CAPCHA_NOT_READY` to `None`.
+    pub fn into_result(self) -> Result<Option<String>> {
+        match self {
+            Self::Ok(value) => Ok(Some(value)),
+            Self::NotReady => Ok(None),
+            Self::Error(code) => Err(LegacyError::Api(code)),
+        }
+    }
+}
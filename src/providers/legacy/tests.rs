@@ -0,0 +1,255 @@
+//! Tests for the legacy `in.php`/`res.php` provider.
+
+use super::errors::{LegacyError, LegacyErrorCode};
+use super::provider::LegacyProvider;
+use super::response::LegacyResponse;
+use super::types::LegacySolution;
+use crate::errors::{KeyFault, RetryableError};
+use crate::providers::traits::Provider;
+use crate::tasks::{Altcha, ImageToText, ReCaptchaV2, Turnstile};
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use wiremock::matchers::{body_string_contains, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn mock_provider(server: &MockServer) -> LegacyProvider {
+    LegacyProvider::builder("test_api_key")
+        .url(Url::parse(&server.uri()).unwrap())
+        .build()
+        .unwrap()
+}
+
+async fn mock_in(server: &MockServer, body: &str) {
+    Mock::given(method("POST"))
+        .and(path("/in.php"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .mount(server)
+        .await;
+}
+
+async fn mock_res(server: &MockServer, body: &str) {
+    Mock::given(method("GET"))
+        .and(path("/res.php"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_create_task_success() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/in.php"))
+        .and(body_string_contains("key=test_api_key"))
+        .and(body_string_contains("method=userrecaptcha"))
+        .and(body_string_contains("googlekey=site-key"))
+        .and(body_string_contains("pageurl=https%3A%2F%2Fexample.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK|2122988149"))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = ReCaptchaV2::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "2122988149");
+}
+
+#[tokio::test]
+async fn test_create_task_image_to_text() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/in.php"))
+        .and(body_string_contains("method=base64"))
+        .and(body_string_contains("body=aGVsbG8%3D"))
+        .and(body_string_contains("regsense=1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK|1"))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = ImageToText::from_base64("aGVsbG8=").case_sensitive();
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "1");
+}
+
+#[tokio::test]
+async fn test_create_task_api_error() {
+    let server = MockServer::start().await;
+    mock_in(&server, "ERROR_ZERO_BALANCE").await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        LegacyError::Api(LegacyErrorCode::ZeroBalance)
+    ));
+    assert!(!err.is_retryable());
+    assert!(!err.should_retry_operation());
+    assert_eq!(err.key_fault(), Some(KeyFault::ZeroBalance));
+}
+
+#[tokio::test]
+async fn test_create_task_max_user_turn_is_retryable() {
+    let server = MockServer::start().await;
+    mock_in(&server, "MAX_USER_TURN").await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert!(err.is_retryable());
+    assert!(err.should_retry_operation());
+    assert_eq!(err.key_fault(), Some(KeyFault::TemporarilyBlocked));
+}
+
+#[test]
+fn test_error_code_key_fault() {
+    assert_eq!(
+        LegacyErrorCode::WrongUserKey.key_fault(),
+        Some(KeyFault::Invalid)
+    );
+    assert_eq!(
+        LegacyErrorCode::IpBanned.key_fault(),
+        Some(KeyFault::TemporarilyBlocked)
+    );
+    assert_eq!(LegacyErrorCode::NoSlotAvailable.key_fault(), None);
+    assert_eq!(LegacyErrorCode::CaptchaUnsolvable.key_fault(), None);
+}
+
+#[tokio::test]
+async fn test_create_task_unexpected_response() {
+    let server = MockServer::start().await;
+    mock_in(&server, "<html>Bad Gateway</html>").await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    assert!(matches!(err, LegacyError::UnexpectedResponse(_)));
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_create_task_unsupported() {
+    let provider = LegacyProvider::new("test_api_key").unwrap();
+    let task = Altcha::new("https://example.com", "https://example.com/challenge");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    match err {
+        LegacyError::UnsupportedTask(error) => {
+            assert_eq!(error.task_type, "Altcha");
+            assert_eq!(error.provider, "Legacy");
+        }
+        _ => panic!("Expected UnsupportedTask error"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_task_result_ready() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/res.php"))
+        .and(query_param("key", "test_api_key"))
+        .and(query_param("action", "get"))
+        .and(query_param("id", "2122988149"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK|03AHJ_Vuve5Asa4koK3KSMyUkCq"))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("2122988149");
+
+    let solution: Option<LegacySolution> = provider.get_task_result(&task_id).await.unwrap();
+    assert_eq!(
+        solution.unwrap().into_recaptcha().token(),
+        "03AHJ_Vuve5Asa4koK3KSMyUkCq"
+    );
+}
+
+#[tokio::test]
+async fn test_get_task_result_not_ready() {
+    let server = MockServer::start().await;
+    mock_res(&server, "CAPCHA_NOT_READY").await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("2122988149");
+
+    let solution = provider.get_task_result(&task_id).await.unwrap();
+    assert!(solution.is_none());
+}
+
+#[tokio::test]
+async fn test_get_task_result_unsolvable() {
+    let server = MockServer::start().await;
+    mock_res(&server, "ERROR_CAPTCHA_UNSOLVABLE").await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("2122988149");
+
+    let err = provider.get_task_result(&task_id).await.unwrap_err();
+    assert!(!err.is_retryable());
+    assert!(err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_base_url_with_path() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/solver/in.php"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK|42"))
+        .mount(&server)
+        .await;
+
+    let url = Url::parse(&format!("{}/solver/", server.uri())).unwrap();
+    let provider = LegacyProvider::with_url(url, "test_api_key").unwrap();
+    let task = Turnstile::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "42");
+}
+
+#[test]
+fn test_builder_default_url() {
+    let provider = LegacyProvider::new("test-key").unwrap();
+    assert_eq!(provider.url().as_str(), "https://2captcha.com/");
+}
+
+#[test]
+fn test_builder_rejects_cannot_be_a_base_url() {
+    let url = Url::parse("mailto:solver@example.com").unwrap();
+    let err = LegacyProvider::with_url(url, "test-key").unwrap_err();
+    assert!(matches!(err, LegacyError::InvalidUrl(_)));
+    assert!(!err.is_retryable());
+}
+
+#[test]
+fn test_response_parse() {
+    assert_eq!(
+        LegacyResponse::parse("OK|abc|def").unwrap(),
+        LegacyResponse::Ok("abc|def".to_string())
+    );
+    assert_eq!(
+        LegacyResponse::parse("CAPCHA_NOT_READY\n").unwrap(),
+        LegacyResponse::NotReady
+    );
+    assert_eq!(
+        LegacyResponse::parse("ERROR_WRONG_USER_KEY").unwrap(),
+        LegacyResponse::Error(LegacyErrorCode::WrongUserKey)
+    );
+    assert_eq!(
+        LegacyResponse::parse("IP_BANNED").unwrap(),
+        LegacyResponse::Error(LegacyErrorCode::IpBanned)
+    );
+    assert_eq!(
+        LegacyResponse::parse("ERROR_SOMETHING_NEW|details").unwrap(),
+        LegacyResponse::Error(LegacyErrorCode::Other("ERROR_SOMETHING_NEW".to_string()))
+    );
+    assert!(matches!(
+        LegacyResponse::parse("Internal Server Error"),
+        Err(LegacyError::UnexpectedResponse(_))
+    ));
+}
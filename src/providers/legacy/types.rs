@@ -0,0 +1,456 @@
+//! Task and solution types for the legacy `in.php`/`res.php` API.
+
+use crate::errors::UnsupportedTaskError;
+use crate::solutions::{ImageToTextSolution, ReCaptchaSolution, TokenSolution, TurnstileSolution};
+use crate::utils::proxy::LegacyProxyFields;
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Provider name reported in [`UnsupportedTaskError`]
+pub(crate) const PROVIDER_NAME: &str = "Legacy";
+
+// ============================================================================
+// Task Types
+// ============================================================================
+
+/// Legacy `in.php` task parameters, tagged by the `method` form field
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "method")]
+pub enum LegacyTask {
+    /// Normal (image) captcha sent as base64
+    #[serde(rename = "base64")]
+    Base64 {
+        body: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        phrase: Option<u8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        regsense: Option<u8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        numeric: Option<u8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        calc: Option<u8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        min_len: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_len: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        textinstructions: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        imginstructions: Option<String>,
+    },
+
+    /// reCAPTCHA V2 and V3 (V3 sets `version=v3`)
+    #[serde(rename = "userrecaptcha")]
+    UserRecaptcha {
+        googlekey: String,
+        pageurl: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<&'static str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        action: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        min_score: Option<f32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        invisible: Option<u8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        enterprise: Option<u8>,
+        #[serde(rename = "data-s", skip_serializing_if = "Option::is_none")]
+        data_s: Option<String>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cookies: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        domain: Option<String>,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<LegacyProxyFields>,
+    },
+
+    /// Cloudflare Turnstile
+    #[serde(rename = "turnstile")]
+    Turnstile {
+        sitekey: String,
+        pageurl: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        action: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pagedata: Option<String>,
+        #[serde(flatten, skip_serializing_if = "Option::is_none")]
+        proxy: Option<LegacyProxyFields>,
+    },
+}
+
+impl Display for LegacyTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Base64 { .. } => write!(f, "base64"),
+            Self::UserRecaptcha { .. } => write!(f, "userrecaptcha"),
+            Self::Turnstile { .. } => write!(f, "turnstile"),
+        }
+    }
+}
+
+// ============================================================================
+// Request Types
+// ============================================================================
+
+/// `in.php` form body
+#[derive(Debug, Serialize)]
+pub(crate) struct InRequest<'a> {
+    pub key: &'a str,
+    #[serde(flatten)]
+    pub task: &'a LegacyTask,
+}
+
+/// `res.php` query parameters
+#[derive(Debug, Serialize)]
+pub(crate) struct ResRequest<'a> {
+    pub key: &'a str,
+    pub action: &'static str,
+    pub id: &'a str,
+}
+
+// ============================================================================
+// Solution Types
+// ============================================================================
+
+/// Legacy API solution
+///
+/// `res.php` returns the answer as plain text without any type information,
+/// so the solution holds the raw answer and converts it into the shared
+/// solution type the caller expects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacySolution {
+    /// Raw answer text after `OK|`
+    pub answer: String,
+}
+
+impl crate::solutions::ProviderSolution for LegacySolution {}
+
+impl LegacySolution {
+    /// Get the raw answer text
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    /// Convert into a ReCaptcha solution
+    pub fn into_recaptcha(self) -> ReCaptchaSolution {
+        ReCaptchaSolution::from_token(self.answer)
+    }
+
+    /// Convert into a Turnstile solution
+    pub fn into_turnstile(self) -> TurnstileSolution {
+        TurnstileSolution::from_token(self.answer)
+    }
+
+    /// Convert into an image to text solution
+    pub fn into_image_to_text(self) -> ImageToTextSolution {
+        ImageToTextSolution::new(self.answer)
+    }
+
+    /// Convert into a token solution
+    pub fn into_token(self) -> TokenSolution {
+        TokenSolution::new(self.answer)
+    }
+}
+
+// ============================================================================
+// Conversions from shared task types
+// ============================================================================
+
+impl TryFrom<crate::tasks::ReCaptchaV2> for LegacyTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::ReCaptchaV2) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.page_action.is_some() {
+            unsupported.push("page_action");
+        }
+        if task.enterprise_payload.is_some() {
+            unsupported.push("enterprise_payload");
+        }
+        if task.is_session {
+            unsupported.push("is_session");
+        }
+        if task.is_session_v3 {
+            unsupported.push("is_session_v3");
+        }
+        if task.anchor.is_some() {
+            unsupported.push("anchor");
+        }
+        if task.reload.is_some() {
+            unsupported.push("reload");
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "ReCaptchaV2",
+                PROVIDER_NAME,
+                unsupported,
+            ));
+        }
+
+        Ok(Self::UserRecaptcha {
+            googlekey: task.website_key,
+            pageurl: task.website_url,
+            version: None,
+            action: None,
+            min_score: None,
+            invisible: task.is_invisible.then_some(1),
+            enterprise: task.is_enterprise.then_some(1),
+            data_s: task.recaptcha_data_s_value,
+            user_agent: task.user_agent,
+            cookies: task.cookies,
+            domain: task.api_domain,
+            proxy: task.proxy.map(|p| p.into_legacy_fields()),
+        })
+    }
+}
+
+impl TryFrom<crate::tasks::ReCaptchaV3> for LegacyTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::ReCaptchaV3) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.proxy.is_some() {
+            unsupported.push("proxy");
+        }
+        if task.enterprise_payload.is_some() {
+            unsupported.push("enterprise_payload");
+        }
+        if task.is_session {
+            unsupported.push("is_session");
+        }
+        if !unsupported.is_empty() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "ReCaptchaV3",
+                PROVIDER_NAME,
+                unsupported,
+            ));
+        }
+
+        Ok(Self::UserRecaptcha {
+            googlekey: task.website_key,
+            pageurl: task.website_url,
+            version: Some("v3"),
+            action: task.page_action,
+            min_score: task.min_score,
+            invisible: None,
+            enterprise: task.is_enterprise.then_some(1),
+            data_s: None,
+            user_agent: None,
+            cookies: None,
+            domain: task.api_domain,
+            proxy: None,
+        })
+    }
+}
+
+impl From<crate::tasks::Turnstile> for LegacyTask {
+    fn from(task: crate::tasks::Turnstile) -> Self {
+        Self::Turnstile {
+            sitekey: task.website_key,
+            pageurl: task.website_url,
+            action: task.action,
+            data: task.cdata,
+            pagedata: task.pagedata,
+            proxy: task.proxy.map(|p| p.into_legacy_fields()),
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::ImageToText> for LegacyTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::ImageToText) -> Result<Self, Self::Error> {
+        if task.module.is_some() {
+            return Err(UnsupportedTaskError::unsupported_fields(
+                "ImageToText",
+                PROVIDER_NAME,
+                vec!["module"],
+            ));
+        }
+
+        Ok(Self::Base64 {
+            body: task.body,
+            phrase: task.phrase.then_some(1),
+            regsense: task.case_sensitive.then_some(1),
+            numeric: (task.numeric != 0).then_some(task.numeric),
+            calc: task.math.then_some(1),
+            min_len: (task.min_length > 0).then_some(task.min_length),
+            max_len: (task.max_length > 0).then_some(task.max_length),
+            textinstructions: task.comment,
+            imginstructions: task.img_instructions,
+        })
+    }
+}
+
+impl TryFrom<crate::tasks::CaptchaTask> for LegacyTask {
+    type Error = UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::CaptchaTask) -> Result<Self, Self::Error> {
+        match task {
+            crate::tasks::CaptchaTask::ReCaptchaV2(t) => t.try_into(),
+            crate::tasks::CaptchaTask::ReCaptchaV3(t) => t.try_into(),
+            crate::tasks::CaptchaTask::Turnstile(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::ImageToText(t) => t.try_into(),
+            crate::tasks::CaptchaTask::TurnstileChallenge(_) => Err(UnsupportedTaskError::new(
+                "TurnstileChallenge",
+                PROVIDER_NAME,
+            )),
+            crate::tasks::CaptchaTask::TurnstileWaitRoom(_) => Err(UnsupportedTaskError::new(
+                "TurnstileWaitRoom",
+                PROVIDER_NAME,
+            )),
+            crate::tasks::CaptchaTask::CloudflareChallenge(_) => Err(UnsupportedTaskError::new(
+                "CloudflareChallenge",
+                PROVIDER_NAME,
+            )),
+            crate::tasks::CaptchaTask::GridClassification(_) => Err(UnsupportedTaskError::new(
+                "GridClassification",
+                PROVIDER_NAME,
+            )),
+            crate::tasks::CaptchaTask::Rotate(_) => {
+                Err(UnsupportedTaskError::new("Rotate", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::AudioToText(_) => {
+                Err(UnsupportedTaskError::new("AudioToText", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::MtCaptcha(_) => {
+                Err(UnsupportedTaskError::new("MtCaptcha", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::FriendlyCaptcha(_) => {
+                Err(UnsupportedTaskError::new("FriendlyCaptcha", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::Cutcaptcha(_) => {
+                Err(UnsupportedTaskError::new("Cutcaptcha", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::YandexSmartCaptcha(_) => Err(UnsupportedTaskError::new(
+                "YandexSmartCaptcha",
+                PROVIDER_NAME,
+            )),
+            crate::tasks::CaptchaTask::Tencent(_) => {
+                Err(UnsupportedTaskError::new("Tencent", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::Lemin(_) => {
+                Err(UnsupportedTaskError::new("Lemin", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::KeyCaptcha(_) => {
+                Err(UnsupportedTaskError::new("KeyCaptcha", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::Capy(_) => {
+                Err(UnsupportedTaskError::new("Capy", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::Imperva(_) => {
+                Err(UnsupportedTaskError::new("Imperva", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::Kasada(_) => {
+                Err(UnsupportedTaskError::new("Kasada", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::Prosopo(_) => {
+                Err(UnsupportedTaskError::new("Prosopo", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::CaptchaFox(_) => {
+                Err(UnsupportedTaskError::new("CaptchaFox", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::Altcha(_) => {
+                Err(UnsupportedTaskError::new("Altcha", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::TextQuestion(_) => {
+                Err(UnsupportedTaskError::new("TextQuestion", PROVIDER_NAME))
+            }
+            crate::tasks::CaptchaTask::HCaptcha(_) => {
+                Err(UnsupportedTaskError::new("HCaptcha", PROVIDER_NAME))
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tasks::{ImageToText, ReCaptchaV2, ReCaptchaV3, Turnstile};
+    use crate::utils::proxy::ProxyConfig;
+
+    #[test]
+    fn test_image_to_text_serialization() {
+        let task: LegacyTask = ImageToText::from_base64("aGVsbG8=")
+            .case_sensitive()
+            .with_min_length(4)
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["method"], "base64");
+        assert_eq!(json["body"], "aGVsbG8=");
+        assert_eq!(json["regsense"], 1);
+        assert_eq!(json["min_len"], 4);
+        assert!(json.get("phrase").is_none());
+    }
+
+    #[test]
+    fn test_recaptcha_v2_with_proxy_serialization() {
+        let task: LegacyTask = ReCaptchaV2::new("https://example.com", "site-key")
+            .invisible()
+            .with_proxy(ProxyConfig::http("1.2.3.4", 8080).with_auth("u", "p"))
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["method"], "userrecaptcha");
+        assert_eq!(json["googlekey"], "site-key");
+        assert_eq!(json["pageurl"], "https://example.com");
+        assert_eq!(json["invisible"], 1);
+        assert_eq!(json["proxy"], "u:p@1.2.3.4:8080");
+        assert_eq!(json["proxytype"], "HTTP");
+        assert!(json.get("version").is_none());
+    }
+
+    #[test]
+    fn test_recaptcha_v3_serialization() {
+        let task: LegacyTask = ReCaptchaV3::new("https://example.com", "site-key")
+            .with_action("login")
+            .with_min_score(0.3)
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["method"], "userrecaptcha");
+        assert_eq!(json["version"], "v3");
+        assert_eq!(json["action"], "login");
+        assert!(json.get("proxy").is_none());
+    }
+
+    #[test]
+    fn test_recaptcha_v3_proxy_unsupported() {
+        let err = LegacyTask::try_from(
+            ReCaptchaV3::new("https://example.com", "site-key")
+                .with_proxy(ProxyConfig::http("1.2.3.4", 8080)),
+        )
+        .unwrap_err();
+        assert_eq!(err.provider, PROVIDER_NAME);
+        assert_eq!(err.unsupported_fields, vec!["proxy"]);
+    }
+
+    #[test]
+    fn test_turnstile_serialization() {
+        let task: LegacyTask = Turnstile::new("https://example.com", "site-key").into();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["method"], "turnstile");
+        assert_eq!(json["sitekey"], "site-key");
+        assert_eq!(json["pageurl"], "https://example.com");
+    }
+
+    #[test]
+    fn test_solution_conversions() {
+        let solution = LegacySolution {
+            answer: "token".to_string(),
+        };
+        assert_eq!(solution.clone().into_recaptcha().token(), "token");
+        assert_eq!(solution.clone().into_turnstile().token(), Some("token"));
+        assert_eq!(solution.clone().into_image_to_text().text(), "token");
+        assert_eq!(solution.into_token().token(), "token");
+    }
+}
@@ -0,0 +1,424 @@
+//! Template-matching recognition engine.
+//!
+//! Images are binarized with Otsu's threshold, split into glyphs at empty
+//! columns, and each glyph is scaled into a fixed grid (keeping its aspect
+//! ratio) and compared against labeled templates.
+
+use super::errors::{LocalOcrError, Result};
+use crate::tasks::ImageToText;
+
+/// Side of the square grid glyphs are normalized into
+const GLYPH_SIZE: usize = 16;
+
+/// Samples per grid cell axis when scaling a glyph
+const SUBSAMPLES: usize = 4;
+
+/// Column runs with fewer foreground pixels are treated as noise
+const MIN_GLYPH_PIXELS: usize = 3;
+
+/// Default minimum similarity for a glyph to be accepted
+pub const DEFAULT_MIN_CONFIDENCE: f32 = 0.7;
+
+/// Built-in 5x7 digit glyphs (`#` is foreground)
+#[rustfmt::skip]
+pub(super) const DIGITS: [(char, [&str; 7]); 10] = [
+    ('0', [".###.", "#...#", "#..##", "#.#.#", "##..#", "#...#", ".###."]),
+    ('1', ["..#..", ".##..", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('2', [".###.", "#...#", "....#", "...#.", "..#..", ".#...", "#####"]),
+    ('3', ["#####", "...#.", "..#..", "...#.", "....#", "#...#", ".###."]),
+    ('4', ["...#.", "..##.", ".#.#.", "#..#.", "#####", "...#.", "...#."]),
+    ('5', ["#####", "#....", "####.", "....#", "....#", "#...#", ".###."]),
+    ('6', ["..##.", ".#...", "#....", "####.", "#...#", "#...#", ".###."]),
+    ('7', ["#####", "....#", "...#.", "..#..", ".#...", ".#...", ".#..."]),
+    ('8', [".###.", "#...#", "#...#", ".###.", "#...#", "#...#", ".###."]),
+    ('9', [".###.", "#...#", "#...#", ".####", "....#", "...#.", ".##.."]),
+];
+
+/// Binarized image, `true` marking foreground (text) pixels
+#[derive(Debug, Clone)]
+pub(crate) struct Bitmap {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+/// Bounding box of a glyph within a [`Bitmap`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rect {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+/// Glyph scaled into a `GLYPH_SIZE` x `GLYPH_SIZE` coverage grid
+#[derive(Debug, Clone)]
+struct Features(Vec<f32>);
+
+impl Bitmap {
+    /// Decode and binarize an encoded image (PNG, JPEG, GIF or BMP).
+    ///
+    /// Transparent pixels are composited over white. Dark text on a light
+    /// background is assumed; the result is inverted if most pixels would
+    /// otherwise be foreground.
+    pub(crate) fn decode(bytes: &[u8]) -> Result<Self> {
+        let image = image::load_from_memory(bytes)
+            .map_err(LocalOcrError::DecodeImage)?
+            .to_rgba8();
+        let (width, height) = (image.width() as usize, image.height() as usize);
+
+        let luma: Vec<u8> = image
+            .pixels()
+            .map(|p| {
+                let [r, g, b, a] = p.0.map(f32::from);
+                let gray = 0.299 * r + 0.587 * g + 0.114 * b;
+                let alpha = a / 255.0;
+                (gray * alpha + 255.0 * (1.0 - alpha)).round() as u8
+            })
+            .collect();
+
+        let threshold = otsu_threshold(&luma);
+        let mut pixels: Vec<bool> = luma.iter().map(|&l| l <= threshold).collect();
+        if pixels.iter().filter(|&&p| p).count() * 2 > pixels.len() {
+            pixels.iter_mut().for_each(|p| *p = !*p);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Build a bitmap from text rows, `#` marking foreground.
+    fn from_rows(rows: &[&str]) -> Self {
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        let pixels = rows
+            .iter()
+            .flat_map(|row| (0..width).map(move |x| row.as_bytes().get(x) == Some(&b'#')))
+            .collect();
+        Self {
+            width,
+            height: rows.len(),
+            pixels,
+        }
+    }
+
+    fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Split the bitmap into glyphs at columns without foreground pixels.
+    fn segments(&self) -> Vec<Rect> {
+        let column_has_ink = |x: usize| (0..self.height).any(|y| self.get(x, y));
+
+        let mut segments = Vec::new();
+        let mut x = 0;
+        while x < self.width {
+            if !column_has_ink(x) {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < self.width && column_has_ink(x) {
+                x += 1;
+            }
+
+            let rows: Vec<usize> = (0..self.height)
+                .filter(|&y| (start..x).any(|cx| self.get(cx, y)))
+                .collect();
+            let count = (start..x)
+                .flat_map(|cx| (0..self.height).map(move |y| (cx, y)))
+                .filter(|&(cx, y)| self.get(cx, y))
+                .count();
+            if count < MIN_GLYPH_PIXELS {
+                continue;
+            }
+
+            let top = rows[0];
+            let bottom = rows[rows.len() - 1];
+            segments.push(Rect {
+                x: start,
+                y: top,
+                width: x - start,
+                height: bottom - top + 1,
+            });
+        }
+        segments
+    }
+
+    /// Scale a glyph into the normalized grid, centered and aspect-preserving.
+    fn features(&self, rect: Rect) -> Features {
+        let side = rect.width.max(rect.height) as f32;
+        let origin_x = rect.x as f32 - (side - rect.width as f32) / 2.0;
+        let origin_y = rect.y as f32 - (side - rect.height as f32) / 2.0;
+        let step = side / GLYPH_SIZE as f32;
+        let sub = SUBSAMPLES as f32;
+
+        let inside = |px: f32, py: f32| {
+            let (x, y) = (px.floor(), py.floor());
+            x >= rect.x as f32
+                && y >= rect.y as f32
+                && x < (rect.x + rect.width) as f32
+                && y < (rect.y + rect.height) as f32
+                && self.get(x as usize, y as usize)
+        };
+
+        let mut cells = Vec::with_capacity(GLYPH_SIZE * GLYPH_SIZE);
+        for gy in 0..GLYPH_SIZE {
+            for gx in 0..GLYPH_SIZE {
+                let mut hits = 0;
+                for sy in 0..SUBSAMPLES {
+                    for sx in 0..SUBSAMPLES {
+                        let px = origin_x + (gx as f32 + (sx as f32 + 0.5) / sub) * step;
+                        let py = origin_y + (gy as f32 + (sy as f32 + 0.5) / sub) * step;
+                        if inside(px, py) {
+                            hits += 1;
+                        }
+                    }
+                }
+                cells.push(hits as f32 / (sub * sub));
+            }
+        }
+        Features(cells)
+    }
+
+    /// Normalized features of every glyph, left to right.
+    fn glyphs(&self) -> Vec<Features> {
+        self.segments()
+            .into_iter()
+            .map(|rect| self.features(rect))
+            .collect()
+    }
+}
+
+impl Features {
+    /// Similarity in `0.0..=1.0` (1.0 for identical glyphs).
+    fn similarity(&self, other: &Features) -> f32 {
+        let (mut diff, mut total) = (0.0, 0.0);
+        for (a, b) in self.0.iter().zip(&other.0) {
+            diff += (a - b).abs();
+            total += a + b;
+        }
+        if total == 0.0 {
+            return 0.0;
+        }
+        1.0 - diff / total
+    }
+}
+
+/// Otsu's method: the threshold maximizing between-class variance.
+fn otsu_threshold(luma: &[u8]) -> u8 {
+    let mut histogram = [0usize; 256];
+    for &l in luma {
+        histogram[l as usize] += 1;
+    }
+
+    let total = luma.len() as f64;
+    let sum: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| i as f64 * c as f64)
+        .sum();
+
+    let (mut best, mut best_variance) = (0u8, -1.0);
+    let (mut weight_bg, mut sum_bg) = (0.0, 0.0);
+    for (t, &count) in histogram.iter().enumerate() {
+        weight_bg += count as f64;
+        if weight_bg == 0.0 {
+            continue;
+        }
+        let weight_fg = total - weight_bg;
+        if weight_fg == 0.0 {
+            break;
+        }
+        sum_bg += t as f64 * count as f64;
+        let mean_bg = sum_bg / weight_bg;
+        let mean_fg = (sum - sum_bg) / weight_fg;
+        let variance = weight_bg * weight_fg * (mean_bg - mean_fg).powi(2);
+        if variance > best_variance {
+            best_variance = variance;
+            best = t as u8;
+        }
+    }
+    best
+}
+
+/// Output constraints taken from an [`ImageToText`] task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Constraints {
+    pub numeric: u8,
+    pub case_sensitive: bool,
+    pub min_length: u32,
+    pub max_length: u32,
+}
+
+impl From<&ImageToText> for Constraints {
+    fn from(task: &ImageToText) -> Self {
+        Self {
+            numeric: task.numeric,
+            case_sensitive: task.case_sensitive,
+            min_length: task.min_length,
+            max_length: task.max_length,
+        }
+    }
+}
+
+/// Labeled glyph templates used for recognition
+///
+/// Start from the built-in [`digits`](Self::digits) or an empty set, and add
+/// labeled samples cut from real captchas of the site you are solving:
+///
+/// ```rust,ignore
+/// use captcha_solvers::localocr::TemplateSet;
+///
+/// let templates = TemplateSet::digits()
+///     .with_sample("ab12", &std::fs::read("samples/ab12.png")?)?
+///     .with_sample("cd34", &std::fs::read("samples/cd34.png")?)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TemplateSet {
+    glyphs: Vec<(char, Features)>,
+}
+
+impl TemplateSet {
+    /// Create an empty template set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Templates for the digits `0`-`9` rendered from a built-in 5x7 font
+    pub fn digits() -> Self {
+        let glyphs = DIGITS
+            .iter()
+            .map(|(ch, rows)| {
+                let bitmap = Bitmap::from_rows(rows);
+                let rect = bitmap.segments()[0];
+                (*ch, bitmap.features(rect))
+            })
+            .collect();
+        Self { glyphs }
+    }
+
+    /// Add the glyphs of a labeled sample image.
+    ///
+    /// The image is segmented like a captcha; `text` (whitespace ignored)
+    /// labels the glyphs from left to right.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the image cannot be decoded or the number of
+    /// glyphs does not match the number of characters in `text`.
+    pub fn with_sample(mut self, text: &str, image: &[u8]) -> Result<Self> {
+        let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let glyphs = Bitmap::decode(image)?.glyphs();
+        if glyphs.len() != chars.len() {
+            return Err(LocalOcrError::SampleMismatch {
+                expected: chars.len(),
+                found: glyphs.len(),
+            });
+        }
+        self.glyphs.extend(chars.into_iter().zip(glyphs));
+        Ok(self)
+    }
+
+    /// Number of templates
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Whether the set has no templates
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    /// Recognize a decoded image under the task's constraints.
+    pub(crate) fn recognize(
+        &self,
+        bitmap: &Bitmap,
+        constraints: Constraints,
+        min_confidence: f32,
+    ) -> Result<String> {
+        let glyphs = bitmap.glyphs();
+
+        let min = (constraints.min_length as usize).max(1);
+        let max = constraints.max_length as usize;
+        if glyphs.len() < min || (max > 0 && glyphs.len() > max) {
+            return Err(LocalOcrError::LengthOutOfRange {
+                found: glyphs.len(),
+                min,
+                max,
+            });
+        }
+
+        let digit = |c: char| c.is_ascii_digit();
+        let letter = |c: char| c.is_alphabetic();
+        let (answer, _) = match constraints.numeric {
+            1 => self.match_glyphs(&glyphs, digit, min_confidence)?,
+            2 => self.match_glyphs(&glyphs, letter, min_confidence)?,
+            3 => {
+                let digits = self.match_glyphs(&glyphs, digit, min_confidence);
+                let letters = self.match_glyphs(&glyphs, letter, min_confidence);
+                match (digits, letters) {
+                    (Ok(d), Ok(l)) => {
+                        if d.1 >= l.1 {
+                            d
+                        } else {
+                            l
+                        }
+                    }
+                    (Ok(d), Err(_)) => d,
+                    (Err(_), Ok(l)) => l,
+                    (Err(e), Err(_)) => return Err(e),
+                }
+            }
+            _ => self.match_glyphs(&glyphs, |_| true, min_confidence)?,
+        };
+
+        if constraints.numeric == 4 && !(answer.chars().any(digit) && answer.chars().any(letter)) {
+            return Err(LocalOcrError::ConstraintViolated(
+                "both numbers and letters required",
+            ));
+        }
+
+        if constraints.case_sensitive {
+            Ok(answer)
+        } else {
+            Ok(answer.to_lowercase())
+        }
+    }
+
+    /// Match every glyph against the allowed templates.
+    ///
+    /// Returns the answer and its mean similarity.
+    fn match_glyphs(
+        &self,
+        glyphs: &[Features],
+        allowed: impl Fn(char) -> bool,
+        min_confidence: f32,
+    ) -> Result<(String, f32)> {
+        let mut answer = String::with_capacity(glyphs.len());
+        let mut total = 0.0;
+
+        for (position, glyph) in glyphs.iter().enumerate() {
+            let (ch, confidence) = self
+                .glyphs
+                .iter()
+                .filter(|(ch, _)| allowed(*ch))
+                .map(|(ch, template)| (*ch, glyph.similarity(template)))
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap_or((' ', 0.0));
+
+            if confidence < min_confidence {
+                return Err(LocalOcrError::LowConfidence {
+                    position,
+                    confidence,
+                });
+            }
+            answer.push(ch);
+            total += confidence;
+        }
+
+        Ok((answer, total / glyphs.len() as f32))
+    }
+}
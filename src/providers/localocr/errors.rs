@@ -0,0 +1,70 @@
+//! Error types for the local OCR provider.
+//!
+//! Recognition is deterministic, so none of these errors are worth retrying
+//! against the same provider. They tell a caller when to hand the captcha to
+//! a paid service instead.
+
+use crate::errors::{RetryableError, UnsupportedTaskError};
+use crate::utils::types::TaskId;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LocalOcrError {
+    #[error("Invalid base64 image: {0}")]
+    InvalidImage(#[source] base64::DecodeError),
+
+    #[error("Failed to decode image: {0}")]
+    DecodeImage(#[source] image::ImageError),
+
+    #[error("Found {found} characters, expected at least {min} and at most {max} (0 = no limit)")]
+    LengthOutOfRange {
+        found: usize,
+        min: usize,
+        max: usize,
+    },
+
+    #[error("Character {position} recognized with confidence {confidence:.2}")]
+    LowConfidence { position: usize, confidence: f32 },
+
+    #[error("Answer does not satisfy the `numeric` constraint: {0}")]
+    ConstraintViolated(&'static str),
+
+    #[error("Sample text has {expected} characters but {found} glyphs were found")]
+    SampleMismatch { expected: usize, found: usize },
+
+    #[error("Unknown task: {0}")]
+    UnknownTask(TaskId),
+
+    #[error("{0}")]
+    UnsupportedTask(#[source] UnsupportedTaskError),
+}
+
+pub type Result<T> = std::result::Result<T, LocalOcrError>;
+
+impl RetryableError for LocalOcrError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            LocalOcrError::InvalidImage(_)
+            | LocalOcrError::DecodeImage(_)
+            | LocalOcrError::LengthOutOfRange { .. }
+            | LocalOcrError::LowConfidence { .. }
+            | LocalOcrError::ConstraintViolated(_)
+            | LocalOcrError::SampleMismatch { .. }
+            | LocalOcrError::UnknownTask(_)
+            | LocalOcrError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            LocalOcrError::InvalidImage(_)
+            | LocalOcrError::DecodeImage(_)
+            | LocalOcrError::LengthOutOfRange { .. }
+            | LocalOcrError::LowConfidence { .. }
+            | LocalOcrError::ConstraintViolated(_)
+            | LocalOcrError::SampleMismatch { .. }
+            | LocalOcrError::UnknownTask(_)
+            | LocalOcrError::UnsupportedTask(_) => false,
+        }
+    }
+}
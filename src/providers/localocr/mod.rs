@@ -0,0 +1,63 @@
+//! # Local OCR Provider
+//!
+//! Solves simple [`ImageToText`](crate::ImageToText) captchas in-process with
+//! a pure-Rust template-matching engine, without paying a solver service.
+//!
+//! Images are binarized, split into characters at empty columns and each
+//! character is matched against a [`TemplateSet`]. The built-in set covers
+//! the digits `0`-`9`; add labeled samples of the captchas you actually see
+//! for better accuracy or for letters.
+//!
+//! ## Supported Options
+//!
+//! | `ImageToText` option | Handling |
+//! |----------------------|----------|
+//! | `numeric` | Restricts which templates may match (digits, letters, either, both) |
+//! | `min_length` / `max_length` | Character count must be in range |
+//! | `case_sensitive` | Answers are lowercased unless set |
+//! | `module`, `phrase`, `math`, `comment`, `img_instructions` | Rejected |
+//!
+//! All other task types are rejected with [`UnsupportedTaskError`](crate::UnsupportedTaskError).
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::localocr::{LocalOcrProvider, TemplateSet};
+//! use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ImageToText};
+//!
+//! let provider = LocalOcrProvider::builder()
+//!     .templates(TemplateSet::digits().with_sample("4821", &sample_png)?)
+//!     .build();
+//! let service = CaptchaSolverService::new(provider);
+//!
+//! let task = ImageToText::from_bytes(image_bytes).numbers_only();
+//! let solution = service.solve_captcha(task).await?;
+//! println!("Text: {}", solution.text());
+//! ```
+//!
+//! ## Error Handling
+//!
+//! Recognition is deterministic, so no [`LocalOcrError`] is retryable. Treat
+//! [`LocalOcrError::LowConfidence`], [`LocalOcrError::LengthOutOfRange`] and
+//! [`LocalOcrError::ConstraintViolated`] as "send this one to a paid provider".
+
+mod engine;
+mod errors;
+mod provider;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+// Engine
+pub use engine::{DEFAULT_MIN_CONFIDENCE, TemplateSet};
+
+// Errors
+pub use errors::LocalOcrError;
+
+// Provider
+pub use provider::{LocalOcrProvider, LocalOcrProviderBuilder};
+
+// Solutions
+pub use crate::solutions::ImageToTextSolution;
+pub use types::LocalOcrSolution;
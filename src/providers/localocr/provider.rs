@@ -0,0 +1,189 @@
+//! Local OCR provider implementation.
+
+use super::engine::{Bitmap, Constraints, DEFAULT_MIN_CONFIDENCE, TemplateSet};
+use super::errors::{LocalOcrError, Result};
+use super::types::{LocalOcrSolution, image_task};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "tracing")]
+use crate::utils::span_status::{record_error, set_span_ok};
+#[cfg(feature = "tracing")]
+use tracing::Span;
+
+/// Provider solving simple image captchas locally by template matching
+///
+/// Only [`ImageToText`](crate::ImageToText) is supported. Recognition runs in
+/// `create_task`, which always returns [`TaskCreationOutcome::Ready`] (the
+/// same way Capsolver answers image tasks), so nothing is ever polled.
+///
+/// The engine suits clean captchas with separated characters in a fixed
+/// font. Anything it cannot read with enough confidence fails with a
+/// [`LocalOcrError`], so callers can hand the task to a paid provider.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::{
+///     CaptchaSolverService, CaptchaSolverServiceTrait, ImageToText,
+///     localocr::LocalOcrProvider,
+/// };
+///
+/// let provider = LocalOcrProvider::new();
+/// let service = CaptchaSolverService::new(provider);
+///
+/// let task = ImageToText::from_bytes(image_bytes).numbers_only().with_min_length(4);
+/// let solution = service.solve_captcha(task).await?;
+/// println!("Text: {}", solution.text());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalOcrProvider {
+    templates: Arc<TemplateSet>,
+    min_confidence: f32,
+    next_id: Arc<AtomicU64>,
+}
+
+/// Builder for configuring a [`LocalOcrProvider`]
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::localocr::{LocalOcrProvider, TemplateSet};
+///
+/// let provider = LocalOcrProvider::builder()
+///     .templates(TemplateSet::new().with_sample("7kq2", &sample)?)
+///     .min_confidence(0.8)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalOcrProviderBuilder {
+    templates: TemplateSet,
+    min_confidence: f32,
+}
+
+impl Default for LocalOcrProviderBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalOcrProviderBuilder {
+    /// Create a new builder with the built-in digit templates
+    pub fn new() -> Self {
+        Self {
+            templates: TemplateSet::digits(),
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
+        }
+    }
+
+    /// Set the templates to match glyphs against
+    ///
+    /// Default: [`TemplateSet::digits`]
+    pub fn templates(mut self, templates: TemplateSet) -> Self {
+        self.templates = templates;
+        self
+    }
+
+    /// Set the minimum similarity (`0.0..=1.0`) each glyph must reach
+    ///
+    /// Default: 0.7
+    pub fn min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Build the [`LocalOcrProvider`]
+    pub fn build(self) -> LocalOcrProvider {
+        LocalOcrProvider {
+            templates: Arc::new(self.templates),
+            min_confidence: self.min_confidence,
+            next_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+}
+
+impl Default for LocalOcrProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalOcrProvider {
+    /// Create a new local OCR provider with the built-in digit templates
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Create a builder for configuring the provider
+    pub fn builder() -> LocalOcrProviderBuilder {
+        LocalOcrProviderBuilder::new()
+    }
+
+    /// Get the templates glyphs are matched against
+    pub fn templates(&self) -> &TemplateSet {
+        &self.templates
+    }
+
+    /// Recognize the text of a base64 encoded image under the given constraints.
+    fn recognize(&self, body: &str, constraints: Constraints) -> Result<String> {
+        let bytes = STANDARD
+            .decode(body.trim())
+            .map_err(LocalOcrError::InvalidImage)?;
+        let bitmap = Bitmap::decode(&bytes)?;
+        self.templates
+            .recognize(&bitmap, constraints, self.min_confidence)
+    }
+}
+
+impl Provider for LocalOcrProvider {
+    type Solution = LocalOcrSolution;
+    type Error = LocalOcrError;
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task",
+            target = "captcha.localocr",
+            skip_all,
+            fields(captcha.task_type, task_id = tracing::field::Empty)
+        )
+    )]
+    async fn create_task(&self, task: CaptchaTask) -> Result<TaskCreationOutcome<Self::Solution>> {
+        #[cfg(feature = "tracing")]
+        Span::current().record("captcha.task_type", task.to_string());
+
+        let result = image_task(task)
+            .map_err(LocalOcrError::UnsupportedTask)
+            .and_then(|task| self.recognize(&task.body, Constraints::from(&task)))
+            .map(|text| {
+                let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+                let task_id = TaskId::from(format!("local-{id}"));
+
+                #[cfg(feature = "tracing")]
+                Span::current().record("task_id", task_id.as_ref());
+
+                TaskCreationOutcome::Ready {
+                    task_id,
+                    solution: LocalOcrSolution { text },
+                }
+            });
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => set_span_ok(),
+            Err(e) => record_error(e, "LocalOcr"),
+        }
+
+        result
+    }
+
+    /// Tasks are answered in `create_task`, so there is never anything to poll.
+    async fn get_task_result(&self, task_id: &TaskId) -> Result<Option<Self::Solution>> {
+        Err(LocalOcrError::UnknownTask(task_id.clone()))
+    }
+}
@@ -0,0 +1,260 @@
+//! Tests for the local OCR provider.
+
+use super::engine::{DIGITS, TemplateSet};
+use super::errors::LocalOcrError;
+use super::provider::LocalOcrProvider;
+use crate::errors::RetryableError;
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::{ImageToText, Turnstile};
+use crate::utils::types::TaskId;
+use image::{ImageFormat, Luma, Rgba, RgbaImage};
+use std::io::Cursor;
+
+/// Block letters used to build sample templates
+#[rustfmt::skip]
+const LETTERS: [(char, [&str; 7]); 3] = [
+    ('A', [".###.", "#...#", "#...#", "#####", "#...#", "#...#", "#...#"]),
+    ('B', ["####.", "#...#", "#...#", "####.", "#...#", "#...#", "####."]),
+    ('C', [".###.", "#...#", "#....", "#....", "#....", "#...#", ".###."]),
+];
+
+fn glyph(ch: char) -> [&'static str; 7] {
+    DIGITS
+        .iter()
+        .chain(LETTERS.iter())
+        .find(|(c, _)| *c == ch)
+        .map(|(_, rows)| *rows)
+        .expect("glyph in test font")
+}
+
+/// Render `text` with the test font, `scale` pixels per font pixel.
+fn render(text: &str, scale: u32, ink: [u8; 4], paper: [u8; 4]) -> Vec<u8> {
+    let glyphs: Vec<_> = text.chars().map(glyph).collect();
+    let margin = 2 * scale;
+    let width = margin * 2 + glyphs.len() as u32 * 7 * scale;
+    let height = margin * 2 + 7 * scale;
+
+    let image = RgbaImage::from_fn(width, height, |x, y| {
+        let (x, y) = (x as i64 - margin as i64, y as i64 - margin as i64);
+        if x < 0 || y < 0 {
+            return Rgba(paper);
+        }
+        let (cell, fx, fy) = (
+            x as u32 / (7 * scale),
+            (x as u32 % (7 * scale)) / scale,
+            y as u32 / scale,
+        );
+        let on = glyphs
+            .get(cell as usize)
+            .and_then(|rows| rows.get(fy as usize))
+            .is_some_and(|row| row.as_bytes().get(fx as usize) == Some(&b'#'));
+        Rgba(if on { ink } else { paper })
+    });
+
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .unwrap();
+    bytes
+}
+
+const BLACK: [u8; 4] = [20, 20, 20, 255];
+const WHITE: [u8; 4] = [240, 240, 240, 255];
+
+async fn solve(provider: &LocalOcrProvider, task: ImageToText) -> Result<String, LocalOcrError> {
+    match provider.create_task(task.into()).await? {
+        TaskCreationOutcome::Ready { solution, .. } => Ok(solution.text),
+        TaskCreationOutcome::Pending(_) => panic!("Expected Ready outcome"),
+    }
+}
+
+#[tokio::test]
+async fn test_recognize_digits() {
+    let provider = LocalOcrProvider::new();
+    let task = ImageToText::from_bytes(render("4271", 4, BLACK, WHITE)).numbers_only();
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert!(outcome.is_ready());
+    assert!(outcome.task_id().as_ref().starts_with("local-"));
+    assert_eq!(outcome.into_solution().unwrap().text, "4271");
+}
+
+#[tokio::test]
+async fn test_recognize_all_digits_at_small_scale() {
+    let provider = LocalOcrProvider::new();
+    let task = ImageToText::from_bytes(render("0123456789", 2, BLACK, WHITE));
+
+    assert_eq!(solve(&provider, task).await.unwrap(), "0123456789");
+}
+
+#[tokio::test]
+async fn test_recognize_light_text_on_dark_background() {
+    let provider = LocalOcrProvider::new();
+    let task = ImageToText::from_bytes(render("905", 3, WHITE, BLACK));
+
+    assert_eq!(solve(&provider, task).await.unwrap(), "905");
+}
+
+#[tokio::test]
+async fn test_noise_specks_are_ignored() {
+    let mut image = image::load_from_memory(&render("38", 4, BLACK, WHITE))
+        .unwrap()
+        .to_luma8();
+    // A single dark pixel in the left margin
+    image.put_pixel(2, 2, Luma([0]));
+    let mut bytes = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .unwrap();
+
+    let provider = LocalOcrProvider::new();
+    let task = ImageToText::from_bytes(bytes);
+    assert_eq!(solve(&provider, task).await.unwrap(), "38");
+}
+
+#[tokio::test]
+async fn test_length_constraints() {
+    let provider = LocalOcrProvider::new();
+    let image = render("4271", 4, BLACK, WHITE);
+
+    let err = solve(
+        &provider,
+        ImageToText::from_bytes(image.clone()).with_min_length(5),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        LocalOcrError::LengthOutOfRange {
+            found: 4,
+            min: 5,
+            max: 0
+        }
+    ));
+
+    let err = solve(&provider, ImageToText::from_bytes(image).with_max_length(3))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        LocalOcrError::LengthOutOfRange { found: 4, .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_sample_templates_and_case() {
+    let templates = TemplateSet::digits()
+        .with_sample("ABC", &render("ABC", 4, BLACK, WHITE))
+        .unwrap();
+    assert_eq!(templates.len(), 13);
+
+    let provider = LocalOcrProvider::builder().templates(templates).build();
+    let image = render("CAB", 3, BLACK, WHITE);
+
+    let task = ImageToText::from_bytes(image.clone()).case_sensitive();
+    assert_eq!(solve(&provider, task).await.unwrap(), "CAB");
+
+    let task = ImageToText::from_bytes(image.clone()).letters_only();
+    assert_eq!(solve(&provider, task).await.unwrap(), "cab");
+
+    let task = ImageToText::from_bytes(image).numbers_or_letters();
+    assert_eq!(solve(&provider, task).await.unwrap(), "cab");
+}
+
+#[tokio::test]
+async fn test_numeric_constraint_without_matching_templates() {
+    let provider = LocalOcrProvider::new();
+    let task = ImageToText::from_bytes(render("42", 4, BLACK, WHITE)).letters_only();
+
+    let err = solve(&provider, task).await.unwrap_err();
+    assert!(matches!(
+        err,
+        LocalOcrError::LowConfidence { position: 0, .. }
+    ));
+    assert!(!err.is_retryable());
+    assert!(!err.should_retry_operation());
+}
+
+#[tokio::test]
+async fn test_numbers_and_letters_required() {
+    let provider = LocalOcrProvider::new();
+    let mut task = ImageToText::from_bytes(render("42", 4, BLACK, WHITE));
+    task.numeric = 4;
+
+    let err = solve(&provider, task).await.unwrap_err();
+    assert!(matches!(err, LocalOcrError::ConstraintViolated(_)));
+}
+
+#[tokio::test]
+async fn test_unrecognized_glyph_low_confidence() {
+    let templates = TemplateSet::new()
+        .with_sample("A", &render("A", 4, BLACK, WHITE))
+        .unwrap();
+    let provider = LocalOcrProvider::builder()
+        .templates(templates)
+        .min_confidence(0.9)
+        .build();
+    let task = ImageToText::from_bytes(render("1", 4, BLACK, WHITE));
+
+    let err = solve(&provider, task).await.unwrap_err();
+    assert!(matches!(err, LocalOcrError::LowConfidence { .. }));
+}
+
+#[tokio::test]
+async fn test_unsupported_options_and_tasks() {
+    let provider = LocalOcrProvider::new();
+
+    let task = ImageToText::from_bytes(render("1", 4, BLACK, WHITE)).with_module("common");
+    match provider.create_task(task.into()).await.unwrap_err() {
+        LocalOcrError::UnsupportedTask(error) => {
+            assert_eq!(error.provider, "LocalOcr");
+            assert_eq!(error.unsupported_fields, vec!["module"]);
+        }
+        _ => panic!("Expected UnsupportedTask error"),
+    }
+
+    let task = Turnstile::new("https://example.com", "site-key");
+    match provider.create_task(task.into()).await.unwrap_err() {
+        LocalOcrError::UnsupportedTask(error) => assert_eq!(error.task_type, "Turnstile"),
+        _ => panic!("Expected UnsupportedTask error"),
+    }
+}
+
+#[tokio::test]
+async fn test_invalid_images() {
+    let provider = LocalOcrProvider::new();
+
+    let err = solve(&provider, ImageToText::from_base64("not base64!"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, LocalOcrError::InvalidImage(_)));
+
+    let err = solve(&provider, ImageToText::from_bytes(b"not an image"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, LocalOcrError::DecodeImage(_)));
+}
+
+#[tokio::test]
+async fn test_get_task_result_unknown() {
+    let provider = LocalOcrProvider::new();
+    let err = provider
+        .get_task_result(&TaskId::from("local-1"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, LocalOcrError::UnknownTask(_)));
+}
+
+#[test]
+fn test_sample_mismatch() {
+    let err = TemplateSet::new()
+        .with_sample("12", &render("123", 4, BLACK, WHITE))
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        LocalOcrError::SampleMismatch {
+            expected: 2,
+            found: 3
+        }
+    ));
+}
@@ -0,0 +1,97 @@
+//! Task and solution types for the local OCR provider.
+
+use crate::errors::UnsupportedTaskError;
+use crate::solutions::ImageToTextSolution;
+use crate::tasks::{CaptchaTask, ImageToText};
+
+/// Provider name reported in [`UnsupportedTaskError`]
+pub(crate) const PROVIDER_NAME: &str = "LocalOcr";
+
+/// Local OCR solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalOcrSolution {
+    /// Recognized text
+    pub text: String,
+}
+
+impl crate::solutions::ProviderSolution for LocalOcrSolution {}
+
+impl LocalOcrSolution {
+    /// Get the recognized text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Convert into an image to text solution
+    pub fn into_image_to_text(self) -> ImageToTextSolution {
+        ImageToTextSolution::new(self.text)
+    }
+}
+
+/// Accept an [`ImageToText`] task whose options can be honored locally.
+///
+/// `numeric`, `min_length`, `max_length` and `case_sensitive` constrain the
+/// output; options that need a human or a server-side model are rejected.
+pub(crate) fn image_task(task: CaptchaTask) -> Result<ImageToText, UnsupportedTaskError> {
+    let task = match task {
+        CaptchaTask::ImageToText(task) => task,
+        other => return Err(unsupported(&other)),
+    };
+
+    let mut unsupported = Vec::new();
+    if task.module.is_some() {
+        unsupported.push("module");
+    }
+    if task.phrase {
+        unsupported.push("phrase");
+    }
+    if task.math {
+        unsupported.push("math");
+    }
+    if task.comment.is_some() {
+        unsupported.push("comment");
+    }
+    if task.img_instructions.is_some() {
+        unsupported.push("img_instructions");
+    }
+    if !unsupported.is_empty() {
+        return Err(UnsupportedTaskError::unsupported_fields(
+            "ImageToText",
+            PROVIDER_NAME,
+            unsupported,
+        ));
+    }
+
+    Ok(task)
+}
+
+fn unsupported(task: &CaptchaTask) -> UnsupportedTaskError {
+    let task_type = match task {
+        CaptchaTask::ImageToText(_) => "ImageToText",
+        CaptchaTask::ReCaptchaV2(_) => "ReCaptchaV2",
+        CaptchaTask::ReCaptchaV3(_) => "ReCaptchaV3",
+        CaptchaTask::Turnstile(_) => "Turnstile",
+        CaptchaTask::TurnstileChallenge(_) => "TurnstileChallenge",
+        CaptchaTask::TurnstileWaitRoom(_) => "TurnstileWaitRoom",
+        CaptchaTask::CloudflareChallenge(_) => "CloudflareChallenge",
+        CaptchaTask::GridClassification(_) => "GridClassification",
+        CaptchaTask::Rotate(_) => "Rotate",
+        CaptchaTask::AudioToText(_) => "AudioToText",
+        CaptchaTask::MtCaptcha(_) => "MtCaptcha",
+        CaptchaTask::FriendlyCaptcha(_) => "FriendlyCaptcha",
+        CaptchaTask::Cutcaptcha(_) => "Cutcaptcha",
+        CaptchaTask::YandexSmartCaptcha(_) => "YandexSmartCaptcha",
+        CaptchaTask::Tencent(_) => "Tencent",
+        CaptchaTask::Lemin(_) => "Lemin",
+        CaptchaTask::KeyCaptcha(_) => "KeyCaptcha",
+        CaptchaTask::Capy(_) => "Capy",
+        CaptchaTask::Imperva(_) => "Imperva",
+        CaptchaTask::Kasada(_) => "Kasada",
+        CaptchaTask::Prosopo(_) => "Prosopo",
+        CaptchaTask::CaptchaFox(_) => "CaptchaFox",
+        CaptchaTask::Altcha(_) => "Altcha",
+        CaptchaTask::TextQuestion(_) => "TextQuestion",
+        CaptchaTask::HCaptcha(_) => "HCaptcha",
+    };
+    UnsupportedTaskError::new(task_type, PROVIDER_NAME)
+}
@@ -0,0 +1,64 @@
+//! Error types for the mock provider.
+
+use crate::errors::RetryableError;
+use crate::utils::types::TaskId;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MockError {
+    /// Error scripted with [`MockOutcome::error`](super::MockOutcome::error)
+    /// or [`MockOutcome::error_after`](super::MockOutcome::error_after)
+    #[error("Scripted error: {message}")]
+    Scripted {
+        message: String,
+        retryable: bool,
+        retry_operation: bool,
+    },
+
+    /// `create_task` was called with no scripted outcome left
+    #[error("No scripted outcome left for create_task")]
+    Exhausted,
+
+    /// `get_task_result` was called with an ID the mock never issued
+    #[error("Unknown task: {0}")]
+    UnknownTask(TaskId),
+}
+
+impl MockError {
+    /// Error that is retried immediately and also restarts the operation
+    pub fn transient(message: impl Into<String>) -> Self {
+        Self::scripted(message, true, true)
+    }
+
+    /// Error that is not retried at all
+    pub fn permanent(message: impl Into<String>) -> Self {
+        Self::scripted(message, false, false)
+    }
+
+    /// Error with explicit [`RetryableError`] answers
+    pub fn scripted(message: impl Into<String>, retryable: bool, retry_operation: bool) -> Self {
+        Self::Scripted {
+            message: message.into(),
+            retryable,
+            retry_operation,
+        }
+    }
+}
+
+impl RetryableError for MockError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            MockError::Scripted { retryable, .. } => *retryable,
+            MockError::Exhausted | MockError::UnknownTask(_) => false,
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            MockError::Scripted {
+                retry_operation, ..
+            } => *retry_operation,
+            MockError::Exhausted | MockError::UnknownTask(_) => false,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! # Mock Provider
+//!
+//! Scriptable in-memory [`Provider`](crate::Provider) for unit-testing code
+//! that calls [`CaptchaSolverService`](crate::CaptchaSolverService), without
+//! network access or a solver account.
+//!
+//! Queue one [`MockOutcome`] per expected `create_task` call:
+//!
+//! | Outcome | Behavior |
+//! |---------|----------|
+//! | [`MockOutcome::ready`] | Solved immediately, no polling |
+//! | [`MockOutcome::pending`] | `None` for N polls, then the solution |
+//! | [`MockOutcome::error`] | `create_task` fails with a [`MockError`] |
+//! | [`MockOutcome::error_after`] | `None` for N polls, then the poll fails |
+//! | [`MockOutcome::never_resolves`] | `None` forever |
+//!
+//! [`MockError::transient`], [`MockError::permanent`] and
+//! [`MockError::scripted`] control how the service and
+//! [`CaptchaRetryableProvider`](crate::CaptchaRetryableProvider) retry.
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::mock::{MockOutcome, MockProvider};
+//! use captcha_solvers::{
+//!     CaptchaSolverService, CaptchaSolverServiceConfig, CaptchaSolverServiceTrait, Turnstile,
+//! };
+//! use std::time::Duration;
+//!
+//! #[tokio::test(start_paused = true)]
+//! async fn times_out() {
+//!     let provider = MockProvider::new().then(MockOutcome::never_resolves());
+//!     let config = CaptchaSolverServiceConfig::builder()
+//!         .timeout(Duration::from_secs(120))
+//!         .build();
+//!     let service = CaptchaSolverService::with_config(provider.clone(), config);
+//!
+//!     // Completes instantly: the paused clock auto-advances through the polls
+//!     let err = service
+//!         .solve_captcha(Turnstile::new("https://example.com", "site-key"))
+//!         .await
+//!         .unwrap_err();
+//!     assert!(err.is_timeout());
+//!     assert_eq!(provider.received_tasks().len(), 1);
+//! }
+//! ```
+
+mod errors;
+mod provider;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+// Errors
+pub use errors::MockError;
+
+// Provider
+pub use provider::MockProvider;
+
+// Outcomes and solutions
+pub use types::{MockOutcome, MockSolution};
@@ -0,0 +1,165 @@
+//! Scriptable in-memory provider implementation.
+
+use super::errors::MockError;
+use super::types::{MockOutcome, MockSolution};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// What a pending task answers once its polls run out
+#[derive(Debug, Clone)]
+enum Resolution {
+    Solution(MockSolution),
+    Error(MockError),
+    Never,
+}
+
+/// A task created by the mock and not yet resolved
+#[derive(Debug, Clone)]
+struct PendingTask {
+    remaining_polls: u32,
+    resolution: Resolution,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    outcomes: VecDeque<MockOutcome>,
+    received: Vec<CaptchaTask>,
+    pending: HashMap<TaskId, PendingTask>,
+    polls: usize,
+    next_id: u64,
+}
+
+/// In-memory provider with a scripted queue of outcomes
+///
+/// Each `create_task` call takes the next [`MockOutcome`] from the queue and
+/// records the [`CaptchaTask`] it received. Pending outcomes are resolved by
+/// `get_task_result` after the scripted number of polls. Calling
+/// `create_task` with an empty queue fails with [`MockError::Exhausted`].
+///
+/// Clones share the queue and the recorded tasks, so keep a clone to assert
+/// on after handing the provider to a service. The mock never sleeps; with
+/// `tokio::time::pause` the service's poll intervals and timeout run on the
+/// paused clock.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::mock::{MockError, MockOutcome, MockProvider};
+/// use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, Turnstile};
+///
+/// let provider = MockProvider::new()
+///     .then(MockOutcome::pending(2, "token"))
+///     .then(MockOutcome::error(MockError::permanent("ERROR_ZERO_BALANCE")));
+/// let service = CaptchaSolverService::new(provider.clone());
+///
+/// let solution = service.solve_captcha(Turnstile::new("https://example.com", "key")).await?;
+/// assert_eq!(solution.token(), "token");
+/// assert_eq!(provider.received_tasks().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockProvider {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockProvider {
+    /// Create a mock provider with an empty queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an outcome to the queue (builder style)
+    pub fn then(self, outcome: MockOutcome) -> Self {
+        self.push(outcome);
+        self
+    }
+
+    /// Append an outcome to the queue
+    ///
+    /// Works on a shared clone, e.g. after the provider was moved into a service.
+    pub fn push(&self, outcome: MockOutcome) {
+        self.state().outcomes.push_back(outcome);
+    }
+
+    /// Tasks received by `create_task`, in call order
+    pub fn received_tasks(&self) -> Vec<CaptchaTask> {
+        self.state().received.clone()
+    }
+
+    /// Number of `get_task_result` calls so far
+    pub fn poll_count(&self) -> usize {
+        self.state().polls
+    }
+
+    /// Number of outcomes left in the queue
+    pub fn remaining_outcomes(&self) -> usize {
+        self.state().outcomes.len()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // A panicking test thread must not hide the recorded state from others
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Provider for MockProvider {
+    type Solution = MockSolution;
+    type Error = MockError;
+
+    async fn create_task(
+        &self,
+        task: CaptchaTask,
+    ) -> Result<TaskCreationOutcome<Self::Solution>, Self::Error> {
+        let mut state = self.state();
+        state.received.push(task);
+
+        let outcome = state.outcomes.pop_front().ok_or(MockError::Exhausted)?;
+        state.next_id += 1;
+        let task_id = TaskId::from(format!("mock-{}", state.next_id));
+
+        let (remaining_polls, resolution) = match outcome {
+            MockOutcome::Ready(solution) => {
+                return Ok(TaskCreationOutcome::Ready { task_id, solution });
+            }
+            MockOutcome::Error(error) => return Err(error),
+            MockOutcome::Pending { polls, solution } => (polls, Resolution::Solution(solution)),
+            MockOutcome::ErrorAfter { polls, error } => (polls, Resolution::Error(error)),
+            MockOutcome::NeverResolves => (0, Resolution::Never),
+        };
+
+        state.pending.insert(
+            task_id.clone(),
+            PendingTask {
+                remaining_polls,
+                resolution,
+            },
+        );
+        Ok(TaskCreationOutcome::Pending(task_id))
+    }
+
+    async fn get_task_result(
+        &self,
+        task_id: &TaskId,
+    ) -> Result<Option<Self::Solution>, Self::Error> {
+        let mut state = self.state();
+        state.polls += 1;
+
+        let pending = state
+            .pending
+            .get_mut(task_id)
+            .ok_or_else(|| MockError::UnknownTask(task_id.clone()))?;
+
+        if pending.remaining_polls > 0 {
+            pending.remaining_polls -= 1;
+            return Ok(None);
+        }
+
+        match &pending.resolution {
+            Resolution::Solution(solution) => Ok(Some(solution.clone())),
+            Resolution::Error(error) => Err(error.clone()),
+            Resolution::Never => Ok(None),
+        }
+    }
+}
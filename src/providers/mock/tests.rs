@@ -0,0 +1,165 @@
+//! Tests for the mock provider.
+
+use super::errors::MockError;
+use super::provider::MockProvider;
+use super::types::MockOutcome;
+use crate::errors::RetryableError;
+use crate::providers::CaptchaRetryableProvider;
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::service::{CaptchaSolverService, CaptchaSolverServiceConfig, CaptchaSolverServiceTrait};
+use crate::tasks::{CaptchaTask, ImageToText, Turnstile};
+use crate::utils::types::TaskId;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+fn service(provider: &MockProvider) -> CaptchaSolverService<MockProvider> {
+    let config = CaptchaSolverServiceConfig::builder()
+        .timeout(Duration::from_secs(120))
+        .poll_interval(Duration::from_secs(5))
+        .build();
+    CaptchaSolverService::with_config(provider.clone(), config)
+}
+
+fn task() -> Turnstile {
+    Turnstile::new("https://example.com", "site-key")
+}
+
+#[tokio::test]
+async fn test_ready_records_task() {
+    let provider = MockProvider::new().then(MockOutcome::ready("token"));
+
+    let solution = service(&provider).solve_captcha(task()).await.unwrap();
+    assert_eq!(solution.into_turnstile().token(), Some("token"));
+
+    let received = provider.received_tasks();
+    assert_eq!(received.len(), 1);
+    match &received[0] {
+        CaptchaTask::Turnstile(t) => assert_eq!(t.website_key, "site-key"),
+        other => panic!("Unexpected task: {other:?}"),
+    }
+    assert_eq!(provider.poll_count(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_pending_then_solution() {
+    let provider = MockProvider::new().then(MockOutcome::pending(3, "polled"));
+
+    let solution = service(&provider).solve_captcha(task()).await.unwrap();
+    assert_eq!(solution.token(), "polled");
+    assert_eq!(provider.poll_count(), 4);
+}
+
+#[tokio::test]
+async fn test_create_error_retryability() {
+    let provider = MockProvider::new()
+        .then(MockOutcome::error(MockError::permanent(
+            "ERROR_ZERO_BALANCE",
+        )))
+        .then(MockOutcome::error(MockError::scripted("busy", false, true)));
+    let service = service(&provider);
+
+    let err = service.solve_captcha(task()).await.unwrap_err();
+    assert!(!err.is_retryable());
+    assert!(!err.should_retry_operation());
+
+    let err = service.solve_captcha(task()).await.unwrap_err();
+    assert!(!err.is_retryable());
+    assert!(err.should_retry_operation());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_error_after_polls() {
+    let provider = MockProvider::new().then(MockOutcome::error_after(
+        2,
+        MockError::permanent("ERROR_CAPTCHA_UNSOLVABLE"),
+    ));
+
+    let err = service(&provider).solve_captcha(task()).await.unwrap_err();
+    assert!(err.to_string().contains("ERROR_CAPTCHA_UNSOLVABLE"));
+    assert_eq!(provider.poll_count(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_never_resolves_times_out_on_paused_clock() {
+    let provider = MockProvider::new().then(MockOutcome::never_resolves());
+
+    let started = std::time::Instant::now();
+    let err = service(&provider).solve_captcha(task()).await.unwrap_err();
+
+    assert!(err.is_timeout());
+    assert!(provider.poll_count() >= 24);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_never_resolves_cancelled() {
+    let provider = MockProvider::new().then(MockOutcome::never_resolves());
+    let token = CancellationToken::new();
+
+    let cancel = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        cancel.cancel();
+    });
+
+    let err = service(&provider)
+        .solve_captcha_cancellable(task(), token)
+        .await
+        .unwrap_err();
+    assert!(err.is_cancelled());
+}
+
+#[tokio::test]
+async fn test_queue_order_and_shared_state() {
+    let provider = MockProvider::new().then(MockOutcome::ready("first"));
+    let service = service(&provider);
+    provider.push(MockOutcome::ready("second"));
+    assert_eq!(provider.remaining_outcomes(), 2);
+
+    let first = service.solve_captcha(task()).await.unwrap();
+    let second = service
+        .solve_captcha(ImageToText::from_base64("aGVsbG8="))
+        .await
+        .unwrap();
+    assert_eq!(first.token(), "first");
+    assert_eq!(second.into_image_to_text().text(), "second");
+
+    let received = provider.received_tasks();
+    assert!(matches!(received[1], CaptchaTask::ImageToText(_)));
+    assert_eq!(provider.remaining_outcomes(), 0);
+}
+
+#[tokio::test]
+async fn test_exhausted_queue() {
+    let provider = MockProvider::new();
+
+    let err = provider.create_task(task().into()).await.unwrap_err();
+    assert_eq!(err, MockError::Exhausted);
+    assert!(!err.is_retryable());
+    assert_eq!(provider.received_tasks().len(), 1);
+}
+
+#[tokio::test]
+async fn test_unknown_task_id() {
+    let provider = MockProvider::new();
+
+    let err = provider
+        .get_task_result(&TaskId::from("mock-42"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, MockError::UnknownTask(_)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_transient_error_retried_by_retryable_provider() {
+    let provider = MockProvider::new()
+        .then(MockOutcome::error(MockError::transient(
+            "ERROR_NO_SLOT_AVAILABLE",
+        )))
+        .then(MockOutcome::ready("token"));
+    let retryable = CaptchaRetryableProvider::new(provider.clone());
+
+    let outcome = retryable.create_task(task().into()).await.unwrap();
+    assert!(matches!(outcome, TaskCreationOutcome::Ready { .. }));
+    assert_eq!(provider.received_tasks().len(), 2);
+}
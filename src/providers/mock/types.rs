@@ -0,0 +1,99 @@
+//! Outcome and solution types for the mock provider.
+
+use super::errors::MockError;
+use crate::solutions::{ImageToTextSolution, ReCaptchaSolution, TokenSolution, TurnstileSolution};
+
+/// Scripted result of one `create_task` call
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockOutcome {
+    /// Solved immediately (`TaskCreationOutcome::Ready`)
+    Ready(MockSolution),
+
+    /// Pending for `polls` polls, then solved
+    Pending { polls: u32, solution: MockSolution },
+
+    /// `create_task` fails with the error
+    Error(MockError),
+
+    /// Pending for `polls` polls, then the poll fails with the error
+    ErrorAfter { polls: u32, error: MockError },
+
+    /// Pending forever (for timeout and cancellation tests)
+    NeverResolves,
+}
+
+impl MockOutcome {
+    /// Solve immediately with `token`
+    pub fn ready(token: impl Into<String>) -> Self {
+        Self::Ready(MockSolution::new(token))
+    }
+
+    /// Stay pending for `polls` polls, then solve with `token`
+    pub fn pending(polls: u32, token: impl Into<String>) -> Self {
+        Self::Pending {
+            polls,
+            solution: MockSolution::new(token),
+        }
+    }
+
+    /// Fail `create_task` with `error`
+    pub fn error(error: MockError) -> Self {
+        Self::Error(error)
+    }
+
+    /// Stay pending for `polls` polls, then fail the poll with `error`
+    pub fn error_after(polls: u32, error: MockError) -> Self {
+        Self::ErrorAfter { polls, error }
+    }
+
+    /// Never produce a solution
+    pub fn never_resolves() -> Self {
+        Self::NeverResolves
+    }
+}
+
+/// Mock provider solution
+///
+/// Holds a single answer string and converts it into the shared solution
+/// type the code under test expects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockSolution {
+    /// Scripted answer (token or recognized text)
+    pub token: String,
+}
+
+impl crate::solutions::ProviderSolution for MockSolution {}
+
+impl MockSolution {
+    /// Create a solution with the given answer
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+
+    /// Get the scripted answer
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Convert into a ReCaptcha solution
+    pub fn into_recaptcha(self) -> ReCaptchaSolution {
+        ReCaptchaSolution::from_token(self.token)
+    }
+
+    /// Convert into a Turnstile solution
+    pub fn into_turnstile(self) -> TurnstileSolution {
+        TurnstileSolution::from_token(self.token)
+    }
+
+    /// Convert into an image to text solution
+    pub fn into_image_to_text(self) -> ImageToTextSolution {
+        ImageToTextSolution::new(self.token)
+    }
+
+    /// Convert into a token solution
+    pub fn into_token(self) -> TokenSolution {
+        TokenSolution::new(self.token)
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! This module contains the core [`Provider`] trait and provider implementations.
 
+mod key_pool;
 mod retryable;
 pub(crate) mod traits;
 
+pub use key_pool::{KeyPoolError, KeyPoolProvider, KeySelection, KeyStatus};
 pub use retryable::{CaptchaRetryableProvider, OnRetryCallback};
 pub use traits::{Provider, TaskCreationOutcome};
 
@@ -16,3 +18,27 @@ pub mod capmonster;
 
 #[cfg(feature = "rucaptcha")]
 pub mod rucaptcha;
+
+#[cfg(feature = "anticaptcha")]
+pub mod anticaptcha;
+
+#[cfg(feature = "twocaptcha")]
+pub mod twocaptcha;
+
+#[cfg(feature = "legacy")]
+pub mod legacy;
+
+#[cfg(feature = "generic")]
+pub mod generic;
+
+#[cfg(feature = "deathbycaptcha")]
+pub mod deathbycaptcha;
+
+#[cfg(feature = "localocr")]
+pub mod localocr;
+
+#[cfg(feature = "mock")]
+pub mod mock;
+
+#[cfg(feature = "chaos")]
+pub mod chaos;
@@ -168,6 +168,8 @@ where
             fields(captcha.task_type)
         )
     )]
+    // Several locals only feed tracing events
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
     async fn create_task(
         &self,
         task: CaptchaTask,
@@ -223,6 +225,7 @@ where
             fields(captcha.task_id = %task_id)
         )
     )]
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
     async fn get_task_result(
         &self,
         task_id: &TaskId,
@@ -1,6 +1,6 @@
 //! Error types for the RuCaptcha provider.
 
-use crate::errors::{RetryableError, UnsupportedTaskError};
+use crate::errors::{KeyFault, RetryableError, UnsupportedTaskError};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
@@ -46,6 +46,13 @@ impl RetryableError for RucaptchaError {
             | RucaptchaError::UnsupportedTask(_) => false,
         }
     }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            RucaptchaError::Api(error) => error.error_code.key_fault(),
+            _ => None,
+        }
+    }
 }
 
 /// RuCaptcha API error codes
@@ -146,6 +153,16 @@ impl RucaptchaErrorCode {
         matches!(self, Self::NoSlotAvailable)
     }
 
+    /// Returns the API key problem this code reports, if any
+    pub fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            Self::KeyDoesNotExist | Self::AccountSuspended => Some(KeyFault::Invalid),
+            Self::ZeroBalance => Some(KeyFault::ZeroBalance),
+            Self::IpBlocked => Some(KeyFault::TemporarilyBlocked),
+            _ => None,
+        }
+    }
+
     /// Returns true if a fresh solve operation (new task) might succeed
     pub fn should_retry_operation(&self) -> bool {
         match self {
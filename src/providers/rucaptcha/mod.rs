@@ -13,9 +13,25 @@
 //! | ReCaptcha V3 Enterprise | [`ReCaptchaV3`](crate::ReCaptchaV3) with `.enterprise()` | No |
 //! | Cloudflare Turnstile | [`Turnstile`](crate::Turnstile) | No |
 //! | Image to Text | [`ImageToText`](crate::ImageToText) | No |
+//! | Grid Classification | [`GridClassification`](crate::GridClassification) | No |
+//! | Rotate | [`Rotate`](crate::Rotate) | No |
+//! | Audio to Text | [`AudioToText`](crate::AudioToText) | No |
+//! | MTCaptcha | [`MtCaptcha`](crate::MtCaptcha) | No |
+//! | Friendly Captcha | [`FriendlyCaptcha`](crate::FriendlyCaptcha) | No |
+//! | Cutcaptcha | [`Cutcaptcha`](crate::Cutcaptcha) | No |
+//! | Yandex SmartCaptcha | [`YandexSmartCaptcha`](crate::YandexSmartCaptcha) | No |
+//! | Tencent | [`Tencent`](crate::Tencent) | No |
+//! | Lemin Cropped | [`Lemin`](crate::Lemin) | No |
+//! | KeyCaptcha | [`KeyCaptcha`](crate::KeyCaptcha) | No |
+//! | Capy | [`Capy`](crate::Capy) | No |
+//! | Prosopo Procaptcha | [`Prosopo`](crate::Prosopo) | No |
+//! | CaptchaFox | [`CaptchaFox`](crate::CaptchaFox) | No |
+//! | Text Question | [`TextQuestion`](crate::TextQuestion) | No |
+//! | hCaptcha | [`HCaptcha`](crate::HCaptcha) | No |
 //!
 //! **Note**: [`CloudflareChallenge`](crate::CloudflareChallenge), [`TurnstileChallenge`](crate::TurnstileChallenge),
-//! and [`TurnstileWaitRoom`](crate::TurnstileWaitRoom) are not supported by RuCaptcha.
+//! [`TurnstileWaitRoom`](crate::TurnstileWaitRoom), [`Imperva`](crate::Imperva), [`Kasada`](crate::Kasada)
+//! and [`Altcha`](crate::Altcha) are not supported by RuCaptcha.
 //!
 //! ## Quick Start
 //!
@@ -85,6 +101,13 @@
 //! - **ReCaptcha V2/V3**: [`ReCaptchaSolution`] with `token()` method
 //! - **Turnstile**: [`TurnstileSolution`] with `token()` method
 //! - **Image to Text**: [`ImageToTextSolution`] with `text()` method
+//! - **Audio to Text**: [`AudioToTextSolution`] with `text()` method
+//! - **Text Question**: [`TextQuestionSolution`] with `text()` method
+//! - **hCaptcha**: [`HCaptchaSolution`] with `token()` and `resp_key()` methods
+//! - **MTCaptcha / Friendly Captcha / Cutcaptcha / Yandex SmartCaptcha / KeyCaptcha / Prosopo / CaptchaFox**: [`TokenSolution`] with `token()` method
+//! - **Tencent**: [`TencentSolution`] with `ticket()` and `randstr()` methods
+//! - **Lemin**: [`LeminSolution`] with `answer()` and `challenge_id()` methods
+//! - **Capy**: [`CapySolution`] with `captcha_key()`, `challenge_key()` and `answer()` methods
 //!
 //! ## Error Handling
 //!
@@ -103,8 +126,8 @@
 
 mod errors;
 mod provider;
-mod response;
-mod types;
+pub(crate) mod response;
+pub(crate) mod types;
 
 #[cfg(test)]
 mod tests;
@@ -113,10 +136,18 @@ mod tests;
 pub use errors::{RucaptchaApiError, RucaptchaError, RucaptchaErrorCode};
 
 // Provider
+#[cfg(feature = "twocaptcha")]
+pub(crate) use provider::Service;
+#[cfg(all(feature = "twocaptcha", feature = "tracing"))]
+pub(crate) use provider::ServiceSpans;
 pub use provider::{DEFAULT_API_URL, RucaptchaProvider, RucaptchaProviderBuilder};
 
 // Solutions (public API)
-pub use types::{ImageToTextSolution, ReCaptchaSolution, RucaptchaSolution, TurnstileSolution};
+pub use types::{
+    AudioToTextSolution, CapySolution, GridClassificationSolution, HCaptchaSolution,
+    ImageToTextSolution, LeminSolution, ReCaptchaSolution, RotateSolution, RucaptchaSolution,
+    TencentSolution, TextQuestionSolution, TokenSolution, TurnstileSolution,
+};
 
 // Re-export proxy types for convenience (also available at crate root)
 pub use crate::utils::proxy::{ProxyConfig, ProxyType};
@@ -4,8 +4,9 @@ use super::errors::{Result, RucaptchaError};
 use super::response::RucaptchaResponse;
 use super::types::{
     CreateTaskData, CreateTaskRequest, GetTaskData, GetTaskResultRequest, RucaptchaSolution,
-    RucaptchaTask,
+    RucaptchaTask, language_pool,
 };
+use crate::errors::UnsupportedTaskError;
 use crate::providers::traits::{Provider, TaskCreationOutcome};
 use crate::tasks::CaptchaTask;
 use crate::utils::types::TaskId;
@@ -18,11 +19,56 @@ use std::fmt::Debug;
 #[cfg(feature = "tracing")]
 use crate::utils::span_status::{record_error, set_span_ok};
 #[cfg(feature = "tracing")]
-use tracing::Span;
+use tracing::{Instrument, Span};
 
 /// Default RuCaptcha API URL
 pub const DEFAULT_API_URL: &str = "https://api.rucaptcha.com";
 
+/// Settings that differ between services running the RuCaptcha API
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Service {
+    /// Provider name reported in [`UnsupportedTaskError`](crate::UnsupportedTaskError)
+    pub(crate) name: &'static str,
+    /// API URL used when the builder is given none
+    pub(crate) default_url: &'static str,
+    /// Inner request spans, under the service's own tracing target
+    #[cfg(feature = "tracing")]
+    pub(crate) spans: ServiceSpans,
+}
+
+/// Constructors for the inner request spans of a [`Service`]
+///
+/// Tracing targets must be literals, so each service builds its own spans.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ServiceSpans {
+    pub(crate) create_task: fn() -> Span,
+    pub(crate) get_task_result: fn(&TaskId) -> Span,
+}
+
+/// The RuCaptcha service itself
+const RUCAPTCHA: Service = Service {
+    name: "RuCaptcha",
+    default_url: DEFAULT_API_URL,
+    #[cfg(feature = "tracing")]
+    spans: ServiceSpans {
+        create_task: || {
+            tracing::info_span!(
+                target: "captcha.rucaptcha",
+                "create_task_internal",
+                task_id = tracing::field::Empty
+            )
+        },
+        get_task_result: |task_id| {
+            tracing::info_span!(
+                target: "captcha.rucaptcha",
+                "get_task_result_internal",
+                task_id = %task_id
+            )
+        },
+    },
+};
+
 /// API endpoint paths
 const CREATE_TASK_PATH: &str = "createTask";
 const GET_TASK_RESULT_PATH: &str = "getTaskResult";
@@ -56,6 +102,7 @@ const GET_TASK_RESULT_PATH: &str = "getTaskResult";
 #[derive(Clone)]
 pub struct RucaptchaProvider {
     http_client: ClientWithMiddleware,
+    service: Service,
     api_key: SecretString,
     url: Url,
 }
@@ -86,6 +133,7 @@ impl Debug for RucaptchaProvider {
 /// ```
 pub struct RucaptchaProviderBuilder {
     api_key: String,
+    service: Service,
     url: Option<Url>,
     http_client: Option<ClientWithMiddleware>,
 }
@@ -95,6 +143,7 @@ impl RucaptchaProviderBuilder {
     pub fn new(api_key: impl Into<String>) -> Self {
         Self {
             api_key: api_key.into(),
+            service: RUCAPTCHA,
             url: None,
             http_client: None,
         }
@@ -108,6 +157,13 @@ impl RucaptchaProviderBuilder {
         self
     }
 
+    /// Target another service running the RuCaptcha API
+    #[cfg(feature = "twocaptcha")]
+    pub(crate) fn service(mut self, service: Service) -> Self {
+        self.service = service;
+        self
+    }
+
     /// Set a custom HTTP client with middleware
     ///
     /// Use this when you need custom middleware (e.g., tracing, retry, rate limiting).
@@ -124,7 +180,7 @@ impl RucaptchaProviderBuilder {
     pub fn build(self) -> Result<RucaptchaProvider> {
         let url = self
             .url
-            .unwrap_or_else(|| Url::parse(DEFAULT_API_URL).expect("Invalid default URL"));
+            .unwrap_or_else(|| Url::parse(self.service.default_url).expect("Invalid default URL"));
 
         let http_client = match self.http_client {
             Some(client) => client,
@@ -138,6 +194,7 @@ impl RucaptchaProviderBuilder {
 
         Ok(RucaptchaProvider {
             http_client,
+            service: self.service,
             api_key: SecretString::from(self.api_key),
             url,
         })
@@ -208,63 +265,68 @@ impl RucaptchaProvider {
     }
 
     /// Create a captcha solving task (internal)
-    #[cfg_attr(
-        feature = "tracing",
-        tracing::instrument(
-            name = "create_task_internal",
-            target = "captcha.rucaptcha",
-            skip_all,
-            fields(task_id = tracing::field::Empty)
-        )
-    )]
-    async fn create_task_internal(&self, task: RucaptchaTask) -> Result<TaskId> {
-        let request = CreateTaskRequest {
-            client_key: self.api_key(),
-            task: &task,
+    ///
+    /// Shared with the services wrapping this provider; the inner span uses
+    /// the service's tracing target.
+    pub(crate) async fn create_task_internal(&self, task: CaptchaTask) -> Result<TaskId> {
+        let create = async {
+            let language_pool = language_pool(&task);
+            let task: RucaptchaTask = task.try_into().map_err(|e: UnsupportedTaskError| {
+                RucaptchaError::UnsupportedTask(e.with_provider(self.service.name))
+            })?;
+
+            let request = CreateTaskRequest {
+                client_key: self.api_key(),
+                task: &task,
+                language_pool,
+            };
+
+            let response: RucaptchaResponse<CreateTaskData> =
+                self.post(CREATE_TASK_PATH, &request).await?;
+
+            let data = response.into_result().map_err(RucaptchaError::Api)?;
+            let task_id = TaskId::from(data.task_id);
+
+            #[cfg(feature = "tracing")]
+            Span::current().record("task_id", task_id.as_ref());
+
+            Ok(task_id)
         };
 
-        let response: RucaptchaResponse<CreateTaskData> =
-            self.post(CREATE_TASK_PATH, &request).await?;
-
-        let data = response.into_result().map_err(RucaptchaError::Api)?;
-        let task_id = TaskId::from(data.task_id);
-
         #[cfg(feature = "tracing")]
-        Span::current().record("task_id", task_id.as_ref());
+        let create = create.instrument((self.service.spans.create_task)());
 
-        Ok(task_id)
+        create.await
     }
 
     /// Get the result of a captcha task (internal)
-    #[cfg_attr(
-        feature = "tracing",
-        tracing::instrument(
-            name = "get_task_result_internal",
-            target = "captcha.rucaptcha",
-            skip_all,
-            fields(task_id = %task_id)
-        )
-    )]
-    async fn get_task_result_internal<T: DeserializeOwned + Debug>(
+    pub(crate) async fn get_task_result_internal(
         &self,
         task_id: &TaskId,
-    ) -> Result<Option<T>> {
-        let request = GetTaskResultRequest {
-            client_key: self.api_key(),
-            task_id: task_id.as_ref(),
-        };
+    ) -> Result<Option<RucaptchaSolution>> {
+        let poll = async {
+            let request = GetTaskResultRequest {
+                client_key: self.api_key(),
+                task_id: task_id.as_ref(),
+            };
+
+            let response: RucaptchaResponse<GetTaskData<RucaptchaSolution>> =
+                self.post(GET_TASK_RESULT_PATH, &request).await?;
 
-        let response: RucaptchaResponse<GetTaskData<T>> =
-            self.post(GET_TASK_RESULT_PATH, &request).await?;
+            let data = response.into_result().map_err(RucaptchaError::Api)?;
 
-        let data = response.into_result().map_err(RucaptchaError::Api)?;
+            #[cfg(feature = "tracing")]
+            if data.solution.is_some() {
+                set_span_ok();
+            }
+
+            Ok(data.solution)
+        };
 
         #[cfg(feature = "tracing")]
-        if data.solution.is_some() {
-            set_span_ok();
-        }
+        let poll = poll.instrument((self.service.spans.get_task_result)(task_id));
 
-        Ok(data.solution)
+        poll.await
     }
 }
 
@@ -285,9 +347,7 @@ impl Provider for RucaptchaProvider {
         #[cfg(feature = "tracing")]
         Span::current().record("captcha.task_type", task.to_string());
 
-        let internal_task: RucaptchaTask =
-            task.try_into().map_err(RucaptchaError::UnsupportedTask)?;
-        let result = self.create_task_internal(internal_task).await;
+        let result = self.create_task_internal(task).await;
 
         #[cfg(feature = "tracing")]
         match &result {
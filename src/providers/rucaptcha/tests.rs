@@ -4,13 +4,14 @@ use super::errors::{RucaptchaError, RucaptchaErrorCode};
 use super::provider::RucaptchaProvider;
 use super::response::RucaptchaResponse;
 use super::types::{CreateTaskData, GetTaskData, RucaptchaSolution};
+use crate::errors::KeyFault;
 use crate::providers::traits::Provider;
-use crate::tasks::Turnstile;
+use crate::tasks::{Altcha, CaptchaFox, HCaptcha, Prosopo, TextQuestion, Turnstile};
 use crate::utils::types::TaskId;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_partial_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // =============================================================================
@@ -190,6 +191,169 @@ async fn test_get_task_result_api_error() {
     }
 }
 
+#[tokio::test]
+async fn test_create_task_prosopo() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "task": {
+                "type": "ProsopoTaskProxyless",
+                "websiteURL": "https://example.com",
+                "websiteKey": "5EZVvs",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(success_create_task_response("1")))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = Prosopo::new("https://example.com", "5EZVvs");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "1");
+}
+
+#[tokio::test]
+async fn test_create_task_captcha_fox_with_proxy() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "task": {
+                "type": "CaptchaFoxTask",
+                "websiteKey": "sk_key",
+                "userAgent": "Mozilla/5.0",
+                "proxyAddress": "1.2.3.4",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(success_create_task_response("2")))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = CaptchaFox::new("https://example.com", "sk_key")
+        .with_user_agent("Mozilla/5.0")
+        .with_proxy(crate::utils::proxy::ProxyConfig::http("1.2.3.4", 8080));
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "2");
+}
+
+#[tokio::test]
+async fn test_create_task_altcha_unsupported() {
+    let provider = RucaptchaProvider::new("test_api_key").unwrap();
+    let task = Altcha::new("https://example.com", "https://example.com/challenge");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    assert!(matches!(err, RucaptchaError::UnsupportedTask(_)));
+}
+
+#[tokio::test]
+async fn test_create_task_text_question_language_pool() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "task": {
+                "type": "TextCaptchaTask",
+                "comment": "Сколько будет 3 + 4?",
+            },
+            "languagePool": "rn",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(success_create_task_response("3")))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = TextQuestion::new("Сколько будет 3 + 4?").with_lang("ru");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "3");
+}
+
+#[tokio::test]
+async fn test_get_task_result_text_question() {
+    let server = MockServer::start().await;
+    mock_get_task_result(
+        &server,
+        ready_solution_response("test-task-id", json!({ "text": "7" })),
+    )
+    .await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("test-task-id");
+
+    let solution: Option<RucaptchaSolution> = provider.get_task_result(&task_id).await.unwrap();
+    assert_eq!(solution.unwrap().into_text_question().text(), "7");
+}
+
+#[tokio::test]
+async fn test_create_task_hcaptcha_enterprise() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "task": {
+                "type": "HCaptchaTaskProxyless",
+                "websiteKey": "site-key",
+                "enterprisePayload": { "rqdata": "rq" },
+                "userAgent": "Mozilla/5.0",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(success_create_task_response("4")))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = HCaptcha::new("https://example.com", "site-key")
+        .with_rqdata("rq")
+        .with_user_agent("Mozilla/5.0");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "4");
+}
+
+#[tokio::test]
+async fn test_get_task_result_hcaptcha() {
+    let server = MockServer::start().await;
+    let solution = json!({
+        "token": "P1_eyJ0eXAi",
+        "gRecaptchaResponse": "P1_eyJ0eXAi",
+        "respKey": "E0_eyJ0eXAi",
+        "userAgent": "Mozilla/5.0"
+    });
+    mock_get_task_result(&server, ready_solution_response("test-task-id", solution)).await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("test-task-id");
+
+    let solution: Option<RucaptchaSolution> = provider.get_task_result(&task_id).await.unwrap();
+    let hcaptcha = solution.unwrap().into_hcaptcha();
+    assert_eq!(hcaptcha.token(), "P1_eyJ0eXAi");
+    assert_eq!(hcaptcha.resp_key(), Some("E0_eyJ0eXAi"));
+}
+
+#[tokio::test]
+async fn test_get_task_result_prosopo_token() {
+    let server = MockServer::start().await;
+    mock_get_task_result(
+        &server,
+        ready_solution_response(
+            "test-task-id",
+            json!({ "token": "0x00016c68747470733a2f2f" }),
+        ),
+    )
+    .await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("test-task-id");
+
+    let solution: Option<RucaptchaSolution> = provider.get_task_result(&task_id).await.unwrap();
+    let token = solution.unwrap().into_token();
+    assert_eq!(token.token(), "0x00016c68747470733a2f2f");
+}
+
 // =============================================================================
 // Builder Tests
 // =============================================================================
@@ -237,6 +401,28 @@ fn test_error_code_retryability() {
     assert!(!RucaptchaErrorCode::KeyDoesNotExist.should_retry_operation());
 }
 
+#[test]
+fn test_error_code_key_fault() {
+    assert_eq!(
+        RucaptchaErrorCode::KeyDoesNotExist.key_fault(),
+        Some(KeyFault::Invalid)
+    );
+    assert_eq!(
+        RucaptchaErrorCode::ZeroBalance.key_fault(),
+        Some(KeyFault::ZeroBalance)
+    );
+    assert_eq!(
+        RucaptchaErrorCode::AccountSuspended.key_fault(),
+        Some(KeyFault::Invalid)
+    );
+    assert_eq!(
+        RucaptchaErrorCode::IpBlocked.key_fault(),
+        Some(KeyFault::TemporarilyBlocked)
+    );
+    assert_eq!(RucaptchaErrorCode::NoSlotAvailable.key_fault(), None);
+    assert_eq!(RucaptchaErrorCode::CaptchaUnsolvable.key_fault(), None);
+}
+
 // =============================================================================
 // Response Deserialization Tests
 // =============================================================================
@@ -5,6 +5,7 @@ use crate::utils::serde_helpers::{
     deserialize_string_or_number, serialize_string_as_number_if_possible,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Display;
 
 // ============================================================================
@@ -72,7 +73,7 @@ pub enum RucaptchaTask {
         #[serde(rename = "websiteKey")]
         website_key: String,
         #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
-        enterprise_payload: Option<serde_json::Value>,
+        enterprise_payload: Option<HashMap<String, serde_json::Value>>,
         #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
         is_invisible: Option<bool>,
         #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
@@ -90,7 +91,7 @@ pub enum RucaptchaTask {
         #[serde(rename = "websiteKey")]
         website_key: String,
         #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
-        enterprise_payload: Option<serde_json::Value>,
+        enterprise_payload: Option<HashMap<String, serde_json::Value>>,
         #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
         is_invisible: Option<bool>,
         #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
@@ -187,6 +188,369 @@ pub enum RucaptchaTask {
         #[serde(rename = "imgInstructions", skip_serializing_if = "Option::is_none")]
         img_instructions: Option<String>,
     },
+
+    // -------------------------------------------------------------------------
+    // Text Captcha
+    // -------------------------------------------------------------------------
+    /// Plain-text question answered by a worker
+    TextCaptchaTask {
+        /// Question text
+        comment: String,
+    },
+
+    // -------------------------------------------------------------------------
+    // Grid Classification
+    // -------------------------------------------------------------------------
+    /// Grid image tile selection
+    GridTask {
+        /// Base64 encoded grid image
+        body: String,
+        /// Number of grid rows
+        rows: u8,
+        /// Number of grid columns
+        columns: u8,
+        /// Question shown to workers
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+        /// Base64-encoded instruction image for workers
+        #[serde(rename = "imgInstructions", skip_serializing_if = "Option::is_none")]
+        img_instructions: Option<String>,
+    },
+
+    // -------------------------------------------------------------------------
+    // Rotate
+    // -------------------------------------------------------------------------
+    /// Rotate-the-image captcha
+    RotateTask {
+        /// Base64 encoded image content
+        body: String,
+        /// Rotation step in degrees
+        #[serde(skip_serializing_if = "Option::is_none")]
+        angle: Option<u16>,
+        /// Additional instruction text for workers
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+    },
+
+    // -------------------------------------------------------------------------
+    // Audio to Text
+    // -------------------------------------------------------------------------
+    /// Audio captcha speech recognition
+    AudioTask {
+        /// Base64 encoded MP3 audio content
+        body: String,
+        /// Language code of the audio (en, fr, de, el, pt, ru)
+        lang: String,
+    },
+
+    // -------------------------------------------------------------------------
+    // MTCaptcha
+    // -------------------------------------------------------------------------
+    /// MTCaptcha using service's built-in proxy
+    MtCaptchaTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+    },
+
+    /// MTCaptcha with custom proxy
+    MtCaptchaTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // Friendly Captcha
+    // -------------------------------------------------------------------------
+    /// Friendly Captcha using service's built-in proxy
+    FriendlyCaptchaTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+    },
+
+    /// Friendly Captcha with custom proxy
+    FriendlyCaptchaTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // Cutcaptcha
+    // -------------------------------------------------------------------------
+    /// Cutcaptcha using service's built-in proxy
+    CutCaptchaTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "miseryKey")]
+        misery_key: String,
+        #[serde(rename = "dataApiKey")]
+        data_api_key: String,
+    },
+
+    /// Cutcaptcha with custom proxy
+    CutCaptchaTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "miseryKey")]
+        misery_key: String,
+        #[serde(rename = "dataApiKey")]
+        data_api_key: String,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // Yandex SmartCaptcha
+    // -------------------------------------------------------------------------
+    /// Yandex SmartCaptcha using service's built-in proxy
+    YandexSmartCaptchaTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+    },
+
+    /// Yandex SmartCaptcha with custom proxy
+    YandexSmartCaptchaTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // Tencent
+    // -------------------------------------------------------------------------
+    /// Tencent captcha using service's built-in proxy
+    TencentTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "appId")]
+        app_id: String,
+        #[serde(rename = "captchaScript", skip_serializing_if = "Option::is_none")]
+        captcha_script: Option<String>,
+    },
+
+    /// Tencent captcha with custom proxy
+    TencentTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "appId")]
+        app_id: String,
+        #[serde(rename = "captchaScript", skip_serializing_if = "Option::is_none")]
+        captcha_script: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // Lemin
+    // -------------------------------------------------------------------------
+    /// Lemin Cropped captcha using service's built-in proxy
+    LeminTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "captchaId")]
+        captcha_id: String,
+        #[serde(rename = "divId")]
+        div_id: String,
+        #[serde(
+            rename = "leminApiServerSubdomain",
+            skip_serializing_if = "Option::is_none"
+        )]
+        api_server_subdomain: Option<String>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+    },
+
+    /// Lemin Cropped captcha with custom proxy
+    LeminTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "captchaId")]
+        captcha_id: String,
+        #[serde(rename = "divId")]
+        div_id: String,
+        #[serde(
+            rename = "leminApiServerSubdomain",
+            skip_serializing_if = "Option::is_none"
+        )]
+        api_server_subdomain: Option<String>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // KeyCaptcha
+    // -------------------------------------------------------------------------
+    /// KeyCaptcha using service's built-in proxy
+    KeyCaptchaTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        s_s_c_user_id: String,
+        s_s_c_session_id: String,
+        s_s_c_web_server_sign: String,
+        s_s_c_web_server_sign2: String,
+    },
+
+    /// KeyCaptcha with custom proxy
+    KeyCaptchaTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        s_s_c_user_id: String,
+        s_s_c_session_id: String,
+        s_s_c_web_server_sign: String,
+        s_s_c_web_server_sign2: String,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // Capy
+    // -------------------------------------------------------------------------
+    /// Capy puzzle captcha using service's built-in proxy
+    CapyTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "apiServer", skip_serializing_if = "Option::is_none")]
+        api_server: Option<String>,
+    },
+
+    /// Capy puzzle captcha with custom proxy
+    CapyTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "apiServer", skip_serializing_if = "Option::is_none")]
+        api_server: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // hCaptcha
+    // -------------------------------------------------------------------------
+    /// hCaptcha using service's built-in proxy
+    HCaptchaTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
+        is_invisible: Option<bool>,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, serde_json::Value>>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+    },
+
+    /// hCaptcha with custom proxy
+    HCaptchaTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "isInvisible", skip_serializing_if = "Option::is_none")]
+        is_invisible: Option<bool>,
+        #[serde(rename = "enterprisePayload", skip_serializing_if = "Option::is_none")]
+        enterprise_payload: Option<HashMap<String, serde_json::Value>>,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // Prosopo Procaptcha
+    // -------------------------------------------------------------------------
+    /// Prosopo Procaptcha using service's built-in proxy
+    ProsopoTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+    },
+
+    /// Prosopo Procaptcha with custom proxy
+    ProsopoTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+
+    // -------------------------------------------------------------------------
+    // CaptchaFox
+    // -------------------------------------------------------------------------
+    /// CaptchaFox using service's built-in proxy
+    CaptchaFoxTaskProxyless {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+    },
+
+    /// CaptchaFox with custom proxy
+    CaptchaFoxTask {
+        #[serde(rename = "websiteURL")]
+        website_url: String,
+        #[serde(rename = "websiteKey")]
+        website_key: String,
+        #[serde(rename = "userAgent", skip_serializing_if = "Option::is_none")]
+        user_agent: Option<String>,
+        #[serde(flatten)]
+        proxy: RucaptchaProxyFields,
+    },
+}
+
+/// Worker language pool for the `createTask` request, if the task sets one.
+///
+/// Sent next to the task as the request-level `languagePool`.
+pub(crate) fn language_pool(task: &crate::tasks::CaptchaTask) -> Option<&'static str> {
+    match task {
+        crate::tasks::CaptchaTask::TextQuestion(t) => {
+            text_language_pool(t.lang.as_deref()).ok().flatten()
+        }
+        _ => None,
+    }
+}
+
+/// RuCaptcha only has English (`en`) and Russian (`rn`) worker pools.
+fn text_language_pool(
+    lang: Option<&str>,
+) -> Result<Option<&'static str>, crate::errors::UnsupportedTaskError> {
+    match lang {
+        None => Ok(None),
+        Some("en") => Ok(Some("en")),
+        Some("ru") => Ok(Some("rn")),
+        Some(_) => Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+            "TextQuestion",
+            "RuCaptcha",
+            vec!["lang"],
+        )),
+    }
 }
 
 impl Display for RucaptchaTask {
@@ -206,6 +570,32 @@ impl Display for RucaptchaTask {
             Self::TurnstileTaskProxyless { .. } => write!(f, "Turnstile"),
             Self::TurnstileTask { .. } => write!(f, "Turnstile"),
             Self::ImageToTextTask { .. } => write!(f, "ImageToText"),
+            Self::TextCaptchaTask { .. } => write!(f, "TextQuestion"),
+            Self::GridTask { .. } => write!(f, "GridClassification"),
+            Self::RotateTask { .. } => write!(f, "Rotate"),
+            Self::AudioTask { .. } => write!(f, "AudioToText"),
+            Self::MtCaptchaTaskProxyless { .. } => write!(f, "MtCaptcha"),
+            Self::MtCaptchaTask { .. } => write!(f, "MtCaptcha"),
+            Self::FriendlyCaptchaTaskProxyless { .. } => write!(f, "FriendlyCaptcha"),
+            Self::FriendlyCaptchaTask { .. } => write!(f, "FriendlyCaptcha"),
+            Self::CutCaptchaTaskProxyless { .. } => write!(f, "Cutcaptcha"),
+            Self::CutCaptchaTask { .. } => write!(f, "Cutcaptcha"),
+            Self::YandexSmartCaptchaTaskProxyless { .. } => write!(f, "YandexSmartCaptcha"),
+            Self::YandexSmartCaptchaTask { .. } => write!(f, "YandexSmartCaptcha"),
+            Self::TencentTaskProxyless { .. } => write!(f, "Tencent"),
+            Self::TencentTask { .. } => write!(f, "Tencent"),
+            Self::LeminTaskProxyless { .. } => write!(f, "Lemin"),
+            Self::LeminTask { .. } => write!(f, "Lemin"),
+            Self::KeyCaptchaTaskProxyless { .. } => write!(f, "KeyCaptcha"),
+            Self::KeyCaptchaTask { .. } => write!(f, "KeyCaptcha"),
+            Self::CapyTaskProxyless { .. } => write!(f, "Capy"),
+            Self::HCaptchaTaskProxyless { .. } => write!(f, "HCaptcha"),
+            Self::HCaptchaTask { .. } => write!(f, "HCaptcha"),
+            Self::ProsopoTaskProxyless { .. } => write!(f, "Prosopo"),
+            Self::ProsopoTask { .. } => write!(f, "Prosopo"),
+            Self::CaptchaFoxTaskProxyless { .. } => write!(f, "CaptchaFox"),
+            Self::CaptchaFoxTask { .. } => write!(f, "CaptchaFox"),
+            Self::CapyTask { .. } => write!(f, "Capy"),
         }
     }
 }
@@ -215,7 +605,11 @@ impl Display for RucaptchaTask {
 // ============================================================================
 
 // Re-export shared solution types for convenience
-pub use crate::solutions::{ImageToTextSolution, ReCaptchaSolution, TurnstileSolution};
+pub use crate::solutions::{
+    AudioToTextSolution, CapySolution, GridClassificationSolution, HCaptchaSolution,
+    ImageToTextSolution, LeminSolution, ReCaptchaSolution, RotateSolution, TencentSolution,
+    TextQuestionSolution, TokenSolution, TurnstileSolution,
+};
 
 /// RuCaptcha solution types
 #[derive(Debug, Clone, Deserialize)]
@@ -223,7 +617,17 @@ pub use crate::solutions::{ImageToTextSolution, ReCaptchaSolution, TurnstileSolu
 pub enum RucaptchaSolution {
     /// Image to text solution (must be first for untagged deserialization priority)
     ImageToText(ImageToTextSolution),
-    /// ReCaptcha solution (V2 or V3)
+    /// Grid classification solution (selected tiles)
+    GridClassification(GridClassificationSolution),
+    /// Rotate solution (angle in degrees)
+    Rotate(RotateSolution),
+    /// Tencent solution (ticket/randstr pair)
+    Tencent(TencentSolution),
+    /// Lemin solution (answer/challenge_id pair)
+    Lemin(LeminSolution),
+    /// Capy solution (captchakey/challengekey/answer)
+    Capy(CapySolution),
+    /// ReCaptcha (V2 or V3) or hCaptcha solution (same shape; see `try_into_hcaptcha`)
     ReCaptcha(ReCaptchaSolution),
     /// Turnstile solution
     Turnstile(TurnstileSolution),
@@ -315,35 +719,266 @@ impl RucaptchaSolution {
         self.try_into_image_to_text()
             .expect("Expected ImageToText solution")
     }
-}
 
-// ============================================================================
-// Internal Types (Request/Response)
-// ============================================================================
+    /// Try to extract AudioToText solution (consumes self)
+    ///
+    /// `AudioTask` results carry the recognized text as `{"token": ...}`, which
+    /// deserializes as [`Turnstile`](Self::Turnstile); a `text` field is
+    /// accepted too. Returns `Err(self)` for any other solution.
+    pub fn try_into_audio_to_text(self) -> Result<AudioToTextSolution, Box<Self>> {
+        match self {
+            Self::ImageToText(solution) => Ok(solution),
+            Self::Turnstile(solution) => TokenSolution::try_from(solution)
+                .map(|solution| AudioToTextSolution::new(solution.token))
+                .map_err(|solution| Box::new(Self::Turnstile(solution))),
+            other => Err(Box::new(other)),
+        }
+    }
 
-/// Response data from RuCaptcha createTask endpoint (success case)
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub(crate) struct CreateTaskData {
-    #[serde(deserialize_with = "deserialize_string_or_number")]
-    pub task_id: String,
-}
+    /// Extract AudioToText solution, panics if no text is present
+    ///
+    /// # Panics
+    /// Panics if the solution contains neither a token nor a text.
+    /// Use `try_into_audio_to_text()` for a non-panicking alternative.
+    pub fn into_audio_to_text(self) -> AudioToTextSolution {
+        self.try_into_audio_to_text()
+            .expect("Expected AudioToText solution")
+    }
 
-/// Response data from RuCaptcha getTaskResult endpoint (success case)
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub(crate) struct GetTaskData<T> {
-    #[allow(dead_code)]
-    pub status: String,
-    pub solution: Option<T>,
-}
+    /// Try to extract hCaptcha solution (consumes self)
+    ///
+    /// hCaptcha results deserialize as [`ReCaptcha`](Self::ReCaptcha), which
+    /// has the same shape; call this for hCaptcha tasks. Returns `Err(self)`
+    /// for any other solution.
+    pub fn try_into_hcaptcha(self) -> Result<HCaptchaSolution, Box<Self>> {
+        match self {
+            Self::ReCaptcha(solution) => Ok(solution.into()),
+            other => Err(Box::new(other)),
+        }
+    }
 
-/// Request payload for creating a new task
-#[derive(Serialize)]
+    /// Extract hCaptcha solution, panics if not hCaptcha
+    ///
+    /// # Panics
+    /// Panics if the solution is not an hCaptcha solution.
+    /// Use `try_into_hcaptcha()` for a non-panicking alternative.
+    pub fn into_hcaptcha(self) -> HCaptchaSolution {
+        self.try_into_hcaptcha()
+            .expect("Expected HCaptcha solution")
+    }
+
+    /// Try to extract TextQuestion solution (returns reference)
+    ///
+    /// Text questions share the [`ImageToTextSolution`] variant.
+    pub fn as_text_question(&self) -> Option<&TextQuestionSolution> {
+        self.as_image_to_text()
+    }
+
+    /// Try to extract TextQuestion solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a text solution, or `Err(self)` otherwise.
+    pub fn try_into_text_question(self) -> Result<TextQuestionSolution, Box<Self>> {
+        self.try_into_image_to_text()
+    }
+
+    /// Extract TextQuestion solution, panics if not a text solution
+    ///
+    /// # Panics
+    /// Panics if the solution is not a text solution.
+    /// Use `try_into_text_question()` for a non-panicking alternative.
+    pub fn into_text_question(self) -> TextQuestionSolution {
+        self.try_into_text_question()
+            .expect("Expected TextQuestion solution")
+    }
+
+    /// Try to extract GridClassification solution (returns reference)
+    pub fn as_grid_classification(&self) -> Option<&GridClassificationSolution> {
+        match self {
+            Self::GridClassification(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract GridClassification solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a GridClassification solution, or `Err(self)` otherwise.
+    pub fn try_into_grid_classification(self) -> Result<GridClassificationSolution, Box<Self>> {
+        match self {
+            Self::GridClassification(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract GridClassification solution, panics if not GridClassification
+    ///
+    /// # Panics
+    /// Panics if the solution is not a GridClassification solution.
+    /// Use `try_into_grid_classification()` for a non-panicking alternative.
+    pub fn into_grid_classification(self) -> GridClassificationSolution {
+        self.try_into_grid_classification()
+            .expect("Expected GridClassification solution")
+    }
+
+    /// Try to extract Rotate solution (returns reference)
+    pub fn as_rotate(&self) -> Option<&RotateSolution> {
+        match self {
+            Self::Rotate(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract Rotate solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a Rotate solution, or `Err(self)` otherwise.
+    pub fn try_into_rotate(self) -> Result<RotateSolution, Box<Self>> {
+        match self {
+            Self::Rotate(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract Rotate solution, panics if not Rotate
+    ///
+    /// # Panics
+    /// Panics if the solution is not a Rotate solution.
+    /// Use `try_into_rotate()` for a non-panicking alternative.
+    pub fn into_rotate(self) -> RotateSolution {
+        self.try_into_rotate().expect("Expected Rotate solution")
+    }
+
+    /// Try to extract Tencent solution (returns reference)
+    pub fn as_tencent(&self) -> Option<&TencentSolution> {
+        match self {
+            Self::Tencent(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract Tencent solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a Tencent solution, or `Err(self)` otherwise.
+    pub fn try_into_tencent(self) -> Result<TencentSolution, Box<Self>> {
+        match self {
+            Self::Tencent(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract Tencent solution, panics if not Tencent
+    ///
+    /// # Panics
+    /// Panics if the solution is not a Tencent solution.
+    /// Use `try_into_tencent()` for a non-panicking alternative.
+    pub fn into_tencent(self) -> TencentSolution {
+        self.try_into_tencent().expect("Expected Tencent solution")
+    }
+
+    /// Try to extract Lemin solution (returns reference)
+    pub fn as_lemin(&self) -> Option<&LeminSolution> {
+        match self {
+            Self::Lemin(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract Lemin solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a Lemin solution, or `Err(self)` otherwise.
+    pub fn try_into_lemin(self) -> Result<LeminSolution, Box<Self>> {
+        match self {
+            Self::Lemin(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract Lemin solution, panics if not Lemin
+    ///
+    /// # Panics
+    /// Panics if the solution is not a Lemin solution.
+    /// Use `try_into_lemin()` for a non-panicking alternative.
+    pub fn into_lemin(self) -> LeminSolution {
+        self.try_into_lemin().expect("Expected Lemin solution")
+    }
+
+    /// Try to extract Capy solution (returns reference)
+    pub fn as_capy(&self) -> Option<&CapySolution> {
+        match self {
+            Self::Capy(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// Try to extract Capy solution (consumes self)
+    ///
+    /// Returns `Ok(solution)` if this is a Capy solution, or `Err(self)` otherwise.
+    pub fn try_into_capy(self) -> Result<CapySolution, Box<Self>> {
+        match self {
+            Self::Capy(solution) => Ok(solution),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract Capy solution, panics if not Capy
+    ///
+    /// # Panics
+    /// Panics if the solution is not a Capy solution.
+    /// Use `try_into_capy()` for a non-panicking alternative.
+    pub fn into_capy(self) -> CapySolution {
+        self.try_into_capy().expect("Expected Capy solution")
+    }
+
+    /// Try to extract generic token solution (consumes self)
+    ///
+    /// Token-only responses (MTCaptcha, Friendly Captcha, Cutcaptcha, Yandex SmartCaptcha, KeyCaptcha,
+    /// Prosopo, CaptchaFox)
+    /// deserialize as the Turnstile variant. Returns `Ok(solution)` if a token is present, or `Err(self)` otherwise.
+    pub fn try_into_token(self) -> Result<TokenSolution, Box<Self>> {
+        match self {
+            Self::Turnstile(solution) => TokenSolution::try_from(solution)
+                .map_err(|solution| Box::new(Self::Turnstile(solution))),
+            other => Err(Box::new(other)),
+        }
+    }
+
+    /// Extract generic token solution, panics if no token is present
+    ///
+    /// # Panics
+    /// Panics if the solution does not contain a token.
+    /// Use `try_into_token()` for a non-panicking alternative.
+    pub fn into_token(self) -> TokenSolution {
+        self.try_into_token().expect("Expected token solution")
+    }
+}
+
+// ============================================================================
+// Internal Types (Request/Response)
+// ============================================================================
+
+/// Response data from RuCaptcha createTask endpoint (success case)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateTaskData {
+    #[serde(deserialize_with = "deserialize_string_or_number")]
+    pub task_id: String,
+}
+
+/// Response data from RuCaptcha getTaskResult endpoint (success case)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GetTaskData<T> {
+    #[allow(dead_code)]
+    pub status: String,
+    pub solution: Option<T>,
+}
+
+/// Request payload for creating a new task
+#[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct CreateTaskRequest<'a> {
     pub(crate) client_key: &'a str,
     pub(crate) task: &'a RucaptchaTask,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) language_pool: Option<&'static str>,
 }
 
 /// Request payload for getting task result
@@ -374,6 +1009,18 @@ impl TryFrom<crate::tasks::ReCaptchaV2> for RucaptchaTask {
         } else if task.enterprise_payload.is_some() {
             unsupported.push("enterprise_payload");
         }
+        if task.is_session {
+            unsupported.push("is_session");
+        }
+        if task.is_session_v3 {
+            unsupported.push("is_session_v3");
+        }
+        if task.anchor.is_some() {
+            unsupported.push("anchor");
+        }
+        if task.reload.is_some() {
+            unsupported.push("reload");
+        }
         if !unsupported.is_empty() {
             return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
                 "ReCaptchaV2",
@@ -383,9 +1030,7 @@ impl TryFrom<crate::tasks::ReCaptchaV2> for RucaptchaTask {
         }
 
         let is_invisible = if task.is_invisible { Some(true) } else { None };
-        let enterprise_payload = task
-            .enterprise_payload
-            .map(|p| serde_json::to_value(p).unwrap_or_default());
+        let enterprise_payload = task.enterprise_payload;
 
         match (task.is_enterprise, task.proxy) {
             // Enterprise with proxy
@@ -413,128 +1058,423 @@ impl TryFrom<crate::tasks::ReCaptchaV2> for RucaptchaTask {
             (false, Some(proxy)) => Ok(Self::RecaptchaV2Task {
                 website_url: task.website_url,
                 website_key: task.website_key,
-                recaptcha_data_s_value: task.recaptcha_data_s_value,
-                is_invisible,
-                user_agent: task.user_agent,
-                cookies: task.cookies,
-                api_domain: task.api_domain,
+                recaptcha_data_s_value: task.recaptcha_data_s_value,
+                is_invisible,
+                user_agent: task.user_agent,
+                cookies: task.cookies,
+                api_domain: task.api_domain,
+                proxy: proxy.into_rucaptcha_fields(),
+            }),
+            // Standard without proxy
+            (false, None) => Ok(Self::RecaptchaV2TaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                recaptcha_data_s_value: task.recaptcha_data_s_value,
+                is_invisible,
+                user_agent: task.user_agent,
+                cookies: task.cookies,
+                api_domain: task.api_domain,
+            }),
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::ReCaptchaV3> for RucaptchaTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::ReCaptchaV3) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        if task.proxy.is_some() {
+            unsupported.push("proxy");
+        }
+        if task.enterprise_payload.is_some() {
+            unsupported.push("enterprise_payload");
+        }
+        if task.is_session {
+            unsupported.push("is_session");
+        }
+        if !unsupported.is_empty() {
+            return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+                "ReCaptchaV3",
+                "RuCaptcha",
+                unsupported,
+            ));
+        }
+
+        let is_enterprise = if task.is_enterprise { Some(true) } else { None };
+        // RuCaptcha V3 uses min_score, default to 0.9 if not specified
+        let min_score = task.min_score.unwrap_or(0.9);
+
+        Ok(Self::RecaptchaV3TaskProxyless {
+            website_url: task.website_url,
+            website_key: task.website_key,
+            min_score,
+            page_action: task.page_action,
+            is_enterprise,
+            api_domain: task.api_domain,
+        })
+    }
+}
+
+impl From<crate::tasks::Turnstile> for RucaptchaTask {
+    fn from(task: crate::tasks::Turnstile) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::TurnstileTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                action: task.action,
+                data: task.cdata,
+                pagedata: task.pagedata,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::TurnstileTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                action: task.action,
+                data: task.cdata,
+                pagedata: task.pagedata,
+            },
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::CloudflareChallenge> for RucaptchaTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    /// Attempt to convert a CloudflareChallenge task to RuCaptcha format.
+    ///
+    /// # Errors
+    ///
+    /// Always returns an error because CloudflareChallenge is not supported by RuCaptcha.
+    /// This task type is only available with Capsolver.
+    fn try_from(_task: crate::tasks::CloudflareChallenge) -> Result<Self, Self::Error> {
+        Err(crate::errors::UnsupportedTaskError::new(
+            "CloudflareChallenge",
+            "RuCaptcha",
+        ))
+    }
+}
+
+impl TryFrom<crate::tasks::TextQuestion> for RucaptchaTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    /// The language itself goes into the request; see [`language_pool`].
+    fn try_from(task: crate::tasks::TextQuestion) -> Result<Self, Self::Error> {
+        text_language_pool(task.lang.as_deref())?;
+        Ok(Self::TextCaptchaTask {
+            comment: task.question,
+        })
+    }
+}
+
+impl From<crate::tasks::ImageToText> for RucaptchaTask {
+    fn from(task: crate::tasks::ImageToText) -> Self {
+        Self::ImageToTextTask {
+            body: task.body,
+            phrase: if task.phrase { Some(true) } else { None },
+            case_sensitive: if task.case_sensitive {
+                Some(true)
+            } else {
+                None
+            },
+            numeric: if task.numeric != 0 {
+                Some(task.numeric)
+            } else {
+                None
+            },
+            math: if task.math { Some(true) } else { None },
+            min_length: if task.min_length > 0 {
+                Some(task.min_length)
+            } else {
+                None
+            },
+            max_length: if task.max_length > 0 {
+                Some(task.max_length)
+            } else {
+                None
+            },
+            comment: task.comment,
+            img_instructions: task.img_instructions,
+        }
+    }
+}
+
+impl TryFrom<crate::tasks::GridClassification> for RucaptchaTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::GridClassification) -> Result<Self, Self::Error> {
+        let mut unsupported = Vec::new();
+        let body = match task.images {
+            crate::tasks::GridImages::Grid(body) => body,
+            crate::tasks::GridImages::Tiles(_) => {
+                unsupported.push("tiles");
+                String::new()
+            }
+        };
+        if task.website_url.is_some() {
+            unsupported.push("website_url");
+        }
+        if task.website_key.is_some() {
+            unsupported.push("website_key");
+        }
+        if !unsupported.is_empty() {
+            return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+                "GridClassification",
+                "RuCaptcha",
+                unsupported,
+            ));
+        }
+
+        Ok(Self::GridTask {
+            body,
+            rows: task.rows,
+            columns: task.columns,
+            comment: task.question,
+            img_instructions: task.img_instructions,
+        })
+    }
+}
+
+impl TryFrom<crate::tasks::Rotate> for RucaptchaTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::Rotate) -> Result<Self, Self::Error> {
+        if task.module.is_some() {
+            return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+                "Rotate",
+                "RuCaptcha",
+                vec!["module"],
+            ));
+        }
+
+        Ok(Self::RotateTask {
+            body: task.body,
+            angle: task.angle_step,
+            comment: task.comment,
+        })
+    }
+}
+
+impl TryFrom<crate::tasks::AudioToText> for RucaptchaTask {
+    type Error = crate::errors::UnsupportedTaskError;
+
+    fn try_from(task: crate::tasks::AudioToText) -> Result<Self, Self::Error> {
+        if task.format == Some(crate::tasks::AudioFormat::Wav) {
+            return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
+                "AudioToText",
+                "RuCaptcha",
+                vec!["format"],
+            ));
+        }
+
+        Ok(Self::AudioTask {
+            body: task.body,
+            lang: task.lang,
+        })
+    }
+}
+
+impl From<crate::tasks::HCaptcha> for RucaptchaTask {
+    fn from(task: crate::tasks::HCaptcha) -> Self {
+        let is_invisible = if task.is_invisible { Some(true) } else { None };
+        let enterprise_payload = task.enterprise_payload;
+
+        match task.proxy {
+            Some(proxy) => Self::HCaptchaTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                is_invisible,
+                enterprise_payload,
+                user_agent: task.user_agent,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::HCaptchaTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                is_invisible,
+                enterprise_payload,
+                user_agent: task.user_agent,
+            },
+        }
+    }
+}
+
+impl From<crate::tasks::Prosopo> for RucaptchaTask {
+    fn from(task: crate::tasks::Prosopo) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::ProsopoTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::ProsopoTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+            },
+        }
+    }
+}
+
+impl From<crate::tasks::CaptchaFox> for RucaptchaTask {
+    fn from(task: crate::tasks::CaptchaFox) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::CaptchaFoxTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                user_agent: task.user_agent,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::CaptchaFoxTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                user_agent: task.user_agent,
+            },
+        }
+    }
+}
+
+impl From<crate::tasks::MtCaptcha> for RucaptchaTask {
+    fn from(task: crate::tasks::MtCaptcha) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::MtCaptchaTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::MtCaptchaTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+            },
+        }
+    }
+}
+
+impl From<crate::tasks::FriendlyCaptcha> for RucaptchaTask {
+    fn from(task: crate::tasks::FriendlyCaptcha) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::FriendlyCaptchaTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
                 proxy: proxy.into_rucaptcha_fields(),
-            }),
-            // Standard without proxy
-            (false, None) => Ok(Self::RecaptchaV2TaskProxyless {
+            },
+            None => Self::FriendlyCaptchaTaskProxyless {
                 website_url: task.website_url,
                 website_key: task.website_key,
-                recaptcha_data_s_value: task.recaptcha_data_s_value,
-                is_invisible,
-                user_agent: task.user_agent,
-                cookies: task.cookies,
-                api_domain: task.api_domain,
-            }),
+            },
         }
     }
 }
 
-impl TryFrom<crate::tasks::ReCaptchaV3> for RucaptchaTask {
-    type Error = crate::errors::UnsupportedTaskError;
-
-    fn try_from(task: crate::tasks::ReCaptchaV3) -> Result<Self, Self::Error> {
-        let mut unsupported = Vec::new();
-        if task.proxy.is_some() {
-            unsupported.push("proxy");
-        }
-        if task.enterprise_payload.is_some() {
-            unsupported.push("enterprise_payload");
-        }
-        if !unsupported.is_empty() {
-            return Err(crate::errors::UnsupportedTaskError::unsupported_fields(
-                "ReCaptchaV3",
-                "RuCaptcha",
-                unsupported,
-            ));
+impl From<crate::tasks::Cutcaptcha> for RucaptchaTask {
+    fn from(task: crate::tasks::Cutcaptcha) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::CutCaptchaTask {
+                website_url: task.website_url,
+                misery_key: task.misery_key,
+                data_api_key: task.api_key,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::CutCaptchaTaskProxyless {
+                website_url: task.website_url,
+                misery_key: task.misery_key,
+                data_api_key: task.api_key,
+            },
         }
-
-        let is_enterprise = if task.is_enterprise { Some(true) } else { None };
-        // RuCaptcha V3 uses min_score, default to 0.9 if not specified
-        let min_score = task.min_score.unwrap_or(0.9);
-
-        Ok(Self::RecaptchaV3TaskProxyless {
-            website_url: task.website_url,
-            website_key: task.website_key,
-            min_score,
-            page_action: task.page_action,
-            is_enterprise,
-            api_domain: task.api_domain,
-        })
     }
 }
 
-impl From<crate::tasks::Turnstile> for RucaptchaTask {
-    fn from(task: crate::tasks::Turnstile) -> Self {
+impl From<crate::tasks::YandexSmartCaptcha> for RucaptchaTask {
+    fn from(task: crate::tasks::YandexSmartCaptcha) -> Self {
         match task.proxy {
-            Some(proxy) => Self::TurnstileTask {
+            Some(proxy) => Self::YandexSmartCaptchaTask {
                 website_url: task.website_url,
                 website_key: task.website_key,
-                action: task.action,
-                data: task.cdata,
-                pagedata: task.pagedata,
                 proxy: proxy.into_rucaptcha_fields(),
             },
-            None => Self::TurnstileTaskProxyless {
+            None => Self::YandexSmartCaptchaTaskProxyless {
                 website_url: task.website_url,
                 website_key: task.website_key,
-                action: task.action,
-                data: task.cdata,
-                pagedata: task.pagedata,
             },
         }
     }
 }
 
-impl TryFrom<crate::tasks::CloudflareChallenge> for RucaptchaTask {
-    type Error = crate::errors::UnsupportedTaskError;
+impl From<crate::tasks::Tencent> for RucaptchaTask {
+    fn from(task: crate::tasks::Tencent) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::TencentTask {
+                website_url: task.website_url,
+                app_id: task.app_id,
+                captcha_script: task.captcha_script,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::TencentTaskProxyless {
+                website_url: task.website_url,
+                app_id: task.app_id,
+                captcha_script: task.captcha_script,
+            },
+        }
+    }
+}
 
-    /// Attempt to convert a CloudflareChallenge task to RuCaptcha format.
-    ///
-    /// # Errors
-    ///
-    /// Always returns an error because CloudflareChallenge is not supported by RuCaptcha.
-    /// This task type is only available with Capsolver.
-    fn try_from(_task: crate::tasks::CloudflareChallenge) -> Result<Self, Self::Error> {
-        Err(crate::errors::UnsupportedTaskError::new(
-            "CloudflareChallenge",
-            "RuCaptcha",
-        ))
+impl From<crate::tasks::Lemin> for RucaptchaTask {
+    fn from(task: crate::tasks::Lemin) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::LeminTask {
+                website_url: task.website_url,
+                captcha_id: task.captcha_id,
+                div_id: task.div_id,
+                api_server_subdomain: task.api_server,
+                user_agent: task.user_agent,
+                proxy: proxy.into_rucaptcha_fields(),
+            },
+            None => Self::LeminTaskProxyless {
+                website_url: task.website_url,
+                captcha_id: task.captcha_id,
+                div_id: task.div_id,
+                api_server_subdomain: task.api_server,
+                user_agent: task.user_agent,
+            },
+        }
     }
 }
 
-impl From<crate::tasks::ImageToText> for RucaptchaTask {
-    fn from(task: crate::tasks::ImageToText) -> Self {
-        Self::ImageToTextTask {
-            body: task.body,
-            phrase: if task.phrase { Some(true) } else { None },
-            case_sensitive: if task.case_sensitive {
-                Some(true)
-            } else {
-                None
+impl From<crate::tasks::KeyCaptcha> for RucaptchaTask {
+    fn from(task: crate::tasks::KeyCaptcha) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::KeyCaptchaTask {
+                website_url: task.website_url,
+                s_s_c_user_id: task.user_id,
+                s_s_c_session_id: task.session_id,
+                s_s_c_web_server_sign: task.web_server_sign,
+                s_s_c_web_server_sign2: task.web_server_sign2,
+                proxy: proxy.into_rucaptcha_fields(),
             },
-            numeric: if task.numeric != 0 {
-                Some(task.numeric)
-            } else {
-                None
+            None => Self::KeyCaptchaTaskProxyless {
+                website_url: task.website_url,
+                s_s_c_user_id: task.user_id,
+                s_s_c_session_id: task.session_id,
+                s_s_c_web_server_sign: task.web_server_sign,
+                s_s_c_web_server_sign2: task.web_server_sign2,
             },
-            math: if task.math { Some(true) } else { None },
-            min_length: if task.min_length > 0 {
-                Some(task.min_length)
-            } else {
-                None
+        }
+    }
+}
+
+impl From<crate::tasks::Capy> for RucaptchaTask {
+    fn from(task: crate::tasks::Capy) -> Self {
+        match task.proxy {
+            Some(proxy) => Self::CapyTask {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                api_server: task.api_server,
+                proxy: proxy.into_rucaptcha_fields(),
             },
-            max_length: if task.max_length > 0 {
-                Some(task.max_length)
-            } else {
-                None
+            None => Self::CapyTaskProxyless {
+                website_url: task.website_url,
+                website_key: task.website_key,
+                api_server: task.api_server,
             },
-            comment: task.comment,
-            img_instructions: task.img_instructions,
         }
     }
 }
@@ -555,6 +1495,33 @@ impl TryFrom<crate::tasks::CaptchaTask> for RucaptchaTask {
             ),
             crate::tasks::CaptchaTask::CloudflareChallenge(t) => t.try_into(),
             crate::tasks::CaptchaTask::ImageToText(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::GridClassification(t) => t.try_into(),
+            crate::tasks::CaptchaTask::Rotate(t) => t.try_into(),
+            crate::tasks::CaptchaTask::AudioToText(t) => t.try_into(),
+            crate::tasks::CaptchaTask::MtCaptcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::FriendlyCaptcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Cutcaptcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::YandexSmartCaptcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Tencent(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Lemin(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::KeyCaptcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Capy(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Imperva(_) => Err(crate::errors::UnsupportedTaskError::new(
+                "Imperva",
+                "RuCaptcha",
+            )),
+            crate::tasks::CaptchaTask::Kasada(_) => Err(crate::errors::UnsupportedTaskError::new(
+                "Kasada",
+                "RuCaptcha",
+            )),
+            crate::tasks::CaptchaTask::Prosopo(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::CaptchaFox(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::TextQuestion(t) => t.try_into(),
+            crate::tasks::CaptchaTask::HCaptcha(t) => Ok(t.into()),
+            crate::tasks::CaptchaTask::Altcha(_) => Err(crate::errors::UnsupportedTaskError::new(
+                "Altcha",
+                "RuCaptcha",
+            )),
         }
     }
 }
@@ -616,6 +1583,26 @@ mod tests {
         assert!(err.unsupported_fields.contains(&"page_action"));
     }
 
+    #[test]
+    fn test_recaptcha_rejects_session_options() {
+        let task = ReCaptchaV2::new("https://example.com", "key")
+            .session_v3()
+            .with_reload("reload-data");
+        let result: Result<RucaptchaTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert!(err.unsupported_fields.contains(&"is_session_v3"));
+        assert!(err.unsupported_fields.contains(&"reload"));
+
+        let task = ReCaptchaV3::new("https://example.com", "key").session();
+        let result: Result<RucaptchaTask, _> = task.try_into();
+        assert!(
+            result
+                .unwrap_err()
+                .unsupported_fields
+                .contains(&"is_session")
+        );
+    }
+
     #[test]
     fn test_recaptcha_v2_rejects_enterprise_payload_on_non_enterprise() {
         use std::collections::HashMap;
@@ -878,4 +1865,317 @@ mod tests {
         let task: RucaptchaTask = ImageToText::from_base64("data").into();
         assert_eq!(task.to_string(), "ImageToText");
     }
+
+    #[test]
+    fn test_grid_classification_serialization() {
+        use crate::tasks::GridClassification;
+        let task: RucaptchaTask = GridClassification::from_grid_base64("grid", 4, 4)
+            .unwrap()
+            .with_question("Select all images with buses")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"GridTask\""));
+        assert!(json.contains("\"body\":\"grid\""));
+        assert!(json.contains("\"rows\":4"));
+        assert!(json.contains("\"columns\":4"));
+        assert!(json.contains("\"comment\":\"Select all images with buses\""));
+        assert_eq!(task.to_string(), "GridClassification");
+    }
+
+    #[test]
+    fn test_grid_classification_rejects_tiles() {
+        use crate::tasks::GridClassification;
+        let task = GridClassification::from_tiles_base64(vec!["a", "b", "c", "d"], 2, 2)
+            .unwrap()
+            .with_website_url("https://example.com");
+        let result: Result<RucaptchaTask, _> = task.try_into();
+        let err = result.unwrap_err();
+        assert!(err.unsupported_fields.contains(&"tiles"));
+        assert!(err.unsupported_fields.contains(&"website_url"));
+    }
+
+    #[test]
+    fn test_grid_classification_solution_deserialization() {
+        let json = r#"{"click": [1, 4]}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_grid_classification().tiles(), &[0, 3]);
+    }
+
+    #[test]
+    fn test_rotate_serialization() {
+        use crate::tasks::Rotate;
+        let task: RucaptchaTask = Rotate::from_base64("image")
+            .with_angle_step(40)
+            .unwrap()
+            .with_comment("Rotate upright")
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"RotateTask\""));
+        assert!(json.contains("\"body\":\"image\""));
+        assert!(json.contains("\"angle\":40"));
+        assert!(json.contains("\"comment\":\"Rotate upright\""));
+        assert_eq!(task.to_string(), "Rotate");
+    }
+
+    #[test]
+    fn test_rotate_rejects_module() {
+        use crate::tasks::Rotate;
+        let task = Rotate::from_base64("image").with_module("rotate_v1");
+        let result: Result<RucaptchaTask, _> = task.try_into();
+        assert!(result.unwrap_err().unsupported_fields.contains(&"module"));
+    }
+
+    #[test]
+    fn test_rotate_solution_deserialization() {
+        let json = r#"{"rotate": 120}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_rotate().angle(), 120.0);
+    }
+
+    #[test]
+    fn test_audio_to_text_serialization() {
+        use crate::tasks::AudioToText;
+        let task: RucaptchaTask = AudioToText::from_bytes(b"ID3\x04\x00", "en")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"AudioTask\""));
+        assert!(json.contains("\"body\":\"SUQzBAA=\""));
+        assert!(json.contains("\"lang\":\"en\""));
+        assert_eq!(task.to_string(), "AudioToText");
+    }
+
+    #[test]
+    fn test_audio_to_text_rejects_wav() {
+        use crate::tasks::AudioToText;
+        let task = AudioToText::from_bytes(b"RIFF\x24\x00\x00\x00WAVEfmt ", "en").unwrap();
+        let result: Result<RucaptchaTask, _> = task.try_into();
+        assert!(result.unwrap_err().unsupported_fields.contains(&"format"));
+    }
+
+    #[test]
+    fn test_audio_to_text_solution_deserialization() {
+        // Documented AudioTask result shape
+        let json = r#"{"token": "hello world"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_audio_to_text().text(), "hello world");
+
+        let json = r#"{"text": "hello world"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_audio_to_text().text(), "hello world");
+
+        let json = r#"{"gRecaptchaResponse": "token"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert!(solution.try_into_audio_to_text().is_err());
+    }
+
+    #[test]
+    fn test_mtcaptcha_serialization() {
+        use crate::tasks::MtCaptcha;
+        let task: RucaptchaTask = MtCaptcha::new("https://example.com", "MTPublic-key").into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"MtCaptchaTaskProxyless\""));
+        assert!(json.contains("\"websiteKey\":\"MTPublic-key\""));
+        assert_eq!(task.to_string(), "MtCaptcha");
+
+        let task: RucaptchaTask = MtCaptcha::new("https://example.com", "MTPublic-key")
+            .with_proxy(crate::utils::proxy::ProxyConfig::http("1.2.3.4", 8080))
+            .into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"MtCaptchaTask\""));
+        assert!(json.contains("\"proxyAddress\":\"1.2.3.4\""));
+    }
+
+    #[test]
+    fn test_friendly_captcha_serialization() {
+        use crate::tasks::FriendlyCaptcha;
+        let task: RucaptchaTask = FriendlyCaptcha::new("https://example.com", "site-key").into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"FriendlyCaptchaTaskProxyless\""));
+        assert!(json.contains("\"websiteKey\":\"site-key\""));
+        assert_eq!(task.to_string(), "FriendlyCaptcha");
+    }
+
+    #[test]
+    fn test_cutcaptcha_serialization() {
+        use crate::tasks::Cutcaptcha;
+        let task: RucaptchaTask = Cutcaptcha::new("https://example.com", "misery", "api").into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"CutCaptchaTaskProxyless\""));
+        assert!(json.contains("\"miseryKey\":\"misery\""));
+        assert!(json.contains("\"dataApiKey\":\"api\""));
+        assert_eq!(task.to_string(), "Cutcaptcha");
+    }
+
+    #[test]
+    fn test_token_solution_deserialization() {
+        let json = r#"{"token": "v1(0ae4a1a3,1,example,...)"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_token().token(), "v1(0ae4a1a3,1,example,...)");
+    }
+
+    #[test]
+    fn test_yandex_smart_captcha_serialization() {
+        use crate::tasks::YandexSmartCaptcha;
+        let task: RucaptchaTask = YandexSmartCaptcha::new("https://example.ru", "site-key")
+            .with_proxy(crate::utils::proxy::ProxyConfig::http("1.2.3.4", 8080))
+            .into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"YandexSmartCaptchaTask\""));
+        assert!(json.contains("\"websiteKey\":\"site-key\""));
+        assert!(json.contains("\"proxyAddress\":\"1.2.3.4\""));
+        assert_eq!(task.to_string(), "YandexSmartCaptcha");
+    }
+
+    #[test]
+    fn test_tencent_serialization() {
+        use crate::tasks::Tencent;
+        let task: RucaptchaTask = Tencent::new("https://example.com", "190014885").into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"TencentTaskProxyless\""));
+        assert!(json.contains("\"appId\":\"190014885\""));
+        assert!(!json.contains("captchaScript"));
+        assert_eq!(task.to_string(), "Tencent");
+    }
+
+    #[test]
+    fn test_tencent_solution_deserialization() {
+        let json = r#"{"appid": "190014885", "ret": 0, "ticket": "tr034", "randstr": "@KVN"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        let tencent = solution.into_tencent();
+        assert_eq!(tencent.ticket(), "tr034");
+        assert_eq!(tencent.randstr(), "@KVN");
+    }
+
+    #[test]
+    fn test_lemin_serialization() {
+        use crate::tasks::Lemin;
+        let task: RucaptchaTask = Lemin::new("https://example.com", "CROPPED_id", "lemin-div")
+            .with_api_server("api.leminnow.com")
+            .into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"LeminTaskProxyless\""));
+        assert!(json.contains("\"captchaId\":\"CROPPED_id\""));
+        assert!(json.contains("\"divId\":\"lemin-div\""));
+        assert!(json.contains("\"leminApiServerSubdomain\":\"api.leminnow.com\""));
+        assert_eq!(task.to_string(), "Lemin");
+    }
+
+    #[test]
+    fn test_key_captcha_serialization() {
+        use crate::tasks::KeyCaptcha;
+        let task: RucaptchaTask =
+            KeyCaptcha::new("https://example.com", "184015", "sess", "sign", "sign2").into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"KeyCaptchaTaskProxyless\""));
+        assert!(json.contains("\"s_s_c_user_id\":\"184015\""));
+        assert!(json.contains("\"s_s_c_session_id\":\"sess\""));
+        assert!(json.contains("\"s_s_c_web_server_sign\":\"sign\""));
+        assert!(json.contains("\"s_s_c_web_server_sign2\":\"sign2\""));
+        assert_eq!(task.to_string(), "KeyCaptcha");
+    }
+
+    #[test]
+    fn test_capy_serialization() {
+        use crate::tasks::Capy;
+        let task: RucaptchaTask = Capy::new("https://example.com", "PUZZLE_key")
+            .with_proxy(crate::utils::proxy::ProxyConfig::http("1.2.3.4", 8080))
+            .into();
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains("\"type\":\"CapyTask\""));
+        assert!(json.contains("\"websiteKey\":\"PUZZLE_key\""));
+        assert!(!json.contains("apiServer"));
+        assert_eq!(task.to_string(), "Capy");
+    }
+
+    #[test]
+    fn test_puzzle_solutions_deserialization() {
+        let json = r#"{"answer": "0xaxakx0xaxaax", "challenge_id": "e0348984"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_lemin().challenge_id(), "e0348984");
+
+        let json = r#"{"captchakey": "PUZZLE_Abc", "challengekey": "qHAPtn", "answer": "0xax8e"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_capy().challenge_key(), "qHAPtn");
+
+        let json = r#"{"token": "d58700b3ad2b1ccb7b2e9e4f16a8a8b0|9c8a6d1b"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            solution.into_token().token(),
+            "d58700b3ad2b1ccb7b2e9e4f16a8a8b0|9c8a6d1b"
+        );
+    }
+
+    #[test]
+    fn test_text_question_serialization() {
+        use crate::tasks::TextQuestion;
+        let task: RucaptchaTask = TextQuestion::new("What is 3 + four?").try_into().unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "TextCaptchaTask");
+        assert_eq!(json["comment"], "What is 3 + four?");
+        assert!(json.get("language_pool").is_none());
+        assert_eq!(task.to_string(), "TextQuestion");
+    }
+
+    #[test]
+    fn test_text_question_language_pool() {
+        use crate::tasks::CaptchaTask;
+        use crate::tasks::TextQuestion;
+        let task: CaptchaTask = TextQuestion::new("Сколько будет 3 + 4?")
+            .with_lang("ru")
+            .into();
+        assert_eq!(language_pool(&task), Some("rn"));
+        assert!(RucaptchaTask::try_from(task).is_ok());
+
+        let task: CaptchaTask = TextQuestion::new("What is 3 + 4?").into();
+        assert_eq!(language_pool(&task), None);
+
+        let result: Result<RucaptchaTask, _> = TextQuestion::new("Wie viel ist 3 + 4?")
+            .with_lang("de")
+            .try_into();
+        assert!(result.unwrap_err().unsupported_fields.contains(&"lang"));
+    }
+
+    #[test]
+    fn test_hcaptcha_serialization() {
+        use crate::tasks::HCaptcha;
+        let task: RucaptchaTask = HCaptcha::new("https://example.com", "site-key")
+            .invisible()
+            .with_rqdata("rq")
+            .into();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "HCaptchaTaskProxyless");
+        assert_eq!(json["isInvisible"], true);
+        assert_eq!(json["enterprisePayload"]["rqdata"], "rq");
+        assert_eq!(task.to_string(), "HCaptcha");
+
+        let task: RucaptchaTask = HCaptcha::new("https://example.com", "site-key")
+            .with_proxy(ProxyConfig::http("1.2.3.4", 8080))
+            .into();
+        let json = serde_json::to_value(&task).unwrap();
+        assert_eq!(json["type"], "HCaptchaTask");
+        assert!(json.get("enterprisePayload").is_none());
+    }
+
+    #[test]
+    fn test_hcaptcha_solution_from_token_shape() {
+        let json = r#"{"gRecaptchaResponse": "P1_token", "respKey": "E0_key"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.into_hcaptcha().resp_key(), Some("E0_key"));
+
+        // Non-enterprise hCaptcha has no respKey and must not panic
+        let json = r#"{"gRecaptchaResponse": "P1_token"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert!(solution.as_recaptcha().is_some());
+        let hcaptcha = solution.into_hcaptcha();
+        assert_eq!(hcaptcha.token(), "P1_token");
+        assert_eq!(hcaptcha.resp_key(), None);
+
+        let json = r#"{"text": "deditur"}"#;
+        let solution: RucaptchaSolution = serde_json::from_str(json).unwrap();
+        assert!(solution.try_into_hcaptcha().is_err());
+    }
 }
@@ -0,0 +1,111 @@
+//! Error types for the 2Captcha provider.
+//!
+//! 2Captcha shares RuCaptcha's backend, so error codes are the same
+//! [`RucaptchaErrorCode`] values; only the error messages name 2Captcha.
+
+use crate::errors::{KeyFault, RetryableError, UnsupportedTaskError};
+use crate::providers::rucaptcha::{RucaptchaApiError, RucaptchaError, RucaptchaErrorCode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// 2Captcha API error codes (identical to RuCaptcha's).
+pub type TwocaptchaErrorCode = RucaptchaErrorCode;
+
+/// 2Captcha error type
+#[derive(Debug, Error)]
+pub enum TwocaptchaError {
+    #[error("Failed to build HTTP client: {0}")]
+    BuildHttpClient(#[source] reqwest::Error),
+
+    #[error("HTTP request failed: {0}")]
+    HttpRequest(#[from] reqwest_middleware::Error),
+
+    #[error("Failed to parse response: {0}")]
+    ParseResponse(#[source] reqwest::Error),
+
+    #[error("2Captcha API error: {0}")]
+    Api(#[source] TwocaptchaApiError),
+
+    #[error("{0}")]
+    UnsupportedTask(#[source] UnsupportedTaskError),
+}
+
+pub type Result<T> = std::result::Result<T, TwocaptchaError>;
+
+impl From<RucaptchaError> for TwocaptchaError {
+    fn from(error: RucaptchaError) -> Self {
+        match error {
+            RucaptchaError::BuildHttpClient(e) => TwocaptchaError::BuildHttpClient(e),
+            RucaptchaError::HttpRequest(e) => TwocaptchaError::HttpRequest(e),
+            RucaptchaError::ParseResponse(e) => TwocaptchaError::ParseResponse(e),
+            RucaptchaError::Api(e) => TwocaptchaError::Api(e.into()),
+            RucaptchaError::UnsupportedTask(e) => TwocaptchaError::UnsupportedTask(e),
+        }
+    }
+}
+
+impl RetryableError for TwocaptchaError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            TwocaptchaError::HttpRequest(_) => true,
+            TwocaptchaError::Api(error) => error.error_code.is_retryable(),
+            TwocaptchaError::BuildHttpClient(_)
+            | TwocaptchaError::ParseResponse(_)
+            | TwocaptchaError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn should_retry_operation(&self) -> bool {
+        match self {
+            TwocaptchaError::HttpRequest(_) => true,
+            TwocaptchaError::Api(error) => error.error_code.should_retry_operation(),
+            TwocaptchaError::BuildHttpClient(_)
+            | TwocaptchaError::ParseResponse(_)
+            | TwocaptchaError::UnsupportedTask(_) => false,
+        }
+    }
+
+    fn key_fault(&self) -> Option<KeyFault> {
+        match self {
+            TwocaptchaError::Api(error) => error.error_code.key_fault(),
+            _ => None,
+        }
+    }
+}
+
+/// 2Captcha API error response
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwocaptchaApiError {
+    pub error_id: u32,
+    pub error_code: TwocaptchaErrorCode,
+    #[serde(default, alias = "errorDescription")]
+    pub error_description: Option<String>,
+}
+
+impl From<RucaptchaApiError> for TwocaptchaApiError {
+    fn from(error: RucaptchaApiError) -> Self {
+        Self {
+            error_id: error.error_id,
+            error_code: error.error_code,
+            error_description: error.error_description,
+        }
+    }
+}
+
+impl fmt::Display for TwocaptchaApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "2Captcha Error [{}]: {} - {}",
+            self.error_id,
+            self.error_code,
+            self.error_description
+                .as_deref()
+                .unwrap_or("No description")
+        )
+    }
+}
+
+impl std::error::Error for TwocaptchaApiError {}
@@ -0,0 +1,84 @@
+//! # 2Captcha Provider
+//!
+//! Implementation of the captcha solver provider for [2Captcha](https://2captcha.com).
+//!
+//! 2Captcha runs on the same backend as [RuCaptcha](crate::rucaptcha), so this
+//! provider reuses the RuCaptcha task types, conversions and solutions. It only
+//! differs in its default API URL (`https://api.2captcha.com`), error messages,
+//! the provider name in [`UnsupportedTaskError`](crate::UnsupportedTaskError)
+//! and the `captcha.twocaptcha` tracing target.
+//!
+//! Supported task types are the same as RuCaptcha's; see the
+//! [`rucaptcha`](crate::rucaptcha) module for the full table.
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use captcha_solvers::twocaptcha::TwocaptchaProvider;
+//! use captcha_solvers::{CaptchaSolverService, CaptchaSolverServiceTrait, ReCaptchaV2};
+//!
+//! let provider = TwocaptchaProvider::new("your_api_key")?;
+//! let service = CaptchaSolverService::new(provider);
+//!
+//! let task = ReCaptchaV2::new("https://example.com", "site_key");
+//! let solution = service.solve_captcha(task).await?;
+//! let token = solution.into_recaptcha().token();
+//! ```
+//!
+//! ## Provider Configuration
+//!
+//! ```rust,ignore
+//! use captcha_solvers::twocaptcha::TwocaptchaProvider;
+//! use url::Url;
+//!
+//! // Simple: default API URL
+//! let provider = TwocaptchaProvider::new("api_key")?;
+//!
+//! // Full builder
+//! let provider = TwocaptchaProvider::builder("api_key")
+//!     .url(Url::parse("https://api.2captcha.com")?)
+//!     .http_client(custom_middleware_client)
+//!     .build()?;
+//! ```
+//!
+//! ## Error Handling
+//!
+//! Error codes are shared with RuCaptcha ([`TwocaptchaErrorCode`] is an alias of
+//! [`RucaptchaErrorCode`](crate::rucaptcha::RucaptchaErrorCode)), so retry
+//! classification is identical:
+//!
+//! ```rust,ignore
+//! use captcha_solvers::RetryableError;
+//! use captcha_solvers::twocaptcha::TwocaptchaError;
+//!
+//! match result {
+//!     Err(e) if e.is_retryable() => { /* retry later */ }
+//!     Err(e) => { /* permanent error, check API key or task data */ }
+//!     Ok(solution) => { /* success */ }
+//! }
+//! ```
+
+mod errors;
+mod provider;
+
+#[cfg(test)]
+mod tests;
+
+// Errors
+pub use errors::{TwocaptchaApiError, TwocaptchaError, TwocaptchaErrorCode};
+
+// Provider
+pub use provider::{DEFAULT_API_URL, TwocaptchaProvider, TwocaptchaProviderBuilder};
+
+/// 2Captcha solution type (identical to RuCaptcha's).
+pub type TwocaptchaSolution = crate::providers::rucaptcha::RucaptchaSolution;
+
+// Solutions (public API)
+pub use crate::providers::rucaptcha::{
+    AudioToTextSolution, CapySolution, GridClassificationSolution, HCaptchaSolution,
+    ImageToTextSolution, LeminSolution, ReCaptchaSolution, RotateSolution, TencentSolution,
+    TextQuestionSolution, TokenSolution, TurnstileSolution,
+};
+
+// Re-export proxy types for convenience (also available at crate root)
+pub use crate::utils::proxy::{ProxyConfig, ProxyType};
@@ -0,0 +1,239 @@
+//! 2Captcha provider implementation.
+//!
+//! Requests, conversions and polling are handled by a [`RucaptchaProvider`]
+//! pointed at 2Captcha; this wrapper only renames errors and tracing spans.
+
+use super::errors::{Result, TwocaptchaError};
+use crate::providers::rucaptcha::{
+    RucaptchaProvider, RucaptchaProviderBuilder, RucaptchaSolution, Service,
+};
+use crate::providers::traits::{Provider, TaskCreationOutcome};
+use crate::tasks::CaptchaTask;
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use reqwest_middleware::ClientWithMiddleware;
+use std::fmt::Debug;
+
+#[cfg(feature = "tracing")]
+use crate::providers::rucaptcha::ServiceSpans;
+#[cfg(feature = "tracing")]
+use crate::utils::span_status::{record_error, set_span_ok};
+#[cfg(feature = "tracing")]
+use tracing::Span;
+
+/// Default 2Captcha API URL
+pub const DEFAULT_API_URL: &str = "https://api.2captcha.com";
+
+/// The 2Captcha service, as seen by the shared RuCaptcha client
+const TWOCAPTCHA: Service = Service {
+    name: "2Captcha",
+    default_url: DEFAULT_API_URL,
+    #[cfg(feature = "tracing")]
+    spans: ServiceSpans {
+        create_task: || {
+            tracing::info_span!(
+                target: "captcha.twocaptcha",
+                "create_task_internal",
+                task_id = tracing::field::Empty
+            )
+        },
+        get_task_result: |task_id| {
+            tracing::info_span!(
+                target: "captcha.twocaptcha",
+                "get_task_result_internal",
+                task_id = %task_id
+            )
+        },
+    },
+};
+
+/// 2Captcha provider implementation
+///
+/// This provider handles all communication with the 2Captcha API,
+/// including task creation and solution polling.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::{
+///     CaptchaSolverService, CaptchaSolverServiceTrait,
+///     ReCaptchaV2, Turnstile,
+///     twocaptcha::TwocaptchaProvider,
+/// };
+///
+/// // Create provider directly with API key
+/// let provider = TwocaptchaProvider::new("api_key")?;
+/// let service = CaptchaSolverService::new(provider);
+///
+/// // Use shared task types
+/// let task = ReCaptchaV2::new("https://example.com", "site_key")
+///     .invisible()
+///     .enterprise();
+///
+/// let solution = service.solve_captcha(task).await?;
+/// println!("Token: {}", solution.into_recaptcha().token());
+/// ```
+#[derive(Clone)]
+pub struct TwocaptchaProvider {
+    inner: RucaptchaProvider,
+}
+
+impl Debug for TwocaptchaProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TwocaptchaProvider")
+            .field("url", self.inner.url())
+            .field("api_key", &crate::utils::REDACTED)
+            .finish()
+    }
+}
+
+/// Builder for configuring a [`TwocaptchaProvider`]
+///
+/// Provides a fluent API for constructing providers with custom settings.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use captcha_solvers::twocaptcha::TwocaptchaProvider;
+/// use url::Url;
+///
+/// let provider = TwocaptchaProvider::builder("your-api-key")
+///     .url(Url::parse("https://custom-api.example.com").unwrap())
+///     .http_client(custom_client)
+///     .build()?;
+/// ```
+pub struct TwocaptchaProviderBuilder {
+    inner: RucaptchaProviderBuilder,
+}
+
+impl TwocaptchaProviderBuilder {
+    /// Create a new builder with the given API key
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            inner: RucaptchaProviderBuilder::new(api_key).service(TWOCAPTCHA),
+        }
+    }
+
+    /// Set a custom API URL
+    ///
+    /// Default: `https://api.2captcha.com`
+    pub fn url(mut self, url: Url) -> Self {
+        self.inner = self.inner.url(url);
+        self
+    }
+
+    /// Set a custom HTTP client with middleware
+    ///
+    /// Use this when you need custom middleware (e.g., tracing, retry, rate limiting).
+    pub fn http_client(mut self, client: ClientWithMiddleware) -> Self {
+        self.inner = self.inner.http_client(client);
+        self
+    }
+
+    /// Build the [`TwocaptchaProvider`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn build(self) -> Result<TwocaptchaProvider> {
+        Ok(TwocaptchaProvider {
+            inner: self.inner.build()?,
+        })
+    }
+}
+
+impl TwocaptchaProvider {
+    /// Create a new 2Captcha provider with the default API URL
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Your 2Captcha API key
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let provider = TwocaptchaProvider::new("your_api_key")?;
+    /// ```
+    pub fn new(api_key: impl Into<String>) -> Result<Self> {
+        Self::builder(api_key).build()
+    }
+
+    /// Create a new 2Captcha provider with a custom URL
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Base URL for the 2Captcha API
+    /// * `api_key` - Your 2Captcha API key
+    pub fn with_url(url: Url, api_key: impl Into<String>) -> Result<Self> {
+        Self::builder(api_key).url(url).build()
+    }
+
+    /// Create a builder for configuring the provider
+    ///
+    /// Use this for advanced configuration options like custom HTTP clients.
+    pub fn builder(api_key: impl Into<String>) -> TwocaptchaProviderBuilder {
+        TwocaptchaProviderBuilder::new(api_key)
+    }
+
+    /// Get the base URL
+    pub fn url(&self) -> &Url {
+        self.inner.url()
+    }
+}
+
+impl Provider for TwocaptchaProvider {
+    type Solution = RucaptchaSolution;
+    type Error = TwocaptchaError;
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_task",
+            target = "captcha.twocaptcha",
+            skip_all,
+            fields(captcha.task_type)
+        )
+    )]
+    async fn create_task(&self, task: CaptchaTask) -> Result<TaskCreationOutcome<Self::Solution>> {
+        #[cfg(feature = "tracing")]
+        Span::current().record("captcha.task_type", task.to_string());
+
+        let result = self
+            .inner
+            .create_task_internal(task)
+            .await
+            .map_err(TwocaptchaError::from);
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(_) => set_span_ok(),
+            Err(e) => record_error(e, "Twocaptcha"),
+        }
+
+        result.map(TaskCreationOutcome::Pending)
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_task_result",
+            target = "captcha.twocaptcha",
+            skip_all,
+            fields(task_id = %task_id)
+        )
+    )]
+    async fn get_task_result(&self, task_id: &TaskId) -> Result<Option<Self::Solution>> {
+        let result = self
+            .inner
+            .get_task_result_internal(task_id)
+            .await
+            .map_err(TwocaptchaError::from);
+
+        #[cfg(feature = "tracing")]
+        if let Err(ref e) = result {
+            record_error(e, "Twocaptcha");
+        }
+
+        result
+    }
+}
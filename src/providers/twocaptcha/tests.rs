@@ -0,0 +1,149 @@
+//! Tests for the 2Captcha provider.
+
+use super::TwocaptchaSolution;
+use super::errors::{TwocaptchaError, TwocaptchaErrorCode};
+use super::provider::TwocaptchaProvider;
+use crate::errors::RetryableError;
+use crate::providers::traits::Provider;
+use crate::tasks::{Altcha, ReCaptchaV2, Turnstile};
+use crate::utils::types::TaskId;
+use reqwest::Url;
+use serde_json::{Value, json};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn mock_provider(server: &MockServer) -> TwocaptchaProvider {
+    TwocaptchaProvider::builder("test_api_key")
+        .url(Url::parse(&server.uri()).unwrap())
+        .build()
+        .unwrap()
+}
+
+async fn mock_create_task(server: &MockServer, response: Value) {
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response))
+        .mount(server)
+        .await;
+}
+
+async fn mock_get_task_result(server: &MockServer, response: Value) {
+    Mock::given(method("POST"))
+        .and(path("/getTaskResult"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_create_task_success() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/createTask"))
+        .and(body_partial_json(json!({
+            "clientKey": "test_api_key",
+            "task": {
+                "type": "TurnstileTaskProxyless",
+                "websiteURL": "https://example.com",
+                "websiteKey": "site-key",
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "errorId": 0,
+            "taskId": 72345678901_u64,
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = mock_provider(&server);
+    let task = Turnstile::new("https://example.com", "site-key");
+
+    let outcome = provider.create_task(task.into()).await.unwrap();
+    assert_eq!(outcome.task_id().as_ref(), "72345678901");
+}
+
+#[tokio::test]
+async fn test_create_task_api_error() {
+    let server = MockServer::start().await;
+    mock_create_task(
+        &server,
+        json!({
+            "errorId": 1,
+            "errorCode": "ERROR_KEY_DOES_NOT_EXIST",
+            "errorDescription": "Your API key is incorrect",
+        }),
+    )
+    .await;
+
+    let provider = mock_provider(&server);
+    let task = ReCaptchaV2::new("https://example.com", "site-key");
+    let err = provider.create_task(task.into()).await.unwrap_err();
+
+    match &err {
+        TwocaptchaError::Api(error) => {
+            assert_eq!(error.error_code, TwocaptchaErrorCode::KeyDoesNotExist);
+        }
+        _ => panic!("Expected Api error"),
+    }
+    assert!(
+        err.to_string()
+            .starts_with("2Captcha API error: 2Captcha Error [1]")
+    );
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_create_task_unsupported_names_provider() {
+    let provider = TwocaptchaProvider::new("test_api_key").unwrap();
+    let task = Altcha::new("https://example.com", "https://example.com/challenge");
+
+    let err = provider.create_task(task.into()).await.unwrap_err();
+    match err {
+        TwocaptchaError::UnsupportedTask(error) => {
+            assert_eq!(error.task_type, "Altcha");
+            assert_eq!(error.provider, "2Captcha");
+        }
+        _ => panic!("Expected UnsupportedTask error"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_task_result_ready() {
+    let server = MockServer::start().await;
+    mock_get_task_result(
+        &server,
+        json!({
+            "errorId": 0,
+            "status": "ready",
+            "solution": {"gRecaptchaResponse": "recaptcha-token"},
+        }),
+    )
+    .await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("72345678901");
+
+    let solution: Option<TwocaptchaSolution> = provider.get_task_result(&task_id).await.unwrap();
+    assert_eq!(
+        solution.unwrap().into_recaptcha().token(),
+        "recaptcha-token"
+    );
+}
+
+#[tokio::test]
+async fn test_get_task_result_processing() {
+    let server = MockServer::start().await;
+    mock_get_task_result(&server, json!({"errorId": 0, "status": "processing"})).await;
+
+    let provider = mock_provider(&server);
+    let task_id = TaskId::from("72345678901");
+
+    let solution: Option<TwocaptchaSolution> = provider.get_task_result(&task_id).await.unwrap();
+    assert!(solution.is_none());
+}
+
+#[test]
+fn test_builder_default_url() {
+    let provider = TwocaptchaProvider::new("test-key").unwrap();
+    assert_eq!(provider.url().as_str(), "https://api.2captcha.com/");
+}
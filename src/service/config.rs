@@ -1,5 +1,7 @@
 //! Service configuration types.
 
+use crate::utils::serde_helpers::human_duration;
+use serde::Deserialize;
 use std::time::Duration;
 use thiserror::Error;
 
@@ -59,11 +61,26 @@ pub const MIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
 ///     .poll_interval(Duration::from_secs(5))
 ///     .build();
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Deserializes from human-readable durations; missing fields keep the
+/// [`balanced`](Self::balanced) values:
+///
+/// ```rust
+/// use captcha_solvers::CaptchaSolverServiceConfig;
+/// use std::time::Duration;
+///
+/// let config: CaptchaSolverServiceConfig =
+///     serde_json::from_str(r#"{"timeout": "3m", "poll_interval": "5s"}"#).unwrap();
+/// assert_eq!(config.timeout, Duration::from_secs(180));
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct CaptchaSolverServiceConfig {
     /// Maximum time to wait for captcha solution before timing out.
+    #[serde(deserialize_with = "human_duration::deserialize")]
     pub timeout: Duration,
     /// Interval between polling attempts when waiting for solution.
+    #[serde(deserialize_with = "human_duration::deserialize")]
     pub poll_interval: Duration,
 }
 
@@ -384,6 +401,21 @@ mod tests {
         assert!(config.is_ok());
     }
 
+    #[test]
+    fn test_config_deserialize() {
+        let config: CaptchaSolverServiceConfig =
+            serde_json::from_str(r#"{"timeout": "1m 30s", "poll_interval": "500ms"}"#).unwrap();
+        assert_eq!(config.timeout, Duration::from_secs(90));
+        assert_eq!(config.poll_interval, Duration::from_millis(500));
+
+        let config: CaptchaSolverServiceConfig =
+            serde_json::from_str(r#"{"poll_interval": 2}"#).unwrap();
+        assert_eq!(config.timeout, Duration::from_secs(120));
+        assert_eq!(config.poll_interval, Duration::from_secs(2));
+
+        assert!(serde_json::from_str::<CaptchaSolverServiceConfig>(r#"{"timout": "1m"}"#).is_err());
+    }
+
     #[test]
     fn test_try_build_failure() {
         let result = CaptchaSolverServiceConfig::builder()
@@ -7,7 +7,7 @@ use crate::errors::RetryableError;
 use crate::providers::traits::{Provider, TaskCreationOutcome};
 use crate::tasks::CaptchaTask;
 use std::fmt::{Debug, Display};
-use std::time::Instant;
+use tokio::time::Instant;
 use tokio_util::sync::CancellationToken;
 
 #[cfg(feature = "tracing")]
@@ -281,6 +281,8 @@ where
             )
         )
     )]
+    // Several locals only feed tracing events
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
     async fn solve_captcha_cancellable<T: Into<CaptchaTask> + Send>(
         &self,
         task: T,
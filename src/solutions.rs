@@ -58,9 +58,32 @@ pub struct ReCaptchaSolution {
     /// Cookie for some V2 websites (Capsolver)
     #[serde(default, rename = "recaptcha-ca-e")]
     pub recaptcha_ca_e: Option<String>,
+
+    /// hCaptcha Enterprise `respKey`
+    ///
+    /// hCaptcha results share this shape; read them through
+    /// [`HCaptchaSolution`] instead.
+    #[serde(default)]
+    pub resp_key: Option<String>,
 }
 
 impl ReCaptchaSolution {
+    /// Create a solution holding only the token
+    ///
+    /// For providers that return the bare `g-recaptcha-response` value.
+    pub fn from_token(token: impl Into<String>) -> Self {
+        Self {
+            g_recaptcha_response: token.into(),
+            token: None,
+            user_agent: None,
+            sec_ch_ua: None,
+            create_time: None,
+            recaptcha_ca_t: None,
+            recaptcha_ca_e: None,
+            resp_key: None,
+        }
+    }
+
     /// Get the reCAPTCHA token
     ///
     /// This is the value to submit in the `g-recaptcha-response` field.
@@ -74,6 +97,66 @@ impl ReCaptchaSolution {
     }
 }
 
+/// hCaptcha solution
+///
+/// Submit the token in the `h-captcha-response` (and `g-recaptcha-response`)
+/// field. Enterprise sites may also check `respKey`, which is returned in the
+/// `hcaptcha.getRespKey()` callback; non-enterprise results have none.
+///
+/// hCaptcha and reCAPTCHA results have the same shape, so provider solution
+/// enums hold both as their ReCaptcha variant. `into_hcaptcha()` converts it
+/// for hCaptcha tasks.
+///
+/// # Example
+///
+/// ```ignore
+/// let solution = service.solve_captcha(task).await?;
+/// let hcaptcha = solution.into_hcaptcha();
+/// println!("Token: {} respKey: {:?}", hcaptcha.token(), hcaptcha.resp_key());
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HCaptchaSolution {
+    /// The hCaptcha token (required field)
+    #[serde(rename = "gRecaptchaResponse")]
+    pub g_recaptcha_response: String,
+
+    /// Response key returned by `hcaptcha.getRespKey()` (hCaptcha Enterprise only)
+    #[serde(default)]
+    pub resp_key: Option<String>,
+
+    /// User-Agent string used during solving
+    #[serde(default)]
+    pub user_agent: Option<String>,
+}
+
+impl HCaptchaSolution {
+    /// Get the hCaptcha token
+    pub fn token(&self) -> &str {
+        &self.g_recaptcha_response
+    }
+
+    /// Get the response key (hCaptcha Enterprise only)
+    pub fn resp_key(&self) -> Option<&str> {
+        self.resp_key.as_deref()
+    }
+
+    /// Get the user agent used during solving
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+}
+
+impl From<ReCaptchaSolution> for HCaptchaSolution {
+    fn from(solution: ReCaptchaSolution) -> Self {
+        Self {
+            g_recaptcha_response: solution.g_recaptcha_response,
+            resp_key: solution.resp_key,
+            user_agent: solution.user_agent,
+        }
+    }
+}
+
 /// Turnstile/Cloudflare Challenge solution
 ///
 /// This solution type is returned when solving Cloudflare Turnstile or
@@ -150,6 +233,16 @@ impl<'de> Deserialize<'de> for TurnstileSolution {
 }
 
 impl TurnstileSolution {
+    /// Create a solution holding only the token
+    pub fn from_token(token: impl Into<String>) -> Self {
+        Self {
+            token: Some(token.into()),
+            cf_clearance: None,
+            cookies: None,
+            user_agent: None,
+        }
+    }
+
     /// Get the token if available.
     pub fn token(&self) -> Option<&str> {
         self.token.as_deref()
@@ -176,6 +269,328 @@ impl TurnstileSolution {
 /// Type alias for backwards compatibility
 pub type CloudflareChallengeSolution = TurnstileSolution;
 
+/// Anti-bot bypass solution (Imperva, Kasada)
+///
+/// Contains the cookies and/or headers that must accompany requests to the
+/// protected site, together with the user agent they are bound to. Provider
+/// responses are normalized on deserialization: top-level `reese84`/`___utmvc`
+/// values and CapMonster's per-domain cookies become [`cookies`](Self::cookies),
+/// and Kasada's `x-kpsdk-*` values become [`headers`](Self::headers).
+///
+/// Payloads shaped like a [`TurnstileSolution`] (a `token`, or a
+/// `cf_clearance` cookie without any anti-bot value) are rejected, so
+/// untagged provider solution enums can try this type before Turnstile.
+///
+/// # Example
+///
+/// ```ignore
+/// let solution = service.solve_captcha(task).await?;
+/// let anti_bot = solution.into_anti_bot();
+/// let response = anti_bot.apply(client.get("https://example.com")).send().await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AntiBotSolution {
+    /// Cookies to send with subsequent requests
+    pub cookies: HashMap<String, String>,
+
+    /// Headers to send with subsequent requests
+    pub headers: HashMap<String, String>,
+
+    /// User-Agent string used (must match your subsequent requests)
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AntiBotSolutionRaw {
+    #[serde(default)]
+    cookies: Option<HashMap<String, String>>,
+    #[serde(default)]
+    headers: Option<HashMap<String, String>>,
+    #[serde(default)]
+    domains: Option<HashMap<String, AntiBotDomainRaw>>,
+    #[serde(default)]
+    reese84: Option<String>,
+    #[serde(default, rename = "___utmvc", alias = "__utmvc")]
+    utmvc: Option<String>,
+    #[serde(default, rename = "x-kpsdk-ct")]
+    kpsdk_ct: Option<String>,
+    #[serde(default, rename = "x-kpsdk-cd")]
+    kpsdk_cd: Option<String>,
+    #[serde(default, rename = "userAgent", alias = "user-agent")]
+    user_agent: Option<String>,
+    #[serde(default)]
+    token: Option<String>,
+    #[serde(default)]
+    cf_clearance: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AntiBotDomainRaw {
+    #[serde(default)]
+    cookies: HashMap<String, String>,
+}
+
+impl<'de> Deserialize<'de> for AntiBotSolution {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = AntiBotSolutionRaw::deserialize(deserializer)?;
+
+        let has_anti_bot_value = raw.headers.as_ref().is_some_and(|h| !h.is_empty())
+            || raw.domains.is_some()
+            || raw.reese84.is_some()
+            || raw.utmvc.is_some()
+            || raw.kpsdk_ct.is_some()
+            || raw.kpsdk_cd.is_some();
+        let is_turnstile = raw.token.is_some()
+            || raw.cf_clearance.is_some()
+            || raw
+                .cookies
+                .as_ref()
+                .is_some_and(|cookies| cookies.contains_key("cf_clearance"));
+        if is_turnstile && !has_anti_bot_value {
+            return Err(D::Error::custom(
+                "Turnstile-shaped payload is not an AntiBotSolution",
+            ));
+        }
+
+        let mut cookies = raw.cookies.unwrap_or_default();
+        for domain in raw.domains.into_iter().flat_map(HashMap::into_values) {
+            cookies.extend(domain.cookies);
+        }
+        if let Some(reese84) = raw.reese84 {
+            cookies.insert("reese84".to_string(), reese84);
+        }
+        if let Some(utmvc) = raw.utmvc {
+            cookies.insert("___utmvc".to_string(), utmvc);
+        }
+
+        let mut headers = raw.headers.unwrap_or_default();
+        if let Some(ct) = raw.kpsdk_ct {
+            headers.insert("x-kpsdk-ct".to_string(), ct);
+        }
+        if let Some(cd) = raw.kpsdk_cd {
+            headers.insert("x-kpsdk-cd".to_string(), cd);
+        }
+
+        if cookies.is_empty() && headers.is_empty() {
+            return Err(D::Error::custom(
+                "AntiBotSolution must contain cookies or headers",
+            ));
+        }
+
+        Ok(Self {
+            cookies,
+            headers,
+            user_agent: raw.user_agent,
+        })
+    }
+}
+
+impl AntiBotSolution {
+    /// Get a cookie value by name
+    pub fn cookie(&self, name: &str) -> Option<&str> {
+        self.cookies.get(name).map(String::as_str)
+    }
+
+    /// Get a header value by name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    /// Get the user agent the solution is bound to
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+
+    /// Format the cookies as a `Cookie` header value (sorted by name)
+    ///
+    /// Returns `None` if the solution has no cookies.
+    pub fn cookie_header(&self) -> Option<String> {
+        if self.cookies.is_empty() {
+            return None;
+        }
+        let mut pairs: Vec<_> = self.cookies.iter().collect();
+        pairs.sort();
+        Some(
+            pairs
+                .into_iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+
+    /// Apply the headers, cookies and user agent to an outgoing request
+    pub fn apply(&self, mut request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        if let Some(cookie) = self.cookie_header() {
+            request = request.header(reqwest::header::COOKIE, cookie);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            request = request.header(reqwest::header::USER_AGENT, user_agent);
+        }
+        request
+    }
+}
+
+/// Generic token solution
+///
+/// This solution type is returned by widget captchas whose answer is a single
+/// token (MTCaptcha, Friendly Captcha, Cutcaptcha, Yandex SmartCaptcha, KeyCaptcha,
+/// Prosopo, CaptchaFox, Altcha).
+///
+/// # Example
+///
+/// ```ignore
+/// let solution = service.solve_captcha(task).await?;
+/// let token = solution.into_token();
+/// println!("Token: {}", token.token());
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenSolution {
+    /// The solved token
+    pub token: String,
+}
+
+impl TokenSolution {
+    /// Create a solution from the solved token
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+
+    /// Get the token
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl TryFrom<TurnstileSolution> for TokenSolution {
+    type Error = TurnstileSolution;
+
+    /// Convert a token-only response, which untagged provider solutions
+    /// deserialize as [`TurnstileSolution`], into a [`TokenSolution`].
+    fn try_from(solution: TurnstileSolution) -> Result<Self, Self::Error> {
+        match solution.token {
+            Some(token) => Ok(Self { token }),
+            None => Err(solution),
+        }
+    }
+}
+
+/// Tencent captcha (TCaptcha) solution
+///
+/// Both `ticket` and `randstr` must be passed to the page's captcha callback
+/// (or submitted with the form) for the answer to be accepted.
+///
+/// # Example
+///
+/// ```ignore
+/// let solution = service.solve_captcha(task).await?;
+/// let tencent = solution.into_tencent();
+/// println!("ticket={} randstr={}", tencent.ticket(), tencent.randstr());
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct TencentSolution {
+    /// The verification ticket
+    pub ticket: String,
+
+    /// The random string paired with the ticket
+    pub randstr: String,
+
+    /// App ID the ticket was issued for
+    #[serde(default, rename = "appid")]
+    pub app_id: Option<String>,
+}
+
+impl TencentSolution {
+    /// Get the verification ticket
+    pub fn ticket(&self) -> &str {
+        &self.ticket
+    }
+
+    /// Get the random string paired with the ticket
+    pub fn randstr(&self) -> &str {
+        &self.randstr
+    }
+}
+
+/// Lemin Cropped captcha solution
+///
+/// # Example
+///
+/// ```ignore
+/// let solution = service.solve_captcha(task).await?;
+/// let lemin = solution.into_lemin();
+/// println!("answer={} challenge_id={}", lemin.answer(), lemin.challenge_id());
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct LeminSolution {
+    /// The answer to submit
+    pub answer: String,
+
+    /// The challenge ID the answer belongs to
+    pub challenge_id: String,
+}
+
+impl LeminSolution {
+    /// Get the answer
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    /// Get the challenge ID
+    pub fn challenge_id(&self) -> &str {
+        &self.challenge_id
+    }
+}
+
+/// Capy puzzle captcha solution
+///
+/// # Example
+///
+/// ```ignore
+/// let solution = service.solve_captcha(task).await?;
+/// let capy = solution.into_capy();
+/// println!("captchakey={} answer={}", capy.captcha_key(), capy.answer());
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct CapySolution {
+    /// The captcha key (site key the puzzle was issued for)
+    #[serde(rename = "captchakey")]
+    pub captcha_key: String,
+
+    /// The challenge key of the solved puzzle
+    #[serde(rename = "challengekey")]
+    pub challenge_key: String,
+
+    /// The answer to submit
+    pub answer: String,
+}
+
+impl CapySolution {
+    /// Get the captcha key
+    pub fn captcha_key(&self) -> &str {
+        &self.captcha_key
+    }
+
+    /// Get the challenge key
+    pub fn challenge_key(&self) -> &str {
+        &self.challenge_key
+    }
+
+    /// Get the answer
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+}
+
 /// Image to text captcha solution
 ///
 /// This solution type is returned when solving image captchas that require
@@ -196,12 +611,166 @@ pub struct ImageToTextSolution {
 }
 
 impl ImageToTextSolution {
+    /// Create a solution from the recognized text
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+
     /// Get the recognized text
     pub fn text(&self) -> &str {
         &self.text
     }
 }
 
+/// Audio to text captcha solution
+///
+/// Audio captchas are answered with the transcribed text, so the image
+/// recognition solution is reused.
+pub type AudioToTextSolution = ImageToTextSolution;
+
+/// Text question captcha solution
+///
+/// Text questions are answered with plain text, so the image recognition
+/// solution is reused.
+pub type TextQuestionSolution = ImageToTextSolution;
+
+/// Grid image classification solution
+///
+/// Contains the zero-based, row-major indices of the tiles to select. Provider
+/// responses are normalized on deserialization: RuCaptcha `click` (1-based),
+/// Capsolver `objects`/`hasObject` and CapMonster `answer` (one flag per tile).
+///
+/// # Example
+///
+/// ```ignore
+/// let solution = service.solve_captcha(task).await?;
+/// let grid = solution.into_grid_classification();
+/// for index in grid.tiles() {
+///     println!("Click tile {}", index);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridClassificationSolution {
+    /// Zero-based indices of the tiles to select
+    pub tiles: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GridClassificationSolutionRaw {
+    #[serde(default)]
+    click: Option<Vec<u32>>,
+    #[serde(default)]
+    objects: Option<Vec<u32>>,
+    #[serde(default)]
+    has_object: Option<bool>,
+    #[serde(default)]
+    answer: Option<Vec<bool>>,
+}
+
+impl<'de> Deserialize<'de> for GridClassificationSolution {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = GridClassificationSolutionRaw::deserialize(deserializer)?;
+
+        let tiles = if let Some(click) = raw.click {
+            click
+                .into_iter()
+                .map(|index| {
+                    index
+                        .checked_sub(1)
+                        .ok_or_else(|| D::Error::custom("click indices are 1-based"))
+                })
+                .collect::<Result<_, _>>()?
+        } else if let Some(objects) = raw.objects {
+            objects
+        } else if let Some(has_object) = raw.has_object {
+            if has_object { vec![0] } else { Vec::new() }
+        } else if let Some(answer) = raw.answer {
+            answer
+                .iter()
+                .enumerate()
+                .filter(|(_, selected)| **selected)
+                .map(|(index, _)| index as u32)
+                .collect()
+        } else {
+            return Err(D::Error::custom(
+                "GridClassificationSolution must contain click, objects, hasObject or answer",
+            ));
+        };
+
+        Ok(Self { tiles })
+    }
+}
+
+impl GridClassificationSolution {
+    /// Get the zero-based indices of the tiles to select
+    pub fn tiles(&self) -> &[u32] {
+        &self.tiles
+    }
+
+    /// Check whether the tile at `index` should be selected
+    pub fn is_selected(&self, index: u32) -> bool {
+        self.tiles.contains(&index)
+    }
+}
+
+/// Rotate captcha solution
+///
+/// Contains the angle (in degrees) the image must be rotated by. Provider
+/// responses are normalized on deserialization: RuCaptcha `rotate`,
+/// Capsolver `angle` and CapMonster `answer`.
+///
+/// # Example
+///
+/// ```ignore
+/// let solution = service.solve_captcha(task).await?;
+/// let rotate = solution.into_rotate();
+/// println!("Angle: {}", rotate.angle());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotateSolution {
+    /// Rotation angle in degrees
+    pub angle: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RotateSolutionRaw {
+    #[serde(default)]
+    rotate: Option<f64>,
+    #[serde(default)]
+    angle: Option<f64>,
+    #[serde(default)]
+    answer: Option<Vec<f64>>,
+}
+
+impl<'de> Deserialize<'de> for RotateSolution {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RotateSolutionRaw::deserialize(deserializer)?;
+        let angle = raw
+            .rotate
+            .or(raw.angle)
+            .or_else(|| raw.answer.and_then(|answer| answer.first().copied()))
+            .ok_or_else(|| {
+                D::Error::custom("RotateSolution must contain rotate, angle or answer")
+            })?;
+
+        Ok(Self { angle })
+    }
+}
+
+impl RotateSolution {
+    /// Get the rotation angle in degrees
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,6 +809,14 @@ mod tests {
         assert_eq!(solution.token(), "token-value");
     }
 
+    #[test]
+    fn test_recaptcha_solution_from_token() {
+        let solution = ReCaptchaSolution::from_token("token");
+        assert_eq!(solution.token(), "token");
+        assert!(solution.token.is_none());
+        assert!(solution.session_cookie().is_none());
+    }
+
     #[test]
     fn test_turnstile_solution_deserialization() {
         let json = r#"{"token": "turnstile-token", "userAgent": "Mozilla/5.0"}"#;
@@ -284,4 +861,223 @@ mod tests {
         let solution: ImageToTextSolution = serde_json::from_str(json).unwrap();
         assert_eq!(solution.text(), "ABC123");
     }
+
+    #[test]
+    fn test_turnstile_solution_from_token() {
+        let solution = TurnstileSolution::from_token("token");
+        assert_eq!(solution.token(), Some("token"));
+        assert!(solution.cf_clearance().is_none());
+    }
+
+    #[test]
+    fn test_token_solution_from_turnstile() {
+        let json = r#"{"token": "v1(abc)"}"#;
+        let turnstile: TurnstileSolution = serde_json::from_str(json).unwrap();
+        let solution = TokenSolution::try_from(turnstile).unwrap();
+        assert_eq!(solution.token(), "v1(abc)");
+
+        let json = r#"{"cf_clearance": "clearance"}"#;
+        let turnstile: TurnstileSolution = serde_json::from_str(json).unwrap();
+        assert!(TokenSolution::try_from(turnstile).is_err());
+    }
+
+    #[test]
+    fn test_hcaptcha_solution_deserialization() {
+        let json = r#"{
+            "token": "P1_eyJ0eXAi",
+            "gRecaptchaResponse": "P1_eyJ0eXAi",
+            "respKey": "E0_eyJ0eXAi",
+            "userAgent": "Mozilla/5.0"
+        }"#;
+        let solution: HCaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.token(), "P1_eyJ0eXAi");
+        assert_eq!(solution.resp_key(), Some("E0_eyJ0eXAi"));
+        assert_eq!(solution.user_agent(), Some("Mozilla/5.0"));
+
+        // Non-enterprise hCaptcha returns no respKey
+        let json = r#"{"gRecaptchaResponse": "P1_token"}"#;
+        let solution: HCaptchaSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.resp_key(), None);
+    }
+
+    #[test]
+    fn test_hcaptcha_solution_from_recaptcha_shape() {
+        let json = r#"{"gRecaptchaResponse": "P1_token", "respKey": "E0_key"}"#;
+        let recaptcha: ReCaptchaSolution = serde_json::from_str(json).unwrap();
+        let hcaptcha = HCaptchaSolution::from(recaptcha);
+        assert_eq!(hcaptcha.token(), "P1_token");
+        assert_eq!(hcaptcha.resp_key(), Some("E0_key"));
+    }
+
+    #[test]
+    fn test_tencent_solution_deserialization() {
+        let json = r#"{"appid": "190014885", "ret": 0, "ticket": "tr034", "randstr": "@KVN"}"#;
+        let solution: TencentSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.ticket(), "tr034");
+        assert_eq!(solution.randstr(), "@KVN");
+        assert_eq!(solution.app_id.as_deref(), Some("190014885"));
+    }
+
+    #[test]
+    fn test_lemin_solution_deserialization() {
+        let json = r#"{"answer": "0xaxakx0xaxaax", "challenge_id": "e0348984-92ec-4d5c"}"#;
+        let solution: LeminSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.answer(), "0xaxakx0xaxaax");
+        assert_eq!(solution.challenge_id(), "e0348984-92ec-4d5c");
+    }
+
+    #[test]
+    fn test_capy_solution_deserialization() {
+        let json =
+            r#"{"captchakey": "PUZZLE_Abc", "challengekey": "qHAPtn", "answer": "0xax8ex0xax84"}"#;
+        let solution: CapySolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.captcha_key(), "PUZZLE_Abc");
+        assert_eq!(solution.challenge_key(), "qHAPtn");
+        assert_eq!(solution.answer(), "0xax8ex0xax84");
+    }
+
+    #[test]
+    fn test_anti_bot_solution_from_kasada_headers() {
+        let json = r#"{"x-kpsdk-ct": "0Hs1a", "x-kpsdk-cd": "{\"workTime\":1}", "user-agent": "Mozilla/5.0"}"#;
+        let solution: AntiBotSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.header("x-kpsdk-ct"), Some("0Hs1a"));
+        assert_eq!(solution.header("x-kpsdk-cd"), Some("{\"workTime\":1}"));
+        assert_eq!(solution.user_agent(), Some("Mozilla/5.0"));
+        assert!(solution.cookie_header().is_none());
+    }
+
+    #[test]
+    fn test_anti_bot_solution_from_domains() {
+        let json = r#"{
+            "domains": {"example.com": {"cookies": {"___utmvc": "utm", "reese84": "r84"}}},
+            "userAgent": "Mozilla/5.0"
+        }"#;
+        let solution: AntiBotSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.cookie("___utmvc"), Some("utm"));
+        assert_eq!(
+            solution.cookie_header().as_deref(),
+            Some("___utmvc=utm; reese84=r84")
+        );
+    }
+
+    #[test]
+    fn test_anti_bot_solution_from_top_level_cookie() {
+        let json = r#"{"reese84": "r84"}"#;
+        let solution: AntiBotSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.cookie("reese84"), Some("r84"));
+    }
+
+    #[test]
+    fn test_anti_bot_solution_requires_cookies_or_headers() {
+        let json = r#"{"userAgent": "Mozilla/5.0"}"#;
+        assert!(serde_json::from_str::<AntiBotSolution>(json).is_err());
+    }
+
+    #[test]
+    fn test_anti_bot_solution_rejects_turnstile_payloads() {
+        for json in [
+            r#"{"token": "0.abc", "userAgent": "Mozilla/5.0"}"#,
+            r#"{"cf_clearance": "clearance"}"#,
+            r#"{"cookies": {"cf_clearance": "clearance"}, "userAgent": "Mozilla/5.0"}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<AntiBotSolution>(json).is_err(),
+                "{json}"
+            );
+        }
+
+        // Anti-bot values win over a cf_clearance cookie set alongside them
+        let json = r#"{"cookies": {"cf_clearance": "c"}, "reese84": "r84"}"#;
+        let solution: AntiBotSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.cookie("reese84"), Some("r84"));
+    }
+
+    #[test]
+    fn test_anti_bot_solution_apply() {
+        let solution = AntiBotSolution {
+            cookies: HashMap::from([("reese84".to_string(), "r84".to_string())]),
+            headers: HashMap::from([("x-kpsdk-ct".to_string(), "ct".to_string())]),
+            user_agent: Some("Mozilla/5.0".to_string()),
+        };
+        let request = solution
+            .apply(reqwest::Client::new().get("https://example.com"))
+            .build()
+            .unwrap();
+        let headers = request.headers();
+        assert_eq!(headers["x-kpsdk-ct"], "ct");
+        assert_eq!(headers[reqwest::header::COOKIE], "reese84=r84");
+        assert_eq!(headers[reqwest::header::USER_AGENT], "Mozilla/5.0");
+    }
+
+    #[test]
+    fn test_grid_solution_from_click() {
+        let json = r#"{"click": [1, 3, 9]}"#;
+        let solution: GridClassificationSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.tiles(), &[0, 2, 8]);
+        assert!(solution.is_selected(2));
+        assert!(!solution.is_selected(1));
+    }
+
+    #[test]
+    fn test_grid_solution_rejects_zero_click() {
+        let json = r#"{"click": [0]}"#;
+        let result: Result<GridClassificationSolution, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_grid_solution_from_objects() {
+        let json = r#"{"objects": [0, 4], "type": "multi"}"#;
+        let solution: GridClassificationSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.tiles(), &[0, 4]);
+    }
+
+    #[test]
+    fn test_grid_solution_from_has_object() {
+        let json = r#"{"hasObject": true, "type": "single"}"#;
+        let solution: GridClassificationSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.tiles(), &[0]);
+    }
+
+    #[test]
+    fn test_grid_solution_from_answer_flags() {
+        let json = r#"{"answer": [false, true, false, true]}"#;
+        let solution: GridClassificationSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.tiles(), &[1, 3]);
+    }
+
+    #[test]
+    fn test_grid_solution_without_selection_fails() {
+        let json = r#"{"text": "ABC"}"#;
+        let result: Result<GridClassificationSolution, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rotate_solution_from_rotate() {
+        let json = r#"{"rotate": 180}"#;
+        let solution: RotateSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.angle(), 180.0);
+    }
+
+    #[test]
+    fn test_rotate_solution_from_angle() {
+        let json = r#"{"angle": 42.5}"#;
+        let solution: RotateSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.angle(), 42.5);
+    }
+
+    #[test]
+    fn test_rotate_solution_from_answer() {
+        let json = r#"{"answer": [90]}"#;
+        let solution: RotateSolution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.angle(), 90.0);
+    }
+
+    #[test]
+    fn test_rotate_solution_rejects_grid_answer() {
+        let json = r#"{"answer": [true, false]}"#;
+        let result: Result<RotateSolution, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
 }
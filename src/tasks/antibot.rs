@@ -0,0 +1,248 @@
+//! Imperva (Incapsula) and Kasada anti-bot task types with builder pattern.
+//!
+//! This module provides provider-agnostic anti-bot bypass task definitions.
+//! Like [`CloudflareChallenge`](crate::CloudflareChallenge), these tasks always
+//! require a proxy and return cookies/headers bound to the solving user agent.
+
+use crate::utils::proxy::ProxyConfig;
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// Imperva (Incapsula) anti-bot task with fluent builder pattern.
+///
+/// Solves the `reese84` and/or `___utmvc` cookies of an Imperva-protected site.
+///
+/// # Important Notes
+///
+/// - **Proxy Required**: A static or sticky proxy is always required.
+/// - **User Agent**: Use the returned user agent with the solved cookies.
+/// - **Provider Inputs**: Capsolver takes the `utmvc`/`reese84` flags, while
+///   CapMonster needs the Incapsula script and session cookie.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::{Imperva, ProxyConfig};
+///
+/// let proxy = ProxyConfig::http("192.168.1.1", 8080);
+///
+/// // Capsolver
+/// let task = Imperva::new("https://example.com", proxy)
+///     .with_reese84()
+///     .with_reese84_script_url("https://example.com/epsilon-Obedience-a-Nor");
+///
+/// // CapMonster
+/// let proxy = ProxyConfig::http("192.168.1.1", 8080);
+/// let task = Imperva::new("https://example.com", proxy)
+///     .with_user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)...")
+///     .with_incapsula_script("dmFyIF8weGMzYTg9...")
+///     .with_session_cookie("incap_ses_1166_2930313=br7iX33ZNCtf3HlpEXcuEDzz72cAAAAA");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Imperva {
+    /// Full URL of the protected page
+    pub website_url: String,
+    /// Proxy configuration (always required)
+    pub proxy: ProxyConfig,
+    /// User agent to use (should match your actual requests)
+    pub user_agent: Option<String>,
+    /// Solve the `___utmvc` cookie
+    pub utmvc: bool,
+    /// Solve the `reese84` cookie
+    pub reese84: bool,
+    /// URL of the reese84 script (or its endpoint)
+    pub reese84_script_url: Option<String>,
+    /// Base64 encoded Incapsula script content
+    pub incapsula_script: Option<String>,
+    /// Incapsula session cookie (`incap_ses_*=...`)
+    pub session_cookie: Option<String>,
+}
+
+impl Imperva {
+    /// Create a new Imperva task.
+    ///
+    /// # Arguments
+    ///
+    /// * `website_url` - Full URL of the protected page
+    /// * `proxy` - Proxy configuration (must be static or sticky, not rotating)
+    pub fn new(website_url: impl Into<String>, proxy: ProxyConfig) -> Self {
+        Self {
+            website_url: website_url.into(),
+            proxy,
+            user_agent: None,
+            utmvc: false,
+            reese84: false,
+            reese84_script_url: None,
+            incapsula_script: None,
+            session_cookie: None,
+        }
+    }
+
+    /// Set a custom user agent.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Request the `___utmvc` cookie.
+    pub fn with_utmvc(mut self) -> Self {
+        self.utmvc = true;
+        self
+    }
+
+    /// Request the `reese84` cookie.
+    pub fn with_reese84(mut self) -> Self {
+        self.reese84 = true;
+        self
+    }
+
+    /// Set the URL of the reese84 script (or its endpoint).
+    pub fn with_reese84_script_url(mut self, url: impl Into<String>) -> Self {
+        self.reese84_script_url = Some(url.into());
+        self
+    }
+
+    /// Set the base64 encoded Incapsula script content.
+    pub fn with_incapsula_script(mut self, base64: impl Into<String>) -> Self {
+        self.incapsula_script = Some(base64.into());
+        self
+    }
+
+    /// Set the Incapsula script from raw bytes (automatically base64-encoded).
+    pub fn with_incapsula_script_bytes(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.incapsula_script = Some(STANDARD.encode(bytes.as_ref()));
+        self
+    }
+
+    /// Set the Incapsula session cookie (`incap_ses_*=...`).
+    pub fn with_session_cookie(mut self, cookie: impl Into<String>) -> Self {
+        self.session_cookie = Some(cookie.into());
+        self
+    }
+
+    /// Get the proxy configuration.
+    pub fn proxy(&self) -> &ProxyConfig {
+        &self.proxy
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the user agent if set.
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+}
+
+/// Kasada anti-bot task with fluent builder pattern.
+///
+/// Solves the `x-kpsdk-ct` (and optionally `x-kpsdk-cd`) headers of a
+/// Kasada-protected site.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::{Kasada, ProxyConfig};
+///
+/// let proxy = ProxyConfig::http("192.168.1.1", 8080);
+/// let task = Kasada::new("https://example.com/login", proxy).with_cd();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kasada {
+    /// Full URL of the protected page
+    pub website_url: String,
+    /// Proxy configuration (always required)
+    pub proxy: ProxyConfig,
+    /// User agent to use (should match your actual requests)
+    pub user_agent: Option<String>,
+    /// Also solve the `x-kpsdk-cd` header
+    pub cd: bool,
+    /// Only solve the `x-kpsdk-cd` header
+    pub only_cd: bool,
+}
+
+impl Kasada {
+    /// Create a new Kasada task.
+    ///
+    /// # Arguments
+    ///
+    /// * `website_url` - Full URL of the protected page
+    /// * `proxy` - Proxy configuration (must be static or sticky, not rotating)
+    pub fn new(website_url: impl Into<String>, proxy: ProxyConfig) -> Self {
+        Self {
+            website_url: website_url.into(),
+            proxy,
+            user_agent: None,
+            cd: false,
+            only_cd: false,
+        }
+    }
+
+    /// Set a custom user agent.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Also solve the `x-kpsdk-cd` header.
+    pub fn with_cd(mut self) -> Self {
+        self.cd = true;
+        self
+    }
+
+    /// Only solve the `x-kpsdk-cd` header.
+    pub fn only_cd(mut self) -> Self {
+        self.only_cd = true;
+        self
+    }
+
+    /// Get the proxy configuration.
+    pub fn proxy(&self) -> &ProxyConfig {
+        &self.proxy
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the user agent if set.
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_imperva_with_options() {
+        let task = Imperva::new("https://example.com", ProxyConfig::http("1.2.3.4", 8080))
+            .with_user_agent("Mozilla/5.0")
+            .with_utmvc()
+            .with_reese84()
+            .with_reese84_script_url("https://example.com/reese.js")
+            .with_incapsula_script_bytes(b"var x;")
+            .with_session_cookie("incap_ses_1=abc");
+
+        assert_eq!(task.website_url(), "https://example.com");
+        assert_eq!(task.proxy().address, "1.2.3.4");
+        assert_eq!(task.user_agent(), Some("Mozilla/5.0"));
+        assert!(task.utmvc);
+        assert!(task.reese84);
+        assert_eq!(task.incapsula_script.as_deref(), Some("dmFyIHg7"));
+        assert_eq!(task.session_cookie.as_deref(), Some("incap_ses_1=abc"));
+    }
+
+    #[test]
+    fn test_kasada_with_options() {
+        let task = Kasada::new("https://example.com", ProxyConfig::http("1.2.3.4", 8080))
+            .with_cd()
+            .only_cd();
+        assert!(task.cd);
+        assert!(task.only_cd);
+        assert!(task.user_agent().is_none());
+    }
+}
@@ -0,0 +1,212 @@
+//! Audio to text captcha task type with builder pattern.
+//!
+//! This module provides provider-agnostic audio captcha recognition task definitions
+//! that can be converted to provider-specific formats. Unsupported combinations are rejected via `TryFrom`.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use std::fmt;
+use thiserror::Error;
+
+/// Maximum accepted audio file size (1 MiB).
+pub const MAX_AUDIO_SIZE: usize = 1024 * 1024;
+
+/// Error when validating audio input.
+#[derive(Debug, Clone, Error)]
+pub enum AudioError {
+    /// Audio file is empty.
+    #[error("Audio file is empty")]
+    Empty,
+    /// Audio file exceeds the maximum size.
+    #[error("Audio file size ({size} bytes) exceeds maximum of {max} bytes")]
+    TooLarge {
+        /// The audio file size in bytes.
+        size: usize,
+        /// The maximum allowed size in bytes.
+        max: usize,
+    },
+    /// Audio file is neither MP3 nor WAV.
+    #[error("Unsupported audio format: expected MP3 or WAV")]
+    UnsupportedFormat,
+}
+
+/// Audio container format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    /// MPEG-1/2 Audio Layer III
+    Mp3,
+    /// RIFF WAVE
+    Wav,
+}
+
+impl AudioFormat {
+    /// Detect the audio format from the file's leading bytes.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [
+                b'R',
+                b'I',
+                b'F',
+                b'F',
+                _,
+                _,
+                _,
+                _,
+                b'W',
+                b'A',
+                b'V',
+                b'E',
+                ..,
+            ] => Some(Self::Wav),
+            [b'I', b'D', b'3', ..] => Some(Self::Mp3),
+            // MPEG audio frame sync (11 set bits)
+            [0xFF, second, ..] if second & 0xE0 == 0xE0 => Some(Self::Mp3),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AudioFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mp3 => write!(f, "mp3"),
+            Self::Wav => write!(f, "wav"),
+        }
+    }
+}
+
+/// Audio to text captcha task with fluent builder pattern.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::AudioToText;
+///
+/// // From raw bytes (format and size are checked, then base64-encoded)
+/// let audio_bytes: Vec<u8> = vec![b'I', b'D', b'3', 0x04, 0x00];
+/// let task = AudioToText::from_bytes(audio_bytes, "en").unwrap();
+///
+/// // From pre-encoded base64 string (not checked)
+/// let task = AudioToText::from_base64("SUQzBAA...", "en");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AudioToText {
+    /// Base64 encoded audio content
+    pub body: String,
+
+    /// Language code of the spoken content (e.g., "en", "ru", "de")
+    pub lang: String,
+
+    /// Detected audio format (`None` for pre-encoded input)
+    pub format: Option<AudioFormat>,
+}
+
+impl AudioToText {
+    /// Create a new audio to text task from raw MP3 or WAV bytes.
+    ///
+    /// The bytes are checked for format and size, then encoded to base64.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AudioError`] if the file is empty, larger than [`MAX_AUDIO_SIZE`],
+    /// or is neither MP3 nor WAV.
+    pub fn from_bytes(
+        bytes: impl AsRef<[u8]>,
+        lang: impl Into<String>,
+    ) -> Result<Self, AudioError> {
+        let bytes = bytes.as_ref();
+        if bytes.is_empty() {
+            return Err(AudioError::Empty);
+        }
+        if bytes.len() > MAX_AUDIO_SIZE {
+            return Err(AudioError::TooLarge {
+                size: bytes.len(),
+                max: MAX_AUDIO_SIZE,
+            });
+        }
+        let format = AudioFormat::detect(bytes).ok_or(AudioError::UnsupportedFormat)?;
+
+        Ok(Self {
+            body: STANDARD.encode(bytes),
+            lang: lang.into(),
+            format: Some(format),
+        })
+    }
+
+    /// Create a new audio to text task from a pre-encoded base64 string.
+    ///
+    /// No format or size checks are performed on pre-encoded input.
+    pub fn from_base64(base64: impl Into<String>, lang: impl Into<String>) -> Self {
+        Self {
+            body: base64.into(),
+            lang: lang.into(),
+            format: None,
+        }
+    }
+
+    /// Get the base64 audio body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Get the language code.
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_to_text_from_mp3_bytes() {
+        let bytes = vec![b'I', b'D', b'3', 0x04, 0x00];
+        let task = AudioToText::from_bytes(&bytes, "en").unwrap();
+        assert_eq!(task.body(), STANDARD.encode(&bytes));
+        assert_eq!(task.lang(), "en");
+        assert_eq!(task.format, Some(AudioFormat::Mp3));
+    }
+
+    #[test]
+    fn test_audio_to_text_from_wav_bytes() {
+        let bytes = b"RIFF\x24\x00\x00\x00WAVEfmt ";
+        let task = AudioToText::from_bytes(bytes, "de").unwrap();
+        assert_eq!(task.format, Some(AudioFormat::Wav));
+    }
+
+    #[test]
+    fn test_audio_format_detect_frame_sync() {
+        assert_eq!(
+            AudioFormat::detect(&[0xFF, 0xFB, 0x90]),
+            Some(AudioFormat::Mp3)
+        );
+        assert_eq!(AudioFormat::detect(&[0x89, 0x50, 0x4E, 0x47]), None);
+    }
+
+    #[test]
+    fn test_audio_to_text_rejects_empty() {
+        let result = AudioToText::from_bytes([], "en");
+        assert!(matches!(result, Err(AudioError::Empty)));
+    }
+
+    #[test]
+    fn test_audio_to_text_rejects_too_large() {
+        let mut bytes = vec![0u8; MAX_AUDIO_SIZE + 1];
+        bytes[..3].copy_from_slice(b"ID3");
+        let result = AudioToText::from_bytes(&bytes, "en");
+        assert!(matches!(result, Err(AudioError::TooLarge { .. })));
+    }
+
+    #[test]
+    fn test_audio_to_text_rejects_unknown_format() {
+        let result = AudioToText::from_bytes([0x89, 0x50, 0x4E, 0x47], "en");
+        assert!(matches!(result, Err(AudioError::UnsupportedFormat)));
+    }
+
+    #[test]
+    fn test_audio_to_text_from_base64() {
+        let task = AudioToText::from_base64("SUQzBAA=", "ru");
+        assert_eq!(task.body(), "SUQzBAA=");
+        assert!(task.format.is_none());
+    }
+}
@@ -0,0 +1,307 @@
+//! Grid image classification task type with builder pattern.
+//!
+//! This module provides provider-agnostic grid classification task definitions
+//! (reCAPTCHA/hCaptcha-style tile selection) that can be converted to provider-specific
+//! formats. Unsupported combinations are rejected via `TryFrom`.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use thiserror::Error;
+
+/// Error when validating grid dimensions.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GridError {
+    /// The grid has no rows or no columns.
+    #[error("Grid must have at least one row and one column, got {rows}x{columns}")]
+    Empty {
+        /// Number of rows given.
+        rows: u8,
+        /// Number of columns given.
+        columns: u8,
+    },
+    /// The number of tiles does not match the grid size.
+    #[error("Expected {expected} tiles for a {rows}x{columns} grid, got {actual}")]
+    TileCount {
+        /// Number of rows given.
+        rows: u8,
+        /// Number of columns given.
+        columns: u8,
+        /// Tiles required by the grid size.
+        expected: usize,
+        /// Tiles given.
+        actual: usize,
+    },
+}
+
+/// Images of a grid classification challenge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridImages {
+    /// A single base64 encoded image containing the whole grid
+    Grid(String),
+    /// Individual base64 encoded tiles in row-major order
+    Tiles(Vec<String>),
+}
+
+/// Grid image classification task with fluent builder pattern.
+///
+/// Use this type when the challenge is rendered on your side and you need to know
+/// which tiles to click. Tiles are numbered from `0` in row-major order, and the
+/// solution returns the indices of the tiles to select.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::GridClassification;
+///
+/// // Single image containing a 3x3 grid
+/// let task = GridClassification::from_grid_base64("iVBORw0KGgoAAAANSUhEUgAA...", 3, 3)
+///     .unwrap()
+///     .with_question("Select all images with buses");
+///
+/// // Individual tiles of a 2x2 grid
+/// let tiles = vec!["tile0", "tile1", "tile2", "tile3"];
+/// let task = GridClassification::from_tiles_base64(tiles, 2, 2)
+///     .unwrap()
+///     .with_question("/m/01bjv");
+/// assert_eq!(task.tile_count(), 4);
+///
+/// // Three tiles cannot fill a 2x2 grid
+/// assert!(GridClassification::from_tiles_base64(vec!["a", "b", "c"], 2, 2).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GridClassification {
+    /// Grid image or individual tiles (base64, without data URI prefix)
+    pub images: GridImages,
+
+    /// Number of rows in the grid
+    pub rows: u8,
+
+    /// Number of columns in the grid
+    pub columns: u8,
+
+    /// Question shown to workers (e.g. "Select all images with buses") or
+    /// object name / ID (e.g. "/m/01bjv")
+    pub question: Option<String>,
+
+    /// Page source URL (optional)
+    pub website_url: Option<String>,
+
+    /// reCAPTCHA site key of the page (optional)
+    pub website_key: Option<String>,
+
+    /// Base64-encoded instruction image for workers
+    pub img_instructions: Option<String>,
+}
+
+impl GridClassification {
+    /// Create a new grid classification task from a single grid image (raw bytes).
+    ///
+    /// The bytes will be automatically encoded to base64.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GridError::Empty`] if `rows` or `columns` is zero.
+    pub fn from_grid_bytes(
+        bytes: impl AsRef<[u8]>,
+        rows: u8,
+        columns: u8,
+    ) -> Result<Self, GridError> {
+        Self::from_grid_base64(STANDARD.encode(bytes.as_ref()), rows, columns)
+    }
+
+    /// Create a new grid classification task from a single pre-encoded grid image.
+    ///
+    /// The string should NOT include the data URI prefix (e.g., "data:image/png;base64,").
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GridError::Empty`] if `rows` or `columns` is zero.
+    pub fn from_grid_base64(
+        base64: impl Into<String>,
+        rows: u8,
+        columns: u8,
+    ) -> Result<Self, GridError> {
+        Self::new(GridImages::Grid(base64.into()), rows, columns)
+    }
+
+    /// Create a new grid classification task from individual tiles (raw bytes).
+    ///
+    /// Tiles must be given in row-major order; each is encoded to base64.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GridError`] if `rows` or `columns` is zero, or if the number
+    /// of tiles is not `rows * columns`.
+    pub fn from_tiles_bytes<I, B>(tiles: I, rows: u8, columns: u8) -> Result<Self, GridError>
+    where
+        I: IntoIterator<Item = B>,
+        B: AsRef<[u8]>,
+    {
+        let tiles = tiles
+            .into_iter()
+            .map(|tile| STANDARD.encode(tile.as_ref()))
+            .collect();
+        Self::new(GridImages::Tiles(tiles), rows, columns)
+    }
+
+    /// Create a new grid classification task from individual pre-encoded tiles.
+    ///
+    /// Tiles must be given in row-major order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GridError`] if `rows` or `columns` is zero, or if the number
+    /// of tiles is not `rows * columns`.
+    pub fn from_tiles_base64<I, S>(tiles: I, rows: u8, columns: u8) -> Result<Self, GridError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let tiles = tiles.into_iter().map(Into::into).collect();
+        Self::new(GridImages::Tiles(tiles), rows, columns)
+    }
+
+    fn new(images: GridImages, rows: u8, columns: u8) -> Result<Self, GridError> {
+        if rows == 0 || columns == 0 {
+            return Err(GridError::Empty { rows, columns });
+        }
+        if let GridImages::Tiles(tiles) = &images {
+            let expected = rows as usize * columns as usize;
+            if tiles.len() != expected {
+                return Err(GridError::TileCount {
+                    rows,
+                    columns,
+                    expected,
+                    actual: tiles.len(),
+                });
+            }
+        }
+
+        Ok(Self {
+            images,
+            rows,
+            columns,
+            question: None,
+            website_url: None,
+            website_key: None,
+            img_instructions: None,
+        })
+    }
+
+    /// Set the question or object name (e.g. "Select all images with buses" or "/m/01bjv").
+    pub fn with_question(mut self, question: impl Into<String>) -> Self {
+        self.question = Some(question.into());
+        self
+    }
+
+    /// Set the page source URL.
+    pub fn with_website_url(mut self, url: impl Into<String>) -> Self {
+        self.website_url = Some(url.into());
+        self
+    }
+
+    /// Set the reCAPTCHA site key of the page.
+    pub fn with_website_key(mut self, key: impl Into<String>) -> Self {
+        self.website_key = Some(key.into());
+        self
+    }
+
+    /// Set base64-encoded instruction image for workers.
+    pub fn with_img_instructions(mut self, img: impl Into<String>) -> Self {
+        self.img_instructions = Some(img.into());
+        self
+    }
+
+    /// Set instruction image from raw bytes (automatically base64-encoded).
+    pub fn with_img_instructions_bytes(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.img_instructions = Some(STANDARD.encode(bytes.as_ref()));
+        self
+    }
+
+    /// Get the question or object name.
+    pub fn question(&self) -> Option<&str> {
+        self.question.as_deref()
+    }
+
+    /// Check if the images are sent as individual tiles.
+    pub fn is_tiles(&self) -> bool {
+        matches!(self.images, GridImages::Tiles(_))
+    }
+
+    /// Get the total number of tiles in the grid.
+    pub fn tile_count(&self) -> usize {
+        self.rows as usize * self.columns as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_classification_from_grid_bytes() {
+        let bytes = vec![0x89, 0x50, 0x4E, 0x47];
+        let task = GridClassification::from_grid_bytes(&bytes, 3, 3).unwrap();
+        assert_eq!(task.images, GridImages::Grid(STANDARD.encode(&bytes)));
+        assert_eq!(task.rows, 3);
+        assert_eq!(task.columns, 3);
+        assert_eq!(task.tile_count(), 9);
+        assert!(!task.is_tiles());
+        assert!(task.question().is_none());
+    }
+
+    #[test]
+    fn test_grid_classification_from_tiles_bytes() {
+        let tiles = vec![vec![1u8], vec![2u8], vec![3u8], vec![4u8]];
+        let task = GridClassification::from_tiles_bytes(&tiles, 2, 2).unwrap();
+        assert!(task.is_tiles());
+        match task.images {
+            GridImages::Tiles(encoded) => {
+                assert_eq!(encoded.len(), 4);
+                assert_eq!(encoded[0], STANDARD.encode([1u8]));
+            }
+            GridImages::Grid(_) => panic!("expected tiles"),
+        }
+    }
+
+    #[test]
+    fn test_grid_classification_with_options() {
+        let task = GridClassification::from_grid_base64("grid", 4, 4)
+            .unwrap()
+            .with_question("Select all images with buses")
+            .with_website_url("https://example.com")
+            .with_website_key("site-key")
+            .with_img_instructions("instructions");
+
+        assert_eq!(task.question(), Some("Select all images with buses"));
+        assert_eq!(task.website_url.as_deref(), Some("https://example.com"));
+        assert_eq!(task.website_key.as_deref(), Some("site-key"));
+        assert_eq!(task.img_instructions.as_deref(), Some("instructions"));
+    }
+
+    #[test]
+    fn test_grid_classification_tile_count_mismatch() {
+        let err = GridClassification::from_tiles_base64(vec!["a", "b"], 3, 3).unwrap_err();
+        assert_eq!(
+            err,
+            GridError::TileCount {
+                rows: 3,
+                columns: 3,
+                expected: 9,
+                actual: 2
+            }
+        );
+        assert_eq!(err.to_string(), "Expected 9 tiles for a 3x3 grid, got 2");
+    }
+
+    #[test]
+    fn test_grid_classification_empty_grid() {
+        let err = GridClassification::from_grid_base64("grid", 0, 3).unwrap_err();
+        assert_eq!(
+            err,
+            GridError::Empty {
+                rows: 0,
+                columns: 3
+            }
+        );
+    }
+}
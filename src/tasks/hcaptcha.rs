@@ -0,0 +1,153 @@
+//! hCaptcha task type with builder pattern.
+//!
+//! This module provides provider-agnostic hCaptcha task definitions that can be
+//! converted to provider-specific formats. Unsupported providers are rejected via `TryFrom`.
+
+use crate::utils::proxy::ProxyConfig;
+use std::collections::HashMap;
+
+/// hCaptcha task with fluent builder pattern.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::{HCaptcha, ProxyConfig};
+///
+/// // Simple proxyless task
+/// let task = HCaptcha::new("https://example.com", "a5f74b19-9e45-40e0-b45d-47ff91b7a6c2");
+/// assert!(!task.is_invisible());
+///
+/// // Enterprise (rqdata) with proxy and matching user agent
+/// let task = HCaptcha::new("https://example.com", "a5f74b19-9e45-40e0-b45d-47ff91b7a6c2")
+///     .invisible()
+///     .with_rqdata("rqdata-value")
+///     .with_user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)...")
+///     .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+/// assert!(task.is_enterprise());
+/// ```
+///
+/// # Finding the Site Key
+///
+/// The site key is the `data-sitekey` attribute of the `h-captcha` element,
+/// or the `sitekey` parameter of the hCaptcha iframe URL.
+#[derive(Debug, Clone)]
+pub struct HCaptcha {
+    /// Full URL of the page with the hCaptcha widget
+    pub website_url: String,
+    /// The hCaptcha site key
+    pub website_key: String,
+    /// Whether this is an invisible hCaptcha
+    pub is_invisible: bool,
+    /// Enterprise payload as key-value pairs (e.g., `rqdata`)
+    pub enterprise_payload: Option<HashMap<String, serde_json::Value>>,
+    /// User agent to use when solving
+    pub user_agent: Option<String>,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl HCaptcha {
+    /// Create a new hCaptcha task.
+    pub fn new(website_url: impl Into<String>, website_key: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            website_key: website_key.into(),
+            is_invisible: false,
+            enterprise_payload: None,
+            user_agent: None,
+            proxy: None,
+        }
+    }
+
+    /// Mark this as an invisible hCaptcha.
+    pub fn invisible(mut self) -> Self {
+        self.is_invisible = true;
+        self
+    }
+
+    /// Set the enterprise payload.
+    pub fn with_enterprise_payload(mut self, payload: HashMap<String, serde_json::Value>) -> Self {
+        self.enterprise_payload = Some(payload);
+        self
+    }
+
+    /// Set the enterprise `rqdata` value.
+    ///
+    /// The value must be taken fresh from the page's `hcaptcha.setData()` call.
+    pub fn with_rqdata(mut self, rqdata: impl Into<String>) -> Self {
+        self.enterprise_payload
+            .get_or_insert_with(HashMap::new)
+            .insert(
+                "rqdata".to_string(),
+                serde_json::Value::String(rqdata.into()),
+            );
+        self
+    }
+
+    /// Set a custom user agent for solving.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Check if this is an invisible hCaptcha.
+    pub fn is_invisible(&self) -> bool {
+        self.is_invisible
+    }
+
+    /// Check if an enterprise payload is set.
+    pub fn is_enterprise(&self) -> bool {
+        self.enterprise_payload.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the website key.
+    pub fn website_key(&self) -> &str {
+        &self.website_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hcaptcha_new() {
+        let task = HCaptcha::new("https://example.com", "site-key");
+        assert_eq!(task.website_url(), "https://example.com");
+        assert_eq!(task.website_key(), "site-key");
+        assert!(!task.is_invisible());
+        assert!(!task.is_enterprise());
+        assert!(!task.has_proxy());
+    }
+
+    #[test]
+    fn test_hcaptcha_with_rqdata() {
+        let task = HCaptcha::new("https://example.com", "site-key")
+            .invisible()
+            .with_rqdata("rq")
+            .with_user_agent("Mozilla/5.0");
+        assert!(task.is_invisible());
+        assert!(task.is_enterprise());
+        assert_eq!(
+            task.enterprise_payload.unwrap()["rqdata"],
+            serde_json::json!("rq")
+        );
+        assert_eq!(task.user_agent.as_deref(), Some("Mozilla/5.0"));
+    }
+}
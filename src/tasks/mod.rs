@@ -14,6 +14,24 @@
 //! | [`TurnstileWaitRoom`] | Cloudflare Waiting Room |
 //! | [`CloudflareChallenge`] | Full-page Cloudflare challenge bypass |
 //! | [`ImageToText`] | Image captcha OCR recognition |
+//! | [`GridClassification`] | Grid image tile classification |
+//! | [`Rotate`] | Rotate-the-image captcha |
+//! | [`AudioToText`] | Audio captcha speech recognition |
+//! | [`MtCaptcha`] | MTCaptcha widget |
+//! | [`FriendlyCaptcha`] | Friendly Captcha widget |
+//! | [`Cutcaptcha`] | Cutcaptcha widget |
+//! | [`YandexSmartCaptcha`] | Yandex SmartCaptcha |
+//! | [`Tencent`] | Tencent captcha (TCaptcha) |
+//! | [`Lemin`] | Lemin Cropped puzzle captcha |
+//! | [`KeyCaptcha`] | KeyCaptcha puzzle captcha |
+//! | [`Capy`] | Capy puzzle captcha |
+//! | [`Imperva`] | Imperva (Incapsula) anti-bot bypass |
+//! | [`Kasada`] | Kasada anti-bot bypass |
+//! | [`Prosopo`] | Prosopo Procaptcha widget |
+//! | [`CaptchaFox`] | CaptchaFox widget |
+//! | [`Altcha`] | Altcha widget |
+//! | [`TextQuestion`] | Plain-text question captcha |
+//! | [`HCaptcha`] | hCaptcha widget |
 //!
 //! # Usage
 //!
@@ -78,17 +96,37 @@
 //! assert!(task.is_invisible());
 //! ```
 
+mod antibot;
+mod audio_to_text;
 mod cloudflare;
+mod grid_classification;
+mod hcaptcha;
 mod image_to_text;
+mod puzzle;
 mod recaptcha;
+mod rotate;
+mod tencent;
+mod text_question;
 mod turnstile_challenge;
 mod turnstile_waitroom;
+mod widget;
+mod yandex;
 
+pub use antibot::{Imperva, Kasada};
+pub use audio_to_text::{AudioError, AudioFormat, AudioToText, MAX_AUDIO_SIZE};
 pub use cloudflare::{CloudflareChallenge, Turnstile};
+pub use grid_classification::{GridClassification, GridError, GridImages};
+pub use hcaptcha::HCaptcha;
 pub use image_to_text::ImageToText;
+pub use puzzle::{Capy, KeyCaptcha, Lemin};
 pub use recaptcha::{ReCaptchaV2, ReCaptchaV3};
+pub use rotate::{Rotate, RotateError};
+pub use tencent::Tencent;
+pub use text_question::TextQuestion;
 pub use turnstile_challenge::{TurnstileChallenge, TurnstileChallengeMode};
 pub use turnstile_waitroom::TurnstileWaitRoom;
+pub use widget::{Altcha, CaptchaFox, Cutcaptcha, FriendlyCaptcha, MtCaptcha, Prosopo};
+pub use yandex::YandexSmartCaptcha;
 
 use std::fmt;
 
@@ -127,6 +165,42 @@ pub enum CaptchaTask {
     CloudflareChallenge(CloudflareChallenge),
     /// Image to text OCR captcha
     ImageToText(ImageToText),
+    /// Grid image classification (tile selection)
+    GridClassification(GridClassification),
+    /// Rotate-the-image captcha
+    Rotate(Rotate),
+    /// Audio captcha speech recognition
+    AudioToText(AudioToText),
+    /// MTCaptcha widget
+    MtCaptcha(MtCaptcha),
+    /// Friendly Captcha widget
+    FriendlyCaptcha(FriendlyCaptcha),
+    /// Cutcaptcha widget
+    Cutcaptcha(Cutcaptcha),
+    /// Yandex SmartCaptcha
+    YandexSmartCaptcha(YandexSmartCaptcha),
+    /// Tencent captcha (TCaptcha)
+    Tencent(Tencent),
+    /// Lemin Cropped puzzle captcha
+    Lemin(Lemin),
+    /// KeyCaptcha puzzle captcha
+    KeyCaptcha(KeyCaptcha),
+    /// Capy puzzle captcha
+    Capy(Capy),
+    /// Imperva (Incapsula) anti-bot bypass
+    Imperva(Imperva),
+    /// Kasada anti-bot bypass
+    Kasada(Kasada),
+    /// Prosopo Procaptcha widget
+    Prosopo(Prosopo),
+    /// CaptchaFox widget
+    CaptchaFox(CaptchaFox),
+    /// Altcha widget
+    Altcha(Altcha),
+    /// Plain-text question captcha
+    TextQuestion(TextQuestion),
+    /// hCaptcha widget
+    HCaptcha(HCaptcha),
 }
 
 impl fmt::Display for CaptchaTask {
@@ -156,6 +230,24 @@ impl fmt::Display for CaptchaTask {
             Self::TurnstileWaitRoom(_) => write!(f, "TurnstileWaitRoom"),
             Self::CloudflareChallenge(_) => write!(f, "CloudflareChallenge"),
             Self::ImageToText(_) => write!(f, "ImageToText"),
+            Self::GridClassification(_) => write!(f, "GridClassification"),
+            Self::Rotate(_) => write!(f, "Rotate"),
+            Self::AudioToText(_) => write!(f, "AudioToText"),
+            Self::MtCaptcha(_) => write!(f, "MtCaptcha"),
+            Self::FriendlyCaptcha(_) => write!(f, "FriendlyCaptcha"),
+            Self::Cutcaptcha(_) => write!(f, "Cutcaptcha"),
+            Self::YandexSmartCaptcha(_) => write!(f, "YandexSmartCaptcha"),
+            Self::Tencent(_) => write!(f, "Tencent"),
+            Self::Lemin(_) => write!(f, "Lemin"),
+            Self::KeyCaptcha(_) => write!(f, "KeyCaptcha"),
+            Self::Capy(_) => write!(f, "Capy"),
+            Self::Imperva(_) => write!(f, "Imperva"),
+            Self::Kasada(_) => write!(f, "Kasada"),
+            Self::Prosopo(_) => write!(f, "Prosopo"),
+            Self::CaptchaFox(_) => write!(f, "CaptchaFox"),
+            Self::Altcha(_) => write!(f, "Altcha"),
+            Self::TextQuestion(_) => write!(f, "TextQuestion"),
+            Self::HCaptcha(_) => write!(f, "HCaptcha"),
         }
     }
 }
@@ -201,3 +293,111 @@ impl From<ImageToText> for CaptchaTask {
         Self::ImageToText(task)
     }
 }
+
+impl From<GridClassification> for CaptchaTask {
+    fn from(task: GridClassification) -> Self {
+        Self::GridClassification(task)
+    }
+}
+
+impl From<Rotate> for CaptchaTask {
+    fn from(task: Rotate) -> Self {
+        Self::Rotate(task)
+    }
+}
+
+impl From<AudioToText> for CaptchaTask {
+    fn from(task: AudioToText) -> Self {
+        Self::AudioToText(task)
+    }
+}
+
+impl From<MtCaptcha> for CaptchaTask {
+    fn from(task: MtCaptcha) -> Self {
+        Self::MtCaptcha(task)
+    }
+}
+
+impl From<FriendlyCaptcha> for CaptchaTask {
+    fn from(task: FriendlyCaptcha) -> Self {
+        Self::FriendlyCaptcha(task)
+    }
+}
+
+impl From<Cutcaptcha> for CaptchaTask {
+    fn from(task: Cutcaptcha) -> Self {
+        Self::Cutcaptcha(task)
+    }
+}
+
+impl From<YandexSmartCaptcha> for CaptchaTask {
+    fn from(task: YandexSmartCaptcha) -> Self {
+        Self::YandexSmartCaptcha(task)
+    }
+}
+
+impl From<Tencent> for CaptchaTask {
+    fn from(task: Tencent) -> Self {
+        Self::Tencent(task)
+    }
+}
+
+impl From<Lemin> for CaptchaTask {
+    fn from(task: Lemin) -> Self {
+        Self::Lemin(task)
+    }
+}
+
+impl From<KeyCaptcha> for CaptchaTask {
+    fn from(task: KeyCaptcha) -> Self {
+        Self::KeyCaptcha(task)
+    }
+}
+
+impl From<Capy> for CaptchaTask {
+    fn from(task: Capy) -> Self {
+        Self::Capy(task)
+    }
+}
+
+impl From<Imperva> for CaptchaTask {
+    fn from(task: Imperva) -> Self {
+        Self::Imperva(task)
+    }
+}
+
+impl From<Kasada> for CaptchaTask {
+    fn from(task: Kasada) -> Self {
+        Self::Kasada(task)
+    }
+}
+
+impl From<Prosopo> for CaptchaTask {
+    fn from(task: Prosopo) -> Self {
+        Self::Prosopo(task)
+    }
+}
+
+impl From<CaptchaFox> for CaptchaTask {
+    fn from(task: CaptchaFox) -> Self {
+        Self::CaptchaFox(task)
+    }
+}
+
+impl From<Altcha> for CaptchaTask {
+    fn from(task: Altcha) -> Self {
+        Self::Altcha(task)
+    }
+}
+
+impl From<TextQuestion> for CaptchaTask {
+    fn from(task: TextQuestion) -> Self {
+        Self::TextQuestion(task)
+    }
+}
+
+impl From<HCaptcha> for CaptchaTask {
+    fn from(task: HCaptcha) -> Self {
+        Self::HCaptcha(task)
+    }
+}
@@ -0,0 +1,271 @@
+//! Lemin, KeyCaptcha and Capy puzzle captcha task types with builder pattern.
+//!
+//! This module provides provider-agnostic puzzle captcha task definitions
+//! that can be converted to provider-specific formats. Unsupported providers are rejected via `TryFrom`.
+
+use crate::utils::proxy::ProxyConfig;
+
+/// Lemin Cropped captcha task with fluent builder pattern.
+///
+/// The solution is an `answer`/`challenge_id` pair, returned as a
+/// [`LeminSolution`](crate::LeminSolution).
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::Lemin;
+///
+/// let task = Lemin::new(
+///     "https://example.com",
+///     "CROPPED_3dfdd5c_d1872b526b794d83ba3b365eb15a200b",
+///     "lemin-cropped-captcha",
+/// )
+/// .with_api_server("api.leminnow.com");
+/// ```
+///
+/// # Finding the Parameters
+///
+/// The captcha ID is part of the Lemin script URL
+/// (`.../captcha/v1/cropped/<captcha_id>/js`), and the div ID is the `id`
+/// of the element the captcha is rendered into.
+#[derive(Debug, Clone)]
+pub struct Lemin {
+    /// Full URL of the page with the captcha
+    pub website_url: String,
+    /// Lemin captcha ID
+    pub captcha_id: String,
+    /// ID of the parent `div` element
+    pub div_id: String,
+    /// Lemin API server domain, if not the default one
+    pub api_server: Option<String>,
+    /// User-Agent to use when solving
+    pub user_agent: Option<String>,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Lemin {
+    /// Create a new Lemin Cropped captcha task.
+    pub fn new(
+        website_url: impl Into<String>,
+        captcha_id: impl Into<String>,
+        div_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            website_url: website_url.into(),
+            captcha_id: captcha_id.into(),
+            div_id: div_id.into(),
+            api_server: None,
+            user_agent: None,
+            proxy: None,
+        }
+    }
+
+    /// Set the Lemin API server domain.
+    pub fn with_api_server(mut self, domain: impl Into<String>) -> Self {
+        self.api_server = Some(domain.into());
+        self
+    }
+
+    /// Set the User-Agent to use when solving.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+}
+
+/// KeyCaptcha task with fluent builder pattern.
+///
+/// The solution is a single token, returned as a [`TokenSolution`](crate::TokenSolution).
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::KeyCaptcha;
+///
+/// let task = KeyCaptcha::new(
+///     "https://example.com",
+///     "184015",
+///     "0917788cad24ad3a69813c4fcd556061",
+///     "02f7f9669f1269595c4c69bcd4a3c52e",
+///     "d888700f6f324ec0f32b44c32c50bde1",
+/// );
+/// ```
+///
+/// # Finding the Parameters
+///
+/// All four values are JavaScript variables in the page source:
+/// `s_s_c_user_id`, `s_s_c_session_id`, `s_s_c_web_server_sign` and
+/// `s_s_c_web_server_sign2`.
+#[derive(Debug, Clone)]
+pub struct KeyCaptcha {
+    /// Full URL of the page with the captcha
+    pub website_url: String,
+    /// Value of `s_s_c_user_id`
+    pub user_id: String,
+    /// Value of `s_s_c_session_id`
+    pub session_id: String,
+    /// Value of `s_s_c_web_server_sign`
+    pub web_server_sign: String,
+    /// Value of `s_s_c_web_server_sign2`
+    pub web_server_sign2: String,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl KeyCaptcha {
+    /// Create a new KeyCaptcha task.
+    pub fn new(
+        website_url: impl Into<String>,
+        user_id: impl Into<String>,
+        session_id: impl Into<String>,
+        web_server_sign: impl Into<String>,
+        web_server_sign2: impl Into<String>,
+    ) -> Self {
+        Self {
+            website_url: website_url.into(),
+            user_id: user_id.into(),
+            session_id: session_id.into(),
+            web_server_sign: web_server_sign.into(),
+            web_server_sign2: web_server_sign2.into(),
+            proxy: None,
+        }
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+}
+
+/// Capy puzzle captcha task with fluent builder pattern.
+///
+/// The solution contains the `captchakey`, `challengekey` and `answer` values,
+/// returned as a [`CapySolution`](crate::CapySolution).
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::Capy;
+///
+/// let task = Capy::new("https://example.com", "PUZZLE_Cme4hZLjuZRMYC3uh14C52D3uNms5w")
+///     .with_api_server("https://jp.api.capy.me/");
+/// ```
+///
+/// # Finding the Site Key
+///
+/// The site key is the `data-captchakey` attribute or the `captchakey`
+/// parameter passed to the Capy script (starts with "PUZZLE_").
+#[derive(Debug, Clone)]
+pub struct Capy {
+    /// Full URL of the page with the captcha
+    pub website_url: String,
+    /// The Capy site key (starts with "PUZZLE_")
+    pub website_key: String,
+    /// Capy API server URL, if not the default one
+    pub api_server: Option<String>,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Capy {
+    /// Create a new Capy puzzle captcha task.
+    pub fn new(website_url: impl Into<String>, website_key: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            website_key: website_key.into(),
+            api_server: None,
+            proxy: None,
+        }
+    }
+
+    /// Set the Capy API server URL.
+    pub fn with_api_server(mut self, url: impl Into<String>) -> Self {
+        self.api_server = Some(url.into());
+        self
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the website key.
+    pub fn website_key(&self) -> &str {
+        &self.website_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lemin_with_options() {
+        let task = Lemin::new("https://example.com", "CROPPED_id", "lemin-div")
+            .with_api_server("api.leminnow.com")
+            .with_user_agent("Mozilla/5.0");
+        assert_eq!(task.website_url(), "https://example.com");
+        assert_eq!(task.captcha_id, "CROPPED_id");
+        assert_eq!(task.div_id, "lemin-div");
+        assert_eq!(task.api_server.as_deref(), Some("api.leminnow.com"));
+        assert_eq!(task.user_agent.as_deref(), Some("Mozilla/5.0"));
+        assert!(!task.has_proxy());
+    }
+
+    #[test]
+    fn test_key_captcha_new() {
+        let task = KeyCaptcha::new("https://example.com", "1", "2", "3", "4")
+            .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+        assert_eq!(task.user_id, "1");
+        assert_eq!(task.session_id, "2");
+        assert_eq!(task.web_server_sign, "3");
+        assert_eq!(task.web_server_sign2, "4");
+        assert!(task.has_proxy());
+    }
+
+    #[test]
+    fn test_capy_new() {
+        let task = Capy::new("https://example.com", "PUZZLE_key");
+        assert_eq!(task.website_key(), "PUZZLE_key");
+        assert!(task.api_server.is_none());
+    }
+}
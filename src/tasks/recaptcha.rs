@@ -69,6 +69,14 @@ pub struct ReCaptchaV2 {
     pub user_agent: Option<String>,
     /// Cookies to pass to the solver
     pub cookies: Option<String>,
+    /// Request the `recaptcha-ca-e` session cookie (Capsolver `isSession`)
+    pub is_session: bool,
+    /// Request the `recaptcha-ca-t` V3 session cookie (Capsolver `isSessionV3`)
+    pub is_session_v3: bool,
+    /// Base64 encoded `anchor` response payload (Capsolver enterprise only)
+    pub anchor: Option<String>,
+    /// Base64 encoded `reload` response payload (Capsolver enterprise only)
+    pub reload: Option<String>,
     /// Proxy configuration (if required)
     pub proxy: Option<ProxyConfig>,
 }
@@ -103,6 +111,10 @@ impl ReCaptchaV2 {
             api_domain: None,
             user_agent: None,
             cookies: None,
+            is_session: false,
+            is_session_v3: false,
+            anchor: None,
+            reload: None,
             proxy: None,
         }
     }
@@ -170,6 +182,38 @@ impl ReCaptchaV2 {
         self
     }
 
+    /// Request the `recaptcha-ca-e` session cookie along with the token.
+    ///
+    /// The cookie is returned in [`ReCaptchaSolution::recaptcha_ca_e`](crate::ReCaptchaSolution::recaptcha_ca_e).
+    pub fn session(mut self) -> Self {
+        self.is_session = true;
+        self
+    }
+
+    /// Request the `recaptcha-ca-t` V3 session cookie along with the token.
+    ///
+    /// The cookie is returned by [`ReCaptchaSolution::session_cookie`](crate::ReCaptchaSolution::session_cookie).
+    pub fn session_v3(mut self) -> Self {
+        self.is_session_v3 = true;
+        self
+    }
+
+    /// Set the base64 encoded `anchor` response payload.
+    ///
+    /// Only used for enterprise reCAPTCHA.
+    pub fn with_anchor(mut self, anchor: impl Into<String>) -> Self {
+        self.anchor = Some(anchor.into());
+        self
+    }
+
+    /// Set the base64 encoded `reload` response payload.
+    ///
+    /// Only used for enterprise reCAPTCHA.
+    pub fn with_reload(mut self, reload: impl Into<String>) -> Self {
+        self.reload = Some(reload.into());
+        self
+    }
+
     /// Set the proxy configuration.
     ///
     /// Some providers require a proxy for certain task types.
@@ -193,6 +237,11 @@ impl ReCaptchaV2 {
         self.is_invisible
     }
 
+    /// Check if a session cookie is requested.
+    pub fn is_session(&self) -> bool {
+        self.is_session || self.is_session_v3
+    }
+
     /// Get the website URL.
     pub fn website_url(&self) -> &str {
         &self.website_url
@@ -250,6 +299,8 @@ pub struct ReCaptchaV3 {
     pub enterprise_payload: Option<HashMap<String, serde_json::Value>>,
     /// Custom API domain
     pub api_domain: Option<String>,
+    /// Request the `recaptcha-ca-t` session cookie (Capsolver `isSession`)
+    pub is_session: bool,
     /// Proxy configuration (if required)
     pub proxy: Option<ProxyConfig>,
 }
@@ -270,6 +321,7 @@ impl ReCaptchaV3 {
             min_score: None,
             enterprise_payload: None,
             api_domain: None,
+            is_session: false,
             proxy: None,
         }
     }
@@ -323,6 +375,14 @@ impl ReCaptchaV3 {
         self
     }
 
+    /// Request the `recaptcha-ca-t` session cookie along with the token.
+    ///
+    /// The cookie is returned by [`ReCaptchaSolution::session_cookie`](crate::ReCaptchaSolution::session_cookie).
+    pub fn session(mut self) -> Self {
+        self.is_session = true;
+        self
+    }
+
     /// Set the proxy configuration.
     pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
         self.proxy = Some(proxy);
@@ -339,6 +399,11 @@ impl ReCaptchaV3 {
         self.is_enterprise
     }
 
+    /// Check if a session cookie is requested.
+    pub fn is_session(&self) -> bool {
+        self.is_session
+    }
+
     /// Get the minimum score threshold if set.
     pub fn min_score(&self) -> Option<f32> {
         self.min_score
@@ -461,6 +526,22 @@ mod tests {
         assert!(task.has_proxy());
     }
 
+    #[test]
+    fn test_recaptcha_v2_session_options() {
+        let task = ReCaptchaV2::new("https://example.com", "site-key")
+            .enterprise()
+            .session()
+            .session_v3()
+            .with_anchor("anchor-data")
+            .with_reload("reload-data");
+
+        assert!(task.is_session());
+        assert!(task.is_session_v3);
+        assert_eq!(task.anchor.as_deref(), Some("anchor-data"));
+        assert_eq!(task.reload.as_deref(), Some("reload-data"));
+        assert!(!ReCaptchaV2::new("https://example.com", "site-key").is_session());
+    }
+
     #[test]
     fn test_recaptcha_v2_clone() {
         let task = ReCaptchaV2::new("https://example.com", "site-key")
@@ -528,6 +609,12 @@ mod tests {
         assert!(task.has_proxy());
     }
 
+    #[test]
+    fn test_recaptcha_v3_session() {
+        let task = ReCaptchaV3::new("https://example.com", "site-key").session();
+        assert!(task.is_session());
+    }
+
     #[test]
     fn test_recaptcha_v3_common_scores() {
         // Test common score values
@@ -0,0 +1,141 @@
+//! Rotate captcha task type with builder pattern.
+//!
+//! This module provides provider-agnostic rotate-the-image captcha task definitions
+//! that can be converted to provider-specific formats. Unsupported combinations are rejected via `TryFrom`.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use thiserror::Error;
+
+/// Error when validating rotate task options.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RotateError {
+    /// The rotation step is outside `1..=360` degrees.
+    #[error("Angle step must be 1..=360 degrees, got {0}")]
+    AngleStep(u16),
+}
+
+/// Rotate captcha task with fluent builder pattern.
+///
+/// The solution contains the angle (in degrees) the image must be rotated by
+/// to restore its upright orientation.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::Rotate;
+///
+/// // From raw bytes (automatically base64-encoded)
+/// let image_bytes: Vec<u8> = vec![0x89, 0x50, 0x4E, 0x47]; // PNG header
+/// let task = Rotate::from_bytes(image_bytes);
+///
+/// // From pre-encoded base64 string with options (RuCaptcha)
+/// let task = Rotate::from_base64("iVBORw0KGgoAAAANSUhEUgAA...")
+///     .with_angle_step(40)
+///     .unwrap()
+///     .with_comment("Rotate the animal to stand upright");
+///
+/// // With module (Capsolver: "rotate_v1", CapMonster: recognition task name)
+/// let task = Rotate::from_base64("iVBORw0KGgoAAAANSUhEUgAA...")
+///     .with_module("rotate_v1");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rotate {
+    /// Base64 encoded image content (without data URI prefix)
+    pub body: String,
+
+    /// Rotation step in degrees (each click rotates the image by this angle)
+    pub angle_step: Option<u16>,
+
+    /// Additional instruction text for workers
+    pub comment: Option<String>,
+
+    /// Recognition module to use (Capsolver: "rotate_v1", CapMonster: task name)
+    pub module: Option<String>,
+}
+
+impl Rotate {
+    /// Create a new rotate captcha task from raw image bytes.
+    ///
+    /// The bytes will be automatically encoded to base64.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        Self::from_base64(STANDARD.encode(bytes.as_ref()))
+    }
+
+    /// Create a new rotate captcha task from a pre-encoded base64 string.
+    ///
+    /// The string should NOT include the data URI prefix (e.g., "data:image/png;base64,").
+    pub fn from_base64(base64: impl Into<String>) -> Self {
+        Self {
+            body: base64.into(),
+            angle_step: None,
+            comment: None,
+            module: None,
+        }
+    }
+
+    /// Set the rotation step in degrees.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RotateError::AngleStep`] if `step` is not in `1..=360`.
+    pub fn with_angle_step(mut self, step: u16) -> Result<Self, RotateError> {
+        if !(1..=360).contains(&step) {
+            return Err(RotateError::AngleStep(step));
+        }
+        self.angle_step = Some(step);
+        Ok(self)
+    }
+
+    /// Set additional instruction text for workers.
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Set the recognition module (Capsolver: "rotate_v1", CapMonster: task name).
+    pub fn with_module(mut self, module: impl Into<String>) -> Self {
+        self.module = Some(module.into());
+        self
+    }
+
+    /// Get the base64 image body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_from_bytes() {
+        let bytes = vec![0x89, 0x50, 0x4E, 0x47];
+        let task = Rotate::from_bytes(&bytes);
+        assert_eq!(task.body(), STANDARD.encode(&bytes));
+        assert!(task.angle_step.is_none());
+        assert!(task.comment.is_none());
+        assert!(task.module.is_none());
+    }
+
+    #[test]
+    fn test_rotate_with_options() {
+        let task = Rotate::from_base64("base64data")
+            .with_angle_step(40)
+            .unwrap()
+            .with_comment("Rotate upright")
+            .with_module("rotate_v1");
+        assert_eq!(task.body(), "base64data");
+        assert_eq!(task.angle_step, Some(40));
+        assert_eq!(task.comment.as_deref(), Some("Rotate upright"));
+        assert_eq!(task.module.as_deref(), Some("rotate_v1"));
+    }
+
+    #[test]
+    fn test_rotate_angle_step_out_of_range() {
+        for step in [0, 361] {
+            let err = Rotate::from_base64("x").with_angle_step(step).unwrap_err();
+            assert_eq!(err, RotateError::AngleStep(step));
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Tencent captcha (TCaptcha) task type with builder pattern.
+//!
+//! This module provides a provider-agnostic Tencent captcha task definition
+//! that can be converted to provider-specific formats. Unsupported providers are rejected via `TryFrom`.
+
+use crate::utils::proxy::ProxyConfig;
+
+/// Tencent captcha (TCaptcha) task with fluent builder pattern.
+///
+/// The solution is a `ticket`/`randstr` pair, returned as a
+/// [`TencentSolution`](crate::TencentSolution).
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::Tencent;
+///
+/// let task = Tencent::new("https://example.com", "190014885")
+///     .with_captcha_script("https://turing.captcha.qcloud.com/TCaptcha.js");
+/// assert_eq!(task.app_id(), "190014885");
+/// ```
+///
+/// # Finding the App ID
+///
+/// The app ID is the first argument of `new TencentCaptcha(appId, callback)`
+/// or the `aid` parameter of requests to `captcha.qcloud.com`.
+#[derive(Debug, Clone)]
+pub struct Tencent {
+    /// Full URL of the page with the captcha
+    pub website_url: String,
+    /// The Tencent captcha app ID
+    pub app_id: String,
+    /// URL of the captcha loader script, if not the default one
+    pub captcha_script: Option<String>,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Tencent {
+    /// Create a new Tencent captcha task.
+    pub fn new(website_url: impl Into<String>, app_id: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            app_id: app_id.into(),
+            captcha_script: None,
+            proxy: None,
+        }
+    }
+
+    /// Set the URL of the captcha loader script.
+    pub fn with_captcha_script(mut self, url: impl Into<String>) -> Self {
+        self.captcha_script = Some(url.into());
+        self
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the app ID.
+    pub fn app_id(&self) -> &str {
+        &self.app_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tencent_with_options() {
+        let task = Tencent::new("https://example.com", "190014885")
+            .with_captcha_script("https://example.com/TCaptcha.js")
+            .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+        assert_eq!(task.website_url(), "https://example.com");
+        assert_eq!(task.app_id(), "190014885");
+        assert_eq!(
+            task.captcha_script.as_deref(),
+            Some("https://example.com/TCaptcha.js")
+        );
+        assert!(task.has_proxy());
+    }
+}
@@ -0,0 +1,72 @@
+//! Text question captcha task type with builder pattern.
+//!
+//! This module provides provider-agnostic text captcha task definitions (plain-text
+//! questions answered by a human worker). Unsupported providers are rejected via `TryFrom`.
+
+/// Text question captcha task with fluent builder pattern.
+///
+/// The solution contains the worker's answer as text, the same as an image captcha.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::TextQuestion;
+///
+/// let task = TextQuestion::new("What is 3 + four?");
+///
+/// // Route the question to Russian-speaking workers
+/// let task = TextQuestion::new("Сколько будет 3 + 4?").with_lang("ru");
+/// assert_eq!(task.lang(), Some("ru"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextQuestion {
+    /// Question text shown to workers
+    pub question: String,
+
+    /// Language of the question (e.g., "en", "ru")
+    pub lang: Option<String>,
+}
+
+impl TextQuestion {
+    /// Create a new text question task.
+    pub fn new(question: impl Into<String>) -> Self {
+        Self {
+            question: question.into(),
+            lang: None,
+        }
+    }
+
+    /// Set the language of the question (e.g., "en", "ru").
+    pub fn with_lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Get the question text.
+    pub fn question(&self) -> &str {
+        &self.question
+    }
+
+    /// Get the language of the question if set.
+    pub fn lang(&self) -> Option<&str> {
+        self.lang.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_question_new() {
+        let task = TextQuestion::new("What is 3 + four?");
+        assert_eq!(task.question(), "What is 3 + four?");
+        assert!(task.lang().is_none());
+    }
+
+    #[test]
+    fn test_text_question_with_lang() {
+        let task = TextQuestion::new("What is 3 + four?").with_lang("en");
+        assert_eq!(task.lang(), Some("en"));
+    }
+}
@@ -0,0 +1,415 @@
+//! MTCaptcha, Friendly Captcha, Cutcaptcha, Prosopo, CaptchaFox and Altcha task types
+//! with builder pattern.
+//!
+//! This module provides provider-agnostic definitions for widget captchas whose
+//! answer is a single token. Unsupported providers are rejected via `TryFrom`.
+
+use crate::utils::proxy::ProxyConfig;
+
+/// MTCaptcha task with fluent builder pattern.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::MtCaptcha;
+///
+/// let task = MtCaptcha::new("https://example.com", "MTPublic-KzqLY1cKH");
+/// assert!(!task.has_proxy());
+/// ```
+///
+/// # Finding the Site Key
+///
+/// The site key is the `sitekey` value of the `mtcaptchaConfig` object in the
+/// page source (starts with "MTPublic-").
+#[derive(Debug, Clone)]
+pub struct MtCaptcha {
+    /// Full URL of the page with the MTCaptcha widget
+    pub website_url: String,
+    /// The MTCaptcha site key (starts with "MTPublic-")
+    pub website_key: String,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl MtCaptcha {
+    /// Create a new MTCaptcha task.
+    pub fn new(website_url: impl Into<String>, website_key: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            website_key: website_key.into(),
+            proxy: None,
+        }
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the website key.
+    pub fn website_key(&self) -> &str {
+        &self.website_key
+    }
+}
+
+/// Friendly Captcha task with fluent builder pattern.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::FriendlyCaptcha;
+///
+/// let task = FriendlyCaptcha::new("https://example.com", "FCMST5VUMCBOCGQ9");
+/// ```
+///
+/// # Finding the Site Key
+///
+/// The site key is the `data-sitekey` attribute of the `frc-captcha` element.
+#[derive(Debug, Clone)]
+pub struct FriendlyCaptcha {
+    /// Full URL of the page with the Friendly Captcha widget
+    pub website_url: String,
+    /// The Friendly Captcha site key
+    pub website_key: String,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl FriendlyCaptcha {
+    /// Create a new Friendly Captcha task.
+    pub fn new(website_url: impl Into<String>, website_key: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            website_key: website_key.into(),
+            proxy: None,
+        }
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the website key.
+    pub fn website_key(&self) -> &str {
+        &self.website_key
+    }
+}
+
+/// Cutcaptcha task with fluent builder pattern.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::Cutcaptcha;
+///
+/// let task = Cutcaptcha::new(
+///     "https://example.com",
+///     "a1488b66da00bf332a1488993a5443c79047e752",
+///     "SAb83IIB",
+/// );
+/// ```
+///
+/// # Finding the Keys
+///
+/// Both keys are set in the page's JavaScript: the misery key is the
+/// `CUTCAPTCHA_MISERY_KEY` variable, and the API key is the `data-apikey`
+/// attribute of the `cutcaptcha` iframe.
+#[derive(Debug, Clone)]
+pub struct Cutcaptcha {
+    /// Full URL of the page with the Cutcaptcha widget
+    pub website_url: String,
+    /// Value of `CUTCAPTCHA_MISERY_KEY`
+    pub misery_key: String,
+    /// Value of the `data-apikey` attribute
+    pub api_key: String,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Cutcaptcha {
+    /// Create a new Cutcaptcha task.
+    pub fn new(
+        website_url: impl Into<String>,
+        misery_key: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            website_url: website_url.into(),
+            misery_key: misery_key.into(),
+            api_key: api_key.into(),
+            proxy: None,
+        }
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+}
+
+/// Prosopo Procaptcha task with fluent builder pattern.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::Prosopo;
+///
+/// let task = Prosopo::new("https://example.com", "5EZVvsHMrKCFKp5NYNoTyDjTjetoVo1Z4UNNbTwJf1GfN6Xm");
+/// ```
+///
+/// # Finding the Site Key
+///
+/// The site key is the `data-sitekey` attribute of the `procaptcha` element.
+#[derive(Debug, Clone)]
+pub struct Prosopo {
+    /// Full URL of the page with the Procaptcha widget
+    pub website_url: String,
+    /// The Prosopo site key
+    pub website_key: String,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Prosopo {
+    /// Create a new Prosopo Procaptcha task.
+    pub fn new(website_url: impl Into<String>, website_key: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            website_key: website_key.into(),
+            proxy: None,
+        }
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the website key.
+    pub fn website_key(&self) -> &str {
+        &self.website_key
+    }
+}
+
+/// CaptchaFox task with fluent builder pattern.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::{CaptchaFox, ProxyConfig};
+///
+/// let task = CaptchaFox::new("https://example.com", "sk_ILKWNruBBVKDOM7dZs59KHnDLEWiH")
+///     .with_user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64)...")
+///     .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+/// ```
+///
+/// # Finding the Site Key
+///
+/// The site key is the `data-sitekey` attribute of the `captchafox` element
+/// (starts with "sk_").
+#[derive(Debug, Clone)]
+pub struct CaptchaFox {
+    /// Full URL of the page with the CaptchaFox widget
+    pub website_url: String,
+    /// The CaptchaFox site key (starts with "sk_")
+    pub website_key: String,
+    /// User agent to use (should match your actual requests)
+    pub user_agent: Option<String>,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl CaptchaFox {
+    /// Create a new CaptchaFox task.
+    pub fn new(website_url: impl Into<String>, website_key: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            website_key: website_key.into(),
+            user_agent: None,
+            proxy: None,
+        }
+    }
+
+    /// Set a custom user agent.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the website key.
+    pub fn website_key(&self) -> &str {
+        &self.website_key
+    }
+}
+
+/// Altcha task with fluent builder pattern.
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::Altcha;
+///
+/// let task = Altcha::new("https://example.com", "https://example.com/altcha/challenge");
+/// ```
+///
+/// # Finding the Site Key
+///
+/// Altcha has no public site key; use the `challengeurl` attribute of the
+/// `altcha-widget` element as the website key.
+#[derive(Debug, Clone)]
+pub struct Altcha {
+    /// Full URL of the page with the Altcha widget
+    pub website_url: String,
+    /// The Altcha challenge URL (`challengeurl` attribute)
+    pub website_key: String,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Altcha {
+    /// Create a new Altcha task.
+    pub fn new(website_url: impl Into<String>, website_key: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            website_key: website_key.into(),
+            proxy: None,
+        }
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the website key.
+    pub fn website_key(&self) -> &str {
+        &self.website_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mtcaptcha_with_proxy() {
+        let task = MtCaptcha::new("https://example.com", "MTPublic-key")
+            .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+        assert_eq!(task.website_url(), "https://example.com");
+        assert_eq!(task.website_key(), "MTPublic-key");
+        assert!(task.has_proxy());
+    }
+
+    #[test]
+    fn test_friendly_captcha_new() {
+        let task = FriendlyCaptcha::new("https://example.com", "site-key");
+        assert_eq!(task.website_key(), "site-key");
+        assert!(!task.has_proxy());
+    }
+
+    #[test]
+    fn test_cutcaptcha_new() {
+        let task = Cutcaptcha::new("https://example.com", "misery", "api");
+        assert_eq!(task.website_url(), "https://example.com");
+        assert_eq!(task.misery_key, "misery");
+        assert_eq!(task.api_key, "api");
+        assert!(!task.has_proxy());
+    }
+
+    #[test]
+    fn test_prosopo_new() {
+        let task = Prosopo::new("https://example.com", "5EZVvs");
+        assert_eq!(task.website_url(), "https://example.com");
+        assert_eq!(task.website_key(), "5EZVvs");
+        assert!(!task.has_proxy());
+    }
+
+    #[test]
+    fn test_captcha_fox_with_options() {
+        let task = CaptchaFox::new("https://example.com", "sk_key")
+            .with_user_agent("Mozilla/5.0")
+            .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+        assert_eq!(task.website_key(), "sk_key");
+        assert_eq!(task.user_agent.as_deref(), Some("Mozilla/5.0"));
+        assert!(task.has_proxy());
+    }
+
+    #[test]
+    fn test_altcha_new() {
+        let task = Altcha::new("https://example.com", "https://example.com/challenge");
+        assert_eq!(task.website_key(), "https://example.com/challenge");
+        assert!(!task.has_proxy());
+    }
+}
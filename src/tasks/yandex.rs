@@ -0,0 +1,79 @@
+//! Yandex SmartCaptcha task type with builder pattern.
+//!
+//! This module provides a provider-agnostic Yandex SmartCaptcha task definition
+//! that can be converted to provider-specific formats. Unsupported providers are rejected via `TryFrom`.
+
+use crate::utils::proxy::ProxyConfig;
+
+/// Yandex SmartCaptcha task with fluent builder pattern.
+///
+/// The solution is a single token, returned as a [`TokenSolution`](crate::TokenSolution).
+///
+/// # Examples
+///
+/// ```
+/// use captcha_solvers::{ProxyConfig, YandexSmartCaptcha};
+///
+/// let task = YandexSmartCaptcha::new("https://example.ru", "FEXfAbHQsToo97VidNVk3j4dC74nGW1DgdxjtNB9")
+///     .with_proxy(ProxyConfig::http("1.2.3.4", 8080));
+/// assert!(task.has_proxy());
+/// ```
+///
+/// # Finding the Site Key
+///
+/// The site key is the `data-sitekey` attribute of the `smart-captcha` element
+/// or the `sitekey` parameter of `window.smartCaptcha.render()`.
+#[derive(Debug, Clone)]
+pub struct YandexSmartCaptcha {
+    /// Full URL of the page with the SmartCaptcha widget
+    pub website_url: String,
+    /// The SmartCaptcha site key
+    pub website_key: String,
+    /// Proxy configuration (optional)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl YandexSmartCaptcha {
+    /// Create a new Yandex SmartCaptcha task.
+    pub fn new(website_url: impl Into<String>, website_key: impl Into<String>) -> Self {
+        Self {
+            website_url: website_url.into(),
+            website_key: website_key.into(),
+            proxy: None,
+        }
+    }
+
+    /// Set the proxy configuration.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Check if this task has a proxy configured.
+    pub fn has_proxy(&self) -> bool {
+        self.proxy.is_some()
+    }
+
+    /// Get the website URL.
+    pub fn website_url(&self) -> &str {
+        &self.website_url
+    }
+
+    /// Get the website key.
+    pub fn website_key(&self) -> &str {
+        &self.website_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yandex_smart_captcha_new() {
+        let task = YandexSmartCaptcha::new("https://example.ru", "site-key");
+        assert_eq!(task.website_url(), "https://example.ru");
+        assert_eq!(task.website_key(), "site-key");
+        assert!(!task.has_proxy());
+    }
+}
@@ -2,8 +2,16 @@
 //!
 //! This module contains utility types and helpers used across the library.
 
+#[cfg(feature = "tracing")]
 pub(crate) mod error_chain;
 pub mod proxy;
+#[cfg(any(
+    feature = "capsolver",
+    feature = "capmonster",
+    feature = "rucaptcha",
+    feature = "anticaptcha",
+    feature = "generic"
+))]
 pub mod response;
 pub mod retry;
 pub mod serde_helpers;
@@ -3,8 +3,10 @@
 //! This module provides a unified proxy configuration that can be used
 //! with any provider that supports proxy-based captcha solving.
 
+use serde::de::{self, Deserializer, MapAccess, Visitor};
 use serde::{Deserialize, Serialize, Serializer};
 use std::fmt;
+use std::str::FromStr;
 
 /// Proxy type for tasks requiring custom proxy
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -75,6 +77,16 @@ pub struct RucaptchaProxyFields {
     pub proxy_password: Option<String>,
 }
 
+/// Proxy fields for form-encoded `in.php` requests (legacy 2Captcha format)
+///
+/// The proxy is sent as `proxy=login:password@address:port` together with an
+/// upper-case `proxytype` (`HTTP`, `HTTPS`, `SOCKS4` or `SOCKS5`).
+#[derive(Clone, Serialize)]
+pub struct LegacyProxyFields {
+    pub proxy: String,
+    pub proxytype: &'static str,
+}
+
 impl fmt::Debug for ApiProxyFields {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ApiProxyFields")
@@ -111,6 +123,17 @@ impl fmt::Debug for RucaptchaProxyFields {
     }
 }
 
+impl fmt::Debug for LegacyProxyFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The proxy string embeds the credentials, so only show the host part
+        let host = self.proxy.rsplit('@').next().unwrap_or_default();
+        f.debug_struct("LegacyProxyFields")
+            .field("proxy", &host)
+            .field("proxytype", &self.proxytype)
+            .finish()
+    }
+}
+
 /// Serialize ProxyType for API providers (Capsolver/CapMonster)
 pub fn serialize_api_proxy_type<S>(proxy_type: &ProxyType, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -154,6 +177,24 @@ impl From<ProxyConfig> for RucaptchaProxyFields {
     }
 }
 
+impl From<ProxyConfig> for LegacyProxyFields {
+    fn from(config: ProxyConfig) -> Self {
+        let proxytype = match config.proxy_type {
+            ProxyType::Http => "HTTP",
+            ProxyType::Https => "HTTPS",
+            ProxyType::Socks4 => "SOCKS4",
+            ProxyType::Socks5 => "SOCKS5",
+        };
+        let proxy = match (config.login, config.password) {
+            (Some(login), Some(password)) => {
+                format!("{}:{}@{}:{}", login, password, config.address, config.port)
+            }
+            _ => format!("{}:{}", config.address, config.port),
+        };
+        Self { proxy, proxytype }
+    }
+}
+
 impl ProxyConfig {
     /// Convert to API-compatible proxy fields (Capsolver/CapMonster format)
     pub fn into_api_proxy_fields(self) -> ApiProxyFields {
@@ -164,6 +205,11 @@ impl ProxyConfig {
     pub fn into_rucaptcha_fields(self) -> RucaptchaProxyFields {
         self.into()
     }
+
+    /// Convert to legacy `in.php` proxy fields for form-encoded requests
+    pub fn into_legacy_fields(self) -> LegacyProxyFields {
+        self.into()
+    }
 }
 
 /// Proxy configuration for captcha solving tasks
@@ -184,6 +230,18 @@ impl ProxyConfig {
 /// let proxy_str = proxy.to_string_format();
 /// // Result: "socks5:proxy.example.com:1080:user:pass"
 /// ```
+///
+/// Deserializes from the same string format or from a table with `type`,
+/// `address`, `port` and optional `login`/`password` fields:
+///
+/// ```rust
+/// use captcha_solvers::ProxyConfig;
+///
+/// let proxy: ProxyConfig = serde_json::from_str(r#""http:10.0.0.1:3128""#).unwrap();
+/// let proxy: ProxyConfig =
+///     serde_json::from_str(r#"{"type": "socks5", "address": "10.0.0.1", "port": 1080}"#).unwrap();
+/// assert_eq!(proxy.port, 1080);
+/// ```
 #[derive(Clone)]
 pub struct ProxyConfig {
     pub proxy_type: ProxyType,
@@ -294,6 +352,96 @@ impl ProxyConfig {
     }
 }
 
+impl FromStr for ProxyConfig {
+    type Err = String;
+
+    /// Parse the `type:address:port[:login:password]` format
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(5, ':');
+        let (Some(kind), Some(address), Some(port)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(format!(
+                "expected 'type:address:port[:login:password]', got '{s}'"
+            ));
+        };
+
+        let proxy_type = match kind.to_ascii_lowercase().as_str() {
+            "http" => ProxyType::Http,
+            "https" => ProxyType::Https,
+            "socks4" => ProxyType::Socks4,
+            "socks5" => ProxyType::Socks5,
+            other => return Err(format!("unknown proxy type '{other}'")),
+        };
+        if address.is_empty() {
+            return Err("proxy address must not be empty".to_string());
+        }
+        let port = port
+            .parse()
+            .map_err(|_| format!("invalid proxy port '{port}'"))?;
+
+        let config = Self {
+            proxy_type,
+            address: address.to_string(),
+            port,
+            login: None,
+            password: None,
+        };
+        match (parts.next(), parts.next()) {
+            (None, _) => Ok(config),
+            (Some(login), Some(password)) => Ok(config.with_auth(login, password)),
+            (Some(_), None) => Err("proxy login given without a password".to_string()),
+        }
+    }
+}
+
+/// Table form of [`ProxyConfig`] in configuration files
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProxyFields {
+    #[serde(rename = "type", alias = "proxy_type")]
+    proxy_type: ProxyType,
+    address: String,
+    port: u16,
+    #[serde(default)]
+    login: Option<String>,
+    #[serde(default)]
+    password: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for ProxyConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProxyVisitor;
+
+        impl<'de> Visitor<'de> for ProxyVisitor {
+            type Value = ProxyConfig;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a 'type:address:port[:login:password]' string or a proxy table")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<ProxyConfig, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<ProxyConfig, A::Error> {
+                let fields = ProxyFields::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(ProxyConfig {
+                    proxy_type: fields.proxy_type,
+                    address: fields.address,
+                    port: fields.port,
+                    login: fields.login,
+                    password: fields.password,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(ProxyVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -340,4 +488,52 @@ mod tests {
         assert_eq!(ProxyConfig::socks4("a", 1).type_str(), "socks4");
         assert_eq!(ProxyConfig::socks5("a", 1).type_str(), "socks5");
     }
+
+    #[test]
+    fn test_into_legacy_fields() {
+        let fields = ProxyConfig::socks5("proxy.example.com", 1080)
+            .with_auth("user", "pass")
+            .into_legacy_fields();
+        assert_eq!(fields.proxy, "user:pass@proxy.example.com:1080");
+        assert_eq!(fields.proxytype, "SOCKS5");
+        assert!(!format!("{:?}", fields).contains("pass"));
+
+        let fields = ProxyConfig::https("192.168.1.1", 8080).into_legacy_fields();
+        assert_eq!(fields.proxy, "192.168.1.1:8080");
+        assert_eq!(fields.proxytype, "HTTPS");
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        let proxy: ProxyConfig = "socks5:proxy.example.com:1080:user:p:ss".parse().unwrap();
+        assert_eq!(proxy.proxy_type, ProxyType::Socks5);
+        assert_eq!(proxy.login.as_deref(), Some("user"));
+        assert_eq!(proxy.password.as_deref(), Some("p:ss"));
+
+        let proxy: ProxyConfig = "HTTP:192.168.1.1:8080".parse().unwrap();
+        assert_eq!(proxy.to_string_format(), "http:192.168.1.1:8080");
+
+        assert!("ftp:host:21".parse::<ProxyConfig>().is_err());
+        assert!("http:host".parse::<ProxyConfig>().is_err());
+        assert!("http:host:port".parse::<ProxyConfig>().is_err());
+        assert!("http:host:80:user".parse::<ProxyConfig>().is_err());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let proxy: ProxyConfig = serde_json::from_str(r#""https:10.0.0.1:3128""#).unwrap();
+        assert_eq!(proxy.proxy_type, ProxyType::Https);
+        assert_eq!(proxy.port, 3128);
+
+        let proxy: ProxyConfig = serde_json::from_str(
+            r#"{"type": "socks4", "address": "10.0.0.1", "port": 1080, "login": "u", "password": "p"}"#,
+        )
+        .unwrap();
+        assert_eq!(proxy.proxy_type, ProxyType::Socks4);
+        assert_eq!(proxy.password.as_deref(), Some("p"));
+
+        let err = serde_json::from_str::<ProxyConfig>(r#""http:host:99999""#).unwrap_err();
+        assert!(err.to_string().contains("invalid proxy port"));
+        assert!(serde_json::from_str::<ProxyConfig>(r#"{"address": "h", "port": 1}"#).is_err());
+    }
 }
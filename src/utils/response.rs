@@ -21,7 +21,8 @@ pub enum ApiResponse<T, E> {
     Error(E),
 }
 
-#[allow(dead_code)]
+// Providers match on the variants; these helpers serve the tests below
+#[cfg(test)]
 impl<T, E> ApiResponse<T, E> {
     /// Convert to Result for convenient use with ?
     pub fn into_result(self) -> Result<T, E> {
@@ -1,4 +1,6 @@
+use super::serde_helpers::human_duration;
 use backon::ExponentialBuilder;
+use serde::Deserialize;
 use std::time::Duration;
 
 /// Configuration for retry behavior
@@ -15,11 +17,17 @@ use std::time::Duration;
 ///     .with_factor(1.5)
 ///     .with_max_retries(5);
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Deserializes with human-readable delays (`"500ms"`, `"1m"`); missing
+/// fields keep their defaults.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct RetryConfig {
     /// Minimum delay between retries (default: 1 second)
+    #[serde(deserialize_with = "human_duration::deserialize")]
     pub min_delay: Duration,
     /// Maximum delay between retries (default: 30 seconds)
+    #[serde(deserialize_with = "human_duration::deserialize")]
     pub max_delay: Duration,
     /// Exponential backoff factor (default: 2.0)
     pub factor: f32,
@@ -3,28 +3,23 @@
 //! This module provides common serde utilities used across different
 //! captcha solving providers.
 
-use serde::{Deserialize, Deserializer, Serializer};
-
-/// Skip serializing if the value is false
-#[allow(dead_code)]
-pub fn skip_if_false(value: &bool) -> bool {
-    !*value
-}
-
-/// Skip serializing if the Option is None
-#[allow(dead_code)]
-pub fn skip_if_none<T>(value: &Option<T>) -> bool {
-    value.is_none()
-}
+use std::time::Duration;
 
 /// Deserialize a value that can be either a string or a number into a String
 ///
 /// This is useful for APIs that inconsistently return numeric IDs as either
 /// strings or numbers.
+#[cfg(any(
+    feature = "capmonster",
+    feature = "rucaptcha",
+    feature = "anticaptcha",
+    feature = "generic"
+))]
 pub fn deserialize_string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
-    D: Deserializer<'de>,
+    D: serde::Deserializer<'de>,
 {
+    use serde::Deserialize;
     use serde::de::Error;
     let value = serde_json::Value::deserialize(deserializer)?;
     match value {
@@ -38,12 +33,18 @@ where
 ///
 /// This is useful for APIs that expect numeric task IDs but our internal
 /// representation stores them as strings.
+#[cfg(any(
+    feature = "capmonster",
+    feature = "rucaptcha",
+    feature = "anticaptcha",
+    feature = "generic"
+))]
 pub fn serialize_string_as_number_if_possible<S>(
     value: &str,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
 where
-    S: Serializer,
+    S: serde::Serializer,
 {
     // Try to parse as u64 first (most common for task IDs)
     if let Ok(n) = value.parse::<u64>() {
@@ -53,84 +54,190 @@ where
     serializer.serialize_str(value)
 }
 
-/// Module for optional boolean serialization
-#[allow(dead_code)]
-pub mod optional_bool {
-    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+/// Parse a human-readable duration such as `"30s"`, `"1m 30s"` or `"500ms"`
+///
+/// Accepts one or more `<integer><unit>` parts, optionally separated by
+/// whitespace. Units: `ms`, `s`, `m`, `h` and `d`, plus the long forms
+/// `msec`, `sec(s)`, `second(s)`, `min(s)`, `minute(s)`, `hour(s)`, `day(s)`.
+pub fn parse_duration(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("empty duration".to_string());
+    }
 
-    pub fn serialize<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match value {
-            Some(v) => v.serialize(serializer),
-            None => serializer.serialize_none(),
+    let mut total = Duration::ZERO;
+    let mut rest = input;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(format!("expected a number in duration '{input}'"));
         }
+        let value: u64 = rest[..digits]
+            .parse()
+            .map_err(|_| format!("number too large in duration '{input}'"))?;
+        rest = rest[digits..].trim_start();
+
+        let unit_len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let unit = &rest[..unit_len];
+        rest = rest[unit_len..].trim_start();
+
+        let millis_per_unit: u64 = match unit {
+            "ms" | "msec" | "millis" => 1,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1_000,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60_000,
+            "h" | "hour" | "hours" => 3_600_000,
+            "d" | "day" | "days" => 86_400_000,
+            "" => return Err(format!("missing unit in duration '{input}'")),
+            other => return Err(format!("unknown unit '{other}' in duration '{input}'")),
+        };
+        total = value
+            .checked_mul(millis_per_unit)
+            .and_then(|millis| total.checked_add(Duration::from_millis(millis)))
+            .ok_or_else(|| format!("duration '{input}' is too large"))?;
     }
+    Ok(total)
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+/// Deserialize a [`Duration`] from a human-readable string or whole seconds
+///
+/// Use with `#[serde(deserialize_with = "human_duration::deserialize")]`.
+/// Strings are parsed with [`parse_duration`]; plain integers are seconds.
+pub mod human_duration {
+    use super::parse_duration;
+    use serde::de::{self, Deserializer, Visitor};
+    use std::fmt;
+    use std::time::Duration;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
     where
         D: Deserializer<'de>,
     {
-        Option::<bool>::deserialize(deserializer)
+        deserializer.deserialize_any(DurationVisitor)
+    }
+
+    struct DurationVisitor;
+
+    impl Visitor<'_> for DurationVisitor {
+        type Value = Duration;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a duration such as \"30s\" or \"1m 30s\", or whole seconds")
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Duration, E> {
+            Ok(Duration::from_secs(value))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Duration, E> {
+            u64::try_from(value)
+                .map(Duration::from_secs)
+                .map_err(|_| E::custom("duration must not be negative"))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Duration, E> {
+            parse_duration(value).map_err(E::custom)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde::{Deserialize, Serialize};
+    use serde::Deserialize;
     use serde_json::json;
 
-    #[derive(Serialize, Deserialize)]
-    struct TestStruct {
-        #[serde(deserialize_with = "deserialize_string_or_number")]
-        id: String,
-    }
+    #[cfg(any(
+        feature = "capmonster",
+        feature = "rucaptcha",
+        feature = "anticaptcha",
+        feature = "generic"
+    ))]
+    mod string_or_number {
+        use super::*;
+        use serde::Serialize;
+
+        #[derive(Serialize, Deserialize)]
+        struct TestStruct {
+            #[serde(deserialize_with = "deserialize_string_or_number")]
+            id: String,
+        }
 
-    #[test]
-    fn test_deserialize_string_or_number_with_string() {
-        let json = json!({"id": "12345"});
-        let result: TestStruct = serde_json::from_value(json).unwrap();
-        assert_eq!(result.id, "12345");
-    }
+        #[test]
+        fn test_deserialize_string_or_number_with_string() {
+            let json = json!({"id": "12345"});
+            let result: TestStruct = serde_json::from_value(json).unwrap();
+            assert_eq!(result.id, "12345");
+        }
 
-    #[test]
-    fn test_deserialize_string_or_number_with_number() {
-        let json = json!({"id": 12345});
-        let result: TestStruct = serde_json::from_value(json).unwrap();
-        assert_eq!(result.id, "12345");
-    }
+        #[test]
+        fn test_deserialize_string_or_number_with_number() {
+            let json = json!({"id": 12345});
+            let result: TestStruct = serde_json::from_value(json).unwrap();
+            assert_eq!(result.id, "12345");
+        }
 
-    #[derive(Serialize)]
-    struct TestSerialize<'a> {
-        #[serde(serialize_with = "serialize_string_as_number_if_possible")]
-        id: &'a str,
-    }
+        #[derive(Serialize)]
+        struct TestSerialize<'a> {
+            #[serde(serialize_with = "serialize_string_as_number_if_possible")]
+            id: &'a str,
+        }
 
-    #[test]
-    fn test_serialize_string_as_number_numeric() {
-        let test = TestSerialize { id: "12345" };
-        let json = serde_json::to_string(&test).unwrap();
-        assert_eq!(json, r#"{"id":12345}"#);
+        #[test]
+        fn test_serialize_string_as_number_numeric() {
+            let test = TestSerialize { id: "12345" };
+            let json = serde_json::to_string(&test).unwrap();
+            assert_eq!(json, r#"{"id":12345}"#);
+        }
+
+        #[test]
+        fn test_serialize_string_as_number_non_numeric() {
+            let test = TestSerialize { id: "abc-123" };
+            let json = serde_json::to_string(&test).unwrap();
+            assert_eq!(json, r#"{"id":"abc-123"}"#);
+        }
     }
 
     #[test]
-    fn test_serialize_string_as_number_non_numeric() {
-        let test = TestSerialize { id: "abc-123" };
-        let json = serde_json::to_string(&test).unwrap();
-        assert_eq!(json, r#"{"id":"abc-123"}"#);
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_duration("1m 30s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("2h5m").unwrap(), Duration::from_secs(7500));
+        assert_eq!(
+            parse_duration(" 3 seconds ").unwrap(),
+            Duration::from_secs(3)
+        );
+
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("30").unwrap_err().contains("missing unit"));
+        assert!(
+            parse_duration("5 fortnights")
+                .unwrap_err()
+                .contains("fortnights")
+        );
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("99999999999999999d").is_err());
     }
 
-    #[test]
-    fn test_skip_if_false() {
-        assert!(!skip_if_false(&true));
-        assert!(skip_if_false(&false));
+    #[derive(Deserialize)]
+    struct WithDuration {
+        #[serde(deserialize_with = "human_duration::deserialize")]
+        timeout: Duration,
     }
 
     #[test]
-    fn test_skip_if_none() {
-        assert!(skip_if_none(&None::<i32>));
-        assert!(!skip_if_none(&Some(42)));
+    fn test_human_duration_deserialize() {
+        let parsed: WithDuration = serde_json::from_value(json!({"timeout": "2m"})).unwrap();
+        assert_eq!(parsed.timeout, Duration::from_secs(120));
+
+        let parsed: WithDuration = serde_json::from_value(json!({"timeout": 45})).unwrap();
+        assert_eq!(parsed.timeout, Duration::from_secs(45));
+
+        assert!(serde_json::from_value::<WithDuration>(json!({"timeout": -1})).is_err());
+        assert!(serde_json::from_value::<WithDuration>(json!({"timeout": "soon"})).is_err());
     }
 }
@@ -1,10 +1,7 @@
-use crate::errors::RetryableError;
 use opentelemetry::trace::Status;
 use tracing::Span;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use super::error_chain::ErrorChain;
-
 #[inline]
 pub(crate) fn set_span_ok() {
     Span::current().set_status(Status::Ok);
@@ -19,8 +16,23 @@ pub(crate) fn set_span_error(err: &dyn std::fmt::Display) {
 ///
 /// Transient errors get a warning log and don't set error status.
 /// Permanent errors set error span status and log at error level.
+#[cfg(any(
+    feature = "capsolver",
+    feature = "capmonster",
+    feature = "rucaptcha",
+    feature = "anticaptcha",
+    feature = "legacy",
+    feature = "generic",
+    feature = "deathbycaptcha",
+    feature = "localocr"
+))]
 #[inline]
-pub(crate) fn record_error<E: std::error::Error + RetryableError>(e: &E, provider: &'static str) {
+pub(crate) fn record_error<E: std::error::Error + crate::errors::RetryableError>(
+    e: &E,
+    provider: &str,
+) {
+    use super::error_chain::ErrorChain;
+
     if e.is_retryable() {
         tracing::warn!(error = %ErrorChain(e), "{provider} transient error");
     } else {